@@ -6,18 +6,32 @@
 
 use bevy::math::Vec2;
 use bevy::prelude::Component;
-use rand::{thread_rng, Rng};
+use rand::Rng;
+
+use crate::physical_interactions::rally_physics::RallyCurve;
 
 // Ball movement control levers
 const BALL_DIRECTION_VARIABILITY: f32 = 0.19;
 const GRAVITY: f32 = 1.015;
 const LATERAL_DIRECTION_REDUCTION_FACTOR: f32 = 1.04;
+/// The furthest, in radians, that a Ball split from another may head away from straight up or down,
+/// so that it does not end up bouncing from SideWall to SideWall.
+const MAX_SPLIT_ANGLE_FROM_VERTICAL: f32 = 1.05;
+/// The time, in seconds, for which a sticky Paddle holds the Ball before releasing it.
+const STICKY_HOLD_SECS: f32 = 0.6;
 
 /// Marker for Ball entities.
 #[derive(Component)]
 pub(crate) struct BallComponent {
     /// Specifies the Ball's current direction.
     direction: Vec2,
+    /// The number of times that the Paddles have returned the Ball since it was served.
+    rally_hits: u32,
+    /// The Ball's speed, in pixels per second.
+    speed: f32,
+    /// While a sticky Paddle holds the Ball: the Ball's horizontal offset from the Paddle's center
+    /// and the time, in seconds, left before the Ball is released.
+    stuck: Option<(f32, f32)>,
 }
 
 /// Public contract
@@ -27,24 +41,87 @@ impl BallComponent {
         self.direction
     }
 
-    /// Creates a new BallComponent instance.
-    pub(crate) fn new(direction: Vec2) -> Self {
-        Self { direction }
+    /// Returns the ball's current speed, in pixels per second.
+    pub(crate) fn get_speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Returns the Ball's horizontal offset from the center of the sticky Paddle holding it, or
+    /// None if the Ball is in flight.
+    pub(crate) fn get_stuck_offset(&self) -> Option<f32> {
+        self.stuck.map(|(offset_x, _)| offset_x)
+    }
+
+    /// Counts down the time for which a sticky Paddle holds the Ball by the specified seconds,
+    /// releasing the Ball, in the direction in which the Paddle returned it, once the time is up.
+    pub(crate) fn hold(&mut self, delta_secs: f32) {
+        if let Some((offset_x, secs_left)) = self.stuck {
+            self.stuck = match secs_left > delta_secs {
+                true => Some((offset_x, secs_left - delta_secs)),
+                false => None,
+            };
+        }
+    }
+
+    /// Creates a new BallComponent instance for a newly served Ball.
+    pub(crate) fn new(direction: Vec2, speed: f32) -> Self {
+        Self {
+            direction,
+            rally_hits: 0,
+            speed,
+            stuck: None,
+        }
+    }
+
+    /// Sends the Ball in the direction in which a Paddle returned it and speeds it up according to
+    /// the specified RallyCurve. The direction is not varied further since it already depends on
+    /// how the Paddle struck the Ball. See RallyCurve::deflect().
+    pub(crate) fn return_from_paddle(&mut self, new_direction: Vec2, rally_curve: &RallyCurve) {
+        self.direction = new_direction.normalize();
+        self.rally_hits += 1;
+        self.speed = rally_curve.speed_after_hits(self.rally_hits);
     }
 
     /// Modifies and finalizes the Ball's direction, including introducing gravity and a
-    /// level of variability. Use this method to set the Ball's direction instead of setting it
-    /// directly.
-    pub(crate) fn set_direction(&mut self, new_direction: Vec2) {
+    /// level of variability drawn from the specified random number generator. Use this method to
+    /// set the Ball's direction instead of setting it directly.
+    pub(crate) fn set_direction(&mut self, new_direction: Vec2, rng: &mut impl Rng) {
         //
 
         let mut new_direction = new_direction;
 
         // Make the game a little more fun by providing some realism in the form of variability.
-        self.randomize_ball_direction(&mut new_direction);
+        Self::randomize_ball_direction(&mut new_direction, rng);
         Self::add_gravity(&mut new_direction);
         self.direction = new_direction.normalize();
     }
+
+    /// Returns a new Ball, in flight, that goes as fast as this one but in a direction turned by
+    /// the specified angle, in radians, while still heading up or down as steeply as it must. See
+    /// the MultiBall power-up.
+    pub(crate) fn split(&self, angle: f32) -> Self {
+        //
+
+        let turned = Vec2::from_angle(angle).rotate(self.direction);
+        let vertical_direction = if self.direction.y < 0_f32 { -1_f32 } else { 1_f32 };
+        let angle_from_vertical = turned
+            .x
+            .atan2(turned.y * vertical_direction)
+            .clamp(-MAX_SPLIT_ANGLE_FROM_VERTICAL, MAX_SPLIT_ANGLE_FROM_VERTICAL);
+
+        Self {
+            direction: Vec2::new(angle_from_vertical.sin(), vertical_direction * angle_from_vertical.cos()),
+            rally_hits: self.rally_hits,
+            speed: self.speed,
+            stuck: None,
+        }
+    }
+
+    /// Has a sticky Paddle hold the Ball, at the specified horizontal offset from the Paddle's
+    /// center, for a moment before releasing it.
+    pub(crate) fn stick_to_paddle(&mut self, offset_x: f32) {
+        self.stuck = Some((offset_x, STICKY_HOLD_SECS));
+    }
 }
 
 /// Helper functions
@@ -58,8 +135,8 @@ impl BallComponent {
     }
 
     /// Provides a bit of randomness to the Ball's direction.
-    fn randomize_ball_direction(&mut self, ball_direction: &mut Vec2) {
-        let direction_variability = thread_rng().gen_range(-BALL_DIRECTION_VARIABILITY..=BALL_DIRECTION_VARIABILITY);
+    fn randomize_ball_direction(ball_direction: &mut Vec2, rng: &mut impl Rng) {
+        let direction_variability = rng.gen_range(-BALL_DIRECTION_VARIABILITY..=BALL_DIRECTION_VARIABILITY);
         ball_direction.x += direction_variability;
         ball_direction.y += direction_variability;
     }
@@ -4,37 +4,49 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use bevy::app::{App, Plugin, Startup};
+use bevy::app::{App, FixedUpdate, Plugin};
 use bevy::asset::AssetServer;
 use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{
-    in_state, Commands, IntoSystemConfigs, Query, Res, SpriteBundle, Time, Transform, Update,
-    Window, With,
+    in_state, Commands, DespawnRecursiveExt, Entity, IntoSystemConfigs, OnEnter, Query, Res,
+    ResMut, SpriteBundle, Time, Transform, Window, With, Without,
 };
 use bevy::window::PrimaryWindow;
-use rand::random;
+use rand::Rng;
 
 use crate::ball::ball_component::BallComponent;
-use crate::game_controller::GamePlayState;
-use crate::physical_interactions::DIRECTION_BACKWARD;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{GameMode, GamePlayState, Player};
+use crate::paddle::paddle_component::PaddleComponent;
+use crate::paddle::paddle_plugin::PADDLE_HEIGHT;
+use crate::physical_interactions::{DIRECTION_BACKWARD, DIRECTION_FORWARD};
+use crate::power_ups::power_ups_resource::PowerUpsResource;
+use crate::round::round_resource::RoundResource;
+use crate::simulation::simulation_rng_resource::SimulationRngResource;
+use crate::simulation::SimulationSet;
 
 const BALL_DIAMETER: f32 = 64_f32;
 pub(crate) const BALL_RADIUS: f32 = 32_f32;
 const BALL_SPAWN_LATERAL_RANDOMNESS_FACTOR: f32 = 3_f32;
-const BALL_SPEED: f32 = 700_f32;
 const BALL_SPRITE: &str = "sprites/ball_blue_large.png";
 const BALL_Z_INDEX: f32 = 1_f32;
 
-/// Handles Ball presentation and movement.
+/// Handles Ball presentation and movement. A new Ball is spawned for each Serve and the Balls are
+/// removed once a point has been scored. The MultiBall power-up can put more than one Ball in play
+/// at a time.
 pub(crate) struct BallPlugin;
 
 impl Plugin for BallPlugin {
     fn build(&self, app: &mut App) {
         app //
-            .add_systems(Startup, Self::spawn_ball)
+            .add_systems(OnEnter(GamePlayState::Serve), Self::spawn_ball)
+            .add_systems(OnEnter(GamePlayState::PointScored), Self::despawn_ball)
+            .add_systems(OnEnter(GamePlayState::StartMenu), Self::despawn_ball)
             .add_systems(
-                Update,
-                Self::move_ball.run_if(in_state(GamePlayState::Playing)),
+                FixedUpdate,
+                Self::move_ball
+                    .in_set(SimulationSet::Ball)
+                    .run_if(in_state(GamePlayState::Rally)),
             );
     }
 }
@@ -42,45 +54,124 @@ impl Plugin for BallPlugin {
 impl BallPlugin {
     //
 
-    /// Implements frame-by-frame movement of the Ball along the direction specified in the
-    /// BallComponent's get_direction() function. See BallComponent.
-    fn move_ball(mut ball_query: Query<(&mut Transform, &BallComponent)>, time: Res<Time>) {
-        if let Ok((mut transform, ball)) = ball_query.get_single_mut() {
-            let translation = ball.get_direction() * BALL_SPEED * time.delta_seconds();
+    /// Returns the sprite of a Ball at the specified point.
+    pub(crate) fn ball_sprite_bundle(asset_server: &AssetServer, translation: Vec3) -> SpriteBundle {
+        SpriteBundle {
+            transform: Transform::from_translation(translation),
+            texture: asset_server.load(BALL_SPRITE),
+            ..Default::default()
+        }
+    }
+
+    /// Removes the Balls once a point has been scored or the Game has ended.
+    fn despawn_ball(ball_query: Query<Entity, With<BallComponent>>, mut commands: Commands) {
+        for ball in &ball_query {
+            commands.entity(ball).despawn_recursive();
+        }
+    }
+
+    /// Implements tick-by-tick movement of each Ball along the direction specified in the
+    /// BallComponent's get_direction() function, slowed by the SlowBall power-up. A Ball held by a
+    /// sticky Paddle is carried along on top of Player One's Paddle instead. See BallComponent.
+    fn move_ball(
+        mut ball_query: Query<(&mut Transform, &mut BallComponent)>,
+        paddle_query: Query<(&Transform, &PaddleComponent), Without<BallComponent>>,
+        power_ups: Res<PowerUpsResource>,
+        time: Res<Time>,
+    ) {
+        //
+
+        let paddle_transform = paddle_query
+            .iter()
+            .find(|(_, paddle)| paddle.get_player() == Player::One)
+            .map(|(transform, _)| transform);
+
+        for (mut transform, mut ball) in &mut ball_query {
+            //
+
+            if let (Some(offset_x), Some(paddle_transform)) = (ball.get_stuck_offset(), paddle_transform) {
+                transform.translation.x = paddle_transform.translation.x + offset_x;
+                transform.translation.y = paddle_transform.translation.y + PADDLE_HEIGHT / 2_f32 + BALL_RADIUS;
+                ball.hold(time.delta_seconds());
+                continue;
+            }
+
+            let speed = ball.get_speed() * power_ups.get_ball_speed_factor();
+            let translation = ball.get_direction() * speed * time.delta_seconds();
             transform.translation += translation.extend(0_f32);
         }
     }
 
-    /// Spawns the Ball and sets its initial direction.
+    /// Returns a random direction, generally toward the specified Player's Paddle.
+    fn serve_direction(receiving_player: Player, rng: &mut impl Rng) -> Vec2 {
+        //
+
+        let vertical_direction = match receiving_player {
+            Player::One => DIRECTION_BACKWARD,
+            Player::Two => DIRECTION_FORWARD,
+        };
+
+        // Keep the Ball from being served almost sideways.
+        let initial_x_direction = rng.gen::<f32>() / BALL_SPAWN_LATERAL_RANDOMNESS_FACTOR;
+        let initial_y_direction = vertical_direction * (0.5 + rng.gen::<f32>() / 2.0);
+        Vec2::new(initial_x_direction, initial_y_direction).normalize()
+    }
+
+    /// Returns the point from which the Ball is served: the top-middle of the window in a Solo
+    /// Game and, so as to be clear of Player Two's Paddle or of the Bricks, the center in a Versus
+    /// or Levels Game.
+    fn serve_point(window: &Window, game_mode: GameMode) -> Vec3 {
+        match game_mode {
+            GameMode::Solo => Vec3::new(window.width() / 2.0, window.height() - BALL_DIAMETER, BALL_Z_INDEX),
+            GameMode::Versus | GameMode::Levels => {
+                Vec3::new(window.width() / 2.0, window.height() / 2.0, BALL_Z_INDEX)
+            }
+        }
+    }
+
+    /// Spawns the Ball for a Serve and sets its initial direction.
     fn spawn_ball(
         asset_server: Res<AssetServer>,
+        ball_query: Query<Entity, With<BallComponent>>,
         mut commands: Commands,
+        game_settings: Res<GameSettingsResource>,
+        mut rng: ResMut<SimulationRngResource>,
+        round_resource: Res<RoundResource>,
         window_query: Query<&Window, With<PrimaryWindow>>,
     ) {
         //
 
+        // Early return. Resuming a paused Serve re-enters the state with the Ball already waiting.
+        if !ball_query.is_empty() {
+            return;
+        }
+
         if let Ok(window) = window_query.get_single() {
             //
 
-            // Start from the top-middle of the view
-            let start_point: Vec3 = Vec3::new(
-                window.width() / 2.0,
-                window.height() - BALL_DIAMETER,
-                BALL_Z_INDEX,
-            );
+            let start_point = Self::serve_point(window, game_settings.game_mode);
+            let sprite_bundle = Self::ball_sprite_bundle(&asset_server, start_point);
 
-            let sprite_bundle = SpriteBundle {
-                transform: Transform::from_translation(start_point),
-                texture: asset_server.load(BALL_SPRITE),
-                ..Default::default()
+            // Aim the Ball in the general direction of the Paddle or, in a Versus Game, of the
+            // Player who conceded the last point, choosing either Player for the first Serve.
+            let initial_direction = match game_settings.game_mode {
+                GameMode::Solo | GameMode::Levels => {
+                    let initial_x_direction = rng.gen::<f32>() / BALL_SPAWN_LATERAL_RANDOMNESS_FACTOR;
+                    let initial_y_direction = DIRECTION_BACKWARD * rng.gen::<f32>();
+                    Vec2::new(initial_x_direction, initial_y_direction).normalize()
+                }
+                GameMode::Versus => match round_resource.receiving_player {
+                    Some(receiving_player) => Self::serve_direction(receiving_player, &mut *rng),
+                    None => match rng.gen::<bool>() {
+                        true => Self::serve_direction(Player::One, &mut *rng),
+                        false => Self::serve_direction(Player::Two, &mut *rng),
+                    },
+                },
             };
 
-            // Aim the Ball in the general direction of the Paddle
-            let initial_x_direction = random::<f32>() / BALL_SPAWN_LATERAL_RANDOMNESS_FACTOR;
-            let initial_y_direction = DIRECTION_BACKWARD * random::<f32>();
-            let initial_direction = Vec2::new(initial_x_direction, initial_y_direction).normalize();
+            let initial_speed = game_settings.pace.curve().initial_speed;
 
-            commands.spawn((sprite_bundle, BallComponent::new(initial_direction)));
+            commands.spawn((sprite_bundle, BallComponent::new(initial_direction, initial_speed)));
         }
     }
 }
@@ -0,0 +1,40 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::math::Vec2;
+use bevy::prelude::{Component, Timer, TimerMode};
+
+use crate::computer_opponent::Difficulty;
+
+/// Marks a Paddle as moved by the computer rather than by a Player's keys.
+#[derive(Component)]
+pub(crate) struct ComputerOpponentComponent {
+    /// How far from the predicted intercept the computer aims, re-chosen whenever the Ball changes
+    /// direction.
+    pub(super) aim_offset: f32,
+    pub(super) difficulty: Difficulty,
+    /// The Ball direction last seen, used to notice when the Ball changes direction.
+    pub(super) last_ball_direction: Vec2,
+    /// Delays the computer's response to each change in the Ball's direction.
+    pub(super) reaction_timer: Timer,
+    /// Where the computer is moving its Paddle, or None to wait at the center.
+    pub(super) target_x: Option<f32>,
+}
+
+impl ComputerOpponentComponent {
+    //
+
+    /// Creates a new ComputerOpponentComponent instance.
+    pub(crate) fn new(difficulty: Difficulty) -> Self {
+        Self {
+            aim_offset: 0_f32,
+            difficulty,
+            last_ball_direction: Vec2::ZERO,
+            reaction_timer: Timer::from_seconds(difficulty.reaction_delay_secs(), TimerMode::Once),
+            target_x: None,
+        }
+    }
+}
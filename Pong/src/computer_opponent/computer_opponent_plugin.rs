@@ -0,0 +1,125 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::app::{App, FixedUpdate, Plugin};
+use bevy::math::Vec2;
+use bevy::prelude::{IntoSystemConfigs, Query, Res, ResMut, Time, Transform, Window, With, Without};
+use bevy::window::PrimaryWindow;
+use rand::Rng;
+
+use crate::ball::ball_component::BallComponent;
+use crate::ball::ball_plugin::BALL_RADIUS;
+use crate::computer_opponent::computer_opponent_component::ComputerOpponentComponent;
+use crate::computer_opponent::intercept_predictor::InterceptPredictor;
+use crate::game_controller::game_in_progress;
+use crate::paddle::paddle_component::PaddleComponent;
+use crate::paddle::paddle_plugin::{PaddlePlugin, PADDLE_HEIGHT};
+use crate::simulation::simulation_rng_resource::SimulationRngResource;
+use crate::simulation::SimulationSet;
+
+/// Moves the Paddles marked with a ComputerOpponentComponent. The computer predicts where the Ball
+/// will reach its Paddle and moves there, within the limits of its Difficulty: it reacts to each
+/// change in the Ball's direction after a delay, its Paddle has a top speed and its aim is off by
+/// a random amount. When more than one Ball is in play, the computer follows the one that will
+/// reach its Paddle first.
+pub(crate) struct ComputerOpponentPlugin;
+
+impl Plugin for ComputerOpponentPlugin {
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            Self::move_computer_paddle
+                .in_set(SimulationSet::Paddles)
+                .run_if(game_in_progress),
+        );
+    }
+}
+
+impl ComputerOpponentPlugin {
+    //
+
+    /// Implements the side-to-side movement of the computer's Paddle.
+    fn move_computer_paddle(
+        ball_query: Query<(&Transform, &BallComponent)>,
+        mut paddle_query: Query<
+            (&mut Transform, &mut PaddleComponent, &mut ComputerOpponentComponent),
+            Without<BallComponent>,
+        >,
+        mut rng: ResMut<SimulationRngResource>,
+        time: Res<Time>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        //
+
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        let predictor = InterceptPredictor::new(window.width(), BALL_RADIUS);
+
+        for (mut paddle_transform, mut paddle, mut opponent) in &mut paddle_query {
+            //
+
+            // Follow the nearest Ball heading toward the Paddle or, if none is, the nearest Ball.
+            let tracked_ball = ball_query
+                .iter()
+                .map(|(transform, ball)| {
+                    let distance_y = transform.translation.y - paddle_transform.translation.y;
+                    let is_approaching = ball.get_direction().y * distance_y < 0_f32;
+                    let distance = match is_approaching {
+                        true => distance_y.abs(),
+                        false => distance_y.abs() + window.height(),
+                    };
+                    (distance, transform, ball)
+                })
+                .min_by(|(distance, _, _), (other_distance, _, _)| distance.total_cmp(other_distance));
+
+            let Some((_, ball_transform, ball)) = tracked_ball else {
+                continue;
+            };
+
+            let ball_position = Vec2::new(ball_transform.translation.x, ball_transform.translation.y);
+            let ball_direction = ball.get_direction();
+
+            // Has the Ball been hit or bounced? If so, take a moment to react and re-choose how far
+            // off the aim will be.
+            if ball_direction != opponent.last_ball_direction {
+                let max_prediction_error = opponent.difficulty.max_prediction_error();
+                opponent.last_ball_direction = ball_direction;
+                opponent.aim_offset = rng.gen_range(-max_prediction_error..=max_prediction_error);
+                opponent.reaction_timer.reset();
+            }
+
+            opponent.reaction_timer.tick(time.delta());
+
+            if opponent.reaction_timer.just_finished() {
+                // The Ball's center touches the Paddle half a Paddle and a Ball radius away from
+                // the Paddle's center.
+                let contact_y = paddle_transform.translation.y
+                    - ball_direction.y.signum() * (PADDLE_HEIGHT / 2_f32 + BALL_RADIUS);
+                opponent.target_x = predictor
+                    .predict(ball_position, ball_direction, contact_y)
+                    .map(|intercept_x| intercept_x + opponent.aim_offset);
+            }
+
+            // While the Ball is heading away, wait at the center.
+            let target_x = opponent.target_x.unwrap_or(window.width() / 2_f32);
+
+            let max_step = opponent.difficulty.max_paddle_speed() * time.delta_seconds();
+            let step = (target_x - paddle_transform.translation.x).clamp(-max_step, max_step);
+
+            let left_edge = paddle.get_width() / 2_f32;
+            let right_edge = window.width() - paddle.get_width() / 2_f32;
+
+            // Constrain the Paddle to the window's viewport.
+            let new_position_x = (paddle_transform.translation.x + step).clamp(left_edge, right_edge);
+
+            paddle.set_velocity(PaddlePlugin::velocity(paddle_transform.translation.x, new_position_x, time.delta_seconds()));
+            paddle_transform.translation.x = new_position_x;
+        }
+    }
+}
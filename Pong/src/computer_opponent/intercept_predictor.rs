@@ -0,0 +1,68 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::math::Vec2;
+
+/// Predicts where the Ball will cross a Paddle's contact line, following the Ball's reflections
+/// off the SideWalls in the same way as PhysicalInteractionsPlugin::ball_and_wall_interaction().
+///
+/// The prediction does not account for the variability and gravity that BallComponent adds at each
+/// bounce. So, it becomes less accurate the more often the Ball is expected to bounce.
+pub(crate) struct InterceptPredictor {
+    /// The furthest left that the Ball's center can travel.
+    min_x: f32,
+    /// The furthest right that the Ball's center can travel.
+    max_x: f32,
+}
+
+impl InterceptPredictor {
+    //
+
+    /// Creates a new InterceptPredictor instance for a window of the specified width.
+    pub(crate) fn new(window_width: f32, ball_radius: f32) -> Self {
+        Self {
+            min_x: ball_radius,
+            max_x: (window_width - ball_radius).max(ball_radius),
+        }
+    }
+
+    /// Returns the x position at which the Ball, traveling from the specified position in the
+    /// specified direction, will reach the contact line at contact_y. Returns None if the Ball is
+    /// traveling away from, or parallel to, the line.
+    pub(crate) fn predict(&self, ball_position: Vec2, ball_direction: Vec2, contact_y: f32) -> Option<f32> {
+        //
+
+        let distance_y = contact_y - ball_position.y;
+
+        // Early return.
+        if ball_direction.y == 0_f32 || distance_y.signum() != ball_direction.y.signum() {
+            return None;
+        }
+
+        let unfolded_x = ball_position.x + ball_direction.x * (distance_y / ball_direction.y);
+
+        Some(self.fold(unfolded_x))
+    }
+
+    /// Maps a position on the unobstructed path of the Ball back into the room, reflecting it off
+    /// the SideWalls as many times as needed.
+    fn fold(&self, unfolded_x: f32) -> f32 {
+        //
+
+        let span = self.max_x - self.min_x;
+
+        // Early return.
+        if span <= 0_f32 {
+            return self.min_x;
+        }
+
+        let offset = (unfolded_x - self.min_x).rem_euclid(2_f32 * span);
+        match offset <= span {
+            true => self.min_x + offset,
+            false => self.max_x - (offset - span),
+        }
+    }
+}
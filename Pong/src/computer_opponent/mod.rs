@@ -0,0 +1,69 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use serde::{Deserialize, Serialize};
+
+pub(crate) mod computer_opponent_component;
+pub(super) mod computer_opponent_plugin;
+pub(crate) mod intercept_predictor;
+
+/// Specifies how well the computer plays.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    //
+
+    /// Returns the next harder Difficulty, wrapping around from the hardest to the easiest.
+    pub(crate) fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    /// Returns the name shown for the Difficulty.
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Returns the seconds the computer takes to notice that the Ball has changed direction.
+    pub(super) fn reaction_delay_secs(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.35,
+            Difficulty::Normal => 0.2,
+            Difficulty::Hard => 0.08,
+        }
+    }
+
+    /// Returns the fastest that the computer moves its Paddle, in pixels per second.
+    pub(super) fn max_paddle_speed(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 350.0,
+            Difficulty::Normal => 500.0,
+            Difficulty::Hard => 700.0,
+        }
+    }
+
+    /// Returns the furthest, in pixels, that the computer's aim may be from the predicted intercept.
+    pub(super) fn max_prediction_error(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 140.0,
+            Difficulty::Normal => 70.0,
+            Difficulty::Hard => 15.0,
+        }
+    }
+}
@@ -0,0 +1,366 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::sync::LazyLock;
+
+use bevy::app::{App, FixedUpdate, Plugin, Update};
+use bevy::prelude::{in_state, Color, Component, IntoSystemConfigs, OnEnter, OnExit};
+
+use crate::controls::controls_resource::ControlsResource;
+use crate::controls::pending_rebind_resource::PendingRebindResource;
+use crate::controls::{ControlsView, Side};
+use crate::game_controller::{game_in_progress, Player};
+use crate::replay::PlaybackState;
+use crate::simulation::SimulationSet;
+
+const MENU_FONT_SIZE: f32 = 30.0;
+const TITLE_FONT_SIZE: f32 = 60.0;
+
+static OVERLAY_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("4d1a8cf0").unwrap() });
+
+#[derive(Clone, PartialEq)]
+/// Defines the purposes of the Controls screen buttons.
+enum ButtonPurpose {
+    ChangeInputSource(Player),
+    CloseControls,
+    RebindKey(Player, Side),
+    ResetControls,
+}
+
+/// Marker to indicate that an entity was spawned on the Controls screen.
+#[derive(Component)]
+struct OnControlsScreen;
+
+/// Companion component for the text of a Controls screen button, allowing the text to show the
+/// newly chosen control.
+#[derive(Component)]
+struct ButtonLabelComponent(ButtonPurpose);
+
+/// Provides the UI and functionality for the Controls screen, shown over the Start Menu, on which
+/// each Player chooses their input device and rebinds their keys. The controls are saved as soon
+/// as they are changed. During a Game, each Player's input is read once per simulation tick.
+pub(crate) struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .insert_resource(ControlsResource::load())
+            .init_resource::<PendingRebindResource>()
+            .insert_state(ControlsView::Hidden)
+            .add_systems(
+                FixedUpdate,
+                functionality::read_paddle_input
+                    .in_set(SimulationSet::Input)
+                    .run_if(in_state(PlaybackState::Off))
+                    .run_if(game_in_progress),
+            )
+            .add_systems(OnEnter(ControlsView::Shown), ui::spawn_controls_screen)
+            .add_systems(
+                Update,
+                (
+                    functionality::button_interaction,
+                    functionality::capture_key,
+                    ui::update_button_labels,
+                )
+                    .chain()
+                    .run_if(in_state(ControlsView::Shown)),
+            )
+            .add_systems(
+                OnExit(ControlsView::Shown),
+                (functionality::cancel_rebind, ui::despawn_controls_screen),
+            );
+    }
+}
+
+mod functionality {
+    use bevy::log::error;
+    use bevy::prelude::{
+        BackgroundColor, Button, ButtonInput, Changed, Interaction, KeyCode, NextState, Query, Res,
+        ResMut, Window, With,
+    };
+    use bevy::window::PrimaryWindow;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::controls::controls_plugin::ButtonPurpose;
+    use crate::controls::controls_resource::ControlsResource;
+    use crate::controls::pending_rebind_resource::PendingRebindResource;
+    use crate::controls::paddle_input::PaddleInput;
+    use crate::controls::ControlsView;
+    use crate::game_controller::Player;
+    use crate::menu_theme::button_color;
+    use crate::paddle::paddle_intents_resource::PaddleIntentsResource;
+
+    /// Provides button functionality, including state changes as well as response when clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn button_interaction(
+        mut controls: ResMut<ControlsResource>,
+        mut interactions: Query<
+            (
+                &Interaction,
+                &mut BackgroundColor,
+                &EntityInfoComponent<ButtonPurpose>,
+            ),
+            (Changed<Interaction>, With<Button>),
+        >,
+        mut next_controls_view: ResMut<NextState<ControlsView>>,
+        mut pending_rebind: ResMut<PendingRebindResource>,
+    ) {
+        for (interaction, mut color, button_info) in &mut interactions {
+            *color = button_color(*interaction);
+
+            // Only a press acts on the button.
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+
+            // Which button was pressed?
+            match button_info.get_purpose() {
+                ButtonPurpose::ChangeInputSource(player) => {
+                    let input_source = controls.get(player).input_source.next();
+                    controls.set_input_source(player, input_source);
+                    save_controls(&controls);
+                }
+                ButtonPurpose::CloseControls => next_controls_view.set(ControlsView::Hidden),
+                ButtonPurpose::RebindKey(player, side) => {
+                    // Clicking the waiting binding again leaves it as it was.
+                    pending_rebind.binding = match pending_rebind.binding {
+                        Some(binding) if binding == (player, side) => None,
+                        _ => Some((player, side)),
+                    };
+                }
+                ButtonPurpose::ResetControls => {
+                    *controls = ControlsResource::default();
+                    pending_rebind.binding = None;
+                    save_controls(&controls);
+                }
+            }
+        }
+    }
+
+    /// Forgets the key binding waiting for a key press when the Controls screen is closed.
+    pub(super) fn cancel_rebind(mut pending_rebind: ResMut<PendingRebindResource>) {
+        pending_rebind.binding = None;
+    }
+
+    /// Binds the next key pressed to the key binding waiting for it, if any.
+    pub(super) fn capture_key(
+        mut controls: ResMut<ControlsResource>,
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut pending_rebind: ResMut<PendingRebindResource>,
+    ) {
+        //
+
+        let Some((player, side)) = pending_rebind.binding else {
+            return;
+        };
+
+        if let Some(key) = keyboard_input.get_just_pressed().next() {
+            controls.bind_key(player, side, *key);
+            pending_rebind.binding = None;
+            save_controls(&controls);
+        }
+    }
+
+    /// Translates each Player's input into what they ask of their Paddle during this tick.
+    pub(super) fn read_paddle_input(
+        paddle_input: PaddleInput,
+        mut paddle_intents: ResMut<PaddleIntentsResource>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        //
+
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        for player in [Player::One, Player::Two] {
+            paddle_intents.set(player, paddle_input.intent(player, window));
+        }
+    }
+
+    fn save_controls(controls: &ControlsResource) {
+        if let Err(error) = controls.save() {
+            error!("Unable to save the controls: {}", error);
+        }
+    }
+}
+
+mod ui {
+    use bevy::prelude::{
+        default, AlignItems, BackgroundColor, BuildChildren, ButtonBundle, Commands,
+        DespawnRecursiveExt, DetectChanges, Entity, FlexDirection, JustifyContent, NodeBundle,
+        Query, Res, Style, Text, TextBundle, TextStyle, UiRect, Val, With, ZIndex,
+    };
+    use bevy::ui::FocusPolicy;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::controls::controls_plugin::{
+        ButtonLabelComponent, ButtonPurpose, OnControlsScreen, MENU_FONT_SIZE, OVERLAY_COLOR,
+        TITLE_FONT_SIZE,
+    };
+    use crate::controls::controls_resource::{key_title, ControlsResource};
+    use crate::controls::pending_rebind_resource::PendingRebindResource;
+    use crate::controls::Side;
+    use crate::game_controller::Player;
+    use crate::menu_theme::{BUTTON_COLOR_NORMAL, MENU_TEXT_COLOR};
+
+    /// Keeps the Controls screen in front of the Start Menu.
+    const CONTROLS_SCREEN_Z_INDEX: i32 = 10;
+
+    /// Removes the Controls screen UI widgets.
+    pub(super) fn despawn_controls_screen(
+        mut commands: Commands,
+        screen_query: Query<Entity, With<OnControlsScreen>>,
+    ) {
+        for entity in &screen_query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Sets up and then spawns the Controls screen UI widgets.
+    pub(super) fn spawn_controls_screen(
+        mut commands: Commands,
+        controls: Res<ControlsResource>,
+        pending_rebind: Res<PendingRebindResource>,
+    ) {
+        //
+
+        let text_style = TextStyle {
+            color: *MENU_TEXT_COLOR,
+            font: default(),
+            font_size: MENU_FONT_SIZE,
+        };
+        let title_style = TextStyle {
+            font_size: TITLE_FONT_SIZE,
+            ..text_style.clone()
+        };
+
+        let instructions = "Click a key, then press the key to use in its place.";
+
+        // Button template
+        let button_bundle = ButtonBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(16.)),
+                ..default()
+            },
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        };
+
+        let button_rows = [
+            vec![
+                ButtonPurpose::ChangeInputSource(Player::One),
+                ButtonPurpose::RebindKey(Player::One, Side::Left),
+                ButtonPurpose::RebindKey(Player::One, Side::Right),
+            ],
+            vec![
+                ButtonPurpose::ChangeInputSource(Player::Two),
+                ButtonPurpose::RebindKey(Player::Two, Side::Left),
+                ButtonPurpose::RebindKey(Player::Two, Side::Right),
+            ],
+            vec![ButtonPurpose::ResetControls, ButtonPurpose::CloseControls],
+        ];
+
+        commands
+            .spawn((
+                NodeBundle {
+                    background_color: BackgroundColor(*OVERLAY_COLOR),
+                    // Keep the clicks from reaching the Start Menu underneath.
+                    focus_policy: FocusPolicy::Block,
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(20.),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    z_index: ZIndex::Global(CONTROLS_SCREEN_Z_INDEX),
+                    ..default()
+                },
+                OnControlsScreen,
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section("CONTROLS", title_style));
+                parent.spawn(TextBundle::from_section(instructions, text_style.clone()));
+
+                for row in button_rows {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(10.),
+                                flex_direction: FlexDirection::Row,
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            // Name the Player whose controls are on the row.
+                            if let Some(ButtonPurpose::ChangeInputSource(player)) = row.first() {
+                                parent.spawn(TextBundle::from_section(player.title(), text_style.clone()));
+                            }
+
+                            for purpose in row {
+                                let title = button_title(&purpose, &controls, &pending_rebind);
+                                parent
+                                    .spawn((button_bundle.clone(), EntityInfoComponent::new(purpose.clone())))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            TextBundle::from_section(title, text_style.clone()),
+                                            ButtonLabelComponent(purpose),
+                                        ));
+                                    });
+                            }
+                        });
+                }
+            });
+    }
+
+    /// Shows the newly chosen controls, and the key binding waiting for a key press, on the
+    /// buttons.
+    pub(super) fn update_button_labels(
+        controls: Res<ControlsResource>,
+        pending_rebind: Res<PendingRebindResource>,
+        mut text_query: Query<(&mut Text, &ButtonLabelComponent)>,
+    ) {
+        if controls.is_changed() || pending_rebind.is_changed() {
+            for (mut text, label) in &mut text_query {
+                text.sections[0].value = button_title(&label.0, &controls, &pending_rebind);
+            }
+        }
+    }
+
+    fn button_title(
+        purpose: &ButtonPurpose,
+        controls: &ControlsResource,
+        pending_rebind: &PendingRebindResource,
+    ) -> String {
+        match purpose {
+            ButtonPurpose::ChangeInputSource(player) => {
+                format!("Input: {}", controls.get(*player).input_source.title())
+            }
+            ButtonPurpose::CloseControls => "Done".to_string(),
+            ButtonPurpose::RebindKey(player, side) => {
+                let side_title = match side {
+                    Side::Left => "Left",
+                    Side::Right => "Right",
+                };
+                match pending_rebind.binding == Some((*player, *side)) {
+                    true => format!("{}: press a key", side_title),
+                    false => format!("{}: {}", side_title, key_title(controls.get(*player).key_bindings.get(*side))),
+                }
+            }
+            ButtonPurpose::ResetControls => "Reset to Defaults".to_string(),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use bevy::log::warn;
+use bevy::prelude::{KeyCode, Resource};
+use serde::{Deserialize, Serialize};
+
+use crate::controls::{InputSource, Side};
+use crate::game_controller::Player;
+
+/// The keys that move a Paddle left and right.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct KeyBindings {
+    pub(crate) left: KeyCode,
+    pub(crate) right: KeyCode,
+}
+
+impl KeyBindings {
+    //
+
+    /// Returns the key that moves the Paddle toward the specified side.
+    pub(crate) fn get(&self, side: Side) -> KeyCode {
+        match side {
+            Side::Left => self.left,
+            Side::Right => self.right,
+        }
+    }
+
+    fn set(&mut self, side: Side, key: KeyCode) {
+        match side {
+            Side::Left => self.left = key,
+            Side::Right => self.right = key,
+        }
+    }
+}
+
+/// How a Player moves their Paddle.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct PlayerControls {
+    pub(crate) input_source: InputSource,
+    /// The keys used when the InputSource is the Keyboard.
+    pub(crate) key_bindings: KeyBindings,
+}
+
+/// Houses each Player's controls. The controls are persisted to a JSON file in the user's
+/// configuration directory.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Resource, Serialize)]
+#[serde(default)]
+pub(crate) struct ControlsResource {
+    player_one: PlayerControls,
+    player_two: PlayerControls,
+}
+
+/// Player One uses the arrow keys and Player Two uses the A and D keys.
+impl Default for ControlsResource {
+    fn default() -> Self {
+        Self {
+            player_one: PlayerControls {
+                input_source: InputSource::Keyboard,
+                key_bindings: KeyBindings {
+                    left: KeyCode::ArrowLeft,
+                    right: KeyCode::ArrowRight,
+                },
+            },
+            player_two: PlayerControls {
+                input_source: InputSource::Keyboard,
+                key_bindings: KeyBindings {
+                    left: KeyCode::KeyA,
+                    right: KeyCode::KeyD,
+                },
+            },
+        }
+    }
+}
+
+impl ControlsResource {
+    //
+
+    /// Binds the key to the specified Player's side. A key already bound elsewhere trades places
+    /// with the key it replaces, so that no key moves two Paddles or both ways.
+    pub(crate) fn bind_key(&mut self, player: Player, side: Side, key: KeyCode) {
+        //
+
+        let replaced_key = self.get(player).key_bindings.get(side);
+
+        for other_player in [Player::One, Player::Two] {
+            for other_side in [Side::Left, Side::Right] {
+                if self.get(other_player).key_bindings.get(other_side) == key {
+                    self.get_mut(other_player).key_bindings.set(other_side, replaced_key);
+                }
+            }
+        }
+
+        self.get_mut(player).key_bindings.set(side, key);
+    }
+
+    /// Describes how the specified Player moves their Paddle, e.g. "A and D".
+    pub(crate) fn describe(&self, player: Player) -> String {
+        let controls = self.get(player);
+        match controls.input_source {
+            InputSource::Keyboard => format!(
+                "{} and {}",
+                key_title(controls.key_bindings.left),
+                key_title(controls.key_bindings.right)
+            ),
+            input_source => input_source.title().to_string(),
+        }
+    }
+
+    /// Returns the specified Player's controls.
+    pub(crate) fn get(&self, player: Player) -> &PlayerControls {
+        match player {
+            Player::One => &self.player_one,
+            Player::Two => &self.player_two,
+        }
+    }
+
+    /// Loads the persisted controls, if any. An unreadable file is ignored in favor of the default
+    /// controls, and is replaced the next time the controls are saved.
+    pub(crate) fn load() -> Self {
+        //
+
+        let Some(path) = Self::file_path() else {
+            return Self::default();
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Self::default(),
+            Err(error) => {
+                warn!("Unable to read the controls file {}: {}", path.display(), error);
+                return Self::default();
+            }
+        };
+
+        serde_json::from_str::<Self>(&contents).unwrap_or_else(|error| {
+            warn!("Ignoring the unreadable controls file {}: {}", path.display(), error);
+            Self::default()
+        })
+    }
+
+    /// Persists the controls to the controls file.
+    pub(crate) fn save(&self) -> Result<(), String> {
+        //
+
+        let path = Self::file_path().ok_or("the platform has no configuration directory")?;
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        fs::write(&path, contents).map_err(|error| error.to_string())
+    }
+
+    /// Sets the device with which the specified Player moves their Paddle.
+    pub(crate) fn set_input_source(&mut self, player: Player, input_source: InputSource) {
+        self.get_mut(player).input_source = input_source;
+    }
+
+    /// Returns the location of the controls file.
+    fn file_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|directory| directory.join("pong").join("controls.json"))
+    }
+
+    fn get_mut(&mut self, player: Player) -> &mut PlayerControls {
+        match player {
+            Player::One => &mut self.player_one,
+            Player::Two => &mut self.player_two,
+        }
+    }
+}
+
+/// Returns the name shown for the key, e.g. "A" for KeyCode::KeyA.
+pub(crate) fn key_title(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    match name.strip_prefix("Key").or(name.strip_prefix("Digit")) {
+        Some(short_name) => short_name.to_string(),
+        None => name,
+    }
+}
@@ -0,0 +1,67 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::States;
+use serde::{Deserialize, Serialize};
+
+pub(super) mod controls_plugin;
+pub(crate) mod controls_resource;
+pub(crate) mod paddle_input;
+mod pending_rebind_resource;
+
+/// Specifies the device with which a Player moves their Paddle.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) enum InputSource {
+    /// A pair of keys, which can be rebound on the Controls screen.
+    #[default]
+    Keyboard,
+    /// The left analog stick or the d-pad of a gamepad. Gamepads are handed out, in the order in
+    /// which they were connected, to the Players who use one.
+    Gamepad,
+    /// The Paddle follows the mouse pointer.
+    Mouse,
+    /// The Paddle follows a finger dragged across the screen. When both Players use touch, each
+    /// uses their own half of the screen.
+    Touch,
+}
+
+impl InputSource {
+    //
+
+    /// Returns the next InputSource, wrapping around from the last to the first.
+    pub(crate) fn next(&self) -> Self {
+        match self {
+            InputSource::Keyboard => InputSource::Gamepad,
+            InputSource::Gamepad => InputSource::Mouse,
+            InputSource::Mouse => InputSource::Touch,
+            InputSource::Touch => InputSource::Keyboard,
+        }
+    }
+
+    /// Returns the name shown for the InputSource.
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            InputSource::Keyboard => "Keyboard",
+            InputSource::Gamepad => "Gamepad",
+            InputSource::Mouse => "Mouse",
+            InputSource::Touch => "Touch",
+        }
+    }
+}
+
+/// Identifies the direction in which a key moves a Paddle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Side {
+    Left,
+    Right,
+}
+
+/// Specifies whether the Controls screen is shown over the Start Menu.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, States)]
+pub(crate) enum ControlsView {
+    Hidden,
+    Shown,
+}
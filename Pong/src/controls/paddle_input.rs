@@ -0,0 +1,130 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::gamepad::{
+    Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
+};
+use bevy::input::touch::Touches;
+use bevy::input::{Axis, ButtonInput};
+use bevy::prelude::{KeyCode, Res, Window};
+
+use crate::controls::controls_resource::{ControlsResource, PlayerControls};
+use crate::controls::InputSource;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{GameMode, Player};
+use crate::paddle::paddle_intents_resource::PaddleIntent;
+use crate::physical_interactions::{DIRECTION_BACKWARD, DIRECTION_FORWARD};
+
+/// Reads each Player's chosen input device, translating it into a PaddleIntent.
+#[derive(SystemParam)]
+pub(crate) struct PaddleInput<'w> {
+    controls: Res<'w, ControlsResource>,
+    game_settings: Res<'w, GameSettingsResource>,
+    gamepad_axes: Res<'w, Axis<GamepadAxis>>,
+    gamepad_buttons: Res<'w, ButtonInput<GamepadButton>>,
+    gamepads: Res<'w, Gamepads>,
+    keyboard_input: Res<'w, ButtonInput<KeyCode>>,
+    touches: Res<'w, Touches>,
+}
+
+impl PaddleInput<'_> {
+    //
+
+    /// Returns what the specified Player asks of their Paddle.
+    pub(crate) fn intent(&self, player: Player, window: &Window) -> PaddleIntent {
+        //
+
+        let controls = self.controls.get(player);
+
+        match controls.input_source {
+            InputSource::Keyboard => self.keyboard_intent(controls),
+            InputSource::Gamepad => self.gamepad_intent(player),
+            InputSource::Mouse => match window.cursor_position() {
+                Some(cursor_position) => PaddleIntent::Follow(cursor_position.x),
+                None => PaddleIntent::Hold,
+            },
+            InputSource::Touch => self.touch_intent(player, window),
+        }
+    }
+
+    /// Returns the gamepad handed to the specified Player, if connected. Gamepads are handed out,
+    /// in the order in which they were connected, to the Players who use one.
+    fn gamepad(&self, player: Player) -> Option<Gamepad> {
+        //
+
+        let mut gamepads: Vec<Gamepad> = self.gamepads.iter().collect();
+        gamepads.sort_by_key(|gamepad| gamepad.id);
+
+        let player_one_uses_gamepad = self.controls.get(Player::One).input_source == InputSource::Gamepad;
+        let index = match player {
+            Player::Two if player_one_uses_gamepad => 1,
+            _ => 0,
+        };
+
+        gamepads.get(index).copied()
+    }
+
+    /// The d-pad moves the Paddle at full speed and the left stick in proportion to how far it is
+    /// pushed.
+    fn gamepad_intent(&self, player: Player) -> PaddleIntent {
+        //
+
+        let Some(gamepad) = self.gamepad(player) else {
+            return PaddleIntent::Hold;
+        };
+
+        let dpad_left = self.gamepad_buttons.pressed(GamepadButton::new(gamepad, GamepadButtonType::DPadLeft));
+        let dpad_right = self.gamepad_buttons.pressed(GamepadButton::new(gamepad, GamepadButtonType::DPadRight));
+        let stick_x = self
+            .gamepad_axes
+            .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+            .unwrap_or(0_f32);
+
+        match (dpad_left, dpad_right) {
+            (true, false) => PaddleIntent::Move(DIRECTION_BACKWARD),
+            (false, true) => PaddleIntent::Move(DIRECTION_FORWARD),
+            _ if stick_x != 0_f32 => PaddleIntent::Move(stick_x.clamp(-1_f32, 1_f32)),
+            _ => PaddleIntent::Hold,
+        }
+    }
+
+    fn keyboard_intent(&self, controls: &PlayerControls) -> PaddleIntent {
+        //
+
+        let left = self.keyboard_input.pressed(controls.key_bindings.left);
+        let right = self.keyboard_input.pressed(controls.key_bindings.right);
+
+        match (left, right) {
+            (true, false) => PaddleIntent::Move(DIRECTION_BACKWARD),
+            (false, true) => PaddleIntent::Move(DIRECTION_FORWARD),
+            _ => PaddleIntent::Hold,
+        }
+    }
+
+    /// Follows a finger touching the screen. When both Players of a Versus Game use touch, Player
+    /// One uses the bottom half of the screen, where their Paddle is, and Player Two the top half.
+    fn touch_intent(&self, player: Player, window: &Window) -> PaddleIntent {
+        //
+
+        let shared_screen = self.game_settings.game_mode == GameMode::Versus
+            && !self.game_settings.player_two_is_computer
+            && self.controls.get(player.opponent()).input_source == InputSource::Touch;
+
+        // Touch positions are measured from the top of the window.
+        let half_height = window.height() / 2_f32;
+        let on_own_half = |y: f32| match player {
+            Player::One => y >= half_height,
+            Player::Two => y < half_height,
+        };
+
+        self.touches
+            .iter()
+            .map(|touch| touch.position())
+            .find(|position| !shared_screen || on_own_half(position.y))
+            .map_or(PaddleIntent::Hold, |position| PaddleIntent::Follow(position.x))
+    }
+}
@@ -0,0 +1,16 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+
+use crate::controls::Side;
+use crate::game_controller::Player;
+
+/// Remembers which key binding, if any, is waiting for the next key press on the Controls screen.
+#[derive(Default, Resource)]
+pub(super) struct PendingRebindResource {
+    pub(super) binding: Option<(Player, Side)>,
+}
@@ -4,11 +4,22 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
+use std::sync::LazyLock;
+
 use bevy::app::{App, FixedUpdate, Plugin, Startup};
+use bevy::prelude::Color;
+
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::paused_game_resource::PausedGameResource;
+
+const HIGH_SCORES_BUTTON_FONT_SIZE: f32 = 24.0;
+
+static HIGH_SCORES_BUTTON_TEXT_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("2f2f2f").unwrap() });
 
 #[derive(Clone, PartialEq)]
 /// Defines the purposes of the Game Controller Screen buttons.
 enum ButtonPurpose {
+    ShowHighScores,
     TogglePlay,
     ToggleSound,
 }
@@ -22,6 +33,8 @@ impl Plugin for GameControllerPlugin {
     /// Composes the plugin.
     fn build(&self, app: &mut App) {
         app //
+            .init_resource::<GameSettingsResource>()
+            .init_resource::<PausedGameResource>()
             .add_systems(Startup, ui::spawn_buttons)
             .add_systems(FixedUpdate, functionality::button_interaction)
             .add_systems(FixedUpdate, functionality::update_button_image);
@@ -37,27 +50,53 @@ mod functionality {
     use helpers_for_bevy::entity_info_component::EntityInfoComponent;
 
     use crate::game_controller::game_controller_plugin::ButtonPurpose;
+    use crate::game_controller::paused_game_resource::PausedGameResource;
     use crate::game_controller::{GamePlayState, SoundSetting};
+    use crate::high_scores::HighScoresView;
 
-    /// Responds to Button clicks.
+    /// Responds to Button clicks. Showing the high-score table pauses a Game under way and resuming
+    /// the Game hides the table.
     #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    #[allow(clippy::too_many_arguments)] // Each button controls a separate state.
     pub(super) fn button_interaction(
         mut interactions: Query<
             (&Interaction, &EntityInfoComponent<ButtonPurpose>),
             (Changed<Interaction>, With<Button>),
         >,
         mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut next_high_scores_view: ResMut<NextState<HighScoresView>>,
         mut next_state_sound: ResMut<NextState<SoundSetting>>,
+        mut paused_game: ResMut<PausedGameResource>,
         state_game_play: Res<State<GamePlayState>>,
+        state_high_scores_view: Res<State<HighScoresView>>,
         state_sound: Res<State<SoundSetting>>,
     ) {
         for (interaction, button_info) in &mut interactions {
             if *interaction == Interaction::Pressed {
                 // Which button was pressed?
                 match button_info.get_purpose() {
+                    ButtonPurpose::ShowHighScores => match state_high_scores_view.get() {
+                        HighScoresView::Hidden => {
+                            if state_game_play.get().is_in_progress() {
+                                paused_game.resume_state = Some(state_game_play.get().clone());
+                                next_game_play.set(GamePlayState::Paused);
+                            }
+                            next_high_scores_view.set(HighScoresView::Shown);
+                        }
+                        HighScoresView::Shown => next_high_scores_view.set(HighScoresView::Hidden),
+                    },
                     ButtonPurpose::TogglePlay => match state_game_play.get() {
-                        GamePlayState::Playing => next_game_play.set(GamePlayState::Paused),
-                        GamePlayState::Paused => next_game_play.set(GamePlayState::Playing),
+                        GamePlayState::Serve | GamePlayState::Rally | GamePlayState::PointScored => {
+                            paused_game.resume_state = Some(state_game_play.get().clone());
+                            next_game_play.set(GamePlayState::Paused);
+                        }
+                        GamePlayState::Paused => {
+                            let resume_state = paused_game.resume_state.take().unwrap_or(GamePlayState::Serve);
+                            next_game_play.set(resume_state);
+                            next_high_scores_view.set(HighScoresView::Hidden);
+                        }
+                        // A Game is started from the Start Menu or the Game Over screen.
+                        GamePlayState::StartMenu | GamePlayState::EnterInitials | GamePlayState::GameOver => {}
                     },
                     ButtonPurpose::ToggleSound => match state_sound.get() {
                         SoundSetting::On => next_state_sound.set(SoundSetting::Off),
@@ -81,11 +120,11 @@ mod functionality {
             for (mut button_image, button_info) in &mut query {
                 // Which button was pressed?
                 if button_info.get_purpose() == ButtonPurpose::TogglePlay {
-                    match state_game_play.get() {
-                        GamePlayState::Playing => {
+                    match state_game_play.get().is_in_progress() {
+                        true => {
                             *button_image = UiImage::new(asset_server.load("sprites/pause.png"));
                         }
-                        GamePlayState::Paused => {
+                        false => {
                             *button_image = UiImage::new(asset_server.load("sprites/play.png"));
                         }
                     }
@@ -116,12 +155,14 @@ mod ui {
     use bevy::asset::AssetServer;
     use bevy::prelude::{
         default, AlignItems, BuildChildren, ButtonBundle, Commands, FlexDirection, JustifyContent,
-        NodeBundle, Res, Style, UiRect, Val,
+        NodeBundle, Res, Style, TextBundle, TextStyle, UiRect, Val,
     };
     use bevy::ui::UiImage;
     use helpers_for_bevy::entity_info_component::EntityInfoComponent;
 
-    use crate::game_controller::game_controller_plugin::ButtonPurpose;
+    use crate::game_controller::game_controller_plugin::{
+        ButtonPurpose, HIGH_SCORES_BUTTON_FONT_SIZE, HIGH_SCORES_BUTTON_TEXT_COLOR,
+    };
 
     /// Sets up and then spawns the Game Controller's UI widgets.
     pub(super) fn spawn_buttons(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -150,6 +191,16 @@ mod ui {
             ..default()
         };
 
+        let high_scores_button_bundle = ButtonBundle {
+            style: button_style.clone(),
+            ..default()
+        };
+        let high_scores_text_style = TextStyle {
+            color: *HIGH_SCORES_BUTTON_TEXT_COLOR,
+            font: default(),
+            font_size: HIGH_SCORES_BUTTON_FONT_SIZE,
+        };
+
         commands
             .spawn((NodeBundle {
                 style: Style {
@@ -165,6 +216,14 @@ mod ui {
                 ..default()
             },))
             .with_children(|parent| {
+                parent
+                    .spawn((
+                        high_scores_button_bundle,
+                        EntityInfoComponent::new(ButtonPurpose::ShowHighScores),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section("High Scores", high_scores_text_style));
+                    });
                 parent.spawn((
                     play_pause_button_bundle,
                     EntityInfoComponent::new(ButtonPurpose::TogglePlay),
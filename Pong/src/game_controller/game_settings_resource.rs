@@ -0,0 +1,42 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+
+use crate::computer_opponent::Difficulty;
+use crate::game_controller::GameMode;
+use crate::physical_interactions::rally_physics::Pace;
+
+/// Holds the choices made on the Start Menu for the current Game.
+#[derive(Resource)]
+pub(crate) struct GameSettingsResource {
+    /// How well the computer plays when it controls Player Two.
+    pub(crate) computer_difficulty: Difficulty,
+    pub(crate) game_mode: GameMode,
+    /// How quickly the Ball speeds up and how sharply the Paddles can angle it.
+    pub(crate) pace: Pace,
+    /// Whether the Game is the playback of a recorded one. See ReplayPlugin.
+    pub(crate) is_replay: bool,
+    /// Whether, in a Versus Game, the computer controls Player Two's Paddle.
+    pub(crate) player_two_is_computer: bool,
+    /// Whether, in a Solo or Levels Game, power-ups drop for the Player to catch. See
+    /// PowerUpsPlugin.
+    pub(crate) power_ups: bool,
+}
+
+/// Provides default instantiation, with power-ups on.
+impl Default for GameSettingsResource {
+    fn default() -> Self {
+        Self {
+            computer_difficulty: Default::default(),
+            game_mode: Default::default(),
+            pace: Default::default(),
+            is_replay: false,
+            player_two_is_computer: false,
+            power_ups: true,
+        }
+    }
+}
@@ -4,15 +4,43 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use bevy::prelude::States;
+use bevy::prelude::{Res, State, States};
+use serde::{Deserialize, Serialize};
 
 pub(super) mod game_controller_plugin;
+pub(crate) mod game_settings_resource;
+mod paused_game_resource;
 
-/// Specifies the state of the Game.
+/// Specifies the state of the Game. A Game moves from the StartMenu through rounds, each made up
+/// of a Serve, a Rally and, once the Ball is missed, a PointScored pause, until the GameOver. A
+/// final score that earns a place in the high-score table is first recorded in EnterInitials.
 #[derive(Clone, Debug, Hash, Eq, PartialEq, States)]
 pub(crate) enum GamePlayState {
-    Playing,
+    StartMenu,
+    /// The Ball waits to be served.
+    Serve,
+    /// The Ball is in play.
+    Rally,
+    /// A Player has just missed the Ball.
+    PointScored,
     Paused,
+    /// The Player who earned a high score enters their initials.
+    EnterInitials,
+    GameOver,
+}
+
+impl GamePlayState {
+    //
+
+    /// Indicates whether a Game is under way and not paused.
+    pub(crate) fn is_in_progress(&self) -> bool {
+        matches!(self, GamePlayState::Serve | GamePlayState::Rally | GamePlayState::PointScored)
+    }
+}
+
+/// Run condition that allows a system to run while a Game is under way and not paused.
+pub(crate) fn game_in_progress(state: Res<State<GamePlayState>>) -> bool {
+    state.get().is_in_progress()
 }
 
 /// Specifies whether Game sounds are on or off.
@@ -21,3 +49,56 @@ pub(crate) enum SoundSetting {
     On,
     Off,
 }
+
+/// Specifies how the Game is played.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) enum GameMode {
+    /// A single Player bounces the Ball off the Ceiling and SideWalls.
+    #[default]
+    Solo,
+    /// Two Players, each with a Paddle, try to get the Ball past one another.
+    Versus,
+    /// A single Player clears level after level of Bricks with the Ball. See LevelsPlugin.
+    Levels,
+}
+
+impl GameMode {
+    //
+
+    /// Returns the name shown for the GameMode.
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            GameMode::Solo => "Solo",
+            GameMode::Versus => "Versus",
+            GameMode::Levels => "Levels",
+        }
+    }
+}
+
+/// Identifies the Players in a Versus Game. Player One's Paddle is at the bottom of the window and
+/// Player Two's at the top. A Solo Game has only Player One.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Player {
+    One,
+    Two,
+}
+
+impl Player {
+    //
+
+    /// Returns the Player's opponent.
+    pub(crate) fn opponent(&self) -> Self {
+        match self {
+            Player::One => Player::Two,
+            Player::Two => Player::One,
+        }
+    }
+
+    /// Returns the name shown for the Player.
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            Player::One => "Player 1",
+            Player::Two => "Player 2",
+        }
+    }
+}
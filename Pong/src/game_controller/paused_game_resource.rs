@@ -0,0 +1,15 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+
+use crate::game_controller::GamePlayState;
+
+/// Remembers the state in which the Game was paused so that play resumes where it left off.
+#[derive(Default, Resource)]
+pub(super) struct PausedGameResource {
+    pub(super) resume_state: Option<GamePlayState>,
+}
@@ -0,0 +1,172 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use bevy::log::warn;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::game_controller::GameMode;
+
+/// The number of scores kept for each GameMode.
+pub(crate) const MAX_ENTRIES_PER_MODE: usize = 10;
+
+/// The number of letters and digits in a Player's initials.
+pub(crate) const INITIALS_LENGTH: usize = 3;
+
+/// A score recorded in the high-score table.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct HighScoreEntry {
+    pub(crate) date: NaiveDate,
+    pub(crate) game_mode: GameMode,
+    pub(crate) initials: String,
+    /// The points of a Solo or Levels Game or the winner's margin of victory in a Versus Game.
+    pub(crate) score: i64,
+}
+
+impl HighScoreEntry {
+    //
+
+    /// Creates a new instance, keeping only the first letters and digits of the initials.
+    pub(crate) fn new(initials: &str, score: i64, game_mode: GameMode, date: NaiveDate) -> Self {
+        Self {
+            date,
+            game_mode,
+            initials: sanitize_initials(initials),
+            score,
+        }
+    }
+}
+
+/// Holds the best scores of each GameMode, highest first. Ties are ranked by who got there first.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct HighScoreTable {
+    entries: Vec<HighScoreEntry>,
+}
+
+impl HighScoreTable {
+    //
+
+    /// Returns the recorded scores of the specified GameMode, highest first.
+    pub(crate) fn get_entries(&self, game_mode: GameMode) -> impl Iterator<Item = &HighScoreEntry> {
+        self.entries.iter().filter(move |entry| entry.game_mode == game_mode)
+    }
+
+    /// Records the entry if it earns a place in the table. Returns its rank, starting at 1, or None
+    /// if it did not earn a place.
+    pub(crate) fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        //
+
+        // Early return.
+        if !self.qualifies(entry.game_mode, entry.score) {
+            return None;
+        }
+
+        let game_mode = entry.game_mode;
+        self.entries.push(entry.clone());
+        self.normalize();
+
+        // A new entry ranks below any identical one recorded earlier.
+        self.get_entries(game_mode)
+            .enumerate()
+            .filter(|(_, recorded)| **recorded == entry)
+            .last()
+            .map(|(index, _)| index + 1)
+    }
+
+    /// Loads the table from the specified file. A missing file yields an empty table. An unreadable
+    /// file is set aside, with ".corrupt" appended to its name, so that it is not overwritten and
+    /// the Game starts over with an empty table.
+    pub(crate) fn load_from(path: &Path) -> Self {
+        //
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Self::default(),
+            Err(error) => {
+                warn!("Unable to read the high scores file {}: {}", path.display(), error);
+                return Self::default();
+            }
+        };
+
+        match serde_json::from_str::<Self>(&contents) {
+            Ok(mut table) => {
+                // The file may have been edited by hand.
+                table.normalize();
+                table
+            }
+            Err(error) => {
+                let mut corrupt_path = path.as_os_str().to_owned();
+                corrupt_path.push(".corrupt");
+                warn!(
+                    "Setting aside the unreadable high scores file {} as {}: {}",
+                    path.display(),
+                    Path::new(&corrupt_path).display(),
+                    error
+                );
+                if let Err(error) = fs::rename(path, &corrupt_path) {
+                    warn!("Unable to set aside the unreadable high scores file: {}", error);
+                }
+                Self::default()
+            }
+        }
+    }
+
+    /// Indicates whether the specified score would earn a place in the table.
+    pub(crate) fn qualifies(&self, game_mode: GameMode, score: i64) -> bool {
+        //
+
+        // Early return.
+        if score <= 0 {
+            return false;
+        }
+
+        let scores: Vec<i64> = self.get_entries(game_mode).map(|entry| entry.score).collect();
+        scores.len() < MAX_ENTRIES_PER_MODE || scores.last().is_some_and(|lowest| score > *lowest)
+    }
+
+    /// Saves the table to the specified file, creating its directory if needed.
+    pub(crate) fn save_to(&self, path: &Path) -> Result<(), String> {
+        //
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        fs::write(path, contents).map_err(|error| error.to_string())
+    }
+
+    /// Ranks the entries, highest score first, and keeps only the best of each GameMode.
+    fn normalize(&mut self) {
+        //
+
+        // The sort is stable, so tied entries stay in the order in which they were recorded.
+        self.entries.sort_by_key(|entry| Reverse(entry.score));
+
+        let mut counts: HashMap<GameMode, usize> = HashMap::new();
+        self.entries.retain_mut(|entry| {
+            entry.initials = sanitize_initials(&entry.initials);
+            let count = counts.entry(entry.game_mode).or_default();
+            *count += 1;
+            *count <= MAX_ENTRIES_PER_MODE
+        });
+    }
+}
+
+/// Keeps the first letters and digits of the initials, in upper case.
+fn sanitize_initials(initials: &str) -> String {
+    initials
+        .chars()
+        .filter(|character| character.is_alphanumeric())
+        .flat_map(char::to_uppercase)
+        .take(INITIALS_LENGTH)
+        .collect()
+}
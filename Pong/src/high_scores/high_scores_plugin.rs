@@ -0,0 +1,412 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::sync::LazyLock;
+
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::{in_state, Color, Component, IntoSystemConfigs, OnEnter, OnExit};
+
+use crate::game_controller::GamePlayState;
+use crate::high_scores::high_scores_resource::HighScoresResource;
+use crate::high_scores::HighScoresView;
+
+const MENU_FONT_SIZE: f32 = 30.0;
+const TABLE_FONT_SIZE: f32 = 24.0;
+const TITLE_FONT_SIZE: f32 = 60.0;
+
+static OVERLAY_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("4d1a8cf0").unwrap() });
+
+#[derive(Clone, PartialEq)]
+/// Defines the purposes of the high-score buttons.
+enum ButtonPurpose {
+    CloseHighScores,
+    SaveInitials,
+}
+
+/// Marker to indicate that an entity was spawned on the initials entry screen.
+#[derive(Component)]
+struct OnInitialsScreen;
+
+/// Marker to indicate that an entity was spawned on the high-score table view.
+#[derive(Component)]
+struct OnHighScoresView;
+
+/// Keeps a table of the best scores of each GameMode, with the initials of the Player who earned
+/// them and the date. A Game that earns a place in the table ends on the initials entry screen,
+/// after which the table is shown. The table can also be shown from the Game Controls.
+pub(crate) struct HighScoresPlugin;
+
+impl Plugin for HighScoresPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .insert_resource(HighScoresResource::load())
+            .insert_state(HighScoresView::Hidden)
+            .add_systems(OnEnter(GamePlayState::EnterInitials), ui::spawn_initials_screen)
+            .add_systems(
+                Update,
+                functionality::submit_initials.run_if(in_state(GamePlayState::EnterInitials)),
+            )
+            .add_systems(OnExit(GamePlayState::EnterInitials), ui::despawn_initials_screen)
+            .add_systems(OnEnter(HighScoresView::Shown), ui::spawn_high_scores_view)
+            .add_systems(OnExit(HighScoresView::Shown), ui::despawn_high_scores_view)
+            .add_systems(Update, functionality::button_interaction);
+    }
+}
+
+mod functionality {
+    use bevy::prelude::{
+        BackgroundColor, Button, Changed, EventReader, Interaction, NextState, Query, Res, ResMut,
+        With,
+    };
+    use chrono::Local;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+    use helpers_for_bevy::text_input::events::TextInputSubmittedEvent;
+    use helpers_for_bevy::text_input::text_input_component::TextInputComponent;
+
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::GamePlayState;
+    use crate::high_scores::high_score_table::HighScoreEntry;
+    use crate::high_scores::high_scores_plugin::ButtonPurpose;
+    use crate::high_scores::high_scores_resource::HighScoresResource;
+    use crate::high_scores::HighScoresView;
+    use crate::menu_theme::button_color;
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+
+    /// Provides button functionality, including state changes as well as response when clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    #[allow(clippy::too_many_arguments)] // Saving the initials needs each of the resources.
+    pub(super) fn button_interaction(
+        game_settings: Res<GameSettingsResource>,
+        mut high_scores: ResMut<HighScoresResource>,
+        mut interactions: Query<
+            (
+                &Interaction,
+                &mut BackgroundColor,
+                &EntityInfoComponent<ButtonPurpose>,
+            ),
+            (Changed<Interaction>, With<Button>),
+        >,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut next_high_scores_view: ResMut<NextState<HighScoresView>>,
+        scoreboard_resource: Res<ScoreboardResource>,
+        text_input_query: Query<&TextInputComponent>,
+    ) {
+        for (interaction, mut color, button_info) in &mut interactions {
+            *color = button_color(*interaction);
+
+            // Only a press acts on the button.
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+
+            // Which button was pressed?
+            match button_info.get_purpose() {
+                ButtonPurpose::CloseHighScores => next_high_scores_view.set(HighScoresView::Hidden),
+                ButtonPurpose::SaveInitials => {
+                    if let Ok(text_input) = text_input_query.get_single() {
+                        record_high_score(
+                            text_input.value(),
+                            &game_settings,
+                            &mut high_scores,
+                            &mut next_game_play,
+                            &mut next_high_scores_view,
+                            &scoreboard_resource,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Saves the initials when Enter is pressed in the initials field.
+    pub(super) fn submit_initials(
+        mut event_reader: EventReader<TextInputSubmittedEvent>,
+        game_settings: Res<GameSettingsResource>,
+        mut high_scores: ResMut<HighScoresResource>,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut next_high_scores_view: ResMut<NextState<HighScoresView>>,
+        scoreboard_resource: Res<ScoreboardResource>,
+    ) {
+        for event in event_reader.read() {
+            record_high_score(
+                &event.value,
+                &game_settings,
+                &mut high_scores,
+                &mut next_game_play,
+                &mut next_high_scores_view,
+                &scoreboard_resource,
+            );
+        }
+    }
+
+    /// Records the final score under the specified initials, then moves on to the Game Over screen
+    /// with the high-score table shown over it. Initials without a letter or digit are ignored.
+    fn record_high_score(
+        initials: &str,
+        game_settings: &GameSettingsResource,
+        high_scores: &mut HighScoresResource,
+        next_game_play: &mut NextState<GamePlayState>,
+        next_high_scores_view: &mut NextState<HighScoresView>,
+        scoreboard_resource: &ScoreboardResource,
+    ) {
+        //
+
+        let game_mode = game_settings.game_mode;
+        let entry = HighScoreEntry::new(
+            initials,
+            scoreboard_resource.get_final_score(game_mode),
+            game_mode,
+            Local::now().date_naive(),
+        );
+
+        // Early return.
+        if entry.initials.is_empty() {
+            return;
+        }
+
+        high_scores.record(entry);
+        next_game_play.set(GamePlayState::GameOver);
+        next_high_scores_view.set(HighScoresView::Shown);
+    }
+}
+
+mod ui {
+    use bevy::prelude::{
+        default, AlignItems, BackgroundColor, BorderColor, BuildChildren, ButtonBundle, ChildBuilder,
+        Color, Commands, DespawnRecursiveExt, Entity, FlexDirection, JustifyContent, NodeBundle,
+        Query, Res, Style, TextBundle, TextStyle, UiRect, Val, With, ZIndex,
+    };
+    use bevy::ui::FocusPolicy;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+    use helpers_for_bevy::text_input::text_input_component::{
+        TextInputBundle, TextInputComponent, TextInputFilter,
+    };
+
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::GameMode;
+    use crate::high_scores::high_score_table::{HighScoreTable, INITIALS_LENGTH};
+    use crate::high_scores::high_scores_plugin::{
+        ButtonPurpose, OnHighScoresView, OnInitialsScreen, MENU_FONT_SIZE, OVERLAY_COLOR,
+        TABLE_FONT_SIZE, TITLE_FONT_SIZE,
+    };
+    use crate::high_scores::high_scores_resource::HighScoresResource;
+    use crate::menu_theme::{BUTTON_COLOR_NORMAL, MENU_TEXT_COLOR};
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+
+    /// Keeps the high-score table in front of the other screens.
+    const HIGH_SCORES_VIEW_Z_INDEX: i32 = 10;
+
+    /// Removes the high-score table view UI widgets.
+    pub(super) fn despawn_high_scores_view(
+        mut commands: Commands,
+        view_query: Query<Entity, With<OnHighScoresView>>,
+    ) {
+        for entity in &view_query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Removes the initials entry screen UI widgets.
+    pub(super) fn despawn_initials_screen(
+        mut commands: Commands,
+        screen_query: Query<Entity, With<OnInitialsScreen>>,
+    ) {
+        for entity in &screen_query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Sets up and then spawns the high-score table view, which covers the rest of the Game until
+    /// closed.
+    pub(super) fn spawn_high_scores_view(mut commands: Commands, high_scores: Res<HighScoresResource>) {
+        //
+
+        let text_style = TextStyle {
+            color: *MENU_TEXT_COLOR,
+            font: default(),
+            font_size: MENU_FONT_SIZE,
+        };
+        let table_style = TextStyle {
+            font_size: TABLE_FONT_SIZE,
+            ..text_style.clone()
+        };
+        let title_style = TextStyle {
+            font_size: TITLE_FONT_SIZE,
+            ..text_style.clone()
+        };
+
+        commands
+            .spawn((
+                NodeBundle {
+                    background_color: BackgroundColor(*OVERLAY_COLOR),
+                    // Keep the clicks from reaching the buttons of the screen underneath.
+                    focus_policy: FocusPolicy::Block,
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(20.),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    z_index: ZIndex::Global(HIGH_SCORES_VIEW_Z_INDEX),
+                    ..default()
+                },
+                OnHighScoresView,
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section("HIGH SCORES", title_style));
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            column_gap: Val::Px(60.),
+                            flex_direction: FlexDirection::Row,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for game_mode in [GameMode::Solo, GameMode::Versus, GameMode::Levels] {
+                            spawn_table_column(parent, high_scores.get_table(), game_mode, &text_style, &table_style);
+                        }
+                    });
+                parent
+                    .spawn((button_bundle(), EntityInfoComponent::new(ButtonPurpose::CloseHighScores)))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section("Close", text_style.clone()));
+                    });
+            });
+    }
+
+    /// Sets up and then spawns the initials entry screen UI widgets.
+    pub(super) fn spawn_initials_screen(
+        mut commands: Commands,
+        game_settings: Res<GameSettingsResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+    ) {
+        //
+
+        let text_style = TextStyle {
+            color: *MENU_TEXT_COLOR,
+            font: default(),
+            font_size: MENU_FONT_SIZE,
+        };
+        let title_style = TextStyle {
+            font_size: TITLE_FONT_SIZE,
+            ..text_style.clone()
+        };
+
+        let game_mode = game_settings.game_mode;
+        let final_score = scoreboard_resource.get_final_score(game_mode);
+        let result = match (game_mode, scoreboard_resource.get_winner()) {
+            (GameMode::Versus, Some(winner)) => format!("{} won by {}", winner.title(), final_score),
+            _ => format!("Score: {}", final_score),
+        };
+
+        let initials = TextInputComponent::new(text_style.clone())
+            .with_filter(TextInputFilter::Alphanumeric)
+            .with_focus()
+            .with_max_length(INITIALS_LENGTH)
+            .with_placeholder("AAA");
+        let initials_style = Style {
+            border: UiRect::all(Val::Px(2.)),
+            padding: UiRect::all(Val::Px(8.)),
+            ..default()
+        };
+
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(20.),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                OnInitialsScreen,
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section("NEW HIGH SCORE!", title_style));
+                parent.spawn(TextBundle::from_section(result, text_style.clone()));
+                parent.spawn(TextBundle::from_section("Enter your initials:", text_style.clone()));
+                parent.spawn((
+                    TextInputBundle::new(initials).with_style(initials_style),
+                    BorderColor(Color::WHITE),
+                ));
+                parent
+                    .spawn((button_bundle(), EntityInfoComponent::new(ButtonPurpose::SaveInitials)))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section("Save", text_style.clone()));
+                    });
+            });
+    }
+
+    fn button_bundle() -> ButtonBundle {
+        ButtonBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(16.)),
+                ..default()
+            },
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        }
+    }
+
+    /// Spawns the column listing the recorded scores of the specified GameMode.
+    fn spawn_table_column(
+        parent: &mut ChildBuilder,
+        table: &HighScoreTable,
+        game_mode: GameMode,
+        heading_style: &TextStyle,
+        row_style: &TextStyle,
+    ) {
+        //
+
+        let heading = match game_mode {
+            GameMode::Solo | GameMode::Levels => game_mode.title().to_string(),
+            GameMode::Versus => format!("{} (winning margin)", game_mode.title()),
+        };
+
+        let mut rows: Vec<String> = table
+            .get_entries(game_mode)
+            .enumerate()
+            .map(|(index, entry)| {
+                format!("{:>2}. {:<3} {:>6}  {}", index + 1, entry.initials, entry.score, entry.date)
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push("No scores yet".to_string());
+        }
+
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    align_items: AlignItems::Start,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(heading, heading_style.clone()));
+                for row in rows {
+                    parent.spawn(TextBundle::from_section(row, row_style.clone()));
+                }
+            });
+    }
+}
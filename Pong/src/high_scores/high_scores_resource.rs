@@ -0,0 +1,94 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::path::PathBuf;
+
+use bevy::log::error;
+use bevy::prelude::Resource;
+
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{GameMode, Player};
+use crate::high_scores::high_score_table::{HighScoreEntry, HighScoreTable};
+use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+
+/// Holds the high-score table, which is persisted to a JSON file in the user's data directory. A
+/// default instance starts empty and is kept in memory only.
+#[derive(Default, Resource)]
+pub(crate) struct HighScoresResource {
+    /// The location of the high scores file, if the platform has a data directory.
+    file_path: Option<PathBuf>,
+    table: HighScoreTable,
+}
+
+impl HighScoresResource {
+    //
+
+    /// Indicates whether the Game that just ended earned a place in the table. A Versus Game won
+    /// by the computer does not, nor does the playback of a recorded Game.
+    pub(crate) fn earns_entry(
+        &self,
+        game_settings: &GameSettingsResource,
+        scoreboard_resource: &ScoreboardResource,
+    ) -> bool {
+        //
+
+        let game_mode = game_settings.game_mode;
+
+        // Early return.
+        if game_settings.is_replay {
+            return false;
+        }
+
+        // Early return.
+        if game_mode == GameMode::Versus
+            && game_settings.player_two_is_computer
+            && scoreboard_resource.get_winner() == Some(Player::Two)
+        {
+            return false;
+        }
+
+        self.table.qualifies(game_mode, scoreboard_resource.get_final_score(game_mode))
+    }
+
+    /// Returns the high-score table.
+    pub(crate) fn get_table(&self) -> &HighScoreTable {
+        &self.table
+    }
+
+    /// Loads the persisted high-score table, if any.
+    pub(crate) fn load() -> Self {
+        //
+
+        let file_path = Self::file_path();
+        let table = file_path.as_deref().map(HighScoreTable::load_from).unwrap_or_default();
+
+        Self { file_path, table }
+    }
+
+    /// Records the entry, if it earns a place in the table, and persists the table. Returns the
+    /// entry's rank, starting at 1, or None if it did not earn a place.
+    pub(crate) fn record(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        //
+
+        let rank = self.table.insert(entry)?;
+
+        match &self.file_path {
+            Some(file_path) => {
+                if let Err(error) = self.table.save_to(file_path) {
+                    error!("Unable to save the high scores to {}: {}", file_path.display(), error);
+                }
+            }
+            None => error!("Unable to save the high scores: the platform has no data directory."),
+        }
+
+        Some(rank)
+    }
+
+    /// Returns the location of the high scores file.
+    fn file_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|directory| directory.join("pong").join("high_scores.json"))
+    }
+}
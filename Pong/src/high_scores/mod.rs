@@ -0,0 +1,18 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::States;
+
+pub(crate) mod high_score_table;
+pub(super) mod high_scores_plugin;
+pub(crate) mod high_scores_resource;
+
+/// Specifies whether the high-score table is shown over the rest of the Game.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, States)]
+pub(crate) enum HighScoresView {
+    Hidden,
+    Shown,
+}
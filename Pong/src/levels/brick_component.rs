@@ -0,0 +1,64 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::math::Vec2;
+use bevy::prelude::{Color, Component};
+
+use crate::levels::level::BrickPlacement;
+
+/// Marker for Brick entities, which the Ball destroys in a Levels Game.
+#[derive(Component)]
+pub(crate) struct BrickComponent {
+    color: Color,
+    /// The Brick's width and height, in pixels.
+    size: Vec2,
+    /// The number of hits the Brick can take before it is destroyed.
+    hit_points: u32,
+    max_hit_points: u32,
+    /// The points granted for destroying the Brick.
+    points: i64,
+}
+
+impl BrickComponent {
+    //
+
+    /// Returns the Brick's color, faded according to how much damage it has taken.
+    pub(crate) fn get_color(&self) -> Color {
+        let health = self.hit_points as f32 / self.max_hit_points.max(1) as f32;
+        self.color.with_a(0.35 + 0.65 * health)
+    }
+
+    /// Returns the points granted for destroying the Brick.
+    pub(crate) fn get_points(&self) -> i64 {
+        self.points
+    }
+
+    /// Returns the Brick's width and height, in pixels.
+    pub(crate) fn get_size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Indicates whether the Brick has taken as many hits as it can.
+    pub(crate) fn is_destroyed(&self) -> bool {
+        self.hit_points == 0
+    }
+
+    /// Creates a new BrickComponent instance of the specified size for the specified placement.
+    pub(crate) fn new(placement: &BrickPlacement, size: Vec2) -> Self {
+        Self {
+            color: placement.color,
+            size,
+            hit_points: placement.hit_points,
+            max_hit_points: placement.hit_points,
+            points: placement.points,
+        }
+    }
+
+    /// Records a hit by the Ball.
+    pub(crate) fn take_hit(&mut self) {
+        self.hit_points = self.hit_points.saturating_sub(1);
+    }
+}
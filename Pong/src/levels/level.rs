@@ -0,0 +1,150 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::Color;
+use serde::Deserialize;
+
+/// The character marking a gap in a level's layout. A space works as well.
+const EMPTY_CELL: char = '.';
+
+/// A kind of Brick, as described in a level file.
+#[derive(Clone, Debug, Deserialize)]
+struct BrickType {
+    /// The Brick's color, as a hex string, e.g. "d94a4a".
+    color: String,
+    /// The number of times the Ball must hit the Brick to destroy it.
+    hit_points: u32,
+    /// The points granted for destroying the Brick.
+    points: i64,
+}
+
+/// The contents of a level file. The layout is a list of rows, from the top down, in which each
+/// character is either a gap or the key of one of the level's brick types, e.g.:
+///
+/// ```json
+/// {
+///   "name": "Warm Up",
+///   "brick_types": { "R": { "color": "d94a4a", "hit_points": 1, "points": 40 } },
+///   "layout": ["RRRR", "R..R"]
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+struct LevelFile {
+    brick_types: BTreeMap<String, BrickType>,
+    layout: Vec<String>,
+    name: String,
+}
+
+/// A Brick's place in a level and what it takes to destroy it.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct BrickPlacement {
+    pub(crate) color: Color,
+    /// The Brick's column, counting from the left.
+    pub(crate) column: usize,
+    pub(crate) hit_points: u32,
+    pub(crate) points: i64,
+    /// The Brick's row, counting from the top.
+    pub(crate) row: usize,
+}
+
+/// A level of a Levels Game: the Bricks to be cleared and how they are laid out.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Level {
+    bricks: Vec<BrickPlacement>,
+    /// The number of columns in the layout, i.e. the length of its longest row.
+    columns: usize,
+    name: String,
+    /// The number of rows in the layout.
+    rows: usize,
+}
+
+impl Level {
+    //
+
+    /// Returns the level's Bricks.
+    pub(crate) fn get_bricks(&self) -> &[BrickPlacement] {
+        &self.bricks
+    }
+
+    /// Returns the number of columns and rows in the level's layout.
+    pub(crate) fn get_grid_size(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// Returns the level's name.
+    pub(crate) fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Loads a level from the specified level file.
+    pub(crate) fn load_from(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|error| error.to_string())?;
+        Self::parse(&contents)
+    }
+
+    /// Parses the contents of a level file. Fails if the layout refers to an unknown brick type,
+    /// if a brick type is invalid or if the level has no Bricks at all.
+    pub(crate) fn parse(contents: &str) -> Result<Self, String> {
+        //
+
+        let level_file = serde_json::from_str::<LevelFile>(contents).map_err(|error| error.to_string())?;
+
+        let mut brick_types = BTreeMap::new();
+        for (key, brick_type) in &level_file.brick_types {
+            let mut key_chars = key.chars();
+            let (Some(character), None) = (key_chars.next(), key_chars.next()) else {
+                return Err(format!("the brick type \"{}\" must be a single character", key));
+            };
+            if character == EMPTY_CELL || character == ' ' {
+                return Err(format!("the brick type \"{}\" marks a gap", key));
+            }
+            if brick_type.hit_points == 0 {
+                return Err(format!("the brick type \"{}\" must have at least one hit point", key));
+            }
+            let color = Color::hex(&brick_type.color)
+                .map_err(|error| format!("the brick type \"{}\" has an invalid color: {}", key, error))?;
+            brick_types.insert(character, (color, brick_type));
+        }
+
+        let mut bricks = vec![];
+        for (row, cells) in level_file.layout.iter().enumerate() {
+            for (column, character) in cells.chars().enumerate() {
+                //
+
+                if character == EMPTY_CELL || character == ' ' {
+                    continue;
+                }
+
+                let (color, brick_type) = brick_types
+                    .get(&character)
+                    .ok_or_else(|| format!("the layout uses the unknown brick type \"{}\"", character))?;
+                bricks.push(BrickPlacement {
+                    color: *color,
+                    column,
+                    hit_points: brick_type.hit_points,
+                    points: brick_type.points,
+                    row,
+                });
+            }
+        }
+
+        // Early return.
+        if bricks.is_empty() {
+            return Err(format!("the level \"{}\" has no bricks", level_file.name));
+        }
+
+        Ok(Self {
+            bricks,
+            columns: level_file.layout.iter().map(|cells| cells.chars().count()).max().unwrap_or(0),
+            name: level_file.name,
+            rows: level_file.layout.len(),
+        })
+    }
+}
@@ -0,0 +1,160 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::app::{App, FixedUpdate, Plugin};
+use bevy::math::Vec2;
+use bevy::prelude::{
+    default, in_state, Changed, Commands, DespawnRecursiveExt, Entity, IntoSystemConfigs,
+    NextState, OnEnter, OnExit, Query, Res, ResMut, Sprite, SpriteBundle, Transform, Window, With,
+};
+use bevy::window::PrimaryWindow;
+
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{GameMode, GamePlayState};
+use crate::levels::brick_component::BrickComponent;
+use crate::levels::levels_resource::LevelsResource;
+use crate::power_ups::power_ups_resource::PowerUpsResource;
+use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+use crate::simulation::SimulationSet;
+
+/// The space, in pixels, between neighboring Bricks.
+const BRICK_GAP: f32 = 6_f32;
+const BRICK_HEIGHT: f32 = 28_f32;
+const BRICK_Z_INDEX: f32 = 0.5;
+/// The space, in pixels, between the Bricks and the SideWalls.
+const SIDE_MARGIN: f32 = 20_f32;
+/// The space, in pixels, between the Bricks and the Ceiling, which leaves room for the scoreboard.
+const TOP_MARGIN: f32 = 90_f32;
+
+/// Runs the levels of a Levels Game, in which the Player clears walls of Bricks by bouncing the Ball
+/// into them. Each level's Bricks are spawned for its first Serve, stretched across the window.
+/// A Brick is destroyed once the Ball has hit it as many times as it has hit points, which grants
+/// its points. Clearing every Brick ends the round and the next level starts with the next Serve.
+/// The Game is over once the Player runs out of lives or clears the last level. The levels are read
+/// from level files. See LevelsResource.
+pub(crate) struct LevelsPlugin;
+
+impl Plugin for LevelsPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .insert_resource(LevelsResource::load())
+            .add_systems(OnEnter(GamePlayState::Serve), Self::spawn_level)
+            .add_systems(OnEnter(GamePlayState::StartMenu), Self::despawn_bricks)
+            .add_systems(OnExit(GamePlayState::GameOver), Self::despawn_bricks)
+            .add_systems(
+                FixedUpdate,
+                (
+                    Self::clear_destroyed_bricks.run_if(in_state(GamePlayState::Rally)),
+                    Self::show_brick_damage,
+                )
+                    .chain()
+                    .in_set(SimulationSet::Outcome),
+            );
+    }
+}
+
+impl LevelsPlugin {
+    //
+
+    /// Removes the Bricks that the Ball has destroyed, granting their points, multiplied by the
+    /// ScoreMultiplier power-up, and completes the level once the last of them is gone.
+    fn clear_destroyed_bricks(
+        brick_query: Query<(Entity, &BrickComponent)>,
+        mut commands: Commands,
+        levels: Res<LevelsResource>,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        power_ups: Res<PowerUpsResource>,
+        mut scoreboard_resource: ResMut<ScoreboardResource>,
+    ) {
+        //
+
+        let mut bricks_destroyed = 0;
+        let mut bricks_left = 0;
+
+        for (entity, brick) in &brick_query {
+            match brick.is_destroyed() {
+                true => {
+                    scoreboard_resource.score += brick.get_points() * power_ups.get_score_multiplier();
+                    commands.entity(entity).despawn_recursive();
+                    bricks_destroyed += 1;
+                }
+                false => bricks_left += 1,
+            }
+        }
+
+        if bricks_destroyed > 0 && bricks_left == 0 {
+            // The RoundPlugin ends the Game once the last level is complete.
+            scoreboard_resource.complete_level(levels.get_level_count());
+            next_game_play.set(GamePlayState::PointScored);
+        }
+    }
+
+    /// Removes the Bricks once the Game has been left or is about to start over.
+    fn despawn_bricks(brick_query: Query<Entity, With<BrickComponent>>, mut commands: Commands) {
+        for brick in &brick_query {
+            commands.entity(brick).despawn_recursive();
+        }
+    }
+
+    /// Fades each Brick that the Ball has hit according to the hits it has left.
+    fn show_brick_damage(mut brick_query: Query<(&BrickComponent, &mut Sprite), Changed<BrickComponent>>) {
+        for (brick, mut sprite) in &mut brick_query {
+            sprite.color = brick.get_color();
+        }
+    }
+
+    /// Spawns the Bricks of the level being played, unless they are still standing from an earlier
+    /// round of the level.
+    fn spawn_level(
+        brick_query: Query<(), With<BrickComponent>>,
+        mut commands: Commands,
+        game_settings: Res<GameSettingsResource>,
+        levels: Res<LevelsResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        //
+
+        // Early return.
+        if game_settings.game_mode != GameMode::Levels || !brick_query.is_empty() {
+            return;
+        }
+
+        let (Ok(window), Some(level)) = (window_query.get_single(), levels.get_level(scoreboard_resource.get_level()))
+        else {
+            return;
+        };
+
+        // Stretch the columns across the window.
+        let (columns, _) = level.get_grid_size();
+        let columns = columns.max(1) as f32;
+        let brick_width = (window.width() - 2_f32 * SIDE_MARGIN - (columns - 1_f32) * BRICK_GAP) / columns;
+        let brick_size = Vec2::new(brick_width, BRICK_HEIGHT);
+
+        for placement in level.get_bricks() {
+            //
+
+            let x = SIDE_MARGIN + placement.column as f32 * (brick_width + BRICK_GAP) + brick_width / 2_f32;
+            let y = window.height() - TOP_MARGIN - placement.row as f32 * (BRICK_HEIGHT + BRICK_GAP) - BRICK_HEIGHT / 2_f32;
+            let brick = BrickComponent::new(placement, brick_size);
+
+            let sprite_bundle = SpriteBundle {
+                sprite: Sprite {
+                    color: brick.get_color(),
+                    custom_size: Some(brick_size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, BRICK_Z_INDEX),
+                ..default()
+            };
+
+            commands.spawn((sprite_bundle, brick));
+        }
+    }
+}
@@ -0,0 +1,95 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::fs;
+use std::path::Path;
+
+use bevy::asset::io::file::FileAssetReader;
+use bevy::log::warn;
+use bevy::prelude::Resource;
+
+use crate::levels::level::Level;
+
+/// The first level, built into the Game so that a Levels Game can be played even if the level
+/// files cannot be read.
+const BUILT_IN_LEVEL: &str = include_str!("../../assets/levels/01_warm_up.json");
+
+/// Holds the levels of a Levels Game, in the order in which they are played. The levels are read
+/// from the JSON files in the "assets/levels" folder, in the order of their file names.
+#[derive(Resource)]
+pub(crate) struct LevelsResource {
+    levels: Vec<Level>,
+}
+
+impl LevelsResource {
+    //
+
+    /// Returns the level at the specified index, counting from zero, if there is one.
+    pub(crate) fn get_level(&self, index: usize) -> Option<&Level> {
+        self.levels.get(index)
+    }
+
+    /// Returns the number of levels.
+    pub(crate) fn get_level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Loads the levels from the level files in the assets folder. Level files that cannot be read
+    /// are skipped and, if none can be read, only the built-in level is played.
+    pub(crate) fn load() -> Self {
+        //
+
+        let directory = FileAssetReader::get_base_path().join("assets").join("levels");
+
+        match Self::load_from(&directory) {
+            Ok(levels) => levels,
+            Err(error) => {
+                warn!("Unable to load the levels from {}: {}", directory.display(), error);
+                Self::default()
+            }
+        }
+    }
+
+    /// Loads the levels from the level files in the specified folder. Level files that cannot be
+    /// read are skipped. Fails if there are no readable level files.
+    pub(crate) fn load_from(directory: &Path) -> Result<Self, String> {
+        //
+
+        let mut paths = fs::read_dir(directory)
+            .map_err(|error| error.to_string())?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut levels = vec![];
+        for path in paths {
+            match Level::load_from(&path) {
+                Ok(level) => levels.push(level),
+                Err(error) => warn!("Skipping the level {}: {}", path.display(), error),
+            }
+        }
+
+        match levels.is_empty() {
+            true => Err("there are no level files".to_string()),
+            false => Ok(Self::new(levels)),
+        }
+    }
+
+    /// Creates a new instance holding the specified levels.
+    pub(crate) fn new(levels: Vec<Level>) -> Self {
+        Self {
+            levels,
+        }
+    }
+}
+
+/// Provides default instantiation, holding only the built-in level.
+impl Default for LevelsResource {
+    fn default() -> Self {
+        Self::new(vec![Level::parse(BUILT_IN_LEVEL).expect("the built-in level is valid")])
+    }
+}
@@ -0,0 +1,10 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+pub(crate) mod brick_component;
+pub(crate) mod level;
+pub(crate) mod levels_plugin;
+pub(crate) mod levels_resource;
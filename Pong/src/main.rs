@@ -1,23 +1,45 @@
 use bevy::prelude::*;
+use helpers_for_bevy::text_input::text_input_plugin::TextInputPlugin;
 
 use physical_interactions::physical_interactions_plugin::PhysicalInteractionsPlugin;
 
 use crate::ball::ball_plugin::BallPlugin;
 use crate::camera::camera_plugin::PongCameraPlugin;
+use crate::computer_opponent::computer_opponent_plugin::ComputerOpponentPlugin;
+use crate::controls::controls_plugin::ControlsPlugin;
 use crate::game_controller::game_controller_plugin::GameControllerPlugin;
 use crate::game_controller::{GamePlayState, SoundSetting};
+use crate::high_scores::high_scores_plugin::HighScoresPlugin;
+use crate::levels::levels_plugin::LevelsPlugin;
 use crate::paddle::paddle_plugin::PaddlePlugin;
+use crate::power_ups::power_ups_plugin::PowerUpsPlugin;
+use crate::replay::replay_plugin::ReplayPlugin;
+use crate::replay::PlaybackState;
+use crate::round::round_plugin::RoundPlugin;
 use crate::scoreboard::scoreboard_plugin::ScoreboardPlugin;
+use crate::simulation::simulation_plugin::SimulationPlugin;
 use crate::sound_player::sound_player_plugin::SoundPlayerPlugin;
+use crate::start_menu::start_menu_plugin::StartMenuPlugin;
 use std::sync::LazyLock;
 
 mod ball;
 mod camera;
+mod computer_opponent;
+mod controls;
 mod game_controller;
+mod high_scores;
+mod levels;
+mod menu_theme;
 mod paddle;
 mod physical_interactions;
+mod power_ups;
+mod replay;
+mod round;
 mod scoreboard;
+mod simulation;
 mod sound_player;
+mod start_menu;
+mod tests;
 
 // Pong Game
 //
@@ -46,14 +68,23 @@ fn main() {
             DefaultPlugins.set(window_plugin),
             PongCameraPlugin,
             SoundPlayerPlugin,
+            SimulationPlugin,
             BallPlugin,
             PaddlePlugin,
+            ComputerOpponentPlugin,
             ScoreboardPlugin,
             PhysicalInteractionsPlugin,
+            RoundPlugin,
             GameControllerPlugin,
+            StartMenuPlugin,
+            ControlsPlugin,
+            HighScoresPlugin,
+            TextInputPlugin::default(),
         ))
+        .add_plugins((LevelsPlugin, PowerUpsPlugin, ReplayPlugin))
         .add_systems(FixedUpdate, bevy::window::close_on_esc)
         .insert_state(SoundSetting::On)
-        .insert_state(GamePlayState::Playing)
+        .insert_state(GamePlayState::StartMenu)
+        .insert_state(PlaybackState::Off)
         .run()
 }
@@ -0,0 +1,23 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::sync::LazyLock;
+
+use bevy::prelude::{BackgroundColor, Color, Interaction};
+
+pub(crate) static BUTTON_COLOR_HOVERED: LazyLock<Color> = LazyLock::new(|| { Color::hex("976ec5").unwrap() });
+pub(crate) static BUTTON_COLOR_NORMAL: LazyLock<Color> = LazyLock::new(|| { Color::hex("875eb5").unwrap() });
+pub(crate) static BUTTON_COLOR_PRESSED: LazyLock<Color> = LazyLock::new(|| { Color::hex("774ea5").unwrap() });
+pub(crate) static MENU_TEXT_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::WHITE });
+
+/// Returns the background color that shows whether a menu button is hovered over or pressed.
+pub(crate) fn button_color(interaction: Interaction) -> BackgroundColor {
+    match interaction {
+        Interaction::Hovered => BackgroundColor(*BUTTON_COLOR_HOVERED),
+        Interaction::None => BackgroundColor(*BUTTON_COLOR_NORMAL),
+        Interaction::Pressed => BackgroundColor(*BUTTON_COLOR_PRESSED),
+    }
+}
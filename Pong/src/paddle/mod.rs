@@ -5,4 +5,5 @@
 // @author JoelDavisEngineering@Gmail.com
 
 pub(crate) mod paddle_component;
+pub(crate) mod paddle_intents_resource;
 pub(crate) mod paddle_plugin;
@@ -6,6 +6,57 @@
 
 use bevy::prelude::Component;
 
+use crate::game_controller::Player;
+use crate::paddle::paddle_plugin::PADDLE_WIDTH;
+
 /// Marker for Paddle entities.
 #[derive(Component)]
-pub(crate) struct PaddleComponent {}
+pub(crate) struct PaddleComponent {
+    /// The Player who controls the Paddle.
+    player: Player,
+    /// The Paddle's horizontal velocity during the last simulation tick, in pixels per second.
+    velocity: f32,
+    /// The Paddle's width, in pixels, which the WidePaddle power-up increases.
+    width: f32,
+}
+
+impl PaddleComponent {
+    //
+
+    /// Returns the Player who controls the Paddle.
+    pub(crate) fn get_player(&self) -> Player {
+        self.player
+    }
+
+    /// Returns the Paddle's horizontal velocity during the last simulation tick, in pixels per
+    /// second.
+    pub(crate) fn get_velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Returns the Paddle's width, in pixels.
+    pub(crate) fn get_width(&self) -> f32 {
+        self.width
+    }
+
+    /// Creates a new PaddleComponent instance. The Player's controls are kept in the
+    /// ControlsResource.
+    pub(crate) fn new(player: Player) -> Self {
+        Self {
+            player,
+            velocity: 0_f32,
+            width: PADDLE_WIDTH,
+        }
+    }
+
+    /// Records the Paddle's horizontal velocity, in pixels per second, so that it can put spin on
+    /// the Ball.
+    pub(crate) fn set_velocity(&mut self, velocity: f32) {
+        self.velocity = velocity;
+    }
+
+    /// Sets the Paddle's width, in pixels.
+    pub(crate) fn set_width(&mut self, width: f32) {
+        self.width = width;
+    }
+}
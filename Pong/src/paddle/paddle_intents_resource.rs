@@ -0,0 +1,52 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::game_controller::Player;
+
+/// What a Player asks of their Paddle.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub(crate) enum PaddleIntent {
+    /// Stay put.
+    #[default]
+    Hold,
+    /// Move at the specified fraction of the Paddle's speed, from -1 (full speed to the left) to 1
+    /// (full speed to the right).
+    Move(f32),
+    /// Move toward the specified horizontal position in the window.
+    Follow(f32),
+}
+
+/// Holds what each Player asks of their Paddle during the current simulation tick. The
+/// ControlsPlugin fills it in from each Player's input device. Anything else, e.g. a test, may fill
+/// it in instead.
+#[derive(Default, Resource)]
+pub(crate) struct PaddleIntentsResource {
+    player_one: PaddleIntent,
+    player_two: PaddleIntent,
+}
+
+impl PaddleIntentsResource {
+    //
+
+    /// Returns what the specified Player asks of their Paddle.
+    pub(crate) fn get(&self, player: Player) -> PaddleIntent {
+        match player {
+            Player::One => self.player_one,
+            Player::Two => self.player_two,
+        }
+    }
+
+    /// Sets what the specified Player asks of their Paddle.
+    pub(crate) fn set(&mut self, player: Player, intent: PaddleIntent) {
+        match player {
+            Player::One => self.player_one = intent,
+            Player::Two => self.player_two = intent,
+        }
+    }
+}
@@ -4,35 +4,44 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use bevy::app::{App, Plugin, Startup};
+use bevy::app::{App, FixedUpdate, Plugin};
 use bevy::asset::AssetServer;
-use bevy::input::ButtonInput;
 use bevy::prelude::{
-    in_state, Commands, IntoSystemConfigs, KeyCode, Query, Res, SpriteBundle, Time, Transform,
-    Update, Window, With,
+    Commands, DespawnRecursiveExt, Entity, IntoSystemConfigs, OnEnter, OnExit, Query, Res,
+    SpriteBundle, Time, Transform, Window, With, Without,
 };
 use bevy::window::PrimaryWindow;
 
-use crate::game_controller::GamePlayState;
+use crate::computer_opponent::computer_opponent_component::ComputerOpponentComponent;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{game_in_progress, GameMode, GamePlayState, Player};
 use crate::paddle::paddle_component::PaddleComponent;
-use crate::physical_interactions::{DIRECTION_BACKWARD, DIRECTION_FORWARD};
+use crate::paddle::paddle_intents_resource::{PaddleIntent, PaddleIntentsResource};
+use crate::simulation::SimulationSet;
 
 const PADDLE_DEPTH: f32 = 1_f32;
 pub(crate) const PADDLE_WIDTH: f32 = 310_f32;
 pub(crate) const PADDLE_HEIGHT: f32 = 30_f32;
 const PADDLE_SPEED: f32 = 700_f32;
+/// The top speed, in pixels per second, at which a Paddle follows the mouse pointer or a finger.
+const PADDLE_FOLLOW_SPEED: f32 = 1400_f32;
 const PADDLE_SPRITE: &str = "sprites/paddle_12.png";
 
 pub(crate) struct PaddlePlugin;
 
-/// Renders and controls the movement of the Paddle with which the ball is hit.
+/// Renders and controls the movement of the Paddles with which the ball is hit. Player One's
+/// Paddle is at the bottom of the window. In a Versus Game, Player Two's Paddle is at the top.
 impl Plugin for PaddlePlugin {
     fn build(&self, app: &mut App) {
         app //
-            .add_systems(Startup, Self::spawn_paddle)
+            .init_resource::<PaddleIntentsResource>()
+            .add_systems(OnExit(GamePlayState::StartMenu), Self::spawn_paddles)
+            .add_systems(OnEnter(GamePlayState::StartMenu), Self::despawn_paddles)
             .add_systems(
-                Update,
-                Self::move_paddle.run_if(in_state(GamePlayState::Playing)),
+                FixedUpdate,
+                Self::move_paddle
+                    .in_set(SimulationSet::Paddles)
+                    .run_if(game_in_progress),
             );
     }
 }
@@ -40,47 +49,71 @@ impl Plugin for PaddlePlugin {
 impl PaddlePlugin {
     //
 
-    /// Implements the Paddle's side-to-side movement.
+    /// Removes the Paddles at the end of a Game.
+    fn despawn_paddles(mut commands: Commands, paddle_query: Query<Entity, With<PaddleComponent>>) {
+        for paddle in &paddle_query {
+            commands.entity(paddle).despawn_recursive();
+        }
+    }
+
+    /// Implements the side-to-side movement of each Player's Paddle, as asked of it in the
+    /// PaddleIntentsResource. The computer's Paddle is moved by the ComputerOpponentPlugin.
     fn move_paddle(
-        keyboard_input: Res<ButtonInput<KeyCode>>,
-        mut paddle_query: Query<&mut Transform, With<PaddleComponent>>,
+        paddle_intents: Res<PaddleIntentsResource>,
+        mut paddle_query: Query<(&mut Transform, &mut PaddleComponent), Without<ComputerOpponentComponent>>,
         time: Res<Time>,
         window_query: Query<&Window, With<PrimaryWindow>>,
     ) {
         //
 
-        if let (Ok(window), Ok(mut paddle_transform)) =
-            (window_query.get_single(), paddle_query.get_single_mut())
-        {
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        for (mut paddle_transform, mut paddle) in &mut paddle_query {
             //
 
-            let new_direction = {
-                if keyboard_input.pressed(KeyCode::ArrowLeft) {
-                    DIRECTION_BACKWARD
-                } else if keyboard_input.pressed(KeyCode::ArrowRight) {
-                    DIRECTION_FORWARD
-                } else {
-                    return;
+            let position_x = paddle_transform.translation.x;
+
+            let new_position_x = match paddle_intents.get(paddle.get_player()) {
+                PaddleIntent::Hold => {
+                    paddle.set_velocity(0_f32);
+                    continue;
+                }
+                PaddleIntent::Move(speed_fraction) => {
+                    position_x + speed_fraction * PADDLE_SPEED * time.delta_seconds()
+                }
+                PaddleIntent::Follow(target_x) => {
+                    let max_distance = PADDLE_FOLLOW_SPEED * time.delta_seconds();
+                    position_x + (target_x - position_x).clamp(-max_distance, max_distance)
                 }
             };
 
-            let left_edge = PADDLE_WIDTH / 2_f32;
-            let right_edge = window.width() - PADDLE_WIDTH / 2_f32;
-
-            let new_position_x = paddle_transform.translation.x
-                + (new_direction * PADDLE_SPEED * time.delta_seconds());
+            let left_edge = paddle.get_width() / 2_f32;
+            let right_edge = window.width() - paddle.get_width() / 2_f32;
 
             // Constrain the Paddle to the window's viewport.
             let new_position_x = new_position_x.clamp(left_edge, right_edge);
 
+            paddle.set_velocity(Self::velocity(position_x, new_position_x, time.delta_seconds()));
             paddle_transform.translation.x = new_position_x;
         }
     }
 
-    /// Spawns the Paddle entity.
-    fn spawn_paddle(
+    /// Returns the velocity, in pixels per second, of a Paddle that moved between the specified
+    /// positions in the specified time.
+    pub(crate) fn velocity(old_position_x: f32, new_position_x: f32, delta_seconds: f32) -> f32 {
+        match delta_seconds > 0_f32 {
+            true => (new_position_x - old_position_x) / delta_seconds,
+            false => 0_f32,
+        }
+    }
+
+    /// Spawns a Paddle entity for each Player, handing Player Two's to the computer if chosen.
+    fn spawn_paddles(
         mut commands: Commands,
         asset_server: Res<AssetServer>,
+        game_settings: Res<GameSettingsResource>,
         window_query: Query<&Window, With<PrimaryWindow>>,
     ) {
         //
@@ -90,13 +123,22 @@ impl PaddlePlugin {
 
             let x = window.width() / 2.0;
 
-            let sprite_bundle = SpriteBundle {
-                transform: Transform::from_xyz(x, PADDLE_HEIGHT / 2_f32, PADDLE_DEPTH),
-                /*.with_scale(scale)*/
-                texture: asset_server.load(PADDLE_SPRITE),
-                ..Default::default()
-            };
-            commands.spawn((sprite_bundle, PaddleComponent {}));
+            let mut paddles = vec![(Player::One, PADDLE_HEIGHT / 2_f32)];
+            if game_settings.game_mode == GameMode::Versus {
+                paddles.push((Player::Two, window.height() - PADDLE_HEIGHT / 2_f32));
+            }
+
+            for (player, y) in paddles {
+                let sprite_bundle = SpriteBundle {
+                    transform: Transform::from_xyz(x, y, PADDLE_DEPTH),
+                    texture: asset_server.load(PADDLE_SPRITE),
+                    ..Default::default()
+                };
+                let mut paddle = commands.spawn((sprite_bundle, PaddleComponent::new(player)));
+                if player == Player::Two && game_settings.player_two_is_computer {
+                    paddle.insert(ComputerOpponentComponent::new(game_settings.computer_difficulty));
+                }
+            }
         }
     }
 }
@@ -4,13 +4,15 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use bevy::prelude::Event;
+use bevy::prelude::{Entity, Event};
 
 use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor;
 
 /// Models a collision between two physical actors, e.g. the ball and the floor.
 #[derive(Clone, Event)]
 pub(crate) struct CollisionEvent {
+    /// The Ball that collided, since more than one can be in play at once.
+    ball: Entity,
     pub(super) source: PhysicalInteractionActor,
     pub(super) target: PhysicalInteractionActor,
 }
@@ -18,12 +20,19 @@ pub(crate) struct CollisionEvent {
 impl CollisionEvent {
     //
 
-    /// Constructs a new CollisionEvent instance.
+    /// Returns the Ball that collided.
+    pub(crate) fn get_ball(&self) -> Entity {
+        self.ball
+    }
+
+    /// Constructs a new CollisionEvent instance for a collision of the specified Ball.
     pub(super) fn new(
         source: PhysicalInteractionActor,
         target: PhysicalInteractionActor,
+        ball: Entity,
     ) -> Self {
         Self {
+            ball,
             source,
             target,
         }
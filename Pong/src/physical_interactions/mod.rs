@@ -8,6 +8,7 @@ pub(crate) mod collision_evaluator;
 pub(super) mod collision_event;
 pub(super) mod physical_interactions_actor;
 pub(super) mod physical_interactions_plugin;
+pub(crate) mod rally_physics;
 
 pub(super) const DIRECTION_BACKWARD: f32 = -1f32;
 pub(super) const DIRECTION_FORWARD: f32 = 1f32;
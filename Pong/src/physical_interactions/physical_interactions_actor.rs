@@ -8,9 +8,15 @@
 #[derive(Clone, PartialEq)]
 pub(crate) enum PhysicalInteractionActor {
     Ball,
+    /// One of the Bricks of a Levels Game.
+    Brick,
     Ceiling,
     Floor,
     None,
     Paddle,
+    /// The bottom edge of the window in a Versus Game, which Player One defends.
+    PlayerOneGoal,
+    /// The top edge of the window in a Versus Game, which Player Two defends.
+    PlayerTwoGoal,
     SideWall,
 }
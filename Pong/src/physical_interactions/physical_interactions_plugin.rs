@@ -7,19 +7,33 @@
 use bevy::app::{App, FixedUpdate, Plugin};
 use bevy::math::bounding::{Aabb2d, BoundingCircle, IntersectsVolume};
 use bevy::math::Vec2;
-use bevy::prelude::{in_state, EventWriter, IntoSystemConfigs, Query, Transform, Window, With};
+use bevy::prelude::{
+    in_state, Commands, DespawnRecursiveExt, Entity, EventWriter, IntoSystemConfigs, Query, Res,
+    ResMut, Transform, Window, With,
+};
 use bevy::window::PrimaryWindow;
 
 use crate::ball::ball_component::BallComponent;
 use crate::ball::ball_plugin::BALL_RADIUS;
-use crate::game_controller::GamePlayState;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{GameMode, GamePlayState, Player};
+use crate::levels::brick_component::BrickComponent;
 use crate::paddle::paddle_component::PaddleComponent;
-use crate::paddle::paddle_plugin::{PADDLE_HEIGHT, PADDLE_WIDTH};
+use crate::paddle::paddle_plugin::PADDLE_HEIGHT;
 use crate::physical_interactions::collision_event::CollisionEvent;
 use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor;
+use crate::physical_interactions::rally_physics::contact_offset;
+use crate::physical_interactions::{DIRECTION_BACKWARD, DIRECTION_FORWARD};
+use crate::power_ups::power_ups_resource::PowerUpsResource;
+use crate::power_ups::PowerUpKind;
+use crate::simulation::simulation_rng_resource::SimulationRngResource;
+use crate::simulation::SimulationSet;
 
 /// Manages the movements and interactions between the Ball, Ceiling, Floor, and Walls. This
 /// includes controlling the ball direction, providing hit-detection, and invoking collision sounds.
+/// In a Versus Game, the Floor and Ceiling are the Players' goals. In a Levels Game, the Ball also
+/// bounces off the Bricks. Each Ball in play is handled on its own, and each CollisionEvent tells
+/// which Ball collided.
 pub(crate) struct PhysicalInteractionsPlugin;
 
 impl Plugin for PhysicalInteractionsPlugin {
@@ -29,11 +43,14 @@ impl Plugin for PhysicalInteractionsPlugin {
             .add_event::<CollisionEvent>()
             .add_systems(
                 FixedUpdate,
-                Self::ball_and_paddle_interaction.run_if(in_state(GamePlayState::Playing)),
-            )
-            .add_systems(
-                FixedUpdate,
-                Self::ball_and_wall_interaction.run_if(in_state(GamePlayState::Playing)),
+                (
+                    Self::ball_and_paddle_interaction,
+                    Self::ball_and_wall_interaction,
+                    Self::ball_and_brick_interaction,
+                )
+                    .chain()
+                    .in_set(SimulationSet::Collisions)
+                    .run_if(in_state(GamePlayState::Rally)),
             );
     }
 }
@@ -41,18 +58,92 @@ impl Plugin for PhysicalInteractionsPlugin {
 impl PhysicalInteractionsPlugin {
     //
 
+    /// Handles the interaction between the Ball and the Bricks of a Levels Game. The Ball bounces
+    /// off the face of the Brick that it struck and the Brick takes a hit. When the Ball touches
+    /// two Bricks at once, it bounces off only the nearer, so that the two bounces do not cancel
+    /// one another out.
+    fn ball_and_brick_interaction(
+        mut ball_query: Query<(Entity, &Transform, &mut BallComponent)>,
+        mut brick_query: Query<(Entity, &Transform, &mut BrickComponent)>,
+        mut event_writer: EventWriter<CollisionEvent>,
+        mut rng: ResMut<SimulationRngResource>,
+    ) {
+        for (ball_entity, ball_transform, mut ball) in &mut ball_query {
+            //
+
+            let ball_center = Vec2::new(ball_transform.translation.x, ball_transform.translation.y);
+            let ball_bounds = BoundingCircle::new(ball_center, BALL_RADIUS);
+            let ball_direction = ball.get_direction();
+
+            // Find the nearest Brick that the Ball is touching and heading into.
+            let mut struck_brick: Option<(Entity, Vec2, f32)> = None;
+            for (brick_entity, brick_transform, brick) in &brick_query {
+                //
+
+                let brick_center = Vec2::new(brick_transform.translation.x, brick_transform.translation.y);
+                let brick_bounds = Aabb2d::new(brick_center, brick.get_size() / 2_f32);
+
+                if brick.is_destroyed() || !ball_bounds.intersects(&brick_bounds) {
+                    continue;
+                }
+
+                let normal = brick_face_normal(ball_center, brick_center, brick.get_size() / 2_f32);
+
+                // Prevent Ball jitter by only bouncing a Ball that is heading into the Brick.
+                if ball_direction.dot(normal) >= 0_f32 {
+                    continue;
+                }
+
+                let distance = ball_center.distance_squared(brick_bounds.closest_point(ball_center));
+                if struck_brick.is_none_or(|(_, _, nearest_distance)| distance < nearest_distance) {
+                    struck_brick = Some((brick_entity, normal, distance));
+                }
+            }
+
+            if let Some((brick_entity, normal, _)) = struck_brick {
+                //
+
+                let new_ball_direction = ball_direction - 2_f32 * ball_direction.dot(normal) * normal;
+                ball.set_direction(new_ball_direction, &mut *rng);
+
+                if let Ok((_, _, mut brick)) = brick_query.get_mut(brick_entity) {
+                    brick.take_hit();
+                }
+
+                // Post an event so that the other areas of the code know that the Ball has hit a
+                // Brick.
+                event_writer.send(CollisionEvent::new(
+                    PhysicalInteractionActor::Ball,
+                    PhysicalInteractionActor::Brick,
+                    ball_entity,
+                ));
+            }
+        }
+    }
+
     /// Handles the interaction between the Ball, Ceiling, Floor, and Walls. This includes
-    /// hit-detection, and invocation of collision sounds.
+    /// hit-detection, and invocation of collision sounds. In a Solo or Levels Game, a Ball that
+    /// reaches the Floor is out of play and is removed. The Player misses once the last Ball is.
     fn ball_and_wall_interaction(
-        mut ball_query: Query<(&Transform, &mut BallComponent)>,
+        mut ball_query: Query<(Entity, &Transform, &mut BallComponent)>,
+        mut commands: Commands,
         mut event_writer: EventWriter<CollisionEvent>,
+        game_settings: Res<GameSettingsResource>,
+        mut rng: ResMut<SimulationRngResource>,
         window_query: Query<&Window, With<PrimaryWindow>>,
     ) {
         //
 
-        if let (Ok(window), Ok((transform, mut ball))) =
-            (window_query.get_single(), ball_query.get_single_mut())
-        {
+        let (ceiling, floor) = match game_settings.game_mode {
+            GameMode::Solo | GameMode::Levels => (PhysicalInteractionActor::Ceiling, PhysicalInteractionActor::Floor),
+            GameMode::Versus => (PhysicalInteractionActor::PlayerTwoGoal, PhysicalInteractionActor::PlayerOneGoal),
+        };
+
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        for (ball_entity, transform, mut ball) in &mut ball_query {
             //
 
             let mut collision_occurred = false;
@@ -83,7 +174,7 @@ impl PhysicalInteractionsPlugin {
                 // If the ball is traveling towards the ceiling and has hit it, then reverse the y-direction
                 new_ball_direction.y = -new_ball_direction.y;
                 collision_occurred = true;
-                target = crate::physical_interactions::physical_interactions_plugin::PhysicalInteractionActor::Ceiling;
+                target = ceiling.clone();
             } else if DirectionDetector::does_vector_point_down(&ball.get_direction())
                 && ((transform.translation.y - BALL_RADIUS) <= 0_f32)
             {
@@ -93,37 +184,46 @@ impl PhysicalInteractionsPlugin {
                 new_ball_direction.y = -new_ball_direction.y;
                 collision_occurred = true;
 
-                target = PhysicalInteractionActor::Floor;
+                target = floor.clone();
             }
 
             if collision_occurred {
                 //
 
-                ball.set_direction(new_ball_direction);
+                match target == PhysicalInteractionActor::Floor {
+                    true => commands.entity(ball_entity).despawn_recursive(),
+                    false => ball.set_direction(new_ball_direction, &mut *rng),
+                }
 
                 // Post an event so that the other areas of the code know that the Ball has
                 // hit one the confines of the room.
                 event_writer.send(CollisionEvent::new(
                     PhysicalInteractionActor::Ball,
                     target,
+                    ball_entity,
                 ));
             }
         }
     }
 
-    /// Handles the interaction between the Ball and the Paddle. This includes hit-detection,
-    /// and invocation of collision sounds.
+    /// Handles the interaction between the Ball and the Paddles. This includes hit-detection,
+    /// and invocation of collision sounds. The Paddle returns the Ball at an angle that depends on
+    /// where the Ball struck it and on how it was moving, and the Ball speeds up with each return.
+    /// See RallyCurve. While the StickyPaddle power-up is in effect, Player One's Paddle holds each
+    /// Ball that it returns for a moment before releasing it.
     fn ball_and_paddle_interaction(
-        mut ball_query: Query<(&Transform, &mut BallComponent)>,
+        mut ball_query: Query<(Entity, &Transform, &mut BallComponent)>,
         mut event_writer: EventWriter<CollisionEvent>,
-        paddle_query: Query<&Transform, With<PaddleComponent>>,
+        game_settings: Res<GameSettingsResource>,
+        paddle_query: Query<(&Transform, &PaddleComponent)>,
+        power_ups: Res<PowerUpsResource>,
     ) {
         //
 
-        if let Ok((ball_transform, mut ball)) = ball_query.get_single_mut() {
+        for (ball_entity, ball_transform, mut ball) in &mut ball_query {
             //
 
-            if let Ok(paddle_transform) = paddle_query.get_single() {
+            for (paddle_transform, paddle) in &paddle_query {
                 //
 
                 // *** Manually test for intersection of the Ball into the bounds of the Paddle. ***
@@ -136,43 +236,67 @@ impl PhysicalInteractionsPlugin {
                     paddle_transform.translation.x,
                     paddle_transform.translation.y,
                 );
-                let paddle_half_size = Vec2::new(PADDLE_WIDTH / 2_f32, PADDLE_HEIGHT / 2_f32);
+                let paddle_half_size = Vec2::new(paddle.get_width() / 2_f32, PADDLE_HEIGHT / 2_f32);
                 let paddle_bounds = Aabb2d::new(paddle_position, paddle_half_size);
 
                 let ball_center =
                     Vec2::new(ball_transform.translation.x, ball_transform.translation.y);
                 let ball_bounds = BoundingCircle::new(ball_center, BALL_RADIUS);
 
-                let mut new_ball_direction: Vec2 = ball.get_direction();
-
                 // Are they touching?
                 if ball_bounds.intersects(&paddle_bounds) {
                     //
 
-                    // *** Reverse the Ball's vertical direction ***
+                    // The Ball is returned upwards from Player One's Paddle and downwards from
+                    // Player Two's.
+                    let vertical_direction = match paddle.get_player() {
+                        Player::One => DIRECTION_FORWARD,
+                        Player::Two => DIRECTION_BACKWARD,
+                    };
 
-                    // Prevent Ball jitter by only changing the direction upwards
-                    let previous_vertical_direction = new_ball_direction.y;
-                    new_ball_direction.y = new_ball_direction.y.abs();
+                    // Prevent Ball jitter by only returning a Ball that is heading toward the Paddle.
+                    if ball.get_direction().y.signum() == vertical_direction {
+                        continue;
+                    }
 
-                    if new_ball_direction.y != previous_vertical_direction {
-                        //
+                    let rally_curve = game_settings.pace.curve();
+                    let offset = contact_offset(ball_center.x, paddle_position.x, paddle_half_size.x);
+                    let new_ball_direction = rally_curve.deflect(offset, paddle.get_velocity(), vertical_direction);
 
-                        ball.set_direction(new_ball_direction);
+                    ball.return_from_paddle(new_ball_direction, &rally_curve);
 
-                        // Post an event so that the other areas of the code know that the Ball has
-                        // hit the Paddle.
-                        event_writer.send(CollisionEvent::new(
-                            PhysicalInteractionActor::Ball,
-                            PhysicalInteractionActor::Paddle,
-                        ));
+                    if paddle.get_player() == Player::One && power_ups.is_active(PowerUpKind::StickyPaddle) {
+                        ball.stick_to_paddle(offset * paddle_half_size.x);
                     }
+
+                    // Post an event so that the other areas of the code know that the Ball has
+                    // hit the Paddle.
+                    event_writer.send(CollisionEvent::new(
+                        PhysicalInteractionActor::Ball,
+                        PhysicalInteractionActor::Paddle,
+                        ball_entity,
+                    ));
                 }
             }
         }
     }
 }
 
+/// Returns the outward normal of the face of a Brick, of the specified center and half size, that a
+/// Ball centered at the specified point struck: a side of the Brick if the Ball is further beyond
+/// it horizontally than vertically and, otherwise, its top or bottom.
+fn brick_face_normal(ball_center: Vec2, brick_center: Vec2, brick_half_size: Vec2) -> Vec2 {
+    //
+
+    let offset = ball_center - brick_center;
+    let beyond = offset.abs() - brick_half_size;
+
+    match beyond.x > beyond.y {
+        true => Vec2::new(offset.x.signum(), 0_f32),
+        false => Vec2::new(0_f32, offset.y.signum()),
+    }
+}
+
 /// Determines the direction of a vector.
 pub(crate) struct DirectionDetector;
 
@@ -0,0 +1,122 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::math::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// Controls how fast the Ball travels during a rally and how sharply the Paddles can angle it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RallyCurve {
+    /// The speed, in pixels per second, of a newly served Ball.
+    pub(crate) initial_speed: f32,
+    /// The speed, in pixels per second, added each time a Paddle returns the Ball.
+    pub(crate) speed_increase_per_hit: f32,
+    /// The fastest, in pixels per second, that the Ball can travel.
+    pub(crate) max_speed: f32,
+    /// The largest angle, in radians from straight up or down, at which a Paddle can return the
+    /// Ball. Keeping it well under 90° prevents near-horizontal returns.
+    pub(crate) max_deflection_angle: f32,
+    /// The angle, in radians, added to a return for each pixel per second that the Paddle was
+    /// moving, so that a moving Paddle puts spin on the Ball.
+    pub(crate) spin_factor: f32,
+}
+
+impl RallyCurve {
+    //
+
+    /// Returns the direction in which a Paddle returns the Ball.
+    ///
+    /// The contact offset is where the Ball struck the Paddle, from -1 at its left end through 0
+    /// at its center to 1 at its right end: the further from the center, the sharper the angle.
+    /// The Paddle's velocity, in pixels per second, adds spin in the direction of its movement.
+    /// A positive vertical direction sends the Ball up and a negative one, down.
+    pub(crate) fn deflect(&self, contact_offset: f32, paddle_velocity: f32, vertical_direction: f32) -> Vec2 {
+        //
+
+        let angle = contact_offset.clamp(-1_f32, 1_f32) * self.max_deflection_angle
+            + paddle_velocity * self.spin_factor;
+        let angle = angle.clamp(-self.max_deflection_angle, self.max_deflection_angle);
+
+        Vec2::new(angle.sin(), vertical_direction.signum() * angle.cos())
+    }
+
+    /// Returns the speed of the Ball after the Paddles have returned it the specified number of
+    /// times in a rally.
+    pub(crate) fn speed_after_hits(&self, hits: u32) -> f32 {
+        (self.initial_speed + self.speed_increase_per_hit * hits as f32).min(self.max_speed)
+    }
+}
+
+/// Returns where the Ball struck a Paddle, from -1 at its left end through 0 at its center to 1 at
+/// its right end. A Ball that clips an end counts as striking the end.
+pub(crate) fn contact_offset(ball_x: f32, paddle_x: f32, paddle_half_width: f32) -> f32 {
+    //
+
+    // Early return.
+    if paddle_half_width <= 0_f32 {
+        return 0_f32;
+    }
+
+    ((ball_x - paddle_x) / paddle_half_width).clamp(-1_f32, 1_f32)
+}
+
+/// Specifies how quickly a Game's rallies become hectic.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) enum Pace {
+    Relaxed,
+    #[default]
+    Standard,
+    Frantic,
+}
+
+impl Pace {
+    //
+
+    /// Returns the RallyCurve for the Pace.
+    pub(crate) fn curve(&self) -> RallyCurve {
+        match self {
+            Pace::Relaxed => RallyCurve {
+                initial_speed: 500_f32,
+                speed_increase_per_hit: 15_f32,
+                max_speed: 900_f32,
+                max_deflection_angle: 50_f32.to_radians(),
+                spin_factor: 0.0002,
+            },
+            Pace::Standard => RallyCurve {
+                initial_speed: 700_f32,
+                speed_increase_per_hit: 25_f32,
+                max_speed: 1300_f32,
+                max_deflection_angle: 60_f32.to_radians(),
+                spin_factor: 0.0004,
+            },
+            Pace::Frantic => RallyCurve {
+                initial_speed: 850_f32,
+                speed_increase_per_hit: 40_f32,
+                max_speed: 1700_f32,
+                max_deflection_angle: 65_f32.to_radians(),
+                spin_factor: 0.0006,
+            },
+        }
+    }
+
+    /// Returns the next faster Pace, wrapping around from the fastest to the slowest.
+    pub(crate) fn next(&self) -> Self {
+        match self {
+            Pace::Relaxed => Pace::Standard,
+            Pace::Standard => Pace::Frantic,
+            Pace::Frantic => Pace::Relaxed,
+        }
+    }
+
+    /// Returns the name shown for the Pace.
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            Pace::Relaxed => "Relaxed",
+            Pace::Standard => "Standard",
+            Pace::Frantic => "Frantic",
+        }
+    }
+}
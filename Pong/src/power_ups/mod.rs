@@ -0,0 +1,73 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Color;
+
+pub(crate) mod power_up_component;
+pub(crate) mod power_ups_plugin;
+pub(crate) mod power_ups_resource;
+
+/// Specifies the power-ups that the Player can catch with their Paddle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PowerUpKind {
+    /// Widens the Player's Paddle.
+    WidePaddle,
+    /// Slows every Ball down.
+    SlowBall,
+    /// Splits each Ball in play into three.
+    MultiBall,
+    /// Holds each Ball that the Paddle returns for a moment, so that the Player can aim it.
+    StickyPaddle,
+    /// Doubles the points granted.
+    ScoreMultiplier,
+}
+
+impl PowerUpKind {
+    //
+
+    /// Every kind of power-up, in the order in which they are shown.
+    pub(crate) const ALL: [PowerUpKind; 5] = [
+        PowerUpKind::WidePaddle,
+        PowerUpKind::SlowBall,
+        PowerUpKind::MultiBall,
+        PowerUpKind::StickyPaddle,
+        PowerUpKind::ScoreMultiplier,
+    ];
+
+    /// Returns the color in which the power-up is shown.
+    pub(crate) fn color(&self) -> Color {
+        match self {
+            PowerUpKind::WidePaddle => Color::rgb(0.2, 0.65, 0.95),
+            PowerUpKind::SlowBall => Color::rgb(0.3, 0.8, 0.45),
+            PowerUpKind::MultiBall => Color::rgb(0.95, 0.55, 0.15),
+            PowerUpKind::StickyPaddle => Color::rgb(0.85, 0.3, 0.6),
+            PowerUpKind::ScoreMultiplier => Color::rgb(0.95, 0.8, 0.2),
+        }
+    }
+
+    /// Returns how long, in seconds, the power-up lasts once caught, or None if it takes effect
+    /// only once, when caught.
+    pub(crate) fn duration_secs(&self) -> Option<f32> {
+        match self {
+            PowerUpKind::WidePaddle => Some(12.0),
+            PowerUpKind::SlowBall => Some(8.0),
+            PowerUpKind::MultiBall => None,
+            PowerUpKind::StickyPaddle => Some(10.0),
+            PowerUpKind::ScoreMultiplier => Some(15.0),
+        }
+    }
+
+    /// Returns the name shown for the power-up.
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            PowerUpKind::WidePaddle => "Wide",
+            PowerUpKind::SlowBall => "Slow",
+            PowerUpKind::MultiBall => "Multi",
+            PowerUpKind::StickyPaddle => "Sticky",
+            PowerUpKind::ScoreMultiplier => "x2",
+        }
+    }
+}
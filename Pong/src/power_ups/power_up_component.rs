@@ -0,0 +1,31 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Component;
+
+use crate::power_ups::PowerUpKind;
+
+/// Marker for a power-up falling toward the Player's Paddle, waiting to be caught.
+#[derive(Component)]
+pub(crate) struct PowerUpComponent {
+    kind: PowerUpKind,
+}
+
+impl PowerUpComponent {
+    //
+
+    /// Returns the kind of power-up.
+    pub(crate) fn get_kind(&self) -> PowerUpKind {
+        self.kind
+    }
+
+    /// Creates a new PowerUpComponent instance of the specified kind.
+    pub(crate) fn new(kind: PowerUpKind) -> Self {
+        Self {
+            kind,
+        }
+    }
+}
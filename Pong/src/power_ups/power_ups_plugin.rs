@@ -0,0 +1,290 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::app::{App, FixedUpdate, Plugin};
+use bevy::asset::AssetServer;
+use bevy::math::bounding::{Aabb2d, IntersectsVolume};
+use bevy::math::Vec2;
+use bevy::prelude::{
+    default, in_state, BuildChildren, Color, Commands, DespawnRecursiveExt, Entity, EventReader,
+    IntoSystemConfigs, OnEnter, Query, Res, ResMut, Sprite, SpriteBundle, Text, Text2dBundle,
+    TextStyle, Time, Transform, Window, With,
+};
+use bevy::window::PrimaryWindow;
+use rand::Rng;
+
+use crate::ball::ball_component::BallComponent;
+use crate::ball::ball_plugin::BallPlugin;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{game_in_progress, GameMode, GamePlayState, Player};
+use crate::paddle::paddle_component::PaddleComponent;
+use crate::paddle::paddle_plugin::{PADDLE_HEIGHT, PADDLE_WIDTH};
+use crate::physical_interactions::collision_evaluator::CollisionEvaluator;
+use crate::physical_interactions::collision_event::CollisionEvent;
+use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor::{
+    Ball, Brick, Ceiling,
+};
+use crate::power_ups::power_up_component::PowerUpComponent;
+use crate::power_ups::power_ups_resource::PowerUpsResource;
+use crate::power_ups::PowerUpKind;
+use crate::simulation::simulation_rng_resource::SimulationRngResource;
+use crate::simulation::SimulationSet;
+
+/// The chance that a power-up drops when the Ball hits a Brick.
+const BRICK_DROP_CHANCE: f64 = 0.2;
+/// The chance that a power-up drops when the Ball hits the Ceiling.
+const CEILING_DROP_CHANCE: f64 = 0.1;
+/// The most Balls that the MultiBall power-up puts in play.
+const MAX_BALLS: usize = 6;
+/// The angles, in radians, by which the MultiBall power-up turns the new Balls away from the Ball
+/// that they split from.
+const MULTI_BALL_SPLIT_ANGLES: [f32; 2] = [-0.4, 0.4];
+/// The speed, in pixels per second, at which power-ups fall toward the Paddle.
+const POWER_UP_FALL_SPEED: f32 = 220_f32;
+const POWER_UP_FONT_SIZE: f32 = 18_f32;
+const POWER_UP_SIZE: Vec2 = Vec2::new(70_f32, 28_f32);
+const POWER_UP_Z_INDEX: f32 = 1.5;
+
+/// Drops power-ups for the Player to catch in a Solo or Levels Game. When the Ball hits a Brick or
+/// the Ceiling, a power-up may drop from where the Ball is and fall toward the Player's Paddle.
+/// Catching it puts it into effect for a while: WidePaddle widens the Paddle, SlowBall slows the
+/// Balls down, StickyPaddle has the Paddle hold each Ball that it returns for a moment and
+/// ScoreMultiplier doubles the points granted. MultiBall instead splits each Ball in play into
+/// three as soon as it is caught. The power-ups end with the round. See PowerUpsResource.
+pub(crate) struct PowerUpsPlugin;
+
+impl Plugin for PowerUpsPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .init_resource::<PowerUpsResource>()
+            .add_systems(OnEnter(GamePlayState::PointScored), Self::end_power_ups)
+            .add_systems(OnEnter(GamePlayState::StartMenu), Self::end_power_ups)
+            .add_systems(
+                FixedUpdate,
+                Self::move_power_ups
+                    .in_set(SimulationSet::Ball)
+                    .run_if(in_state(GamePlayState::Rally)),
+            )
+            .add_systems(
+                FixedUpdate,
+                Self::catch_power_ups
+                    .in_set(SimulationSet::Collisions)
+                    .run_if(in_state(GamePlayState::Rally)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    Self::drop_power_ups.run_if(in_state(GamePlayState::Rally)),
+                    Self::tick_power_ups.run_if(in_state(GamePlayState::Rally)),
+                    Self::apply_paddle_width.run_if(game_in_progress),
+                )
+                    .chain()
+                    .in_set(SimulationSet::Outcome),
+            );
+    }
+}
+
+impl PowerUpsPlugin {
+    //
+
+    /// Sizes Player One's Paddle according to whether the WidePaddle power-up is in effect,
+    /// keeping the Paddle within the window.
+    fn apply_paddle_width(
+        mut paddle_query: Query<(&mut Transform, &mut PaddleComponent)>,
+        power_ups: Res<PowerUpsResource>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        //
+
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        let width = power_ups.get_paddle_width();
+
+        for (mut transform, mut paddle) in &mut paddle_query {
+            //
+
+            if paddle.get_player() != Player::One || paddle.get_width() == width {
+                continue;
+            }
+
+            paddle.set_width(width);
+            transform.scale.x = width / PADDLE_WIDTH;
+            transform.translation.x = transform.translation.x.clamp(width / 2_f32, window.width() - width / 2_f32);
+        }
+    }
+
+    /// Puts each power-up that Player One's Paddle catches into effect.
+    fn catch_power_ups(
+        asset_server: Res<AssetServer>,
+        ball_query: Query<(&Transform, &BallComponent)>,
+        mut commands: Commands,
+        paddle_query: Query<(&Transform, &PaddleComponent)>,
+        mut power_ups: ResMut<PowerUpsResource>,
+        power_up_query: Query<(Entity, &Transform, &PowerUpComponent)>,
+    ) {
+        //
+
+        let Some((paddle_transform, paddle)) =
+            paddle_query.iter().find(|(_, paddle)| paddle.get_player() == Player::One)
+        else {
+            return;
+        };
+
+        let paddle_bounds = Aabb2d::new(
+            paddle_transform.translation.truncate(),
+            Vec2::new(paddle.get_width() / 2_f32, PADDLE_HEIGHT / 2_f32),
+        );
+
+        let mut ball_count = ball_query.iter().count();
+
+        for (entity, transform, power_up) in &power_up_query {
+            //
+
+            let power_up_bounds = Aabb2d::new(transform.translation.truncate(), POWER_UP_SIZE / 2_f32);
+            if !power_up_bounds.intersects(&paddle_bounds) {
+                continue;
+            }
+
+            commands.entity(entity).despawn_recursive();
+
+            match power_up.get_kind() {
+                PowerUpKind::MultiBall => {
+                    // Split each Ball in play, up to the most Balls allowed.
+                    for (ball_transform, ball) in &ball_query {
+                        for angle in MULTI_BALL_SPLIT_ANGLES {
+                            if ball_count >= MAX_BALLS {
+                                break;
+                            }
+                            let sprite_bundle = BallPlugin::ball_sprite_bundle(&asset_server, ball_transform.translation);
+                            commands.spawn((sprite_bundle, ball.split(angle)));
+                            ball_count += 1;
+                        }
+                    }
+                }
+                kind => power_ups.activate(kind),
+            }
+        }
+    }
+
+    /// Drops a power-up, at random, from where a Ball hit a Brick or the Ceiling. Power-ups are
+    /// only dropped in a Solo or Levels Game, and only if they have been turned on.
+    fn drop_power_ups(
+        ball_query: Query<&Transform, With<BallComponent>>,
+        mut commands: Commands,
+        mut event_reader: EventReader<CollisionEvent>,
+        game_settings: Res<GameSettingsResource>,
+        mut rng: ResMut<SimulationRngResource>,
+    ) {
+        //
+
+        // Early return.
+        if !game_settings.power_ups || game_settings.game_mode == GameMode::Versus {
+            event_reader.clear();
+            return;
+        }
+
+        for collision_event in event_reader.read() {
+            //
+
+            let mut evaluator = CollisionEvaluator::new(collision_event);
+
+            let drop_chance = if evaluator.did(Ball).collide_with(Brick).evaluate() {
+                BRICK_DROP_CHANCE
+            } else if evaluator.did(Ball).collide_with(Ceiling).evaluate() {
+                CEILING_DROP_CHANCE
+            } else {
+                continue;
+            };
+
+            let Ok(ball_transform) = ball_query.get(collision_event.get_ball()) else {
+                continue;
+            };
+
+            if !rng.gen_bool(drop_chance) {
+                continue;
+            }
+
+            let kind = PowerUpKind::ALL[rng.gen_range(0..PowerUpKind::ALL.len())];
+            Self::spawn_power_up(&mut commands, kind, ball_transform.translation.truncate());
+        }
+    }
+
+    /// Ends every power-up and removes those still falling, once the round is over or the Game has
+    /// been left.
+    fn end_power_ups(
+        mut commands: Commands,
+        mut power_ups: ResMut<PowerUpsResource>,
+        power_up_query: Query<Entity, With<PowerUpComponent>>,
+    ) {
+        //
+
+        power_ups.clear();
+
+        for power_up in &power_up_query {
+            commands.entity(power_up).despawn_recursive();
+        }
+    }
+
+    /// Moves each power-up down toward the Paddle, removing those that the Player missed.
+    fn move_power_ups(
+        mut commands: Commands,
+        mut power_up_query: Query<(Entity, &mut Transform), With<PowerUpComponent>>,
+        time: Res<Time>,
+    ) {
+        for (entity, mut transform) in &mut power_up_query {
+            //
+
+            transform.translation.y -= POWER_UP_FALL_SPEED * time.delta_seconds();
+
+            if transform.translation.y < -POWER_UP_SIZE.y {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+
+    /// Spawns a power-up of the specified kind, labeled with its name, at the specified point.
+    fn spawn_power_up(commands: &mut Commands, kind: PowerUpKind, position: Vec2) {
+        //
+
+        let sprite_bundle = SpriteBundle {
+            sprite: Sprite {
+                color: kind.color(),
+                custom_size: Some(POWER_UP_SIZE),
+                ..default()
+            },
+            transform: Transform::from_translation(position.extend(POWER_UP_Z_INDEX)),
+            ..default()
+        };
+
+        let label = Text2dBundle {
+            text: Text::from_section(
+                kind.title(),
+                TextStyle {
+                    color: Color::WHITE,
+                    font: default(),
+                    font_size: POWER_UP_FONT_SIZE,
+                },
+            ),
+            // Draw the label in front of the power-up.
+            transform: Transform::from_xyz(0_f32, 0_f32, 0.1),
+            ..default()
+        };
+
+        commands.spawn((sprite_bundle, PowerUpComponent::new(kind))).with_children(|parent| {
+            parent.spawn(label);
+        });
+    }
+
+    /// Counts down the time left on each power-up in effect.
+    fn tick_power_ups(mut power_ups: ResMut<PowerUpsResource>, time: Res<Time>) {
+        power_ups.tick(time.delta_seconds());
+    }
+}
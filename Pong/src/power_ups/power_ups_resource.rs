@@ -0,0 +1,96 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+
+use crate::paddle::paddle_plugin::PADDLE_WIDTH;
+use crate::power_ups::PowerUpKind;
+
+/// How much wider the WidePaddle power-up makes the Player's Paddle.
+const WIDE_PADDLE_FACTOR: f32 = 1.5;
+/// How much the ScoreMultiplier power-up multiplies the points granted.
+const SCORE_MULTIPLIER: i64 = 2;
+/// How much the SlowBall power-up slows the Balls down.
+const SLOW_BALL_FACTOR: f32 = 0.6;
+
+/// Holds the power-ups that the Player has caught and that are still in effect, along with the
+/// time, in seconds, that each has left. Catching a power-up that is already in effect restarts
+/// its time.
+#[derive(Default, Resource)]
+pub(crate) struct PowerUpsResource {
+    active: Vec<(PowerUpKind, f32)>,
+}
+
+impl PowerUpsResource {
+    //
+
+    /// Puts the specified power-up into effect for its full duration. Power-ups that take effect
+    /// only once, when caught, are ignored.
+    pub(crate) fn activate(&mut self, kind: PowerUpKind) {
+        //
+
+        let Some(duration_secs) = kind.duration_secs() else {
+            return;
+        };
+
+        match self.active.iter_mut().find(|(active_kind, _)| *active_kind == kind) {
+            Some((_, secs_left)) => *secs_left = duration_secs,
+            None => self.active.push((kind, duration_secs)),
+        }
+    }
+
+    /// Ends every power-up.
+    pub(crate) fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    /// Returns the factor by which the Balls' speed is multiplied.
+    pub(crate) fn get_ball_speed_factor(&self) -> f32 {
+        match self.is_active(PowerUpKind::SlowBall) {
+            true => SLOW_BALL_FACTOR,
+            false => 1_f32,
+        }
+    }
+
+    /// Returns the width, in pixels, of the Player's Paddle.
+    pub(crate) fn get_paddle_width(&self) -> f32 {
+        match self.is_active(PowerUpKind::WidePaddle) {
+            true => PADDLE_WIDTH * WIDE_PADDLE_FACTOR,
+            false => PADDLE_WIDTH,
+        }
+    }
+
+    /// Returns the factor by which the points granted are multiplied.
+    pub(crate) fn get_score_multiplier(&self) -> i64 {
+        match self.is_active(PowerUpKind::ScoreMultiplier) {
+            true => SCORE_MULTIPLIER,
+            false => 1,
+        }
+    }
+
+    /// Returns the time, in seconds, that the specified power-up has left, or None if it is not in
+    /// effect.
+    pub(crate) fn get_secs_left(&self, kind: PowerUpKind) -> Option<f32> {
+        self.active
+            .iter()
+            .find(|(active_kind, _)| *active_kind == kind)
+            .map(|(_, secs_left)| *secs_left)
+    }
+
+    /// Indicates whether the specified power-up is in effect.
+    pub(crate) fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.get_secs_left(kind).is_some()
+    }
+
+    /// Counts down the time left on each power-up by the specified seconds, ending those whose
+    /// time is up.
+    pub(crate) fn tick(&mut self, delta_secs: f32) {
+        for (_, secs_left) in &mut self.active {
+            *secs_left -= delta_secs;
+        }
+        self.active.retain(|(_, secs_left)| *secs_left > 0_f32);
+    }
+}
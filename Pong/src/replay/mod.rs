@@ -0,0 +1,27 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::{Res, States};
+
+use crate::replay::playback_resource::PlaybackResource;
+
+pub(crate) mod playback_resource;
+pub(crate) mod replay_log;
+pub(super) mod replay_plugin;
+pub(crate) mod replays_resource;
+
+/// Specifies whether a replay is being played back.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, States)]
+pub(crate) enum PlaybackState {
+    #[default]
+    Off,
+    Playing,
+}
+
+/// Run condition that allows a system to run unless a replay is jumping ahead.
+pub(crate) fn replay_not_seeking(playback: Res<PlaybackResource>) -> bool {
+    !playback.seeking
+}
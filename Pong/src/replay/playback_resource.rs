@@ -0,0 +1,111 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+
+use crate::paddle::paddle_intents_resource::PaddleIntent;
+use crate::replay::replay_log::{ReplayLog, ReplaySettings};
+
+/// The speeds, relative to real time, at which a replay can be played.
+pub(crate) const PLAYBACK_SPEEDS: [f32; 4] = [1.0, 2.0, 4.0, 8.0];
+
+/// Tracks the playback of a replay.
+#[derive(Default, Resource)]
+pub(crate) struct PlaybackResource {
+    /// What the Players asked of their Paddles during each tick of the replay.
+    inputs: Vec<(PaddleIntent, PaddleIntent)>,
+    pub(crate) paused: bool,
+    /// The replay being played.
+    replay: Option<ReplayLog>,
+    /// The settings chosen on the Start Menu before the playback began, restored once it ends.
+    saved_settings: Option<ReplaySettings>,
+    /// The tick to which the playback is to jump, and whether the replay must first be played
+    /// again from its start in order to get there.
+    seek_request: Option<(usize, bool)>,
+    /// Whether the playback is jumping ahead, simulating ticks as fast as possible.
+    pub(crate) seeking: bool,
+    speed_index: usize,
+    /// The number of ticks played so far.
+    tick: usize,
+}
+
+impl PlaybackResource {
+    //
+
+    /// Moves the playback on by one tick. Returns what the Players asked of their Paddles during
+    /// the tick, or None once the replay has run out.
+    pub(crate) fn advance(&mut self) -> Option<(PaddleIntent, PaddleIntent)> {
+        let intents = self.inputs.get(self.tick).copied()?;
+        self.tick += 1;
+        Some(intents)
+    }
+
+    /// Returns the replay being played, if any.
+    pub(crate) fn get_replay(&self) -> Option<&ReplayLog> {
+        self.replay.as_ref()
+    }
+
+    /// Returns the speed, relative to real time, at which the replay is played.
+    pub(crate) fn get_speed(&self) -> f32 {
+        PLAYBACK_SPEEDS[self.speed_index]
+    }
+
+    /// Returns the number of ticks played so far.
+    pub(crate) fn get_tick(&self) -> usize {
+        self.tick
+    }
+
+    /// Returns the number of ticks in the replay.
+    pub(crate) fn get_tick_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Indicates whether the replay has run out.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.tick >= self.inputs.len()
+    }
+
+    /// Plays the replay at the next faster speed, wrapping around from the fastest to real time.
+    pub(crate) fn next_speed(&mut self) {
+        self.speed_index = (self.speed_index + 1) % PLAYBACK_SPEEDS.len();
+    }
+
+    /// Notes that the replay is being played again from its start.
+    pub(crate) fn restarted(&mut self) {
+        self.tick = 0;
+    }
+
+    /// Asks for the playback to jump to the specified tick. Jumping back means playing the replay
+    /// again from its start.
+    pub(crate) fn seek(&mut self, tick: usize) {
+        let tick = tick.min(self.inputs.len());
+        self.seek_request = Some((tick, tick < self.tick));
+    }
+
+    /// Starts playing the specified replay from its start, remembering the settings to restore
+    /// once the playback ends.
+    pub(crate) fn start(&mut self, replay: ReplayLog, saved_settings: ReplaySettings) {
+        *self = Self {
+            inputs: replay.expand(),
+            replay: Some(replay),
+            saved_settings: Some(saved_settings),
+            seek_request: Some((0, true)),
+            ..Self::default()
+        };
+    }
+
+    /// Ends the playback. Returns the settings chosen on the Start Menu before it began.
+    pub(crate) fn stop(&mut self) -> Option<ReplaySettings> {
+        let saved_settings = self.saved_settings.take();
+        *self = Self::default();
+        saved_settings
+    }
+
+    /// Takes the pending request to jump to a tick, if any.
+    pub(crate) fn take_seek_request(&mut self) -> Option<(usize, bool)> {
+        self.seek_request.take()
+    }
+}
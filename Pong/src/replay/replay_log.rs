@@ -0,0 +1,166 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::computer_opponent::Difficulty;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::GameMode;
+use crate::paddle::paddle_intents_resource::PaddleIntent;
+use crate::physical_interactions::rally_physics::Pace;
+use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+
+/// The version of the replay file format. Replay files of any other version are not played.
+pub(crate) const REPLAY_FORMAT_VERSION: u32 = 2;
+
+/// What both Players asked of their Paddles for a run of consecutive simulation ticks.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct InputRun {
+    pub(crate) player_one: PaddleIntent,
+    pub(crate) player_two: PaddleIntent,
+    /// The number of consecutive ticks for which the Players asked the same.
+    pub(crate) ticks: u32,
+}
+
+/// The choices made on the Start Menu for a recorded Game.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct ReplaySettings {
+    pub(crate) computer_difficulty: Difficulty,
+    pub(crate) game_mode: GameMode,
+    pub(crate) pace: Pace,
+    pub(crate) player_two_is_computer: bool,
+    pub(crate) power_ups: bool,
+    pub(crate) target_score: u32,
+}
+
+impl ReplaySettings {
+    //
+
+    /// Makes the specified Game settings and scoreboard those of the recorded Game.
+    pub(crate) fn apply(&self, game_settings: &mut GameSettingsResource, scoreboard_resource: &mut ScoreboardResource) {
+        game_settings.computer_difficulty = self.computer_difficulty;
+        game_settings.game_mode = self.game_mode;
+        game_settings.pace = self.pace;
+        game_settings.player_two_is_computer = self.player_two_is_computer;
+        game_settings.power_ups = self.power_ups;
+        scoreboard_resource.set_target_score(self.target_score);
+    }
+
+    /// Captures the settings of the current Game.
+    pub(crate) fn capture(game_settings: &GameSettingsResource, scoreboard_resource: &ScoreboardResource) -> Self {
+        Self {
+            computer_difficulty: game_settings.computer_difficulty,
+            game_mode: game_settings.game_mode,
+            pace: game_settings.pace,
+            player_two_is_computer: game_settings.player_two_is_computer,
+            power_ups: game_settings.power_ups,
+            target_score: scoreboard_resource.get_target_score(),
+        }
+    }
+}
+
+/// A recorded Game: its settings, the seed of its random number generator and what the Players
+/// asked of their Paddles during each simulation tick. Since the simulation is deterministic, this
+/// is all that is needed to play the Game out again exactly. Consecutive ticks with the same input
+/// are stored as a single InputRun to keep replay files small.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct ReplayLog {
+    inputs: Vec<InputRun>,
+    seed: u64,
+    settings: ReplaySettings,
+    version: u32,
+    /// The size of the window in which the Game was played, which bounds the Ball and Paddles.
+    window_height: f32,
+    window_width: f32,
+}
+
+impl ReplayLog {
+    //
+
+    /// Expands the input log into what the Players asked of their Paddles during each tick.
+    pub(crate) fn expand(&self) -> Vec<(PaddleIntent, PaddleIntent)> {
+        self.inputs
+            .iter()
+            .flat_map(|run| (0..run.ticks).map(|_| (run.player_one, run.player_two)))
+            .collect()
+    }
+
+    /// Returns the seed of the recorded Game's random number generator.
+    pub(crate) fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the recorded Game's settings.
+    pub(crate) fn get_settings(&self) -> &ReplaySettings {
+        &self.settings
+    }
+
+    /// Returns the width and height of the window in which the Game was played.
+    pub(crate) fn get_window_size(&self) -> (f32, f32) {
+        (self.window_width, self.window_height)
+    }
+
+    /// Loads a replay from the specified file.
+    pub(crate) fn load_from(path: &Path) -> Result<Self, String> {
+        //
+
+        let contents = fs::read_to_string(path).map_err(|error| error.to_string())?;
+        let replay_log = serde_json::from_str::<Self>(&contents).map_err(|error| error.to_string())?;
+
+        match replay_log.version == REPLAY_FORMAT_VERSION {
+            true => Ok(replay_log),
+            false => Err(format!(
+                "the replay is of version {}, but only version {} can be played",
+                replay_log.version, REPLAY_FORMAT_VERSION
+            )),
+        }
+    }
+
+    /// Creates a new, empty, instance for a Game played with the specified seed and settings in a
+    /// window of the specified size.
+    pub(crate) fn new(seed: u64, settings: ReplaySettings, window_width: f32, window_height: f32) -> Self {
+        Self {
+            inputs: vec![],
+            seed,
+            settings,
+            version: REPLAY_FORMAT_VERSION,
+            window_height,
+            window_width,
+        }
+    }
+
+    /// Records what the Players asked of their Paddles during the next tick.
+    pub(crate) fn push(&mut self, player_one: PaddleIntent, player_two: PaddleIntent) {
+        //
+
+        if let Some(last_run) = self.inputs.last_mut() {
+            if last_run.player_one == player_one && last_run.player_two == player_two {
+                last_run.ticks += 1;
+                return;
+            }
+        }
+
+        self.inputs.push(InputRun {
+            player_one,
+            player_two,
+            ticks: 1,
+        });
+    }
+
+    /// Saves the replay to the specified file, creating its directory if needed.
+    pub(crate) fn save_to(&self, path: &Path) -> Result<(), String> {
+        //
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+        }
+        let contents = serde_json::to_string(self).map_err(|error| error.to_string())?;
+        fs::write(path, contents).map_err(|error| error.to_string())
+    }
+}
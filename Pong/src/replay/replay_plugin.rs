@@ -0,0 +1,590 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::sync::LazyLock;
+
+use bevy::app::{App, FixedUpdate, Plugin, Startup, Update};
+use bevy::prelude::{in_state, Color, Component, IntoSystemConfigs, OnEnter, OnExit};
+
+use crate::game_controller::{game_in_progress, GamePlayState};
+use crate::replay::playback_resource::PlaybackResource;
+use crate::replay::replays_resource::ReplaysResource;
+use crate::replay::PlaybackState;
+use crate::simulation::{SimulationSet, SIMULATION_TICKS_PER_SECOND};
+
+/// How far the playback jumps back or ahead, in seconds of the replay.
+const JUMP_SECONDS: f64 = 5.0;
+
+const MENU_FONT_SIZE: f32 = 24.0;
+
+static BAR_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("4d1a8cc0").unwrap() });
+static PROGRESS_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("d6c4eb").unwrap() });
+
+#[derive(Clone, PartialEq)]
+/// Defines the purposes of the playback bar buttons.
+enum ButtonPurpose {
+    ChangeSpeed,
+    ExitReplay,
+    JumpBack,
+    JumpForward,
+    /// Jumps to the point of the replay clicked on the progress bar.
+    Seek,
+    TogglePause,
+}
+
+/// Marker to indicate that an entity was spawned on the playback bar.
+#[derive(Component)]
+struct OnPlaybackBar;
+
+/// Companion component for the text of a playback bar button, allowing the text to show the
+/// playback's new speed or whether it is paused.
+#[derive(Component)]
+struct ButtonLabelComponent(ButtonPurpose);
+
+/// Marker for the part of the progress bar showing how much of the replay has been played.
+#[derive(Component)]
+struct ProgressFillComponent;
+
+/// Marker for the text showing how much of the replay has been played.
+#[derive(Component)]
+struct PlaybackTimeComponent;
+
+/// Records each Game as it is played and plays recorded Games back. A Game is recorded as the
+/// seed of its random number generator and what the Players asked of their Paddles during each
+/// simulation tick. Since the simulation is deterministic, playing these inputs back from the same
+/// seed plays the Game out again exactly. During playback, a bar at the bottom of the window
+/// pauses the replay, speeds it up and jumps through it. Jumping back plays the replay again from
+/// its start, as fast as possible, up to the chosen point.
+///
+/// The latest Game can be saved to a replay file from the Game Over screen and watched from the
+/// Start Menu. A replay file named on the command line is played as soon as the Game starts.
+pub(crate) struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .init_resource::<PlaybackResource>()
+            .init_resource::<ReplaysResource>()
+            .add_systems(Startup, functionality::load_replay_file)
+            .add_systems(
+                FixedUpdate,
+                functionality::record_tick
+                    .after(SimulationSet::Input)
+                    .before(SimulationSet::Paddles)
+                    .run_if(in_state(PlaybackState::Off))
+                    .run_if(game_in_progress),
+            )
+            .add_systems(
+                FixedUpdate,
+                functionality::play_tick
+                    .in_set(SimulationSet::Input)
+                    .run_if(in_state(PlaybackState::Playing))
+                    .run_if(game_in_progress),
+            )
+            .add_systems(OnEnter(GamePlayState::GameOver), functionality::finish_recording)
+            .add_systems(OnEnter(GamePlayState::StartMenu), functionality::finish_recording)
+            .add_systems(
+                OnEnter(PlaybackState::Playing),
+                (functionality::start_playback, ui::spawn_playback_bar),
+            )
+            .add_systems(
+                Update,
+                (
+                    functionality::button_interaction,
+                    functionality::keyboard_shortcuts,
+                    functionality::seek,
+                    functionality::apply_playback_clock,
+                    ui::update_playback_bar,
+                )
+                    .chain()
+                    .run_if(in_state(PlaybackState::Playing)),
+            )
+            .add_systems(
+                OnExit(PlaybackState::Playing),
+                (functionality::stop_playback, ui::despawn_playback_bar),
+            );
+    }
+}
+
+/// Returns the number of ticks by which the playback jumps back or ahead.
+fn jump_ticks() -> usize {
+    (JUMP_SECONDS * SIMULATION_TICKS_PER_SECOND) as usize
+}
+
+mod functionality {
+    use std::path::PathBuf;
+
+    use bevy::app::FixedMain;
+    use bevy::log::error;
+    use bevy::prelude::{
+        apply_state_transition, BackgroundColor, Button, ButtonInput, Changed, Fixed, Interaction,
+        KeyCode, Mut, NextState, Query, Res, ResMut, State, Time, Virtual, Window, With, World,
+    };
+    use bevy::ui::RelativeCursorPosition;
+    use bevy::window::PrimaryWindow;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::{GameMode, GamePlayState, Player};
+    use crate::menu_theme::button_color;
+    use crate::paddle::paddle_intents_resource::{PaddleIntent, PaddleIntentsResource};
+    use crate::replay::playback_resource::PlaybackResource;
+    use crate::replay::replay_log::{ReplayLog, ReplaySettings};
+    use crate::replay::replay_plugin::{jump_ticks, ButtonPurpose};
+    use crate::replay::replays_resource::ReplaysResource;
+    use crate::replay::PlaybackState;
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+    use crate::simulation::simulation_rng_resource::SimulationRngResource;
+
+    /// Pauses the replay and sets its speed. The replay is also held still once it has run out
+    /// partway through a Game, i.e. when the recording was cut short.
+    pub(super) fn apply_playback_clock(
+        game_play_state: Res<State<GamePlayState>>,
+        playback: Res<PlaybackResource>,
+        mut time: ResMut<Time<Virtual>>,
+    ) {
+        //
+
+        let paused = playback.paused || (playback.is_finished() && game_play_state.get().is_in_progress());
+
+        if paused && !time.is_paused() {
+            time.pause();
+        } else if !paused && time.is_paused() {
+            time.unpause();
+        }
+
+        if time.relative_speed() != playback.get_speed() {
+            time.set_relative_speed(playback.get_speed());
+        }
+    }
+
+    /// Provides button functionality, including state changes as well as response when clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn button_interaction(
+        mut interactions: Query<
+            (
+                &Interaction,
+                &mut BackgroundColor,
+                &EntityInfoComponent<ButtonPurpose>,
+                Option<&RelativeCursorPosition>,
+            ),
+            (Changed<Interaction>, With<Button>),
+        >,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut next_playback: ResMut<NextState<PlaybackState>>,
+        mut playback: ResMut<PlaybackResource>,
+    ) {
+        for (interaction, mut color, button_info, cursor_position) in &mut interactions {
+            *color = button_color(*interaction);
+
+            // Only a press acts on the button.
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+
+            // Which button was pressed?
+            match button_info.get_purpose() {
+                ButtonPurpose::ChangeSpeed => playback.next_speed(),
+                ButtonPurpose::ExitReplay => {
+                    next_playback.set(PlaybackState::Off);
+                    next_game_play.set(GamePlayState::StartMenu);
+                }
+                ButtonPurpose::JumpBack => {
+                    let tick = playback.get_tick().saturating_sub(jump_ticks());
+                    playback.seek(tick);
+                }
+                ButtonPurpose::JumpForward => {
+                    let tick = playback.get_tick() + jump_ticks();
+                    playback.seek(tick);
+                }
+                ButtonPurpose::Seek => {
+                    if let Some(position) = cursor_position.and_then(|cursor| cursor.normalized) {
+                        let fraction = position.x.clamp(0_f32, 1_f32);
+                        let tick = (fraction * playback.get_tick_count() as f32) as usize;
+                        playback.seek(tick);
+                    }
+                }
+                ButtonPurpose::TogglePause => playback.paused = !playback.paused,
+            }
+        }
+    }
+
+    /// Ends the recording of a Game once it is over or has been left.
+    pub(super) fn finish_recording(mut replays: ResMut<ReplaysResource>) {
+        replays.finish_recording();
+    }
+
+    /// Provides keyboard shortcuts for the playback bar: Space pauses and resumes the replay, and
+    /// the Left and Right arrow keys jump back and ahead.
+    pub(super) fn keyboard_shortcuts(
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut playback: ResMut<PlaybackResource>,
+    ) {
+        //
+
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            playback.paused = !playback.paused;
+        }
+        if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+            let tick = playback.get_tick().saturating_sub(jump_ticks());
+            playback.seek(tick);
+        }
+        if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+            let tick = playback.get_tick() + jump_ticks();
+            playback.seek(tick);
+        }
+    }
+
+    /// Loads the replay file named on the command line, if any, and plays it.
+    pub(super) fn load_replay_file(
+        mut next_playback: ResMut<NextState<PlaybackState>>,
+        mut replays: ResMut<ReplaysResource>,
+    ) {
+        //
+
+        let Some(path) = std::env::args_os().nth(1).map(PathBuf::from) else {
+            return;
+        };
+
+        match replays.load(&path) {
+            Ok(()) => next_playback.set(PlaybackState::Playing),
+            Err(error) => error!("Unable to load the replay {}: {}", path.display(), error),
+        }
+    }
+
+    /// Hands the Paddles what the Players asked of them during the tick being played back.
+    pub(super) fn play_tick(
+        mut paddle_intents: ResMut<PaddleIntentsResource>,
+        mut playback: ResMut<PlaybackResource>,
+    ) {
+        //
+
+        // Once the replay has run out, the Paddles stay put.
+        let (player_one, player_two) = playback.advance().unwrap_or_default();
+
+        paddle_intents.set(Player::One, player_one);
+        paddle_intents.set(Player::Two, player_two);
+    }
+
+    /// Records what the Players asked of their Paddles during this tick, starting a new recording
+    /// on the first tick of each Game.
+    pub(super) fn record_tick(
+        game_settings: Res<GameSettingsResource>,
+        paddle_intents: Res<PaddleIntentsResource>,
+        mut replays: ResMut<ReplaysResource>,
+        rng: Res<SimulationRngResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        //
+
+        if !replays.is_recording() {
+            let Ok(window) = window_query.get_single() else {
+                return;
+            };
+            let settings = ReplaySettings::capture(&game_settings, &scoreboard_resource);
+            replays.start_recording(ReplayLog::new(rng.get_seed(), settings, window.width(), window.height()));
+        }
+
+        // Player Two's input has no bearing on a Solo Game or on the computer's Paddle. Leaving it
+        // out keeps the recording compact.
+        let player_two = match game_settings.game_mode == GameMode::Versus && !game_settings.player_two_is_computer {
+            true => paddle_intents.get(Player::Two),
+            false => PaddleIntent::Hold,
+        };
+
+        replays.record(paddle_intents.get(Player::One), player_two);
+    }
+
+    /// Jumps to the tick asked for, if any, by running the simulation as fast as possible up to
+    /// it. Jumping back first starts the replay over, by way of the Start Menu, which clears away
+    /// the Game under way.
+    pub(super) fn seek(world: &mut World) {
+        //
+
+        let Some((target_tick, restart)) = world.resource_mut::<PlaybackResource>().take_seek_request() else {
+            return;
+        };
+
+        if restart {
+            restart_playback(world);
+        }
+
+        world.resource_mut::<PlaybackResource>().seeking = true;
+
+        // Run the simulation ticks directly, as the FixedUpdate schedule would, one after another.
+        let timestep = world.resource::<Time<Fixed>>().timestep();
+        while world.resource::<PlaybackResource>().get_tick() < target_tick
+            && world.resource::<State<GamePlayState>>().get().is_in_progress()
+        {
+            world.resource_mut::<Time<Fixed>>().advance_by(timestep);
+            let fixed_time = world.resource::<Time<Fixed>>().as_generic();
+            *world.resource_mut::<Time>() = fixed_time;
+            world.run_schedule(FixedMain);
+        }
+
+        let virtual_time = world.resource::<Time<Virtual>>().as_generic();
+        *world.resource_mut::<Time>() = virtual_time;
+
+        world.resource_mut::<PlaybackResource>().seeking = false;
+    }
+
+    /// Starts playing the latest replay, remembering the current settings so that they can be
+    /// restored once the playback ends.
+    pub(super) fn start_playback(
+        game_settings: Res<GameSettingsResource>,
+        mut next_playback: ResMut<NextState<PlaybackState>>,
+        mut playback: ResMut<PlaybackResource>,
+        replays: Res<ReplaysResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+    ) {
+        match replays.get_latest() {
+            Some(replay) => {
+                playback.start(replay.clone(), ReplaySettings::capture(&game_settings, &scoreboard_resource));
+            }
+            None => next_playback.set(PlaybackState::Off),
+        }
+    }
+
+    /// Ends the playback, restoring the settings chosen before it began and the flow of time.
+    pub(super) fn stop_playback(
+        mut game_settings: ResMut<GameSettingsResource>,
+        mut playback: ResMut<PlaybackResource>,
+        mut scoreboard_resource: ResMut<ScoreboardResource>,
+        mut time: ResMut<Time<Virtual>>,
+    ) {
+        //
+
+        if let Some(saved_settings) = playback.stop() {
+            saved_settings.apply(&mut game_settings, &mut scoreboard_resource);
+        }
+        game_settings.is_replay = false;
+
+        time.unpause();
+        time.set_relative_speed(1_f32);
+    }
+
+    /// Starts a new Game with the replay's settings, seed and window size.
+    fn restart_playback(world: &mut World) {
+        //
+
+        let Some(replay) = world.resource::<PlaybackResource>().get_replay().cloned() else {
+            return;
+        };
+
+        world.resource_mut::<NextState<GamePlayState>>().set(GamePlayState::StartMenu);
+        apply_state_transition::<GamePlayState>(world);
+
+        world.resource_scope(|world, mut game_settings: Mut<GameSettingsResource>| {
+            let mut scoreboard_resource = world.resource_mut::<ScoreboardResource>();
+            replay.get_settings().apply(&mut game_settings, &mut scoreboard_resource);
+            game_settings.is_replay = true;
+        });
+        world.resource_mut::<SimulationRngResource>().next_seed = Some(replay.get_seed());
+
+        // The Ball and Paddles are bounded by the window, so the Game must be played back in a
+        // window of the size in which it was recorded.
+        let (width, height) = replay.get_window_size();
+        let mut window_query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        if let Ok(mut window) = window_query.get_single_mut(world) {
+            window.resolution.set(width, height);
+        }
+
+        world.resource_mut::<NextState<GamePlayState>>().set(GamePlayState::Serve);
+        apply_state_transition::<GamePlayState>(world);
+
+        world.resource_mut::<PlaybackResource>().restarted();
+    }
+}
+
+mod ui {
+    use bevy::prelude::{
+        default, AlignItems, BackgroundColor, BuildChildren, ButtonBundle, Commands,
+        ChildBuilder, DespawnRecursiveExt, DetectChanges, Entity, FlexDirection, JustifyContent,
+        NodeBundle, PositionType, Query, Res, Style, Text, TextBundle, TextStyle, UiRect, Val, With,
+        Without, ZIndex,
+    };
+    use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::menu_theme::{BUTTON_COLOR_NORMAL, MENU_TEXT_COLOR};
+    use crate::replay::playback_resource::PlaybackResource;
+    use crate::replay::replay_plugin::{
+        ButtonLabelComponent, ButtonPurpose, OnPlaybackBar, PlaybackTimeComponent,
+        ProgressFillComponent, BAR_COLOR, MENU_FONT_SIZE, PROGRESS_COLOR,
+    };
+    use crate::simulation::SIMULATION_TICKS_PER_SECOND;
+
+    /// Keeps the playback bar in front of the Game Over screen.
+    const PLAYBACK_BAR_Z_INDEX: i32 = 10;
+
+    /// Removes the playback bar UI widgets.
+    pub(super) fn despawn_playback_bar(
+        mut commands: Commands,
+        bar_query: Query<Entity, With<OnPlaybackBar>>,
+    ) {
+        for entity in &bar_query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Sets up and then spawns the playback bar UI widgets.
+    pub(super) fn spawn_playback_bar(mut commands: Commands) {
+        //
+
+        let text_style = TextStyle {
+            color: *MENU_TEXT_COLOR,
+            font: default(),
+            font_size: MENU_FONT_SIZE,
+        };
+
+        // Button template
+        let button_bundle = ButtonBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(10.)),
+                ..default()
+            },
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        };
+
+        let spawn_button = |parent: &mut ChildBuilder, purpose: ButtonPurpose| {
+            parent
+                .spawn((button_bundle.clone(), EntityInfoComponent::new(purpose.clone())))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(button_title(&purpose, false, 1_f32), text_style.clone()),
+                        ButtonLabelComponent(purpose),
+                    ));
+                });
+        };
+
+        commands
+            .spawn((
+                NodeBundle {
+                    background_color: BackgroundColor(*BAR_COLOR),
+                    // Keep the clicks from reaching the Game Over screen underneath.
+                    focus_policy: FocusPolicy::Block,
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        bottom: Val::Px(0.),
+                        column_gap: Val::Px(10.),
+                        flex_direction: FlexDirection::Row,
+                        padding: UiRect::all(Val::Px(10.)),
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    z_index: ZIndex::Global(PLAYBACK_BAR_Z_INDEX),
+                    ..default()
+                },
+                OnPlaybackBar,
+            ))
+            .with_children(|parent| {
+                spawn_button(parent, ButtonPurpose::TogglePause);
+                spawn_button(parent, ButtonPurpose::JumpBack);
+                spawn_button(parent, ButtonPurpose::JumpForward);
+                spawn_button(parent, ButtonPurpose::ChangeSpeed);
+
+                // The progress bar, which jumps to the point clicked.
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                flex_grow: 1.0,
+                                height: Val::Px(16.),
+                                ..default()
+                            },
+                            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+                            ..default()
+                        },
+                        EntityInfoComponent::new(ButtonPurpose::Seek),
+                        RelativeCursorPosition::default(),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            NodeBundle {
+                                background_color: BackgroundColor(*PROGRESS_COLOR),
+                                focus_policy: FocusPolicy::Pass,
+                                style: Style {
+                                    height: Val::Percent(100.0),
+                                    width: Val::Percent(0.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            ProgressFillComponent,
+                        ));
+                    });
+
+                parent.spawn((
+                    TextBundle::from_section(format_time(0), text_style.clone()),
+                    PlaybackTimeComponent,
+                ));
+
+                spawn_button(parent, ButtonPurpose::ExitReplay);
+            });
+    }
+
+    /// Shows how much of the replay has been played, and its speed and whether it is paused on
+    /// the buttons.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn update_playback_bar(
+        mut fill_query: Query<&mut Style, With<ProgressFillComponent>>,
+        mut label_query: Query<(&mut Text, &ButtonLabelComponent)>,
+        playback: Res<PlaybackResource>,
+        mut time_query: Query<&mut Text, (With<PlaybackTimeComponent>, Without<ButtonLabelComponent>)>,
+    ) {
+        //
+
+        // Early return.
+        if !playback.is_changed() {
+            return;
+        }
+
+        let tick_count = playback.get_tick_count().max(1);
+        for mut style in &mut fill_query {
+            style.width = Val::Percent(100.0 * playback.get_tick() as f32 / tick_count as f32);
+        }
+
+        for mut text in &mut time_query {
+            text.sections[0].value = format!(
+                "{} / {}",
+                format_time(playback.get_tick()),
+                format_time(playback.get_tick_count())
+            );
+        }
+
+        for (mut text, label) in &mut label_query {
+            text.sections[0].value = button_title(&label.0, playback.paused, playback.get_speed());
+        }
+    }
+
+    fn button_title(purpose: &ButtonPurpose, paused: bool, speed: f32) -> String {
+        match purpose {
+            ButtonPurpose::ChangeSpeed => format!("Speed: {}x", speed),
+            ButtonPurpose::ExitReplay => "Exit Replay".to_string(),
+            ButtonPurpose::JumpBack => "<< 5s".to_string(),
+            ButtonPurpose::JumpForward => "5s >>".to_string(),
+            ButtonPurpose::Seek => String::new(),
+            ButtonPurpose::TogglePause => match paused {
+                true => "Play".to_string(),
+                false => "Pause".to_string(),
+            },
+        }
+    }
+
+    /// Formats the specified number of ticks as minutes and seconds, e.g. "1:05".
+    fn format_time(ticks: usize) -> String {
+        let seconds = (ticks as f64 / SIMULATION_TICKS_PER_SECOND) as u64;
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -0,0 +1,75 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::Resource;
+use chrono::Local;
+
+use crate::paddle::paddle_intents_resource::PaddleIntent;
+use crate::replay::replay_log::ReplayLog;
+
+/// Holds the recording of the Game under way and the latest replay, which is either the last Game
+/// played or a replay file loaded from disk. Replays are saved to the "pong/replays" folder of the
+/// user's data directory.
+#[derive(Default, Resource)]
+pub(crate) struct ReplaysResource {
+    latest: Option<ReplayLog>,
+    recording: Option<ReplayLog>,
+}
+
+impl ReplaysResource {
+    //
+
+    /// Ends the recording of the Game under way, if any, making it the latest replay.
+    pub(crate) fn finish_recording(&mut self) {
+        if let Some(recording) = self.recording.take() {
+            self.latest = Some(recording);
+        }
+    }
+
+    /// Returns the latest replay, if any.
+    pub(crate) fn get_latest(&self) -> Option<&ReplayLog> {
+        self.latest.as_ref()
+    }
+
+    /// Indicates whether a Game is being recorded.
+    pub(crate) fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Loads the specified replay file, making it the latest replay.
+    pub(crate) fn load(&mut self, path: &Path) -> Result<(), String> {
+        self.latest = Some(ReplayLog::load_from(path)?);
+        Ok(())
+    }
+
+    /// Records what the Players asked of their Paddles during the next tick of the Game under way.
+    pub(crate) fn record(&mut self, player_one: PaddleIntent, player_two: PaddleIntent) {
+        if let Some(recording) = &mut self.recording {
+            recording.push(player_one, player_two);
+        }
+    }
+
+    /// Saves the latest replay to a new file, named after the current time, in the replays folder.
+    /// Returns the file's location.
+    pub(crate) fn save_latest(&self) -> Result<PathBuf, String> {
+        //
+
+        let latest = self.latest.as_ref().ok_or("there is no replay to save")?;
+        let directory = dirs::data_dir().ok_or("the platform has no data directory")?;
+        let file_name = format!("replay-{}.json", Local::now().format("%Y%m%d-%H%M%S"));
+        let path = directory.join("pong").join("replays").join(file_name);
+
+        latest.save_to(&path)?;
+        Ok(path)
+    }
+
+    /// Starts recording a new Game.
+    pub(crate) fn start_recording(&mut self, recording: ReplayLog) {
+        self.recording = Some(recording);
+    }
+}
@@ -0,0 +1,8 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+pub(super) mod round_plugin;
+pub(crate) mod round_resource;
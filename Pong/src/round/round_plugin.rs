@@ -0,0 +1,328 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::app::{App, FixedUpdate, Plugin, Update};
+use bevy::prelude::{in_state, Component, IntoSystemConfigs, OnEnter, OnExit};
+
+use crate::game_controller::GamePlayState;
+use crate::round::round_resource::RoundResource;
+use crate::simulation::SimulationSet;
+
+const MENU_FONT_SIZE: f32 = 30.0;
+const TITLE_FONT_SIZE: f32 = 80.0;
+
+#[derive(Clone, PartialEq)]
+/// Defines the purposes of the Game Over screen buttons.
+enum ButtonPurpose {
+    MainMenu,
+    PlayAgain,
+    SaveReplay,
+}
+
+/// Marker to indicate that an entity was spawned on the Game Over screen.
+#[derive(Component)]
+struct OnGameOverScreen;
+
+/// Marker for the text telling where the replay was saved.
+#[derive(Component)]
+struct SaveStatusComponent;
+
+/// Runs the rounds of a Game. Each round starts with a Serve, during which the Ball waits at its
+/// serve point. The Rally follows, until a Player misses the Ball. After a short PointScored pause
+/// the next round begins or, once the Solo or Levels Player has no lives left, a Versus Player has
+/// reached the target or the last level has been cleared, the Game is over. The Game Over screen
+/// shows the final score and offers a restart and to save the Game's replay. When a replay is being
+/// watched, the playback bar takes the place of the Game Over screen's buttons.
+pub(crate) struct RoundPlugin;
+
+impl Plugin for RoundPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .init_resource::<RoundResource>()
+            .add_systems(OnExit(GamePlayState::StartMenu), functionality::reset_round)
+            .add_systems(OnExit(GamePlayState::GameOver), functionality::reset_round)
+            .add_systems(
+                FixedUpdate,
+                (
+                    functionality::serve_ball.run_if(in_state(GamePlayState::Serve)),
+                    functionality::detect_missed_ball.run_if(in_state(GamePlayState::Rally)),
+                    functionality::end_point.run_if(in_state(GamePlayState::PointScored)),
+                )
+                    .in_set(SimulationSet::Outcome),
+            )
+            .add_systems(OnEnter(GamePlayState::GameOver), ui::spawn_game_over_screen)
+            .add_systems(
+                Update,
+                functionality::button_interaction.run_if(in_state(GamePlayState::GameOver)),
+            )
+            .add_systems(OnExit(GamePlayState::GameOver), ui::despawn_game_over_screen);
+    }
+}
+
+mod functionality {
+    use bevy::prelude::{
+        BackgroundColor, Button, Changed, EventReader, Interaction, NextState, Query, Res, ResMut,
+        Text, Time, With,
+    };
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::ball::ball_component::BallComponent;
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::{GameMode, GamePlayState, Player};
+    use crate::high_scores::high_scores_resource::HighScoresResource;
+    use crate::menu_theme::button_color;
+    use crate::physical_interactions::collision_evaluator::CollisionEvaluator;
+    use crate::physical_interactions::collision_event::CollisionEvent;
+    use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor::{
+        Ball, Floor, PlayerOneGoal, PlayerTwoGoal,
+    };
+    use crate::replay::replays_resource::ReplaysResource;
+    use crate::round::round_plugin::{ButtonPurpose, SaveStatusComponent};
+    use crate::round::round_resource::RoundResource;
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+
+    /// Provides button functionality, including state changes as well as response when clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn button_interaction(
+        mut interactions: Query<
+            (
+                &Interaction,
+                &mut BackgroundColor,
+                &EntityInfoComponent<ButtonPurpose>,
+            ),
+            (Changed<Interaction>, With<Button>),
+        >,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        replays: Res<ReplaysResource>,
+        mut status_query: Query<&mut Text, With<SaveStatusComponent>>,
+    ) {
+        for (interaction, mut color, button_info) in &mut interactions {
+            *color = button_color(*interaction);
+
+            // Only a press acts on the button.
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+
+            // Which button was pressed?
+            match button_info.get_purpose() {
+                ButtonPurpose::MainMenu => next_game_play.set(GamePlayState::StartMenu),
+                // The scores are cleared on the way out of the Game Over screen.
+                ButtonPurpose::PlayAgain => next_game_play.set(GamePlayState::Serve),
+                ButtonPurpose::SaveReplay => {
+                    let status = match replays.save_latest() {
+                        Ok(path) => format!("Saved to {}", path.display()),
+                        Err(error) => format!("Unable to save the replay: {}", error),
+                    };
+                    for mut text in &mut status_query {
+                        text.sections[0].value = status.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Ends the Rally once the Ball gets past a Paddle: in a Solo or Levels Game, by hitting the
+    /// Floor once no other Ball is in play and, in a Versus Game, by entering either Player's goal.
+    pub(super) fn detect_missed_ball(
+        ball_query: Query<(), With<BallComponent>>,
+        mut event_reader: EventReader<CollisionEvent>,
+        game_settings: Res<GameSettingsResource>,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut round_resource: ResMut<RoundResource>,
+    ) {
+        for collision_event in event_reader.read() {
+            //
+
+            let mut evaluator = CollisionEvaluator::new(collision_event);
+
+            let conceding_player = match game_settings.game_mode {
+                GameMode::Solo | GameMode::Levels
+                    if evaluator.did(Ball).collide_with(Floor).evaluate() && ball_query.is_empty() =>
+                {
+                    Player::One
+                }
+                GameMode::Versus if evaluator.did(Ball).collide_with(PlayerOneGoal).evaluate() => Player::One,
+                GameMode::Versus if evaluator.did(Ball).collide_with(PlayerTwoGoal).evaluate() => Player::Two,
+                _ => continue,
+            };
+
+            round_resource.receiving_player = Some(conceding_player);
+            next_game_play.set(GamePlayState::PointScored);
+        }
+    }
+
+    /// Once the pause after a point is over, starts the next round or, if the point ended the Game,
+    /// shows the Game Over screen, first asking for the Player's initials if the Game earned a
+    /// high score.
+    pub(super) fn end_point(
+        game_settings: Res<GameSettingsResource>,
+        high_scores: Res<HighScoresResource>,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut round_resource: ResMut<RoundResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+        time: Res<Time>,
+    ) {
+        //
+
+        round_resource.point_scored_timer.tick(time.delta());
+
+        if round_resource.point_scored_timer.finished() {
+            round_resource.point_scored_timer.reset();
+            if !scoreboard_resource.is_game_over(game_settings.game_mode) {
+                next_game_play.set(GamePlayState::Serve);
+            } else if high_scores.earns_entry(&game_settings, &scoreboard_resource) {
+                next_game_play.set(GamePlayState::EnterInitials);
+            } else {
+                next_game_play.set(GamePlayState::GameOver);
+            }
+        }
+    }
+
+    /// Prepares for the first round of a new Game.
+    pub(super) fn reset_round(mut round_resource: ResMut<RoundResource>) {
+        round_resource.reset();
+    }
+
+    /// Puts the Ball into play once it has waited at its serve point.
+    pub(super) fn serve_ball(
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut round_resource: ResMut<RoundResource>,
+        time: Res<Time>,
+    ) {
+        //
+
+        round_resource.serve_timer.tick(time.delta());
+
+        if round_resource.serve_timer.finished() {
+            round_resource.serve_timer.reset();
+            next_game_play.set(GamePlayState::Rally);
+        }
+    }
+}
+
+mod ui {
+    use bevy::prelude::{
+        default, AlignItems, BackgroundColor, BuildChildren, ButtonBundle, Commands,
+        DespawnRecursiveExt, Entity, FlexDirection, JustifyContent, NodeBundle, Query, Res, Style,
+        TextBundle, TextStyle, UiRect, Val, With,
+    };
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::GameMode;
+    use crate::menu_theme::{BUTTON_COLOR_NORMAL, MENU_TEXT_COLOR};
+    use crate::round::round_plugin::{
+        ButtonPurpose, OnGameOverScreen, SaveStatusComponent, MENU_FONT_SIZE, TITLE_FONT_SIZE,
+    };
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+
+    /// Removes the Game Over screen UI widgets.
+    pub(super) fn despawn_game_over_screen(
+        mut commands: Commands,
+        screen_query: Query<Entity, With<OnGameOverScreen>>,
+    ) {
+        for entity in &screen_query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Sets up and then spawns the Game Over screen UI widgets, showing the final score. The buttons
+    /// are left out when a replay is being watched.
+    pub(super) fn spawn_game_over_screen(
+        mut commands: Commands,
+        game_settings: Res<GameSettingsResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+    ) {
+        //
+
+        let text_style = TextStyle {
+            color: *MENU_TEXT_COLOR,
+            font: default(),
+            font_size: MENU_FONT_SIZE,
+        };
+        let title_style = TextStyle {
+            font_size: TITLE_FONT_SIZE,
+            ..text_style.clone()
+        };
+
+        let result = match (game_settings.game_mode, scoreboard_resource.get_winner()) {
+            (GameMode::Versus, Some(winner)) => format!(
+                "{} wins {} to {}!",
+                winner.title(),
+                scoreboard_resource.get_player_score(winner),
+                scoreboard_resource.get_player_score(winner.opponent()),
+            ),
+            (GameMode::Levels, Some(_)) => format!("Every level cleared! Final Score: {}", scoreboard_resource.score),
+            _ => format!("Final Score: {}", scoreboard_resource.score),
+        };
+
+        // Button template
+        let button_bundle = ButtonBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(16.)),
+                ..default()
+            },
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        };
+
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(20.),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                OnGameOverScreen,
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section("GAME OVER", title_style));
+                parent.spawn(TextBundle::from_section(result, text_style.clone()));
+
+                // Early return.
+                if game_settings.is_replay {
+                    return;
+                }
+
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            column_gap: Val::Px(10.),
+                            flex_direction: FlexDirection::Row,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for (purpose, title) in [
+                            (ButtonPurpose::PlayAgain, "Play Again"),
+                            (ButtonPurpose::MainMenu, "Main Menu"),
+                            (ButtonPurpose::SaveReplay, "Save Replay"),
+                        ] {
+                            parent
+                                .spawn((button_bundle.clone(), EntityInfoComponent::new(purpose)))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(title, text_style.clone()));
+                                });
+                        }
+                    });
+                parent.spawn((TextBundle::from_section("", text_style.clone()), SaveStatusComponent));
+            });
+    }
+}
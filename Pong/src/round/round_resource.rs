@@ -0,0 +1,48 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+use bevy::time::{Timer, TimerMode};
+
+use crate::game_controller::Player;
+
+/// How long, in seconds, the Ball waits before it is served.
+const SERVE_DELAY_SECS: f32 = 1.0;
+
+/// How long, in seconds, the Game pauses after a point is scored.
+const POINT_SCORED_DELAY_SECS: f32 = 1.0;
+
+/// Tracks the progress of the current round: the Serve, the Rally and the pause after a point.
+#[derive(Resource)]
+pub(crate) struct RoundResource {
+    /// Counts down the pause after a point is scored.
+    pub(super) point_scored_timer: Timer,
+    /// The Player toward whom the next Ball is served, if any. In a Versus Game, the Player who
+    /// conceded the last point receives the next Serve.
+    pub(crate) receiving_player: Option<Player>,
+    /// Counts down the wait before the Ball is served.
+    pub(super) serve_timer: Timer,
+}
+
+impl RoundResource {
+    //
+
+    /// Prepares for the first round of a new Game.
+    pub(super) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Provides default instantiation.
+impl Default for RoundResource {
+    fn default() -> Self {
+        Self {
+            point_scored_timer: Timer::from_seconds(POINT_SCORED_DELAY_SECS, TimerMode::Once),
+            receiving_player: None,
+            serve_timer: Timer::from_seconds(SERVE_DELAY_SECS, TimerMode::Once),
+        }
+    }
+}
@@ -5,5 +5,5 @@
 // @author JoelDavisEngineering@Gmail.com
 
 pub(crate) mod scoreboard_plugin;
-mod scoreboard_resource;
+pub(crate) mod scoreboard_resource;
 mod scoreboard_ui_component;
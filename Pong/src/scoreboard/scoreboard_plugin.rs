@@ -4,29 +4,41 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use bevy::prelude::{in_state, IntoSystemConfigs};
+use bevy::prelude::{in_state, IntoSystemConfigs, OnExit};
 use bevy::prelude::{App, EventReader, FixedUpdate, Plugin, ResMut, Startup};
 use bevy::prelude::{
     Color, Commands, DetectChanges, PositionType, Query, Res, TextBundle, TextSection, Window, With,
 };
+use bevy::prelude::{BuildChildren, Display, FlexDirection, NodeBundle, UiRect};
 use bevy::text::{Text, TextStyle};
 use bevy::ui::{Style, Val};
 use bevy::utils::default;
 use bevy::window::PrimaryWindow;
 use std::sync::LazyLock;
 
-use crate::game_controller::GamePlayState;
+use crate::ball::ball_component::BallComponent;
+use crate::game_controller::game_settings_resource::GameSettingsResource;
+use crate::game_controller::{GameMode, GamePlayState, Player};
+use crate::levels::levels_resource::LevelsResource;
 use crate::physical_interactions::collision_evaluator::CollisionEvaluator;
 use crate::physical_interactions::collision_event::CollisionEvent;
 use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor::{
-    Ball, Floor, Paddle,
+    Ball, Floor, Paddle, PlayerOneGoal, PlayerTwoGoal,
 };
+use crate::power_ups::power_ups_resource::PowerUpsResource;
 use crate::scoreboard::scoreboard_resource::ScoreboardResource;
-use crate::scoreboard::scoreboard_ui_component::ScoreBoardUiComponent;
+use crate::power_ups::PowerUpKind;
+use crate::scoreboard::scoreboard_ui_component::{PowerUpIndicatorComponent, ScoreBoardUiComponent};
+use crate::simulation::SimulationSet;
 
 // Scoring (current scheme):
-// Points are granted for each Ball return.
-// Points are taken away for missing the Ball.
+// Solo: Points are granted for each Ball return. Missing the last Ball in play costs one of the
+// Player's lives.
+// Versus: A Player is granted a point each time the Ball gets past the opponent's Paddle. The first
+// Player to reach the target score wins.
+// Levels: Points are granted for each Brick destroyed, by the LevelsPlugin. Missing the last Ball in
+// play costs one of the Player's lives.
+// The ScoreMultiplier power-up multiplies the points granted for Ball returns and Bricks.
 
 // TODO: JD: Future scoring ideas:
 // The closer to the paddle center the ball was returned, the more the points granted.
@@ -36,30 +48,33 @@ use crate::scoreboard::scoreboard_ui_component::ScoreBoardUiComponent;
 // Decrease the Paddle width over time.
 
 // Scoring parameters
-const MAX_DEMERITS_FOR_MISSING_BALL: i64 = 5;
 const MAX_POINTS_TO_GRANT_FOR_RETURNING_BALL: i64 = 5;
 
+const POWER_UP_INDICATOR_FONT_SIZE: f32 = 22.0;
 const SCOREBOARD_FONT_SIZE: f32 = 35.0;
 
 static SCOREBOARD_TEXT_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("2f2f2f").unwrap() });
 
 pub(crate) struct ScoreboardPlugin;
 
-/// Displays the game score in realtime.
+/// Displays the game score in realtime, along with an indicator for each power-up in effect.
 impl Plugin for ScoreboardPlugin {
     /// Constructs the plugin.
     fn build(&self, app: &mut App) {
         app //
             .insert_resource(ScoreboardResource::default())
-            .add_systems(Startup, Self::spawn_scoreboard_ui)
+            .add_systems(Startup, (Self::spawn_scoreboard_ui, Self::spawn_power_up_indicators))
+            .add_systems(OnExit(GamePlayState::StartMenu), Self::reset_scores)
+            .add_systems(OnExit(GamePlayState::GameOver), Self::reset_scores)
             .add_systems(
                 FixedUpdate,
                 (
-                    Self::handle_physical_interaction_events,
+                    Self::handle_physical_interaction_events.run_if(in_state(GamePlayState::Rally)),
                     Self::update_scoreboard,
+                    Self::update_power_up_indicators,
                 )
                     .chain()
-                    .run_if(in_state(GamePlayState::Playing)),
+                    .in_set(SimulationSet::Outcome),
             );
     }
 }
@@ -70,33 +85,99 @@ impl ScoreboardPlugin {
     /// Listens for collision event and updates the score resource based on how the Paddle and Ball
     /// are interacting with the environment.
     fn handle_physical_interaction_events(
+        ball_query: Query<(), With<BallComponent>>,
         mut event_reader: EventReader<CollisionEvent>,
+        game_settings: Res<GameSettingsResource>,
+        power_ups: Res<PowerUpsResource>,
         mut scoreboard_resource: ResMut<ScoreboardResource>,
     ) {
         //
 
+        let mut ball_missed = false;
+
         // Look for the following situations:
         // a) The Ball hitting the Floor.
         // b) The Paddle returning the Ball.
+        // c) The Ball getting past either Player's Paddle into their goal.
         for collision_event in event_reader.read() {
             //
 
             let mut evaluator = CollisionEvaluator::new(collision_event);
 
-            if evaluator.did(Ball).collide_with(Floor).evaluate() {
+            if game_settings.game_mode == GameMode::Versus {
                 //
 
-                // Give demerits for hitting the floor because it means the Player
-                // missed the Ball.
-                let new_score: i64 = scoreboard_resource.score - MAX_DEMERITS_FOR_MISSING_BALL;
-                scoreboard_resource.score = new_score.clamp(0, i64::MAX);
-            } else if evaluator.did(Ball).collide_with(Paddle).evaluate() {
+                let scoring_player = if evaluator.did(Ball).collide_with(PlayerOneGoal).evaluate() {
+                    Player::Two
+                } else if evaluator.did(Ball).collide_with(PlayerTwoGoal).evaluate() {
+                    Player::One
+                } else {
+                    continue;
+                };
+
+                // The RoundPlugin ends the Game once a Player reaches the target.
+                scoreboard_resource.score_point(scoring_player);
+            } else if evaluator.did(Ball).collide_with(Floor).evaluate() {
+                // The Ball hitting the Floor means the Player missed it.
+                ball_missed = true;
+            } else if game_settings.game_mode == GameMode::Solo
+                && evaluator.did(Ball).collide_with(Paddle).evaluate()
+            {
                 //
 
                 // Grant points for the Ball being returned by the Paddle.
-                scoreboard_resource.score += MAX_POINTS_TO_GRANT_FOR_RETURNING_BALL;
+                scoreboard_resource.score += MAX_POINTS_TO_GRANT_FOR_RETURNING_BALL * power_ups.get_score_multiplier();
             }
         }
+
+        // A life is lost only once the last Ball in play has been missed, even if more than one
+        // Ball reached the Floor at the same time.
+        if ball_missed && ball_query.is_empty() {
+            scoreboard_resource.lose_life();
+        }
+    }
+
+    /// Clears the scores at the start of each Game.
+    fn reset_scores(mut scoreboard_resource: ResMut<ScoreboardResource>) {
+        scoreboard_resource.reset();
+    }
+
+    /// Spawns a row of power-up indicators beneath the score, each hidden until its power-up is in
+    /// effect.
+    fn spawn_power_up_indicators(mut commands: Commands) {
+        //
+
+        let row_style = Style {
+            column_gap: Val::Px(8.0),
+            flex_direction: FlexDirection::Row,
+            position_type: PositionType::Absolute,
+            left: Val::Px(30.0),
+            top: Val::Px(75.0),
+            ..default()
+        };
+
+        commands.spawn(NodeBundle { style: row_style, ..default() }).with_children(|parent| {
+            for kind in PowerUpKind::ALL {
+                //
+
+                let text_style = TextStyle {
+                    color: Color::WHITE,
+                    font: Default::default(),
+                    font_size: POWER_UP_INDICATOR_FONT_SIZE,
+                };
+                let indicator_style = Style {
+                    display: Display::None,
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    ..default()
+                };
+
+                let text_bundle = TextBundle::from_section(kind.title(), text_style)
+                    .with_style(indicator_style)
+                    .with_background_color(kind.color());
+
+                parent.spawn((text_bundle, PowerUpIndicatorComponent(kind)));
+            }
+        });
     }
 
     /// Spawns the scoreboard UI.
@@ -138,14 +219,87 @@ impl ScoreboardPlugin {
         commands.spawn((text_bundle, ScoreBoardUiComponent {}));
     }
 
-    /// Keeps the Scoreboard UI updated with the latest score.
+    /// Keeps the Scoreboard UI updated with the latest score. A Solo Game shows the lives left, a
+    /// Versus Game shows each Player's points along with the target and a Levels Game shows the
+    /// lives left along with the level being played.
     fn update_scoreboard(
+        game_settings: Res<GameSettingsResource>,
+        levels: Res<LevelsResource>,
         scoreboard_resource: Res<ScoreboardResource>,
         mut text_query: Query<&mut Text, With<ScoreBoardUiComponent>>,
     ) {
         if scoreboard_resource.is_changed() {
             if let Ok(mut text_sections) = text_query.get_single_mut() {
-                text_sections.sections[1].value = scoreboard_resource.score.to_string();
+                let (label, value) = match game_settings.game_mode {
+                    GameMode::Solo => (
+                        "Score: ".to_string(),
+                        format!("{}   Lives: {}", scoreboard_resource.score, scoreboard_resource.get_lives()),
+                    ),
+                    GameMode::Levels => {
+                        // Once the last level is cleared, keep showing it.
+                        let level_index =
+                            scoreboard_resource.get_level().min(levels.get_level_count().saturating_sub(1));
+                        let level_name = levels.get_level(level_index).map_or("", |level| level.get_name());
+                        (
+                            "Score: ".to_string(),
+                            format!(
+                                "{}   Lives: {}   Level {}: {}",
+                                scoreboard_resource.score,
+                                scoreboard_resource.get_lives(),
+                                level_index + 1,
+                                level_name,
+                            ),
+                        )
+                    }
+                    GameMode::Versus => (
+                        format!("First to {}   ", scoreboard_resource.get_target_score()),
+                        format!(
+                            "{}: {}   {}: {}",
+                            Player::One.title(),
+                            scoreboard_resource.get_player_score(Player::One),
+                            Player::Two.title(),
+                            scoreboard_resource.get_player_score(Player::Two),
+                        ),
+                    ),
+                };
+                text_sections.sections[0].value = label;
+                text_sections.sections[1].value = value;
+            }
+        }
+    }
+
+    /// Shows an indicator, with the time left, for each power-up in effect and, while more than one
+    /// Ball is in play, the number of Balls.
+    fn update_power_up_indicators(
+        ball_query: Query<(), With<BallComponent>>,
+        mut indicator_query: Query<(&mut Text, &mut Style, &PowerUpIndicatorComponent)>,
+        power_ups: Res<PowerUpsResource>,
+    ) {
+        //
+
+        let ball_count = ball_query.iter().count();
+
+        for (mut text, mut style, indicator) in &mut indicator_query {
+            //
+
+            let kind = indicator.0;
+            let value = match (kind, power_ups.get_secs_left(kind)) {
+                (PowerUpKind::MultiBall, _) if ball_count > 1 => Some(format!("{} x{}", kind.title(), ball_count)),
+                (_, Some(secs_left)) => Some(format!("{} {}s", kind.title(), secs_left.ceil())),
+                _ => None,
+            };
+
+            let display = match value {
+                Some(value) => {
+                    if text.sections[0].value != value {
+                        text.sections[0].value = value;
+                    }
+                    Display::Flex
+                }
+                None => Display::None,
+            };
+            if style.display != display {
+                style.display = display;
             }
         }
     }
@@ -6,8 +6,152 @@
 
 use bevy::prelude::Resource;
 
+use crate::game_controller::{GameMode, Player};
+
+/// The points needed to win a Versus Game unless another target is chosen on the Start Menu.
+pub(crate) const DEFAULT_TARGET_SCORE: u32 = 11;
+
+/// The number of times the Player of a Solo or Levels Game can miss the Ball before the Game is over.
+pub(crate) const STARTING_LIVES: u32 = 3;
+
 /// Models the info needed to represent the Game score in the UI.
-#[derive(Default, Resource)]
-pub(super) struct ScoreboardResource {
-    pub(super) score: i64,
+#[derive(Resource)]
+pub(crate) struct ScoreboardResource {
+    /// The score of a Solo or Levels Game.
+    pub(crate) score: i64,
+    /// The index, counting from zero, of the level being played in a Levels Game.
+    level: usize,
+    /// The misses left before a Solo or Levels Game is over.
+    lives: u32,
+    /// The points of Player One and Player Two in a Versus Game.
+    player_scores: [u32; 2],
+    /// The points needed to win a Versus Game.
+    target_score: u32,
+    /// The Player who won the last Versus Game, or cleared every level of a Levels Game, if any.
+    winner: Option<Player>,
+}
+
+impl ScoreboardResource {
+    //
+
+    /// Completes the level being played in a Levels Game, moving on to the next of the specified
+    /// number of levels. Returns true if it was the last level, which wins the Game.
+    pub(crate) fn complete_level(&mut self, level_count: usize) -> bool {
+        //
+
+        self.level += 1;
+
+        if self.level >= level_count {
+            self.winner = Some(Player::One);
+        }
+
+        self.winner.is_some()
+    }
+
+    /// Returns the score with which the Game is ranked in the high-score table: the points of a
+    /// Solo or Levels Game and, for a Versus Game, the winner's margin of victory.
+    pub(crate) fn get_final_score(&self, game_mode: GameMode) -> i64 {
+        match (game_mode, self.winner) {
+            (GameMode::Solo | GameMode::Levels, _) => self.score,
+            (GameMode::Versus, Some(winner)) => {
+                self.get_player_score(winner) as i64 - self.get_player_score(winner.opponent()) as i64
+            }
+            (GameMode::Versus, None) => 0,
+        }
+    }
+
+    /// Returns the index, counting from zero, of the level being played in a Levels Game.
+    pub(crate) fn get_level(&self) -> usize {
+        self.level
+    }
+
+    /// Returns the misses left before a Solo or Levels Game is over.
+    pub(crate) fn get_lives(&self) -> u32 {
+        self.lives
+    }
+
+    /// Returns the specified Player's points.
+    pub(crate) fn get_player_score(&self, player: Player) -> u32 {
+        self.player_scores[Self::index(player)]
+    }
+
+    /// Returns the points needed to win a Versus Game.
+    pub(crate) fn get_target_score(&self) -> u32 {
+        self.target_score
+    }
+
+    /// Returns the Player who won the last Versus Game, or cleared every level of a Levels Game, if
+    /// any.
+    pub(crate) fn get_winner(&self) -> Option<Player> {
+        self.winner
+    }
+
+    /// Indicates whether the Game is over: in a Solo Game, once the Player has no lives left, in a
+    /// Versus Game, once a Player has reached the target and, in a Levels Game, once the Player has
+    /// no lives left or has cleared the last level.
+    pub(crate) fn is_game_over(&self, game_mode: GameMode) -> bool {
+        match game_mode {
+            GameMode::Solo => self.lives == 0,
+            GameMode::Versus => self.winner.is_some(),
+            GameMode::Levels => self.lives == 0 || self.winner.is_some(),
+        }
+    }
+
+    /// Takes away one of the Solo or Levels Player's lives for missing the Ball.
+    pub(crate) fn lose_life(&mut self) {
+        self.lives = self.lives.saturating_sub(1);
+    }
+
+    /// Clears the scores in preparation for a new Game, keeping the target.
+    pub(crate) fn reset(&mut self) {
+        self.score = 0;
+        self.level = 0;
+        self.lives = STARTING_LIVES;
+        self.player_scores = [0; 2];
+        self.winner = None;
+    }
+
+    /// Grants the specified Player a point. Returns true if the point won the Game.
+    pub(crate) fn score_point(&mut self, player: Player) -> bool {
+        //
+
+        // Early return.
+        if self.winner.is_some() {
+            return false;
+        }
+
+        self.player_scores[Self::index(player)] += 1;
+
+        if self.get_player_score(player) >= self.target_score {
+            self.winner = Some(player);
+        }
+
+        self.winner.is_some()
+    }
+
+    /// Sets the points needed to win a Versus Game.
+    pub(crate) fn set_target_score(&mut self, target_score: u32) {
+        self.target_score = target_score.max(1);
+    }
+
+    fn index(player: Player) -> usize {
+        match player {
+            Player::One => 0,
+            Player::Two => 1,
+        }
+    }
+}
+
+/// Provides default instantiation.
+impl Default for ScoreboardResource {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            level: 0,
+            lives: STARTING_LIVES,
+            player_scores: [0; 2],
+            target_score: DEFAULT_TARGET_SCORE,
+            winner: None,
+        }
+    }
 }
@@ -6,6 +6,13 @@
 
 use bevy::prelude::Component;
 
+use crate::power_ups::PowerUpKind;
+
 /// Marker for entities added to the Scoreboard UI.
 #[derive(Component)]
 pub(super) struct ScoreBoardUiComponent;
+
+/// Marker for the Scoreboard UI's indicator of the specified power-up, which is shown while the
+/// power-up is in effect.
+#[derive(Component)]
+pub(super) struct PowerUpIndicatorComponent(pub(super) PowerUpKind);
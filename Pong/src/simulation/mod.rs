@@ -0,0 +1,29 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::SystemSet;
+
+pub(crate) mod simulation_plugin;
+pub(crate) mod simulation_rng_resource;
+
+/// The number of times per second that the Game's simulation is advanced.
+pub(crate) const SIMULATION_TICKS_PER_SECOND: f64 = 120.0;
+
+/// Orders the steps of each simulation tick. The steps run one after another in FixedUpdate so
+/// that, given the same seed and the same Player input, a Game always plays out the same way.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub(crate) enum SimulationSet {
+    /// Each Player's input is read into the PaddleIntentsResource.
+    Input,
+    /// The Paddles are moved.
+    Paddles,
+    /// The Ball is moved.
+    Ball,
+    /// Collisions are detected and CollisionEvents sent.
+    Collisions,
+    /// The CollisionEvents are scored and the round moves on.
+    Outcome,
+}
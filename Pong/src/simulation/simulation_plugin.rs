@@ -0,0 +1,57 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::app::{App, FixedUpdate, Plugin};
+use bevy::prelude::{
+    apply_state_transition, Fixed, IntoSystemConfigs, IntoSystemSetConfigs, OnExit, ResMut, Time,
+};
+
+use crate::game_controller::GamePlayState;
+use crate::simulation::simulation_rng_resource::SimulationRngResource;
+use crate::simulation::{SimulationSet, SIMULATION_TICKS_PER_SECOND};
+
+/// Runs the Ball, Paddle and collision systems on a fixed timestep, in a fixed order and with a
+/// seeded random number generator, so that a Game plays out the same way regardless of the frame
+/// rate. Changes to the GamePlayState are applied at the start of each tick, rather than once per
+/// frame, so that they too land on the same tick every time.
+pub(crate) struct SimulationPlugin;
+
+impl Plugin for SimulationPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .insert_resource(Time::<Fixed>::from_hz(SIMULATION_TICKS_PER_SECOND))
+            .init_resource::<SimulationRngResource>()
+            .configure_sets(
+                FixedUpdate,
+                (
+                    SimulationSet::Input,
+                    SimulationSet::Paddles,
+                    SimulationSet::Ball,
+                    SimulationSet::Collisions,
+                    SimulationSet::Outcome,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                FixedUpdate,
+                apply_state_transition::<GamePlayState>.before(SimulationSet::Input),
+            )
+            .add_systems(OnExit(GamePlayState::StartMenu), Self::reseed)
+            .add_systems(OnExit(GamePlayState::GameOver), Self::reseed);
+    }
+}
+
+impl SimulationPlugin {
+    //
+
+    /// Reseeds the random number generator at the start of each Game.
+    fn reseed(mut rng: ResMut<SimulationRngResource>) {
+        rng.start_game();
+    }
+}
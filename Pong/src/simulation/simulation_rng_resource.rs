@@ -0,0 +1,71 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Resource;
+use rand::{random, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The source of all of the Game's randomness, e.g. the direction in which the Ball is served.
+/// Each Game is played with a freshly seeded generator so that it can be played out again from its
+/// seed. ChaCha8Rng is used since it yields the same numbers on every platform.
+#[derive(Resource)]
+pub(crate) struct SimulationRngResource {
+    /// The seed with which the next Game is to be played, if chosen in advance.
+    pub(crate) next_seed: Option<u64>,
+    rng: ChaCha8Rng,
+    seed: u64,
+}
+
+impl Default for SimulationRngResource {
+    fn default() -> Self {
+        Self::new(random())
+    }
+}
+
+impl SimulationRngResource {
+    //
+
+    /// Returns the seed with which the current Game is played.
+    pub(crate) fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Creates a new instance seeded with the specified seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self {
+            next_seed: None,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            seed,
+        }
+    }
+
+    /// Reseeds the generator for a new Game, using the seed chosen in advance, if any.
+    pub(crate) fn start_game(&mut self) {
+        let seed = self.next_seed.take().unwrap_or_else(random);
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        self.seed = seed;
+    }
+}
+
+impl RngCore for SimulationRngResource {
+    //
+
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
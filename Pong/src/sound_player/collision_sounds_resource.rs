@@ -6,6 +6,7 @@ use bevy::prelude::Resource;
 /// Holds preloaded audio resources.
 pub(crate) struct CollisionSoundsResource {
     pub(crate) ball_missed: Handle<AudioSource>,
+    pub(crate) brick_hit: Handle<AudioSource>,
     pub(crate) paddle_hit: Handle<AudioSource>,
     pub(crate) ceiling_or_side_wall_hit: Handle<AudioSource>,
 }
\ No newline at end of file
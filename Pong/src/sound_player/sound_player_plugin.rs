@@ -13,9 +13,14 @@ use crate::game_controller::{GamePlayState, SoundSetting};
 use crate::physical_interactions::collision_evaluator::CollisionEvaluator;
 use crate::physical_interactions::collision_event::CollisionEvent;
 use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor::*;
+use crate::replay::replay_not_seeking;
+use crate::simulation::SimulationSet;
 use crate::sound_player::collision_sounds_resource::CollisionSoundsResource;
 
 const SOUND_BALL_MISSED: &str = "audio/impactBell_heavy_001.ogg";
+const SOUND_BRICK_HIT: &str = "audio/impactGlass_medium_000.ogg";
+/// Plays the Brick sound faster, and so higher, than the Paddle sound from which it is made.
+const SOUND_BRICK_HIT_SPEED: f32 = 1.6;
 const SOUND_PADDLE_HIT: &str = "audio/impactGlass_medium_000.ogg";
 const SOUND_TOP_OR_SIDE_WALL_HIT: &str = "audio/impactMetal_medium_004.ogg";
 
@@ -31,7 +36,9 @@ impl Plugin for SoundPlayerPlugin {
                 FixedUpdate,
                 Self::handle_physical_interaction_events
                     .run_if(in_state(SoundSetting::On))
-                    .run_if(in_state(GamePlayState::Playing)),
+                    .run_if(in_state(GamePlayState::Rally))
+                    .run_if(replay_not_seeking)
+                    .in_set(SimulationSet::Outcome),
             );
     }
 }
@@ -47,21 +54,23 @@ impl SoundPlayerPlugin {
     ) {
         //
 
-        let mut spawn_audio = |handle: Handle<AudioSource>| {
+        let mut spawn_audio = |handle: Handle<AudioSource>, speed: f32| {
             commands.spawn(AudioBundle {
                 source: handle,
-                settings: PlaybackSettings::DESPAWN,
+                settings: PlaybackSettings::DESPAWN.with_speed(speed),
             });
         };
 
         for event in event_reader.read() {
             let mut eval = CollisionEvaluator::new(event);
             if eval.did(Ball).collide_with(Ceiling).or(SideWall).evaluate() {
-                spawn_audio(collision_sounds_resource.ceiling_or_side_wall_hit.clone());
-            } else if eval.did(Ball).collide_with(Floor).evaluate() {
-                spawn_audio(collision_sounds_resource.ball_missed.clone());
+                spawn_audio(collision_sounds_resource.ceiling_or_side_wall_hit.clone(), 1_f32);
+            } else if eval.did(Ball).collide_with(Floor).or(PlayerOneGoal).or(PlayerTwoGoal).evaluate() {
+                spawn_audio(collision_sounds_resource.ball_missed.clone(), 1_f32);
             } else if eval.did(Ball).collide_with(Paddle).evaluate() {
-                spawn_audio(collision_sounds_resource.paddle_hit.clone());
+                spawn_audio(collision_sounds_resource.paddle_hit.clone(), 1_f32);
+            } else if eval.did(Ball).collide_with(Brick).evaluate() {
+                spawn_audio(collision_sounds_resource.brick_hit.clone(), SOUND_BRICK_HIT_SPEED);
             }
         }
     }
@@ -70,6 +79,7 @@ impl SoundPlayerPlugin {
 fn preload_sound_effects(mut commands: Commands, asset_server: Res<AssetServer>) {
     let sounds = CollisionSoundsResource {
         ball_missed: asset_server.load(SOUND_BALL_MISSED),
+        brick_hit: asset_server.load(SOUND_BRICK_HIT),
         paddle_hit: asset_server.load(SOUND_PADDLE_HIT),
         ceiling_or_side_wall_hit: asset_server.load(SOUND_TOP_OR_SIDE_WALL_HIT),
     };
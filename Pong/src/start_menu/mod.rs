@@ -0,0 +1,7 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+pub(super) mod start_menu_plugin;
@@ -0,0 +1,343 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::{in_state, Component, IntoSystemConfigs, OnEnter, OnExit};
+
+use crate::game_controller::GamePlayState;
+
+/// The targets from which the Players choose the points needed to win a Versus Game.
+const TARGET_SCORES: [u32; 4] = [5, 7, 11, 21];
+
+const MENU_FONT_SIZE: f32 = 30.0;
+const TITLE_FONT_SIZE: f32 = 80.0;
+
+#[derive(Clone, PartialEq)]
+/// Defines the purposes of the Start Menu buttons.
+enum ButtonPurpose {
+    ChangeDifficulty,
+    ChangePace,
+    /// Turns the power-ups of Solo and Levels Games on or off.
+    ChangePowerUps,
+    ChangeTargetScore,
+    OpenControls,
+    PlayLevels,
+    PlaySolo,
+    PlayVersus,
+    PlayVersusComputer,
+    /// Plays back the latest Game, or the replay file loaded at startup.
+    WatchReplay,
+}
+
+/// Marker to indicate that an entity was spawned on the Start Menu.
+#[derive(Component)]
+struct OnStartMenu;
+
+/// Companion component for the text of a Start Menu button, allowing the text of the buttons that
+/// change a setting to show the setting's new value.
+#[derive(Component)]
+struct ButtonLabelComponent(ButtonPurpose);
+
+/// Marker for the text describing each Player's controls.
+#[derive(Component)]
+struct ControlsLabelComponent;
+
+/// Provides the UI and functionality for the Start Menu, on which the Players choose between a
+/// Solo Game, a Versus Game against one another or the computer and a Levels Game, as well as how
+/// quickly the rallies become hectic, the points needed to win, how well the computer plays and
+/// whether power-ups drop.
+/// The Controls screen is opened and the latest replay watched from here.
+pub(crate) struct StartMenuPlugin;
+
+impl Plugin for StartMenuPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .add_systems(OnEnter(GamePlayState::StartMenu), ui::spawn_menu)
+            .add_systems(
+                Update,
+                (functionality::button_interaction, ui::update_button_labels)
+                    .chain()
+                    .run_if(in_state(GamePlayState::StartMenu)),
+            )
+            .add_systems(OnExit(GamePlayState::StartMenu), ui::despawn_menu);
+    }
+}
+
+mod functionality {
+    use bevy::prelude::{
+        BackgroundColor, Button, Changed, Interaction, NextState, Query, Res, ResMut, With,
+    };
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::controls::ControlsView;
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::{GameMode, GamePlayState};
+    use crate::menu_theme::button_color;
+    use crate::replay::replays_resource::ReplaysResource;
+    use crate::replay::PlaybackState;
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+    use crate::start_menu::start_menu_plugin::{ButtonPurpose, TARGET_SCORES};
+
+    /// Provides button functionality, including state changes as well as response when clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn button_interaction(
+        mut game_settings: ResMut<GameSettingsResource>,
+        mut interactions: Query<
+            (
+                &Interaction,
+                &mut BackgroundColor,
+                &EntityInfoComponent<ButtonPurpose>,
+            ),
+            (Changed<Interaction>, With<Button>),
+        >,
+        mut next_controls_view: ResMut<NextState<ControlsView>>,
+        mut next_game_play: ResMut<NextState<GamePlayState>>,
+        mut next_playback: ResMut<NextState<PlaybackState>>,
+        replays: Res<ReplaysResource>,
+        mut scoreboard_resource: ResMut<ScoreboardResource>,
+    ) {
+        for (interaction, mut color, button_info) in &mut interactions {
+            *color = button_color(*interaction);
+
+            // Only a press acts on the button.
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+
+            // Which button was pressed?
+            match button_info.get_purpose() {
+                ButtonPurpose::ChangeDifficulty => {
+                    game_settings.computer_difficulty = game_settings.computer_difficulty.next();
+                }
+                ButtonPurpose::ChangePace => {
+                    game_settings.pace = game_settings.pace.next();
+                }
+                ButtonPurpose::ChangePowerUps => {
+                    game_settings.power_ups = !game_settings.power_ups;
+                }
+                ButtonPurpose::ChangeTargetScore => {
+                    // Move on to the next target, wrapping around after the last.
+                    let target_score = scoreboard_resource.get_target_score();
+                    let next_target_score = TARGET_SCORES
+                        .iter()
+                        .find(|score| **score > target_score)
+                        .unwrap_or(&TARGET_SCORES[0]);
+                    scoreboard_resource.set_target_score(*next_target_score);
+                }
+                ButtonPurpose::OpenControls => next_controls_view.set(ControlsView::Shown),
+                ButtonPurpose::PlayLevels => {
+                    game_settings.game_mode = GameMode::Levels;
+                    next_game_play.set(GamePlayState::Serve);
+                }
+                ButtonPurpose::PlaySolo => {
+                    game_settings.game_mode = GameMode::Solo;
+                    next_game_play.set(GamePlayState::Serve);
+                }
+                ButtonPurpose::PlayVersus => {
+                    game_settings.game_mode = GameMode::Versus;
+                    game_settings.player_two_is_computer = false;
+                    next_game_play.set(GamePlayState::Serve);
+                }
+                ButtonPurpose::PlayVersusComputer => {
+                    game_settings.game_mode = GameMode::Versus;
+                    game_settings.player_two_is_computer = true;
+                    next_game_play.set(GamePlayState::Serve);
+                }
+                ButtonPurpose::WatchReplay => {
+                    if replays.get_latest().is_some() {
+                        next_playback.set(PlaybackState::Playing);
+                    }
+                }
+            }
+        }
+    }
+}
+
+mod ui {
+    use bevy::prelude::{
+        default, AlignItems, BackgroundColor, BuildChildren, ButtonBundle, Commands,
+        DespawnRecursiveExt, DetectChanges, Entity, FlexDirection, JustifyContent, NodeBundle,
+        Query, Res, Style, Text, TextBundle, TextStyle, UiRect, Val, With, Without,
+    };
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
+    use crate::controls::controls_resource::ControlsResource;
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::Player;
+    use crate::menu_theme::{BUTTON_COLOR_NORMAL, MENU_TEXT_COLOR};
+    use crate::scoreboard::scoreboard_resource::ScoreboardResource;
+    use crate::start_menu::start_menu_plugin::{
+        ButtonLabelComponent, ButtonPurpose, ControlsLabelComponent, OnStartMenu, MENU_FONT_SIZE,
+        TITLE_FONT_SIZE,
+    };
+
+    /// The Start Menu buttons, row by row.
+    const BUTTON_ROWS: [&[ButtonPurpose]; 3] = [
+        &[
+            ButtonPurpose::PlaySolo,
+            ButtonPurpose::PlayVersus,
+            ButtonPurpose::PlayVersusComputer,
+            ButtonPurpose::PlayLevels,
+        ],
+        &[
+            ButtonPurpose::ChangePace,
+            ButtonPurpose::ChangeTargetScore,
+            ButtonPurpose::ChangeDifficulty,
+            ButtonPurpose::ChangePowerUps,
+        ],
+        &[ButtonPurpose::OpenControls, ButtonPurpose::WatchReplay],
+    ];
+
+    /// Removes the Start Menu UI widgets.
+    pub(super) fn despawn_menu(mut commands: Commands, menu_query: Query<Entity, With<OnStartMenu>>) {
+        for entity in &menu_query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Sets up and then spawns the Start Menu UI widgets.
+    pub(super) fn spawn_menu(
+        mut commands: Commands,
+        controls: Res<ControlsResource>,
+        game_settings: Res<GameSettingsResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+    ) {
+        //
+
+        let text_style = TextStyle {
+            color: *MENU_TEXT_COLOR,
+            font: default(),
+            font_size: MENU_FONT_SIZE,
+        };
+        let title_style = TextStyle {
+            font_size: TITLE_FONT_SIZE,
+            ..text_style.clone()
+        };
+
+        // Button template
+        let button_bundle = ButtonBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(16.)),
+                ..default()
+            },
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        };
+
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(20.),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                OnStartMenu,
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section("PONG", title_style));
+
+                for row in BUTTON_ROWS {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                column_gap: Val::Px(10.),
+                                flex_direction: FlexDirection::Row,
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for purpose in row {
+                                let title = button_title(purpose, &game_settings, &scoreboard_resource);
+                                parent
+                                    .spawn((button_bundle.clone(), EntityInfoComponent::new(purpose.clone())))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            TextBundle::from_section(title, text_style.clone()),
+                                            ButtonLabelComponent(purpose.clone()),
+                                        ));
+                                    });
+                            }
+                        });
+                }
+
+                parent.spawn((
+                    TextBundle::from_section(controls_description(&controls), text_style.clone()),
+                    ControlsLabelComponent,
+                ));
+            });
+    }
+
+    /// Shows the newly chosen settings on the buttons that change them and the newly chosen
+    /// controls below the buttons.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn update_button_labels(
+        controls: Res<ControlsResource>,
+        mut controls_text_query: Query<&mut Text, (With<ControlsLabelComponent>, Without<ButtonLabelComponent>)>,
+        game_settings: Res<GameSettingsResource>,
+        scoreboard_resource: Res<ScoreboardResource>,
+        mut text_query: Query<(&mut Text, &ButtonLabelComponent)>,
+    ) {
+        //
+
+        if game_settings.is_changed() || scoreboard_resource.is_changed() {
+            for (mut text, label) in &mut text_query {
+                text.sections[0].value = button_title(&label.0, &game_settings, &scoreboard_resource);
+            }
+        }
+
+        if controls.is_changed() {
+            for mut text in &mut controls_text_query {
+                text.sections[0].value = controls_description(&controls);
+            }
+        }
+    }
+
+    fn button_title(
+        purpose: &ButtonPurpose,
+        game_settings: &GameSettingsResource,
+        scoreboard_resource: &ScoreboardResource,
+    ) -> String {
+        match purpose {
+            ButtonPurpose::ChangeDifficulty => format!("Computer: {}", game_settings.computer_difficulty.title()),
+            ButtonPurpose::ChangePace => format!("Pace: {}", game_settings.pace.title()),
+            ButtonPurpose::ChangePowerUps => match game_settings.power_ups {
+                true => "Power-ups: On".to_string(),
+                false => "Power-ups: Off".to_string(),
+            },
+            ButtonPurpose::ChangeTargetScore => format!("First to {}", scoreboard_resource.get_target_score()),
+            ButtonPurpose::OpenControls => "Controls".to_string(),
+            ButtonPurpose::PlayLevels => "Levels".to_string(),
+            ButtonPurpose::PlaySolo => "Solo".to_string(),
+            ButtonPurpose::PlayVersus => "Versus".to_string(),
+            ButtonPurpose::PlayVersusComputer => "Versus Computer".to_string(),
+            ButtonPurpose::WatchReplay => "Watch Replay".to_string(),
+        }
+    }
+
+    /// Describes how each Player moves their Paddle.
+    fn controls_description(controls: &ControlsResource) -> String {
+        format!(
+            "{}: {}      {}: {}",
+            Player::One.title(),
+            controls.describe(Player::One),
+            Player::Two.title(),
+            controls.describe(Player::Two)
+        )
+    }
+}
@@ -0,0 +1,1282 @@
+// Pong Game
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod test_support {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    /// A directory of its own for a test's files, which is deleted along with them when dropped.
+    pub(crate) struct TempDirectory {
+        path: PathBuf,
+    }
+
+    impl TempDirectory {
+        pub(crate) fn new() -> Self {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let path = std::env::temp_dir().join(format!("pong-tests-{}-{}", std::process::id(), id));
+            fs::create_dir_all(&path).unwrap();
+            Self { path }
+        }
+
+        pub(crate) fn file(&self, name: &str) -> PathBuf {
+            self.path.join(name)
+        }
+    }
+
+    impl Drop for TempDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod rally_physics_tests {
+    use crate::physical_interactions::rally_physics::{contact_offset, Pace, RallyCurve};
+
+    const EPSILON: f32 = 0.0001;
+
+    fn curve() -> RallyCurve {
+        RallyCurve {
+            initial_speed: 500_f32,
+            speed_increase_per_hit: 50_f32,
+            max_speed: 700_f32,
+            max_deflection_angle: 60_f32.to_radians(),
+            spin_factor: 0.001,
+        }
+    }
+
+    #[test]
+    fn test_center_hit_returns_ball_straight() {
+        let direction = curve().deflect(0_f32, 0_f32, 1_f32);
+        assert!(direction.x.abs() < EPSILON);
+        assert!((direction.y - 1_f32).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_end_hit_returns_ball_at_max_angle() {
+        let max_angle = curve().max_deflection_angle;
+
+        let direction = curve().deflect(1_f32, 0_f32, 1_f32);
+        assert!((direction.x - max_angle.sin()).abs() < EPSILON);
+        assert!((direction.y - max_angle.cos()).abs() < EPSILON);
+
+        let direction = curve().deflect(-1_f32, 0_f32, 1_f32);
+        assert!((direction.x + max_angle.sin()).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angle_grows_with_offset() {
+        let near_center = curve().deflect(0.25, 0_f32, 1_f32);
+        let near_end = curve().deflect(0.75, 0_f32, 1_f32);
+        assert!(near_center.x > 0_f32);
+        assert!(near_end.x > near_center.x);
+    }
+
+    #[test]
+    fn test_paddle_velocity_adds_spin() {
+        let moving_right = curve().deflect(0_f32, 200_f32, 1_f32);
+        let moving_left = curve().deflect(0_f32, -200_f32, 1_f32);
+        assert!((moving_right.x - 0.2_f32.sin()).abs() < EPSILON);
+        assert!((moving_left.x + 0.2_f32.sin()).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angle_is_capped() {
+        let max_angle = curve().max_deflection_angle;
+        let direction = curve().deflect(1_f32, 10_000_f32, 1_f32);
+        assert!((direction.x - max_angle.sin()).abs() < EPSILON);
+        let direction = curve().deflect(-5_f32, -10_000_f32, 1_f32);
+        assert!((direction.x + max_angle.sin()).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_vertical_direction_is_kept() {
+        assert!(curve().deflect(0.5, 100_f32, 1_f32).y > 0_f32);
+        assert!(curve().deflect(0.5, 100_f32, -1_f32).y < 0_f32);
+    }
+
+    #[test]
+    fn test_deflection_is_a_unit_vector() {
+        for offset in [-1_f32, -0.3, 0_f32, 0.6, 1_f32] {
+            let direction = curve().deflect(offset, 150_f32, -1_f32);
+            assert!((direction.length() - 1_f32).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_speed_increases_with_each_hit_up_to_the_cap() {
+        assert_eq!(curve().speed_after_hits(0), 500_f32);
+        assert_eq!(curve().speed_after_hits(1), 550_f32);
+        assert_eq!(curve().speed_after_hits(4), 700_f32);
+        assert_eq!(curve().speed_after_hits(1_000), 700_f32);
+    }
+
+    #[test]
+    fn test_contact_offset() {
+        assert_eq!(contact_offset(100_f32, 100_f32, 50_f32), 0_f32);
+        assert_eq!(contact_offset(125_f32, 100_f32, 50_f32), 0.5);
+        assert_eq!(contact_offset(75_f32, 100_f32, 50_f32), -0.5);
+        assert_eq!(contact_offset(200_f32, 100_f32, 50_f32), 1_f32);
+        assert_eq!(contact_offset(0_f32, 100_f32, 50_f32), -1_f32);
+        assert_eq!(contact_offset(10_f32, 100_f32, 0_f32), 0_f32);
+    }
+
+    #[test]
+    fn test_paces_become_more_hectic() {
+        let relaxed = Pace::Relaxed.curve();
+        let standard = Pace::Standard.curve();
+        let frantic = Pace::Frantic.curve();
+
+        assert!(relaxed.initial_speed < standard.initial_speed && standard.initial_speed < frantic.initial_speed);
+        assert!(relaxed.max_speed < standard.max_speed && standard.max_speed < frantic.max_speed);
+        for curve in [relaxed, standard, frantic] {
+            assert!(curve.initial_speed <= curve.max_speed);
+            assert!(curve.max_deflection_angle < 90_f32.to_radians());
+        }
+    }
+
+    #[test]
+    fn test_pace_wraps_around() {
+        assert_eq!(Pace::Relaxed.next(), Pace::Standard);
+        assert_eq!(Pace::Standard.next(), Pace::Frantic);
+        assert_eq!(Pace::Frantic.next(), Pace::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod intercept_predictor_tests {
+    use crate::computer_opponent::intercept_predictor::InterceptPredictor;
+    use bevy::math::Vec2;
+
+    const EPSILON: f32 = 0.001;
+
+    /// The Ball's center travels between x = 10 and x = 790.
+    fn predictor() -> InterceptPredictor {
+        InterceptPredictor::new(800_f32, 10_f32)
+    }
+
+    fn assert_predicts(prediction: Option<f32>, expected_x: f32) {
+        let x = prediction.expect("Expected a prediction");
+        assert!((x - expected_x).abs() < EPSILON, "Predicted {x}, expected {expected_x}");
+    }
+
+    #[test]
+    fn test_straight_shot() {
+        //
+
+        assert_predicts(predictor().predict(Vec2::new(400_f32, 100_f32), Vec2::new(0_f32, 1_f32), 500_f32), 400_f32);
+        assert_predicts(predictor().predict(Vec2::new(400_f32, 100_f32), Vec2::new(1_f32, 1_f32), 300_f32), 600_f32);
+
+        // Toward a contact line below the Ball.
+        assert_predicts(predictor().predict(Vec2::new(400_f32, 500_f32), Vec2::new(-1_f32, -2_f32), 100_f32), 200_f32);
+    }
+
+    #[test]
+    fn test_one_side_wall_reflection() {
+        //
+
+        // Off the right SideWall, 110 past it.
+        assert_predicts(predictor().predict(Vec2::new(700_f32, 100_f32), Vec2::new(1_f32, 1_f32), 300_f32), 680_f32);
+
+        // Off the left SideWall, 110 past it.
+        assert_predicts(predictor().predict(Vec2::new(100_f32, 0_f32), Vec2::new(-1_f32, 1_f32), 200_f32), 120_f32);
+    }
+
+    #[test]
+    fn test_several_side_wall_reflections() {
+        //
+
+        // Right, left and then right again, ending 50 back from the right SideWall.
+        assert_predicts(predictor().predict(Vec2::new(400_f32, 0_f32), Vec2::new(1_f32, 1_f32), 2000_f32), 740_f32);
+
+        // A whole number of round trips returns to the same x.
+        assert_predicts(predictor().predict(Vec2::new(400_f32, 0_f32), Vec2::new(1_f32, 1_f32), 1560_f32), 400_f32);
+    }
+
+    #[test]
+    fn test_ball_moving_away_or_horizontally() {
+        //
+
+        assert_eq!(predictor().predict(Vec2::new(400_f32, 100_f32), Vec2::new(0_f32, -1_f32), 500_f32), None);
+        assert_eq!(predictor().predict(Vec2::new(400_f32, 500_f32), Vec2::new(1_f32, 1_f32), 100_f32), None);
+        assert_eq!(predictor().predict(Vec2::new(400_f32, 100_f32), Vec2::new(1_f32, 0_f32), 500_f32), None);
+    }
+
+    #[test]
+    fn test_degenerate_window() {
+        //
+
+        // The window is no wider than the Ball. So, its center can only be at one x.
+        let predictor = InterceptPredictor::new(20_f32, 10_f32);
+        assert_predicts(predictor.predict(Vec2::new(10_f32, 0_f32), Vec2::new(1_f32, 1_f32), 500_f32), 10_f32);
+
+        let predictor = InterceptPredictor::new(10_f32, 10_f32);
+        assert_predicts(predictor.predict(Vec2::new(5_f32, 0_f32), Vec2::new(-1_f32, 1_f32), 500_f32), 10_f32);
+    }
+}
+
+#[cfg(test)]
+mod scoreboard_resource_tests {
+    use crate::game_controller::{GameMode, Player};
+    use crate::scoreboard::scoreboard_resource::{ScoreboardResource, STARTING_LIVES};
+
+    #[test]
+    fn test_solo_game_is_over_once_lives_run_out() {
+        let mut scoreboard = ScoreboardResource::default();
+        for _ in 1..STARTING_LIVES {
+            scoreboard.lose_life();
+            assert!(!scoreboard.is_game_over(GameMode::Solo));
+        }
+        scoreboard.lose_life();
+        assert_eq!(scoreboard.get_lives(), 0);
+        assert!(scoreboard.is_game_over(GameMode::Solo));
+
+        // Lives never go below zero.
+        scoreboard.lose_life();
+        assert_eq!(scoreboard.get_lives(), 0);
+    }
+
+    #[test]
+    fn test_versus_game_is_over_once_target_reached() {
+        let mut scoreboard = ScoreboardResource::default();
+        scoreboard.set_target_score(2);
+        assert!(!scoreboard.score_point(Player::Two));
+        assert!(!scoreboard.is_game_over(GameMode::Versus));
+        assert!(scoreboard.score_point(Player::Two));
+        assert!(scoreboard.is_game_over(GameMode::Versus));
+        assert_eq!(scoreboard.get_winner(), Some(Player::Two));
+    }
+
+    #[test]
+    fn test_levels_game_is_over_once_last_level_cleared() {
+        let mut scoreboard = ScoreboardResource::default();
+        assert_eq!(scoreboard.get_level(), 0);
+        assert!(!scoreboard.complete_level(2));
+        assert_eq!(scoreboard.get_level(), 1);
+        assert!(!scoreboard.is_game_over(GameMode::Levels));
+        assert!(scoreboard.complete_level(2));
+        assert!(scoreboard.is_game_over(GameMode::Levels));
+        assert_eq!(scoreboard.get_winner(), Some(Player::One));
+    }
+
+    #[test]
+    fn test_levels_game_is_over_once_lives_run_out() {
+        let mut scoreboard = ScoreboardResource::default();
+        for _ in 0..STARTING_LIVES {
+            scoreboard.lose_life();
+        }
+        assert!(scoreboard.is_game_over(GameMode::Levels));
+        assert_eq!(scoreboard.get_winner(), None);
+    }
+
+    #[test]
+    fn test_reset_restores_lives_and_keeps_target() {
+        let mut scoreboard = ScoreboardResource::default();
+        scoreboard.set_target_score(5);
+        scoreboard.lose_life();
+        scoreboard.score = 40;
+        scoreboard.complete_level(3);
+        scoreboard.reset();
+        assert_eq!(scoreboard.get_lives(), STARTING_LIVES);
+        assert_eq!(scoreboard.get_level(), 0);
+        assert_eq!(scoreboard.score, 0);
+        assert_eq!(scoreboard.get_target_score(), 5);
+    }
+}
+
+#[cfg(test)]
+mod level_tests {
+    use std::fs;
+    use std::path::Path;
+
+    use bevy::prelude::Color;
+
+    use crate::levels::level::Level;
+    use crate::levels::levels_resource::LevelsResource;
+
+    fn level_file(brick_types: &str, layout: &str) -> String {
+        format!(r#"{{ "name": "Test", "brick_types": {{ {} }}, "layout": [{}] }}"#, brick_types, layout)
+    }
+
+    #[test]
+    fn test_layout_places_bricks_by_row_and_column() {
+        let contents = level_file(
+            r#""R": { "color": "ff0000", "hit_points": 1, "points": 10 },
+               "S": { "color": "808080", "hit_points": 3, "points": 50 }"#,
+            r#""R.R", "S S S", "   ""#,
+        );
+        let level = Level::parse(&contents).unwrap();
+
+        assert_eq!(level.get_name(), "Test");
+        assert_eq!(level.get_grid_size(), (5, 3));
+        let cells: Vec<(usize, usize, u32)> =
+            level.get_bricks().iter().map(|brick| (brick.row, brick.column, brick.hit_points)).collect();
+        assert_eq!(cells, vec![(0, 0, 1), (0, 2, 1), (1, 0, 3), (1, 2, 3), (1, 4, 3)]);
+        assert_eq!(level.get_bricks()[0].color, Color::hex("ff0000").unwrap());
+        assert_eq!(level.get_bricks()[2].points, 50);
+    }
+
+    #[test]
+    fn test_invalid_levels_are_rejected() {
+        let brick = r#""R": { "color": "ff0000", "hit_points": 1, "points": 10 }"#;
+
+        assert!(Level::parse(&level_file(brick, r#""RX""#)).unwrap_err().contains("unknown brick type"));
+        assert!(Level::parse(&level_file(brick, r#""...""#)).unwrap_err().contains("no bricks"));
+        let no_hit_points = r#""R": { "color": "ff0000", "hit_points": 0, "points": 10 }"#;
+        assert!(Level::parse(&level_file(no_hit_points, r#""R""#)).unwrap_err().contains("hit point"));
+        let bad_color = r#""R": { "color": "red", "hit_points": 1, "points": 10 }"#;
+        assert!(Level::parse(&level_file(bad_color, r#""R""#)).unwrap_err().contains("invalid color"));
+        let long_key = r#""RR": { "color": "ff0000", "hit_points": 1, "points": 10 }"#;
+        assert!(Level::parse(&level_file(long_key, r#""R""#)).unwrap_err().contains("single character"));
+        assert!(Level::parse("not json").is_err());
+    }
+
+    #[test]
+    fn test_bundled_level_files_are_valid() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join("levels");
+        for entry in fs::read_dir(&directory).unwrap() {
+            let path = entry.unwrap().path();
+            assert!(Level::load_from(&path).is_ok(), "{} is not a valid level", path.display());
+        }
+
+        let levels = LevelsResource::load_from(&directory).unwrap();
+        assert!(levels.get_level_count() > 1);
+        assert_eq!(levels.get_level(0), LevelsResource::default().get_level(0));
+    }
+
+    #[test]
+    fn test_missing_levels_folder_is_an_error() {
+        assert!(LevelsResource::load_from(Path::new("no-such-levels-folder")).is_err());
+    }
+}
+
+#[cfg(test)]
+mod high_score_table_tests {
+    use std::fs;
+
+    use chrono::NaiveDate;
+
+    use crate::game_controller::GameMode;
+    use crate::high_scores::high_score_table::{HighScoreEntry, HighScoreTable, MAX_ENTRIES_PER_MODE};
+    use crate::tests::test_support::TempDirectory;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+    }
+
+    fn entry(initials: &str, score: i64, game_mode: GameMode) -> HighScoreEntry {
+        HighScoreEntry::new(initials, score, game_mode, date())
+    }
+
+    #[test]
+    fn test_entries_are_ranked_highest_first() {
+        let mut table = HighScoreTable::default();
+        assert_eq!(table.insert(entry("AAA", 10, GameMode::Solo)), Some(1));
+        assert_eq!(table.insert(entry("BBB", 30, GameMode::Solo)), Some(1));
+        assert_eq!(table.insert(entry("CCC", 20, GameMode::Solo)), Some(2));
+
+        // A tie ranks below the score recorded first.
+        assert_eq!(table.insert(entry("DDD", 20, GameMode::Solo)), Some(3));
+
+        let initials: Vec<&str> = table.get_entries(GameMode::Solo).map(|entry| entry.initials.as_str()).collect();
+        assert_eq!(initials, ["BBB", "CCC", "DDD", "AAA"]);
+    }
+
+    #[test]
+    fn test_game_modes_are_ranked_separately() {
+        let mut table = HighScoreTable::default();
+        table.insert(entry("AAA", 50, GameMode::Solo));
+        assert_eq!(table.insert(entry("BBB", 3, GameMode::Versus)), Some(1));
+        assert_eq!(table.get_entries(GameMode::Solo).count(), 1);
+        assert_eq!(table.get_entries(GameMode::Versus).count(), 1);
+    }
+
+    #[test]
+    fn test_full_table_keeps_only_the_best() {
+        let mut table = HighScoreTable::default();
+        for score in 1..=MAX_ENTRIES_PER_MODE as i64 {
+            table.insert(entry("AAA", score * 10, GameMode::Solo));
+        }
+        assert!(!table.qualifies(GameMode::Solo, 10));
+        assert!(table.qualifies(GameMode::Solo, 11));
+        assert_eq!(table.insert(entry("BBB", 5, GameMode::Solo)), None);
+        assert_eq!(table.insert(entry("CCC", 15, GameMode::Solo)), Some(MAX_ENTRIES_PER_MODE));
+        assert_eq!(table.get_entries(GameMode::Solo).count(), MAX_ENTRIES_PER_MODE);
+    }
+
+    #[test]
+    fn test_zero_score_does_not_qualify() {
+        assert!(!HighScoreTable::default().qualifies(GameMode::Solo, 0));
+    }
+
+    #[test]
+    fn test_initials_are_sanitized() {
+        assert_eq!(entry("j.d-x9", 1, GameMode::Solo).initials, "JDX");
+    }
+
+    #[test]
+    fn test_table_survives_save_and_load() {
+        let directory = TempDirectory::new();
+        let path = directory.file("round_trip.json");
+        let mut table = HighScoreTable::default();
+        table.insert(entry("AAA", 40, GameMode::Solo));
+        table.insert(entry("BBB", 2, GameMode::Versus));
+        table.save_to(&path).unwrap();
+        assert_eq!(HighScoreTable::load_from(&path), table);
+    }
+
+    #[test]
+    fn test_missing_file_loads_empty_table() {
+        let directory = TempDirectory::new();
+        let path = directory.file("missing.json");
+        assert_eq!(HighScoreTable::load_from(&path), HighScoreTable::default());
+    }
+
+    #[test]
+    fn test_corrupt_file_is_set_aside() {
+        let directory = TempDirectory::new();
+        let path = directory.file("corrupt.json");
+        let corrupt_path = directory.file("corrupt.json.corrupt");
+        fs::write(&path, "{ not json").unwrap();
+
+        assert_eq!(HighScoreTable::load_from(&path), HighScoreTable::default());
+        assert!(!path.exists());
+        assert_eq!(fs::read_to_string(&corrupt_path).unwrap(), "{ not json");
+    }
+}
+
+#[cfg(test)]
+mod controls_resource_tests {
+    use bevy::prelude::KeyCode;
+
+    use crate::controls::controls_resource::{key_title, ControlsResource};
+    use crate::controls::{InputSource, Side};
+    use crate::game_controller::Player;
+
+    #[test]
+    fn test_default_controls() {
+        let controls = ControlsResource::default();
+        assert_eq!(controls.describe(Player::One), "ArrowLeft and ArrowRight");
+        assert_eq!(controls.describe(Player::Two), "A and D");
+    }
+
+    #[test]
+    fn test_bind_unused_key() {
+        let mut controls = ControlsResource::default();
+        controls.bind_key(Player::Two, Side::Left, KeyCode::KeyJ);
+        assert_eq!(controls.get(Player::Two).key_bindings.left, KeyCode::KeyJ);
+        assert_eq!(controls.get(Player::Two).key_bindings.right, KeyCode::KeyD);
+    }
+
+    #[test]
+    fn test_bind_used_key_trades_places() {
+        let mut controls = ControlsResource::default();
+
+        // Another Player's key.
+        controls.bind_key(Player::One, Side::Left, KeyCode::KeyD);
+        assert_eq!(controls.get(Player::One).key_bindings.left, KeyCode::KeyD);
+        assert_eq!(controls.get(Player::Two).key_bindings.right, KeyCode::ArrowLeft);
+
+        // The Player's own key for the other side.
+        controls.bind_key(Player::One, Side::Right, KeyCode::KeyD);
+        assert_eq!(controls.get(Player::One).key_bindings.right, KeyCode::KeyD);
+        assert_eq!(controls.get(Player::One).key_bindings.left, KeyCode::ArrowRight);
+    }
+
+    #[test]
+    fn test_describe_other_input_sources() {
+        let mut controls = ControlsResource::default();
+        controls.set_input_source(Player::One, InputSource::Gamepad);
+        controls.set_input_source(Player::Two, InputSource::Touch);
+        assert_eq!(controls.describe(Player::One), "Gamepad");
+        assert_eq!(controls.describe(Player::Two), "Touch");
+    }
+
+    #[test]
+    fn test_controls_survive_serialization() {
+        let mut controls = ControlsResource::default();
+        controls.set_input_source(Player::Two, InputSource::Mouse);
+        controls.bind_key(Player::One, Side::Left, KeyCode::Digit1);
+        let json = serde_json::to_string(&controls).unwrap();
+        assert_eq!(serde_json::from_str::<ControlsResource>(&json).unwrap(), controls);
+    }
+
+    #[test]
+    fn test_key_titles() {
+        assert_eq!(key_title(KeyCode::KeyA), "A");
+        assert_eq!(key_title(KeyCode::Digit7), "7");
+        assert_eq!(key_title(KeyCode::ArrowLeft), "ArrowLeft");
+    }
+}
+
+#[cfg(test)]
+mod replay_log_tests {
+    use std::fs;
+
+    use crate::computer_opponent::Difficulty;
+    use crate::game_controller::GameMode;
+    use crate::paddle::paddle_intents_resource::PaddleIntent;
+    use crate::physical_interactions::rally_physics::Pace;
+    use crate::replay::replay_log::{ReplayLog, ReplaySettings, REPLAY_FORMAT_VERSION};
+    use crate::tests::test_support::TempDirectory;
+
+    fn settings() -> ReplaySettings {
+        ReplaySettings {
+            computer_difficulty: Difficulty::Hard,
+            game_mode: GameMode::Versus,
+            pace: Pace::Frantic,
+            player_two_is_computer: false,
+            power_ups: true,
+            target_score: 7,
+        }
+    }
+
+    #[test]
+    fn test_repeated_input_is_stored_as_a_run() {
+        let mut replay = ReplayLog::new(42, settings(), 1280_f32, 720_f32);
+        for _ in 0..100 {
+            replay.push(PaddleIntent::Hold, PaddleIntent::Hold);
+        }
+        replay.push(PaddleIntent::Move(-1_f32), PaddleIntent::Hold);
+        replay.push(PaddleIntent::Move(-1_f32), PaddleIntent::Follow(10_f32));
+
+        let contents = serde_json::to_string(&replay).unwrap();
+        assert_eq!(contents.matches("\"ticks\"").count(), 3);
+
+        let inputs = replay.expand();
+        assert_eq!(inputs.len(), 102);
+        assert!(inputs[..100].iter().all(|intents| *intents == (PaddleIntent::Hold, PaddleIntent::Hold)));
+        assert_eq!(inputs[100], (PaddleIntent::Move(-1_f32), PaddleIntent::Hold));
+        assert_eq!(inputs[101], (PaddleIntent::Move(-1_f32), PaddleIntent::Follow(10_f32)));
+    }
+
+    #[test]
+    fn test_replay_survives_save_and_load() {
+        let mut replay = ReplayLog::new(42, settings(), 1280_f32, 720_f32);
+        replay.push(PaddleIntent::Follow(300_f32), PaddleIntent::Move(0.5));
+        let directory = TempDirectory::new();
+        let path = directory.file("saved.json");
+
+        replay.save_to(&path).unwrap();
+        let loaded = ReplayLog::load_from(&path).unwrap();
+
+        assert_eq!(loaded, replay);
+        assert_eq!(loaded.get_seed(), 42);
+        assert_eq!(*loaded.get_settings(), settings());
+        assert_eq!(loaded.get_window_size(), (1280_f32, 720_f32));
+    }
+
+    #[test]
+    fn test_other_versions_are_not_played() {
+        let replay = ReplayLog::new(42, settings(), 1280_f32, 720_f32);
+        let contents = serde_json::to_string(&replay).unwrap().replace(&format!("\"version\":{}", REPLAY_FORMAT_VERSION), "\"version\":99");
+        let directory = TempDirectory::new();
+        let path = directory.file("future.json");
+        fs::write(&path, contents).unwrap();
+
+        let error = ReplayLog::load_from(&path).unwrap_err();
+        assert!(error.contains("version 99"));
+    }
+
+    #[test]
+    fn test_missing_file_is_an_error() {
+        let directory = TempDirectory::new();
+        assert!(ReplayLog::load_from(&directory.file("missing.json")).is_err());
+    }
+}
+
+#[cfg(test)]
+mod power_ups_resource_tests {
+    use crate::paddle::paddle_plugin::PADDLE_WIDTH;
+    use crate::power_ups::power_ups_resource::PowerUpsResource;
+    use crate::power_ups::PowerUpKind;
+
+    #[test]
+    fn test_power_up_lasts_for_its_duration() {
+        let mut power_ups = PowerUpsResource::default();
+        power_ups.activate(PowerUpKind::SlowBall);
+        let duration_secs = PowerUpKind::SlowBall.duration_secs().unwrap();
+
+        power_ups.tick(duration_secs - 1_f32);
+        assert!(power_ups.is_active(PowerUpKind::SlowBall));
+        assert!(power_ups.get_ball_speed_factor() < 1_f32);
+
+        power_ups.tick(1_f32);
+        assert!(!power_ups.is_active(PowerUpKind::SlowBall));
+        assert_eq!(power_ups.get_ball_speed_factor(), 1_f32);
+    }
+
+    #[test]
+    fn test_catching_an_active_power_up_restarts_its_time() {
+        let mut power_ups = PowerUpsResource::default();
+        let duration_secs = PowerUpKind::WidePaddle.duration_secs().unwrap();
+        power_ups.activate(PowerUpKind::WidePaddle);
+        power_ups.tick(duration_secs / 2_f32);
+
+        power_ups.activate(PowerUpKind::WidePaddle);
+
+        assert_eq!(power_ups.get_secs_left(PowerUpKind::WidePaddle), Some(duration_secs));
+        assert!(power_ups.get_paddle_width() > PADDLE_WIDTH);
+    }
+
+    #[test]
+    fn test_power_ups_are_independent_and_cleared_together() {
+        let mut power_ups = PowerUpsResource::default();
+        assert_eq!(power_ups.get_score_multiplier(), 1);
+
+        power_ups.activate(PowerUpKind::ScoreMultiplier);
+        power_ups.activate(PowerUpKind::StickyPaddle);
+        assert_eq!(power_ups.get_score_multiplier(), 2);
+        assert!(power_ups.is_active(PowerUpKind::StickyPaddle));
+        assert_eq!(power_ups.get_paddle_width(), PADDLE_WIDTH);
+
+        power_ups.clear();
+        assert_eq!(power_ups.get_score_multiplier(), 1);
+        assert!(!power_ups.is_active(PowerUpKind::StickyPaddle));
+    }
+
+    #[test]
+    fn test_multi_ball_is_not_timed() {
+        let mut power_ups = PowerUpsResource::default();
+        power_ups.activate(PowerUpKind::MultiBall);
+        assert!(!power_ups.is_active(PowerUpKind::MultiBall));
+    }
+}
+
+#[cfg(test)]
+mod simulation_tests {
+    use bevy::asset::{AssetApp, AssetPlugin};
+    use bevy::prelude::{
+        App, Entity, EventReader, Fixed, FixedUpdate, Image, IntoSystemConfigs,
+        MinimalPlugins, NextState, ResMut, Resource, State, Time, Transform, Vec2, Window,
+    };
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::window::PrimaryWindow;
+
+    use crate::ball::ball_component::BallComponent;
+    use crate::ball::ball_plugin::BallPlugin;
+    use crate::computer_opponent::computer_opponent_plugin::ComputerOpponentPlugin;
+    use crate::game_controller::game_settings_resource::GameSettingsResource;
+    use crate::game_controller::{GameMode, GamePlayState, Player};
+    use crate::high_scores::high_scores_resource::HighScoresResource;
+    use crate::levels::brick_component::BrickComponent;
+    use crate::levels::level::Level;
+    use crate::levels::levels_plugin::LevelsPlugin;
+    use crate::levels::levels_resource::LevelsResource;
+    use crate::paddle::paddle_component::PaddleComponent;
+    use crate::paddle::paddle_intents_resource::{PaddleIntent, PaddleIntentsResource};
+    use crate::paddle::paddle_plugin::{PaddlePlugin, PADDLE_WIDTH};
+    use crate::physical_interactions::collision_evaluator::CollisionEvaluator;
+    use crate::physical_interactions::collision_event::CollisionEvent;
+    use crate::physical_interactions::physical_interactions_actor::PhysicalInteractionActor;
+    use crate::physical_interactions::physical_interactions_plugin::PhysicalInteractionsPlugin;
+    use crate::power_ups::power_up_component::PowerUpComponent;
+    use crate::power_ups::power_ups_plugin::PowerUpsPlugin;
+    use crate::power_ups::power_ups_resource::PowerUpsResource;
+    use crate::power_ups::PowerUpKind;
+    use crate::replay::replay_log::{ReplayLog, ReplaySettings};
+    use crate::replay::replays_resource::ReplaysResource;
+    use crate::round::round_plugin::RoundPlugin;
+    use crate::scoreboard::scoreboard_plugin::ScoreboardPlugin;
+    use crate::scoreboard::scoreboard_resource::{ScoreboardResource, STARTING_LIVES};
+    use crate::simulation::simulation_plugin::SimulationPlugin;
+    use crate::simulation::simulation_rng_resource::SimulationRngResource;
+    use crate::simulation::SimulationSet;
+
+    const EPSILON: f32 = 0.001;
+    const SEED: u64 = 2024;
+
+    /// Counts the simulation ticks run so far.
+    #[derive(Default, Resource)]
+    struct TickCount(u64);
+
+    /// Keeps every CollisionEvent sent so far.
+    #[derive(Default, Resource)]
+    struct Collisions(Vec<CollisionEvent>);
+
+    /// Plays a Game without a window, renderer or input devices. Each Player's input is scripted
+    /// through the PaddleIntentsResource, and each call to tick() advances the simulation by
+    /// exactly one fixed timestep.
+    struct HeadlessGame {
+        app: App,
+    }
+
+    impl HeadlessGame {
+        //
+
+        /// Starts a Game, played with the specified seed, and stops at its first Serve.
+        fn new(game_mode: GameMode, seed: u64) -> Self {
+            Self::start(game_mode, seed, LevelsResource::default())
+        }
+
+        /// Starts a Levels Game, played with the specified seed and levels, and stops at its first
+        /// Serve.
+        fn with_levels(levels: Vec<Level>, seed: u64) -> Self {
+            Self::start(GameMode::Levels, seed, LevelsResource::new(levels))
+        }
+
+        fn start(game_mode: GameMode, seed: u64, levels: LevelsResource) -> Self {
+            //
+
+            let mut app = App::new();
+            app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+                .init_asset::<Image>()
+                .init_resource::<GameSettingsResource>()
+                .init_resource::<HighScoresResource>()
+                .init_resource::<ReplaysResource>()
+                .insert_state(GamePlayState::StartMenu)
+                .add_plugins((
+                    SimulationPlugin,
+                    BallPlugin,
+                    PaddlePlugin,
+                    ComputerOpponentPlugin,
+                    PhysicalInteractionsPlugin,
+                    ScoreboardPlugin,
+                    RoundPlugin,
+                    LevelsPlugin,
+                    PowerUpsPlugin,
+                ))
+                .insert_resource(levels)
+                .init_resource::<TickCount>()
+                .init_resource::<Collisions>()
+                .add_systems(
+                    FixedUpdate,
+                    (
+                        count_tick.before(SimulationSet::Input),
+                        record_collisions.after(SimulationSet::Collisions).before(SimulationSet::Outcome),
+                    ),
+                );
+
+            // Advance the clock by exactly one timestep per update.
+            let timestep = app.world.resource::<Time<Fixed>>().timestep();
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+
+            app.world.spawn((Window::default(), PrimaryWindow));
+            app.world.resource_mut::<GameSettingsResource>().game_mode = game_mode;
+            // Power-ups drop at random, so the tests that need one place it themselves.
+            app.world.resource_mut::<GameSettingsResource>().power_ups = false;
+            app.world.resource_mut::<SimulationRngResource>().next_seed = Some(seed);
+            app.world.resource_mut::<NextState<GamePlayState>>().set(GamePlayState::Serve);
+
+            let mut game = Self { app };
+            game.tick();
+            game
+        }
+
+        fn brick_count(&mut self) -> usize {
+            self.app.world.query::<&BrickComponent>().iter(&self.app.world).count()
+        }
+
+        fn ball(&mut self) -> Option<(Vec2, Vec2, f32)> {
+            self.app
+                .world
+                .query::<(&Transform, &BallComponent)>()
+                .get_single(&self.app.world)
+                .ok()
+                .map(|(transform, ball)| (transform.translation.truncate(), ball.get_direction(), ball.get_speed()))
+        }
+
+        fn ball_position(&mut self) -> Option<Vec2> {
+            self.ball().map(|(position, _, _)| position)
+        }
+
+        fn balls(&mut self) -> Vec<(Entity, Vec2)> {
+            self.app
+                .world
+                .query::<(Entity, &Transform, &BallComponent)>()
+                .iter(&self.app.world)
+                .map(|(entity, transform, _)| (entity, transform.translation.truncate()))
+                .collect()
+        }
+
+        fn is_ball_stuck(&mut self) -> bool {
+            self.app
+                .world
+                .query::<&BallComponent>()
+                .get_single(&self.app.world)
+                .is_ok_and(|ball| ball.get_stuck_offset().is_some())
+        }
+
+        fn collision_count(&self, target: PhysicalInteractionActor) -> usize {
+            self.app
+                .world
+                .resource::<Collisions>()
+                .0
+                .iter()
+                .filter(|event| {
+                    CollisionEvaluator::new(event)
+                        .did(PhysicalInteractionActor::Ball)
+                        .collide_with(target.clone())
+                        .evaluate()
+                })
+                .count()
+        }
+
+        fn paddle_width(&mut self, player: Player) -> f32 {
+            self.app
+                .world
+                .query::<&PaddleComponent>()
+                .iter(&self.app.world)
+                .find(|paddle| paddle.get_player() == player)
+                .map(|paddle| paddle.get_width())
+                .unwrap()
+        }
+
+        /// Places a power-up of the specified kind on Player One's Paddle, to be caught during the
+        /// next tick.
+        fn place_power_up(&mut self, kind: PowerUpKind) {
+            let x = self.paddle_x(Player::One);
+            self.app.world.spawn((Transform::from_xyz(x, 40_f32, 1.5), PowerUpComponent::new(kind)));
+        }
+
+        fn power_up_count(&mut self) -> usize {
+            self.app.world.query::<&PowerUpComponent>().iter(&self.app.world).count()
+        }
+
+        fn power_ups(&self) -> &PowerUpsResource {
+            self.app.world.resource::<PowerUpsResource>()
+        }
+
+        fn paddle_x(&mut self, player: Player) -> f32 {
+            self.app
+                .world
+                .query::<(&Transform, &PaddleComponent)>()
+                .iter(&self.app.world)
+                .find(|(_, paddle)| paddle.get_player() == player)
+                .map(|(transform, _)| transform.translation.x)
+                .unwrap()
+        }
+
+        fn scoreboard(&self) -> &ScoreboardResource {
+            self.app.world.resource::<ScoreboardResource>()
+        }
+
+        fn set_intent(&mut self, player: Player, intent: PaddleIntent) {
+            self.app.world.resource_mut::<PaddleIntentsResource>().set(player, intent);
+        }
+
+        fn state(&self) -> GamePlayState {
+            self.app.world.resource::<State<GamePlayState>>().get().clone()
+        }
+
+        /// Runs a single simulation tick.
+        fn tick(&mut self) {
+            //
+
+            let tick_count = self.app.world.resource::<TickCount>().0;
+
+            // The first update starts the clock without running a tick.
+            for _ in 0..3 {
+                self.app.update();
+                if self.app.world.resource::<TickCount>().0 > tick_count {
+                    return;
+                }
+            }
+
+            panic!("The simulation did not tick.");
+        }
+
+        /// Runs ticks until the condition is met, returning the number of ticks run, or panics if
+        /// it is not met within the specified number of ticks.
+        fn tick_until(&mut self, max_ticks: u32, condition: impl Fn(&mut Self) -> bool) -> u32 {
+            for ticks in 0..max_ticks {
+                if condition(self) {
+                    return ticks;
+                }
+                self.tick();
+            }
+            panic!("The condition was not met within {} ticks.", max_ticks);
+        }
+    }
+
+    fn count_tick(mut tick_count: ResMut<TickCount>) {
+        tick_count.0 += 1;
+    }
+
+    fn record_collisions(mut collisions: ResMut<Collisions>, mut event_reader: EventReader<CollisionEvent>) {
+        collisions.0.extend(event_reader.read().cloned());
+    }
+
+    /// Returns the scripted input of both Players for the specified tick.
+    fn scripted_intents(tick: u32) -> (PaddleIntent, PaddleIntent) {
+        match tick % 240 {
+            0..=59 => (PaddleIntent::Move(-1_f32), PaddleIntent::Hold),
+            60..=119 => (PaddleIntent::Hold, PaddleIntent::Move(0.5)),
+            120..=179 => (PaddleIntent::Follow(900_f32), PaddleIntent::Move(-1_f32)),
+            _ => (PaddleIntent::Move(1_f32), PaddleIntent::Follow(200_f32)),
+        }
+    }
+
+    /// Plays a Versus Game with scripted input, returning the Ball's position after each tick.
+    fn play_scripted_versus_game(seed: u64, ticks: u32) -> (Vec<Option<Vec2>>, HeadlessGame) {
+        //
+
+        let mut game = HeadlessGame::new(GameMode::Versus, seed);
+        let mut positions = vec![];
+
+        for tick in 0..ticks {
+            let (player_one_intent, player_two_intent) = scripted_intents(tick);
+            game.set_intent(Player::One, player_one_intent);
+            game.set_intent(Player::Two, player_two_intent);
+            game.tick();
+            positions.push(game.ball_position());
+        }
+
+        (positions, game)
+    }
+
+    #[test]
+    fn test_ball_waits_to_be_served_then_moves_one_step_per_tick() {
+        let mut game = HeadlessGame::new(GameMode::Solo, SEED);
+        let serve_point = game.ball_position().unwrap();
+
+        // The Ball waits for a second at its serve point.
+        let serve_ticks = game.tick_until(200, |game| {
+            let serving = game.state() == GamePlayState::Serve;
+            if serving {
+                assert_eq!(game.ball_position(), Some(serve_point));
+            }
+            !serving
+        });
+        assert!((115..=125).contains(&serve_ticks), "served after {} ticks", serve_ticks);
+
+        let timestep = game.app.world.resource::<Time<Fixed>>().timestep().as_secs_f32();
+        for _ in 0..10 {
+            let (position, direction, speed) = game.ball().unwrap();
+            game.tick();
+            let expected_position = position + direction * speed * timestep;
+            assert!(game.ball_position().unwrap().distance(expected_position) < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_paddle_follows_scripted_input() {
+        let mut game = HeadlessGame::new(GameMode::Solo, SEED);
+        let start_x = game.paddle_x(Player::One);
+        let timestep = game.app.world.resource::<Time<Fixed>>().timestep().as_secs_f32();
+
+        // Full speed to the left moves the Paddle PADDLE_SPEED pixels per second.
+        game.set_intent(Player::One, PaddleIntent::Move(-1_f32));
+        game.tick();
+        assert!((start_x - game.paddle_x(Player::One) - 700_f32 * timestep).abs() < EPSILON);
+
+        // Holding keeps it in place.
+        let held_x = game.paddle_x(Player::One);
+        game.set_intent(Player::One, PaddleIntent::Hold);
+        game.tick();
+        assert_eq!(game.paddle_x(Player::One), held_x);
+
+        // Following reaches the target and stops there.
+        game.set_intent(Player::One, PaddleIntent::Follow(900_f32));
+        game.tick_until(120, |game| (game.paddle_x(Player::One) - 900_f32).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_returning_the_ball_scores_points() {
+        let mut game = HeadlessGame::new(GameMode::Solo, SEED);
+
+        // Keep the Paddle under the Ball until it has been returned.
+        game.tick_until(2_000, |game| {
+            if let Some(ball_position) = game.ball_position() {
+                game.set_intent(Player::One, PaddleIntent::Follow(ball_position.x));
+            }
+            game.collision_count(PhysicalInteractionActor::Paddle) > 0
+        });
+
+        assert_eq!(game.collision_count(PhysicalInteractionActor::Floor), 0);
+        assert_eq!(game.scoreboard().score, 5);
+        assert_eq!(game.scoreboard().get_lives(), STARTING_LIVES);
+        assert!(game.ball().unwrap().1.y > 0_f32);
+    }
+
+    #[test]
+    fn test_missing_the_ball_costs_a_life_and_starts_a_new_round() {
+        let mut game = HeadlessGame::new(GameMode::Solo, SEED);
+
+        // Keep the Paddle well away from the Ball.
+        game.tick_until(2_000, |game| {
+            if let Some(ball_position) = game.ball_position() {
+                let window_width = 1280_f32;
+                let away_x = (ball_position.x + window_width / 2_f32) % window_width;
+                game.set_intent(Player::One, PaddleIntent::Follow(away_x));
+            }
+            game.state() == GamePlayState::PointScored
+        });
+
+        assert_eq!(game.collision_count(PhysicalInteractionActor::Floor), 1);
+        assert_eq!(game.collision_count(PhysicalInteractionActor::Paddle), 0);
+        assert_eq!(game.scoreboard().get_lives(), STARTING_LIVES - 1);
+        assert_eq!(game.ball(), None);
+
+        // After a short pause, the next round starts with a new Ball.
+        game.tick_until(200, |game| game.state() == GamePlayState::Serve);
+        assert!(game.ball().is_some());
+    }
+
+    #[test]
+    fn test_goal_scores_a_point_for_the_opponent() {
+        let mut game = HeadlessGame::new(GameMode::Versus, SEED);
+
+        game.tick_until(10_000, |game| game.state() == GamePlayState::PointScored);
+
+        let player_one_score = game.scoreboard().get_player_score(Player::One);
+        let player_two_score = game.scoreboard().get_player_score(Player::Two);
+        assert_eq!(player_one_score + player_two_score, 1);
+        let goals = game.collision_count(PhysicalInteractionActor::PlayerOneGoal)
+            + game.collision_count(PhysicalInteractionActor::PlayerTwoGoal);
+        assert_eq!(goals, 1);
+    }
+
+    #[test]
+    fn test_same_seed_and_input_play_out_identically() {
+        let (positions, game) = play_scripted_versus_game(SEED, 3_000);
+        let (replayed_positions, replayed_game) = play_scripted_versus_game(SEED, 3_000);
+
+        assert_eq!(positions, replayed_positions);
+        for player in [Player::One, Player::Two] {
+            assert_eq!(
+                game.scoreboard().get_player_score(player),
+                replayed_game.scoreboard().get_player_score(player)
+            );
+        }
+        assert_eq!(
+            game.app.world.resource::<Collisions>().0.len(),
+            replayed_game.app.world.resource::<Collisions>().0.len()
+        );
+
+        // A different seed serves the Ball differently.
+        let (other_positions, _) = play_scripted_versus_game(SEED + 1, 3_000);
+        assert_ne!(positions, other_positions);
+    }
+
+    #[test]
+    fn test_recorded_game_plays_back_exactly() {
+        //
+
+        let (positions, game) = play_scripted_versus_game(SEED, 3_000);
+
+        // Record the Game as the ReplayPlugin would and pass it through a replay file's contents.
+        let settings = ReplaySettings::capture(
+            game.app.world.resource::<GameSettingsResource>(),
+            game.app.world.resource::<ScoreboardResource>(),
+        );
+        let mut replay = ReplayLog::new(SEED, settings, 1280_f32, 720_f32);
+        for tick in 0..3_000 {
+            let (player_one_intent, player_two_intent) = scripted_intents(tick);
+            replay.push(player_one_intent, player_two_intent);
+        }
+        let contents = serde_json::to_string(&replay).unwrap();
+        let replay = serde_json::from_str::<ReplayLog>(&contents).unwrap();
+
+        // Play it back.
+        let mut played_back = HeadlessGame::new(replay.get_settings().game_mode, replay.get_seed());
+        let mut played_back_positions = vec![];
+        for (player_one_intent, player_two_intent) in replay.expand() {
+            played_back.set_intent(Player::One, player_one_intent);
+            played_back.set_intent(Player::Two, player_two_intent);
+            played_back.tick();
+            played_back_positions.push(played_back.ball_position());
+        }
+
+        assert_eq!(positions, played_back_positions);
+        for player in [Player::One, Player::Two] {
+            assert_eq!(
+                game.scoreboard().get_player_score(player),
+                played_back.scoreboard().get_player_score(player)
+            );
+        }
+    }
+
+    /// Returns a level with a single Brick, spanning the window, that takes the specified hits.
+    fn single_brick_level(name: &str, hit_points: u32) -> Level {
+        Level::parse(&format!(
+            r#"{{ "name": "{}", "brick_types": {{ "B": {{ "color": "4a8fd9", "hit_points": {}, "points": 50 }} }}, "layout": ["B"] }}"#,
+            name, hit_points
+        ))
+        .unwrap()
+    }
+
+    /// Keeps the Paddle under the Ball until the round is over.
+    fn return_ball_until_round_over(game: &mut HeadlessGame) {
+        game.tick_until(10_000, |game| {
+            if let Some(ball_position) = game.ball_position() {
+                game.set_intent(Player::One, PaddleIntent::Follow(ball_position.x));
+            }
+            game.state() == GamePlayState::PointScored
+        });
+    }
+
+    #[test]
+    fn test_clearing_a_level_moves_on_to_the_next() {
+        let levels = vec![single_brick_level("One", 2), single_brick_level("Two", 1)];
+        let mut game = HeadlessGame::with_levels(levels, SEED);
+        assert_eq!(game.brick_count(), 1);
+
+        return_ball_until_round_over(&mut game);
+
+        assert_eq!(game.collision_count(PhysicalInteractionActor::Floor), 0);
+        assert_eq!(game.collision_count(PhysicalInteractionActor::Brick), 2);
+        assert_eq!(game.brick_count(), 0);
+        assert_eq!(game.scoreboard().score, 50);
+        assert_eq!(game.scoreboard().get_level(), 1);
+        assert_eq!(game.scoreboard().get_lives(), STARTING_LIVES);
+
+        // After a short pause, the next level's Bricks are spawned for the Serve.
+        game.tick_until(200, |game| game.state() == GamePlayState::Serve);
+        assert_eq!(game.brick_count(), 1);
+    }
+
+    #[test]
+    fn test_clearing_the_last_level_wins_the_game() {
+        let mut game = HeadlessGame::with_levels(vec![single_brick_level("Only", 1)], SEED);
+
+        return_ball_until_round_over(&mut game);
+        assert_eq!(game.scoreboard().get_winner(), Some(Player::One));
+
+        game.tick_until(200, |game| game.state() != GamePlayState::PointScored);
+        assert_eq!(game.state(), GamePlayState::EnterInitials);
+        assert_eq!(game.brick_count(), 0);
+    }
+
+    /// Starts a Game and runs it until the Ball has been served.
+    fn start_rally(game_mode: GameMode) -> HeadlessGame {
+        let mut game = HeadlessGame::new(game_mode, SEED);
+        game.tick_until(200, |game| game.state() == GamePlayState::Rally);
+        game
+    }
+
+    /// Keeps the Paddle under the Ball until the Paddle has returned it the specified number of
+    /// times.
+    fn return_ball(game: &mut HeadlessGame, returns: usize) {
+        game.tick_until(5_000, |game| {
+            if let Some(ball_position) = game.ball_position() {
+                game.set_intent(Player::One, PaddleIntent::Follow(ball_position.x));
+            }
+            game.collision_count(PhysicalInteractionActor::Paddle) >= returns
+        });
+    }
+
+    #[test]
+    fn test_wide_paddle_lasts_until_the_round_is_over() {
+        let mut game = start_rally(GameMode::Solo);
+
+        game.place_power_up(PowerUpKind::WidePaddle);
+        game.tick();
+
+        assert_eq!(game.power_up_count(), 0);
+        assert!(game.power_ups().is_active(PowerUpKind::WidePaddle));
+        assert!(game.paddle_width(Player::One) > PADDLE_WIDTH);
+
+        // Missing the Ball ends the round and with it the power-up.
+        game.set_intent(Player::One, PaddleIntent::Follow(0_f32));
+        game.tick_until(2_000, |game| game.state() == GamePlayState::PointScored);
+        game.tick();
+        assert!(!game.power_ups().is_active(PowerUpKind::WidePaddle));
+        assert_eq!(game.paddle_width(Player::One), PADDLE_WIDTH);
+    }
+
+    #[test]
+    fn test_score_multiplier_doubles_the_points_for_a_return() {
+        let mut game = start_rally(GameMode::Solo);
+
+        game.place_power_up(PowerUpKind::ScoreMultiplier);
+        return_ball(&mut game, 1);
+
+        assert_eq!(game.scoreboard().score, 10);
+    }
+
+    #[test]
+    fn test_multi_ball_splits_the_ball_and_only_the_last_ball_costs_a_life() {
+        let mut game = start_rally(GameMode::Solo);
+
+        game.place_power_up(PowerUpKind::MultiBall);
+        game.tick();
+        assert_eq!(game.balls().len(), 3);
+
+        // Take the Paddle out of play so that every Ball is missed.
+        let paddle = game.app.world.query::<(Entity, &PaddleComponent)>().single(&game.app.world).0;
+        game.app.world.despawn(paddle);
+
+        let mut balls_in_play = vec![];
+        for _ in 0..5_000 {
+            if game.state() != GamePlayState::Rally {
+                break;
+            }
+            balls_in_play.push(game.balls().len());
+            game.tick();
+        }
+
+        // The Rally carried on while any Ball was in play.
+        assert_eq!(game.state(), GamePlayState::PointScored);
+        assert!(balls_in_play.contains(&2) || balls_in_play.contains(&1));
+        assert_eq!(game.scoreboard().get_lives(), STARTING_LIVES - 1);
+
+        // Each CollisionEvent tells which Ball hit the Floor.
+        let mut missed_balls = game
+            .app
+            .world
+            .resource::<Collisions>()
+            .0
+            .iter()
+            .filter(|event| {
+                CollisionEvaluator::new(event)
+                    .did(PhysicalInteractionActor::Ball)
+                    .collide_with(PhysicalInteractionActor::Floor)
+                    .evaluate()
+            })
+            .map(|event| event.get_ball())
+            .collect::<Vec<_>>();
+        missed_balls.sort();
+        missed_balls.dedup();
+        assert_eq!(missed_balls.len(), 3);
+    }
+
+    #[test]
+    fn test_sticky_paddle_holds_the_ball_then_releases_it() {
+        let mut game = start_rally(GameMode::Solo);
+
+        game.place_power_up(PowerUpKind::StickyPaddle);
+        return_ball(&mut game, 1);
+        game.tick();
+        assert!(game.is_ball_stuck());
+
+        // The held Ball is carried along with the Paddle.
+        let held_at = game.ball_position().unwrap();
+        let offset_x = held_at.x - game.paddle_x(Player::One);
+        game.set_intent(Player::One, PaddleIntent::Move(-1_f32));
+        game.tick();
+        let carried_to = game.ball_position().unwrap();
+        assert!((carried_to.x - game.paddle_x(Player::One) - offset_x).abs() < EPSILON);
+        assert_eq!(carried_to.y, held_at.y);
+
+        // Then released, upwards.
+        game.set_intent(Player::One, PaddleIntent::Hold);
+        game.tick_until(120, |game| !game.is_ball_stuck());
+        game.tick();
+        assert!(game.ball_position().unwrap().y > held_at.y);
+        assert_eq!(game.collision_count(PhysicalInteractionActor::Paddle), 1);
+    }
+
+    #[test]
+    fn test_power_ups_drop_from_bricks_when_turned_on() {
+        let mut game = HeadlessGame::new(GameMode::Levels, SEED);
+        game.app.world.resource_mut::<GameSettingsResource>().power_ups = true;
+
+        game.tick_until(10_000, |game| {
+            if let Some(ball_position) = game.ball_position() {
+                game.set_intent(Player::One, PaddleIntent::Follow(ball_position.x));
+            }
+            game.power_up_count() > 0
+        });
+
+        assert!(game.collision_count(PhysicalInteractionActor::Brick) > 0);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod entity_info_component;
 pub mod status_text;
+pub mod text_input;
+mod tests;
 
 //  Bevy Helpers Lib
 //
@@ -0,0 +1,131 @@
+//  Bevy Helpers Lib
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod text_input_component_tests {
+    use bevy::prelude::TextStyle;
+
+    use crate::text_input::text_input_component::{TextInputComponent, TextInputFilter};
+
+    fn new_text_input(value: &str) -> TextInputComponent {
+        TextInputComponent::new(TextStyle::default()).with_value(value)
+    }
+
+    #[test]
+    fn test_insert() {
+        //
+
+        let mut text_input = new_text_input("héllo");
+        text_input.move_caret(1, false);
+
+        assert!(text_input.insert("ü"));
+        assert_eq!(text_input.value(), "hüéllo");
+        assert_eq!(text_input.caret(), 2);
+
+        // Inserting replaces the selection.
+        text_input.select_all();
+        assert!(text_input.insert("日本"));
+        assert_eq!(text_input.value(), "日本");
+        assert_eq!(text_input.caret(), 2);
+        assert_eq!(text_input.selection(), None);
+    }
+
+    #[test]
+    fn test_insert_applies_filter() {
+        //
+
+        let mut text_input = new_text_input("").with_filter(TextInputFilter::Digits);
+        assert!(text_input.insert("1a2 3\n"));
+        assert_eq!(text_input.value(), "123");
+        assert!(!text_input.insert("abc"));
+
+        let mut text_input = new_text_input("").with_filter(TextInputFilter::Alphanumeric);
+        assert!(text_input.insert("Zoë 42!"));
+        assert_eq!(text_input.value(), "Zoë42");
+    }
+
+    #[test]
+    fn test_paste_is_truncated_at_max_length() {
+        //
+
+        let mut text_input = new_text_input("").with_max_length(5);
+        assert!(text_input.insert("ñ123456789"));
+        assert_eq!(text_input.value(), "ñ1234");
+        assert_eq!(text_input.len(), 5);
+        assert!(!text_input.insert("5"));
+
+        // A selection makes room for the pasted text.
+        text_input.move_left(true);
+        text_input.move_left(true);
+        assert!(text_input.insert("abcdef"));
+        assert_eq!(text_input.value(), "ñ12ab");
+    }
+
+    #[test]
+    fn test_delete_backward() {
+        //
+
+        let mut text_input = new_text_input("añb");
+        text_input.move_left(false);
+
+        assert!(text_input.delete_backward());
+        assert_eq!(text_input.value(), "ab");
+        assert_eq!(text_input.caret(), 1);
+
+        assert!(text_input.delete_backward());
+        assert_eq!(text_input.value(), "b");
+        assert!(!text_input.delete_backward());
+
+        // A selection is deleted as a whole.
+        let mut text_input = new_text_input("日本語");
+        text_input.move_caret(1, false);
+        text_input.move_caret(3, true);
+        assert!(text_input.delete_backward());
+        assert_eq!(text_input.value(), "日");
+        assert_eq!(text_input.caret(), 1);
+    }
+
+    #[test]
+    fn test_move_left_and_right() {
+        //
+
+        let mut text_input = new_text_input("héllo");
+
+        text_input.move_right(false);
+        assert_eq!(text_input.caret(), 5);
+
+        text_input.move_left(true);
+        text_input.move_left(true);
+        assert_eq!(text_input.selection(), Some(3..5));
+        assert_eq!(text_input.selected_text(), Some("lo"));
+
+        // Without extending, a selection collapses to its start or end.
+        text_input.move_right(false);
+        assert_eq!(text_input.caret(), 5);
+        assert_eq!(text_input.selection(), None);
+
+        text_input.move_caret(1, false);
+        text_input.move_right(true);
+        assert_eq!(text_input.selected_text(), Some("é"));
+        text_input.move_left(false);
+        assert_eq!(text_input.caret(), 1);
+
+        text_input.move_left(false);
+        text_input.move_left(false);
+        assert_eq!(text_input.caret(), 0);
+    }
+
+    #[test]
+    fn test_slice() {
+        //
+
+        let text_input = new_text_input("日本語 text");
+
+        assert_eq!(text_input.slice(0..1), "日");
+        assert_eq!(text_input.slice(1..3), "本語");
+        assert_eq!(text_input.slice(3..text_input.len()), " text");
+        assert_eq!(text_input.slice(2..2), "");
+    }
+}
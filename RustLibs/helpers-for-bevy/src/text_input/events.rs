@@ -0,0 +1,19 @@
+use bevy::prelude::{Entity, Event};
+
+/// Sent whenever the user edits the value of a text field.
+#[derive(Event)]
+pub struct TextInputChangedEvent {
+    /// The text field that was edited.
+    pub entity: Entity,
+    /// The new value of the text field.
+    pub value: String,
+}
+
+/// Sent when the user presses Enter in a text field.
+#[derive(Event)]
+pub struct TextInputSubmittedEvent {
+    /// The text field that was submitted.
+    pub entity: Entity,
+    /// The value of the text field.
+    pub value: String,
+}
@@ -0,0 +1,4 @@
+pub mod events;
+pub mod text_input_component;
+pub mod text_input_plugin;
+mod text_input_resource;
@@ -0,0 +1,326 @@
+use std::ops::Range;
+
+use bevy::prelude::{Bundle, Component, Interaction, Style, TextBundle, TextStyle};
+
+//  Bevy Helpers Lib
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// Limits the characters that may be typed or pasted into a text field.
+#[derive(Clone, Copy, Default)]
+pub enum TextInputFilter {
+    /// Accepts any printable character.
+    #[default]
+    Any,
+    /// Accepts letters and digits.
+    Alphanumeric,
+    /// Accepts the digits 0 through 9.
+    Digits,
+    /// Accepts the characters for which the function returns true.
+    Custom(fn(char) -> bool),
+}
+
+impl TextInputFilter {
+    //
+
+    /// Indicates whether the specified character may be entered.
+    pub fn accepts(&self, char: char) -> bool {
+        //
+
+        // Early return.
+        if char.is_control() {
+            return false;
+        }
+
+        match self {
+            TextInputFilter::Any => true,
+            TextInputFilter::Alphanumeric => char.is_alphanumeric(),
+            TextInputFilter::Digits => char.is_ascii_digit(),
+            TextInputFilter::Custom(accepts) => accepts(char),
+        }
+    }
+}
+
+/// A focusable, single-line text field. Spawn it using a TextInputBundle and listen for
+/// TextInputChangedEvent and TextInputSubmittedEvent to learn of the user's edits.
+///
+/// The caret and selection are kept as character, rather than byte, positions.
+#[derive(Clone, Component)]
+pub struct TextInputComponent {
+    caret: usize,
+    filter: TextInputFilter,
+    focused: bool,
+    max_length: Option<usize>,
+    placeholder: String,
+    selection_anchor: Option<usize>,
+    pub(crate) text_style: TextStyle,
+    value: String,
+}
+
+impl TextInputComponent {
+    //
+
+    /// Creates a new, empty and unfocused, TextInputComponent instance that draws its text using
+    /// the specified TextStyle.
+    pub fn new(text_style: TextStyle) -> Self {
+        Self {
+            caret: 0,
+            filter: TextInputFilter::Any,
+            focused: false,
+            max_length: None,
+            placeholder: "".to_string(),
+            selection_anchor: None,
+            text_style,
+            value: "".to_string(),
+        }
+    }
+
+    /// Limits the characters that may be entered.
+    pub fn with_filter(mut self, filter: TextInputFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Gives the text field the keyboard focus.
+    pub fn with_focus(mut self) -> Self {
+        self.focused = true;
+        self
+    }
+
+    /// Limits the number of characters that may be entered.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the text shown, in the placeholder color, while the value is empty.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Indicates whether the text field has the keyboard focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns the placeholder text.
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    /// Replaces the value, placing the caret at its end. The value is not filtered.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.caret = self.len();
+        self.selection_anchor = None;
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub(crate) fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Deletes the selection or, without one, the character before the caret. Returns whether
+    /// the value changed.
+    pub(crate) fn delete_backward(&mut self) -> bool {
+        //
+
+        // Early return.
+        if self.delete_selection() {
+            return true;
+        }
+
+        // Early return.
+        if self.caret == 0 {
+            return false;
+        }
+
+        self.caret -= 1;
+        let start = self.byte_index(self.caret);
+        self.value.remove(start);
+        true
+    }
+
+    /// Deletes the selection or, without one, the character after the caret. Returns whether the
+    /// value changed.
+    pub(crate) fn delete_forward(&mut self) -> bool {
+        //
+
+        // Early return.
+        if self.delete_selection() {
+            return true;
+        }
+
+        // Early return.
+        if self.caret == self.len() {
+            return false;
+        }
+
+        let start = self.byte_index(self.caret);
+        self.value.remove(start);
+        true
+    }
+
+    /// Inserts the accepted characters of the specified text at the caret, replacing any
+    /// selection and stopping at the maximum length. Returns whether the value changed.
+    pub(crate) fn insert(&mut self, text: &str) -> bool {
+        //
+
+        let accepted: Vec<char> = text.chars().filter(|char| self.filter.accepts(*char)).collect();
+
+        // Early return.
+        if accepted.is_empty() {
+            return false;
+        }
+
+        let mut changed = self.delete_selection();
+
+        for char in accepted {
+            if self.max_length.is_some_and(|max_length| self.len() >= max_length) {
+                break;
+            }
+            let index = self.byte_index(self.caret);
+            self.value.insert(index, char);
+            self.caret += 1;
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Moves the caret to the specified character position. When extending the selection, the
+    /// text between the caret's old and new positions becomes selected.
+    pub(crate) fn move_caret(&mut self, position: usize, extend_selection: bool) {
+        //
+
+        let position = position.min(self.len());
+
+        match extend_selection {
+            true => {
+                if self.selection_anchor.is_none() {
+                    self.selection_anchor = Some(self.caret);
+                }
+            }
+            false => self.selection_anchor = None,
+        }
+
+        self.caret = position;
+    }
+
+    /// Moves the caret one character to the left. Without extending the selection, a selection
+    /// collapses to its start.
+    pub(crate) fn move_left(&mut self, extend_selection: bool) {
+        match (self.selection(), extend_selection) {
+            (Some(selection), false) => self.move_caret(selection.start, false),
+            _ => self.move_caret(self.caret.saturating_sub(1), extend_selection),
+        }
+    }
+
+    /// Moves the caret one character to the right. Without extending the selection, a selection
+    /// collapses to its end.
+    pub(crate) fn move_right(&mut self, extend_selection: bool) {
+        match (self.selection(), extend_selection) {
+            (Some(selection), false) => self.move_caret(selection.end, false),
+            _ => self.move_caret(self.caret + 1, extend_selection),
+        }
+    }
+
+    /// Selects the entire value.
+    pub(crate) fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.caret = self.len();
+    }
+
+    /// Returns the selected character positions, if any text is selected.
+    pub(crate) fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+        match anchor == self.caret {
+            true => None,
+            false => Some(anchor.min(self.caret)..anchor.max(self.caret)),
+        }
+    }
+
+    /// Returns the selected text, if any.
+    pub(crate) fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|selection| self.slice(selection))
+    }
+
+    /// Gives or takes away the keyboard focus, e.g. when the app moves the focus between its text
+    /// fields. Clicking a text field focuses it without this.
+    pub fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.selection_anchor = None;
+        }
+    }
+
+    /// Returns the text at the specified character positions.
+    pub(crate) fn slice(&self, range: Range<usize>) -> &str {
+        &self.value[self.byte_index(range.start)..self.byte_index(range.end)]
+    }
+
+    /// Converts a character position into a byte index of the value.
+    fn byte_index(&self, position: usize) -> usize {
+        self.value.char_indices().nth(position).map(|(index, _)| index).unwrap_or(self.value.len())
+    }
+
+    /// Deletes the selected text. Returns whether there was a selection to delete.
+    fn delete_selection(&mut self) -> bool {
+        //
+
+        let Some(selection) = self.selection() else {
+            self.selection_anchor = None;
+            return false;
+        };
+
+        let range = self.byte_index(selection.start)..self.byte_index(selection.end);
+        self.value.replace_range(range, "");
+        self.caret = selection.start;
+        self.selection_anchor = None;
+        true
+    }
+
+    /// Returns the length of the value, in characters.
+    pub(crate) fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+}
+
+/// Bundles a TextInputComponent with the widgets needed to draw it and to focus it when clicked.
+#[derive(Bundle)]
+pub struct TextInputBundle {
+    interaction: Interaction,
+    text: TextBundle,
+    text_input: TextInputComponent,
+}
+
+impl TextInputBundle {
+    //
+
+    /// Creates a new TextInputBundle instance for the specified text field.
+    pub fn new(text_input: TextInputComponent) -> Self {
+        Self {
+            interaction: Interaction::default(),
+            text: TextBundle::from_section("", text_input.text_style.clone()),
+            text_input,
+        }
+    }
+
+    /// Sets the layout Style of the text field.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.text.style = style;
+        self
+    }
+}
@@ -0,0 +1,225 @@
+use bevy::prelude::{App, Color, IntoSystemConfigs, Plugin, Update};
+
+use crate::text_input::events::{TextInputChangedEvent, TextInputSubmittedEvent};
+use crate::text_input::text_input_resource::{TextInputClipboardResource, TextInputSettingsResource};
+
+//  Bevy Helpers Lib
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// TextInputPlugin provides single-line text fields, spawned using a TextInputBundle. A text field
+/// gains the keyboard focus when clicked and supports caret movement, shift-selection, Select All,
+/// Cut, Copy and Paste, a character filter, a maximum length and placeholder text. Pressing Enter
+/// sends a TextInputSubmittedEvent.
+#[derive(Default)]
+pub struct TextInputPlugin {
+    settings_resource: TextInputSettingsResource,
+}
+
+impl Plugin for TextInputPlugin {
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .insert_resource(self.settings_resource.clone())
+            .insert_non_send_resource(TextInputClipboardResource::default())
+            .add_event::<TextInputChangedEvent>()
+            .add_event::<TextInputSubmittedEvent>()
+            .add_systems(
+                Update,
+                (
+                    functionality::focus_on_click,
+                    functionality::handle_keyboard,
+                    ui::update_text,
+                )
+                    .chain(),
+            );
+    }
+}
+
+impl TextInputPlugin {
+    //
+
+    /// Creates a new TextInputPlugin instance with the specified placeholder and selected text
+    /// colors.
+    pub fn new_with_colors(placeholder_color: Color, selection_color: Color) -> Self {
+        Self {
+            settings_resource: TextInputSettingsResource::new_with_colors(placeholder_color, selection_color),
+        }
+    }
+}
+
+mod functionality {
+    use bevy::input::keyboard::KeyboardInput;
+    use bevy::input::ButtonState;
+    use bevy::prelude::{
+        ButtonInput, Changed, Entity, EventReader, EventWriter, Interaction, KeyCode, NonSendMut,
+        Query, ReceivedCharacter, Res, With,
+    };
+
+    use crate::text_input::events::{TextInputChangedEvent, TextInputSubmittedEvent};
+    use crate::text_input::text_input_component::TextInputComponent;
+    use crate::text_input::text_input_resource::TextInputClipboardResource;
+
+    /// Moves the keyboard focus to the text field that was clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn focus_on_click(
+        interactions: Query<(Entity, &Interaction), (Changed<Interaction>, With<TextInputComponent>)>,
+        mut text_inputs: Query<(Entity, &mut TextInputComponent)>,
+    ) {
+        //
+
+        let Some((clicked, _)) = interactions.iter().find(|(_, interaction)| **interaction == Interaction::Pressed) else {
+            return;
+        };
+
+        for (entity, mut text_input) in &mut text_inputs {
+            let focused = entity == clicked;
+            if text_input.is_focused() != focused {
+                text_input.set_focus(focused);
+            }
+        }
+    }
+
+    /// Applies the keyboard input to the focused text field.
+    #[allow(clippy::too_many_arguments)] // Each input source is a separate system parameter.
+    pub(super) fn handle_keyboard(
+        mut changed_event_writer: EventWriter<TextInputChangedEvent>,
+        mut character_reader: EventReader<ReceivedCharacter>,
+        mut clipboard: NonSendMut<TextInputClipboardResource>,
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut key_reader: EventReader<KeyboardInput>,
+        mut submitted_event_writer: EventWriter<TextInputSubmittedEvent>,
+        mut text_inputs: Query<(Entity, &mut TextInputComponent)>,
+    ) {
+        //
+
+        // Read the events even without a focused text field so that they are not applied later.
+        // Unlike ButtonInput, the KeyboardInput events repeat while a key is held down.
+        let characters: String = character_reader.read().flat_map(|event| event.char.chars()).collect();
+        let key_codes: Vec<KeyCode> = key_reader
+            .read()
+            .filter(|event| event.state == ButtonState::Pressed)
+            .map(|event| event.key_code)
+            .collect();
+
+        let Some((entity, mut text_input)) = text_inputs.iter_mut().find(|(_, text_input)| text_input.is_focused()) else {
+            return;
+        };
+
+        // Control on Linux and Windows, Command on macOS.
+        let shortcut = keyboard_input.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ]);
+        let shift = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+        let mut changed = false;
+
+        for key_code in key_codes {
+            match (key_code, shortcut) {
+                (KeyCode::ArrowLeft, _) => text_input.move_left(shift),
+                (KeyCode::ArrowRight, _) => text_input.move_right(shift),
+                (KeyCode::Home, _) => text_input.move_caret(0, shift),
+                (KeyCode::End, _) => {
+                    let end = text_input.len();
+                    text_input.move_caret(end, shift);
+                }
+                (KeyCode::Backspace, _) => changed |= text_input.delete_backward(),
+                (KeyCode::Delete, _) => changed |= text_input.delete_forward(),
+                (KeyCode::Enter | KeyCode::NumpadEnter, _) => {
+                    submitted_event_writer.send(TextInputSubmittedEvent {
+                        entity,
+                        value: text_input.value().to_string(),
+                    });
+                }
+                (KeyCode::KeyA, true) => text_input.select_all(),
+                (KeyCode::KeyC, true) => {
+                    if let Some(text) = text_input.selected_text() {
+                        clipboard.set_text(text);
+                    }
+                }
+                (KeyCode::KeyX, true) => {
+                    if let Some(text) = text_input.selected_text() {
+                        clipboard.set_text(text);
+                        changed |= text_input.delete_backward();
+                    }
+                }
+                (KeyCode::KeyV, true) => {
+                    if let Some(text) = clipboard.get_text() {
+                        changed |= text_input.insert(&text);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Characters typed along with Control or Command are shortcuts rather than text.
+        if !shortcut {
+            changed |= text_input.insert(&characters);
+        }
+
+        if changed {
+            changed_event_writer.send(TextInputChangedEvent {
+                entity,
+                value: text_input.value().to_string(),
+            });
+        }
+    }
+}
+
+mod ui {
+    use bevy::prelude::{Changed, Query, Res, Text, TextSection, TextStyle};
+
+    use crate::text_input::text_input_component::TextInputComponent;
+    use crate::text_input::text_input_resource::TextInputSettingsResource;
+
+    /// Redraws the text fields that have changed, showing the caret in the focused field, the
+    /// selection in the selection color and, in place of an empty value, the placeholder.
+    pub(super) fn update_text(
+        settings: Res<TextInputSettingsResource>,
+        mut text_inputs: Query<(&TextInputComponent, &mut Text), Changed<TextInputComponent>>,
+    ) {
+        //
+
+        for (text_input, mut text) in &mut text_inputs {
+            //
+
+            let text_style = text_input.text_style.clone();
+            let caret = match text_input.is_focused() {
+                true => settings.caret.to_string(),
+                false => "".to_string(),
+            };
+
+            text.sections = if text_input.value().is_empty() {
+                let placeholder_style = TextStyle {
+                    color: settings.placeholder_color,
+                    ..text_style.clone()
+                };
+                vec![
+                    TextSection::new(caret, text_style),
+                    TextSection::new(text_input.placeholder(), placeholder_style),
+                ]
+            } else if let Some(selection) = text_input.selection() {
+                let selection_style = TextStyle {
+                    color: settings.selection_color,
+                    ..text_style.clone()
+                };
+                vec![
+                    TextSection::new(text_input.slice(0..selection.start), text_style.clone()),
+                    TextSection::new(text_input.slice(selection.clone()), selection_style),
+                    TextSection::new(text_input.slice(selection.end..text_input.len()), text_style),
+                ]
+            } else {
+                let caret_position = text_input.caret();
+                vec![
+                    TextSection::new(text_input.slice(0..caret_position), text_style.clone()),
+                    TextSection::new(caret, text_style.clone()),
+                    TextSection::new(text_input.slice(caret_position..text_input.len()), text_style),
+                ]
+            };
+        }
+    }
+}
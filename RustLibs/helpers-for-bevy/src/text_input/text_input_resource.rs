@@ -0,0 +1,73 @@
+use arboard::Clipboard;
+use bevy::prelude::{Color, Resource};
+
+//  Bevy Helpers Lib
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// Encapsulates the TextInputPlugin style settings.
+#[derive(Clone, Resource)]
+pub(super) struct TextInputSettingsResource {
+    pub(super) caret: char,
+    pub(super) placeholder_color: Color,
+    pub(super) selection_color: Color,
+}
+
+impl TextInputSettingsResource {
+    //
+
+    /// Creates a new TextInputSettingsResource instance.
+    pub(super) fn new_with_colors(placeholder_color: Color, selection_color: Color) -> Self {
+        Self {
+            placeholder_color,
+            selection_color,
+            ..Default::default()
+        }
+    }
+}
+
+/// Provides default instantiation.
+impl Default for TextInputSettingsResource {
+    fn default() -> Self {
+        Self {
+            caret: '|',
+            placeholder_color: Color::GRAY,
+            selection_color: Color::rgb(0.4, 0.6, 1.0),
+        }
+    }
+}
+
+/// Provides access to the system clipboard. The clipboard is kept for the life of the app because,
+/// on some platforms, copied text is lost once the clipboard is dropped.
+///
+/// The clipboard is not available on every platform, e.g. without a display server. Copying and
+/// pasting then quietly do nothing.
+#[derive(Default)]
+pub(super) struct TextInputClipboardResource {
+    clipboard: Option<Clipboard>,
+}
+
+impl TextInputClipboardResource {
+    //
+
+    /// Returns the text on the clipboard, if any.
+    pub(super) fn get_text(&mut self) -> Option<String> {
+        self.clipboard()?.get_text().ok()
+    }
+
+    /// Places the specified text on the clipboard.
+    pub(super) fn set_text(&mut self, text: &str) {
+        if let Some(clipboard) = self.clipboard() {
+            let _ = clipboard.set_text(text);
+        }
+    }
+
+    /// Opens the clipboard on first use.
+    fn clipboard(&mut self) -> Option<&mut Clipboard> {
+        if self.clipboard.is_none() {
+            self.clipboard = Clipboard::new().ok();
+        }
+        self.clipboard.as_mut()
+    }
+}
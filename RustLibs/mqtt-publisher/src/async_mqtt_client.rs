@@ -4,20 +4,51 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use log::{debug, error, trace};
-use rumqttc::v5::{AsyncClient as AsyncClientV5, EventLoop as EventLoopV5, MqttOptions as MqttOptionsV5};
-use rumqttc::{AsyncClient as AsyncClientV3, EventLoop as EventLoopV3, MqttOptions as MqttOptionsV3};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use log::{debug, error, trace, warn};
+use rumqttc::v5::mqttbytes::v5::{ConnAck as ConnAckV5, LastWill as LastWillV5, Packet as PacketV5, Publish as PublishV5};
+use rumqttc::v5::{AsyncClient as AsyncClientV5, Event as EventV5, EventLoop as EventLoopV5, MqttOptions as MqttOptionsV5};
+use rumqttc::{AsyncClient as AsyncClientV3, Event as EventV3, EventLoop as EventLoopV3, LastWill as LastWillV3, MqttOptions as MqttOptionsV3, Outgoing, Packet as PacketV3};
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::broker_info::{BrokerInfo, MqttProtocolVersion};
+use crate::outbox::{Outbox, OutboxMessage};
+use crate::publish_options::PublishOptions;
 use crate::publisher_error::PublisherError;
 use crate::publisher_qos::PublisherQoS;
+use crate::publisher_warning::PublisherWarning;
+
+/// The length of time to wait for the event loop to send the DISCONNECT before stopping it anyway.
+const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// The highest QoS level defined by MQTT, assumed until a v5 broker advertises otherwise.
+const MAX_QOS_LEVEL: u8 = 2;
 
 /// Provides unified access to multiple client versions of the MQTT protocol.
 #[derive(Clone)]
 pub(crate) struct AsyncMqttClient {
     client_v3: Option<AsyncClientV3>,
     client_v5: Option<AsyncClientV5>,
+    /// Whether the broker has acknowledged the current connection.
+    connected: Arc<AtomicBool>,
+    /// The background task that drives the client's event loop.
+    event_loop_task: Arc<Mutex<Option<JoinHandle<()>>>>,
+    /// Whether the client is believed to be able to reach its broker. Unlike `connected`, this
+    /// starts out true so that messages published before the first connection are not rejected.
+    healthy: Arc<AtomicBool>,
+    /// The maximum QoS level advertised by a v5 broker in its CONNACK.
+    max_qos: Arc<AtomicU8>,
+    /// The maximum packet size, in bytes, advertised by a v5 broker in its CONNACK.
+    max_packet_size: Arc<AtomicU32>,
+    /// Holds messages while the broker is unreachable. Only present when configured.
+    outbox: Option<Arc<Outbox>>,
+    /// Guards against more than one Outbox replay running at a time.
+    replaying: Arc<AtomicBool>,
+    /// Whether the client is disconnecting, after which the event loop ends.
+    stopping: Arc<AtomicBool>,
 }
 
 impl AsyncMqttClient {
@@ -27,6 +58,8 @@ impl AsyncMqttClient {
     pub fn new(config: BrokerInfo) -> Self {
         //
 
+        let outbox = config.outbox.clone().map(|outbox_config| Arc::new(Outbox::new(outbox_config)));
+
         // Which version of the client is being requested?
         match config.protocol_version {
             //
@@ -37,17 +70,35 @@ impl AsyncMqttClient {
                                                             config.broker_address.clone(),
                                                             config.broker_port);
                 client_options.set_keep_alive(config.keep_alive);
+                if let Some(last_will) = config.last_will.clone() {
+                    client_options.set_last_will(LastWillV3::new(last_will.topic,
+                                                                 last_will.payload,
+                                                                 last_will.qos.into(),
+                                                                 last_will.retain));
+                }
 
                 let (client, event_loop) = AsyncClientV3::new(client_options, config.capacity);
 
-                tokio::spawn(async move {
-                    AsyncMqttClient::enter_event_loop_v3(event_loop).await;
-                });
-
-                Self {
+                let instance = Self {
                     client_v3: Some(client),
                     client_v5: None,
-                }
+                    connected: Arc::new(AtomicBool::new(false)),
+                    event_loop_task: Arc::new(Mutex::new(None)),
+                    healthy: Arc::new(AtomicBool::new(true)),
+                    max_qos: Arc::new(AtomicU8::new(MAX_QOS_LEVEL)),
+                    max_packet_size: Arc::new(AtomicU32::new(u32::MAX)),
+                    outbox,
+                    replaying: Arc::new(AtomicBool::new(false)),
+                    stopping: Arc::new(AtomicBool::new(false)),
+                };
+
+                let client = instance.clone();
+                let task = tokio::spawn(async move {
+                    AsyncMqttClient::enter_event_loop_v3(event_loop, client).await;
+                });
+                *instance.event_loop_task.lock().unwrap() = Some(task);
+
+                instance
             }
 
             // Setup a V5 client
@@ -56,75 +107,310 @@ impl AsyncMqttClient {
                                                             config.broker_address.clone(),
                                                             config.broker_port);
                 client_options.set_keep_alive(config.keep_alive);
+                if let Some(last_will) = config.last_will.clone() {
+                    client_options.set_last_will(LastWillV5::new(last_will.topic,
+                                                                 last_will.payload,
+                                                                 last_will.qos.into(),
+                                                                 last_will.retain,
+                                                                 None));
+                }
 
                 let (client, event_loop) = AsyncClientV5::new(client_options, config.capacity);
 
-                tokio::spawn(async move {
-                    AsyncMqttClient::enter_event_loop_v5(event_loop).await;
-                });
-
-                Self {
+                let instance = Self {
                     client_v3: None,
                     client_v5: Some(client),
-                }
+                    connected: Arc::new(AtomicBool::new(false)),
+                    event_loop_task: Arc::new(Mutex::new(None)),
+                    healthy: Arc::new(AtomicBool::new(true)),
+                    max_qos: Arc::new(AtomicU8::new(MAX_QOS_LEVEL)),
+                    max_packet_size: Arc::new(AtomicU32::new(u32::MAX)),
+                    outbox,
+                    replaying: Arc::new(AtomicBool::new(false)),
+                    stopping: Arc::new(AtomicBool::new(false)),
+                };
+
+                let client = instance.clone();
+                let task = tokio::spawn(async move {
+                    AsyncMqttClient::enter_event_loop_v5(event_loop, client).await;
+                });
+                *instance.event_loop_task.lock().unwrap() = Some(task);
+
+                instance
             }
         }
     }
 
+    /// Disconnects from the broker and ends the event loop. The client cannot be used afterwards.
+    pub(crate) async fn disconnect(&self) {
+        //
+
+        self.stopping.store(true, Ordering::SeqCst);
+
+        // Not waiting for room in the request queue keeps a wedged client from blocking the caller.
+        let result = match (&self.client_v3, &self.client_v5) {
+            (Some(client_v3), _) => client_v3.try_disconnect().map_err(|error| error.to_string()),
+            (_, Some(client_v5)) => client_v5.try_disconnect().map_err(|error| error.to_string()),
+            _ => Ok(()),
+        };
+        if let Err(error) = result {
+            debug!("Failed to request a disconnect: {}", error);
+        }
+
+        let Some(mut task) = self.event_loop_task.lock().unwrap().take() else {
+            return; // Early return. The event loop has already been stopped.
+        };
+
+        if tokio::time::timeout(DISCONNECT_TIMEOUT, &mut task).await.is_err() {
+            warn!("Event loop did not end after disconnecting. Stopping it.");
+            task.abort();
+            let _ = (&mut task).await;
+        }
+
+        self.on_disconnected();
+        *self.event_loop_task.lock().unwrap() = Some(task);
+    }
+
     /// Begins the background event loop required for the rumqttc V3 Client to publish MQTT messages.
-    async fn enter_event_loop_v3(mut event_loop: EventLoopV3) {
+    async fn enter_event_loop_v3(mut event_loop: EventLoopV3, client: AsyncMqttClient) {
         trace!("Beginning event v3 loop");
         loop {
             let event = event_loop.poll().await;
             match &event {
                 Ok(v) => {
                     debug!("Event = {v:?}");
+                    match v {
+                        EventV3::Incoming(PacketV3::ConnAck(_)) => client.on_connected(),
+                        EventV3::Outgoing(Outgoing::Disconnect) => break,
+                        _ => {}
+                    }
                 }
                 Err(e) => {
                     debug!("Error = {e:?}");
+                    client.on_disconnected();
+                    if client.stopping.load(Ordering::SeqCst) {
+                        break;
+                    }
                 }
             }
         }
+        trace!("Ended event v3 loop");
     }
 
     /// Begins the background event loop required for the rumqttc V5 Client to publish MQTT messages.
-    async fn enter_event_loop_v5(mut event_loop: EventLoopV5) {
+    async fn enter_event_loop_v5(mut event_loop: EventLoopV5, client: AsyncMqttClient) {
         trace!("Beginning event v5 loop");
         loop {
             let event = event_loop.poll().await;
             match &event {
                 Ok(v) => {
                     debug!("Event = {v:?}");
+                    match v {
+                        EventV5::Incoming(PacketV5::ConnAck(connack)) => {
+                            client.record_broker_limits(connack);
+                            client.on_connected();
+                        }
+                        EventV5::Outgoing(Outgoing::Disconnect) => break,
+                        _ => {}
+                    }
                 }
                 Err(e) => {
                     debug!("Error = {e:?}");
+                    client.on_disconnected();
+                    if client.stopping.load(Ordering::SeqCst) {
+                        break;
+                    }
                 }
             }
         }
+        trace!("Ended event v5 loop");
+    }
+
+    /// Returns whether the client is believed to be able to reach its broker.
+    pub(crate) fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether the broker has acknowledged the current connection.
+    #[cfg(test)]
+    pub(crate) fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether the background event loop is still running.
+    #[cfg(test)]
+    pub(crate) fn is_event_loop_running(&self) -> bool {
+        self.event_loop_task.lock().unwrap().as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    /// Returns whether messages can be accepted even while the broker is unreachable.
+    pub(crate) fn has_outbox(&self) -> bool {
+        self.outbox.is_some()
+    }
+
+    /// Records that the broker accepted the connection and replays any queued messages.
+    fn on_connected(&self) {
+        //
+
+        self.connected.store(true, Ordering::SeqCst);
+        self.healthy.store(true, Ordering::SeqCst);
+
+        if self.outbox_depth() > 0 {
+            // The replay publishes through the same client whose requests are drained by the
+            // event loop, so it must not run on the event loop's own task.
+            let client = self.clone();
+            tokio::spawn(async move {
+                client.replay_outbox().await;
+            });
+        }
+    }
+
+    /// Records the limits advertised by a v5 broker. Per the MQTT spec, an absent limit means that
+    /// the broker imposes none.
+    fn record_broker_limits(&self, connack: &ConnAckV5) {
+        //
+
+        let properties = connack.properties.as_ref();
+        let max_qos = properties.and_then(|properties| properties.max_qos).unwrap_or(MAX_QOS_LEVEL);
+        let max_packet_size = properties.and_then(|properties| properties.max_packet_size).unwrap_or(u32::MAX);
+        debug!("Broker limits: max QoS = {max_qos}, max packet size = {max_packet_size}");
+
+        self.max_qos.store(max_qos, Ordering::SeqCst);
+        self.max_packet_size.store(max_packet_size, Ordering::SeqCst);
+    }
+
+    /// Records that the connection to the broker has been lost.
+    fn on_disconnected(&self) {
+        self.connected.store(false, Ordering::SeqCst);
+        self.healthy.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the number of messages waiting in the Outbox.
+    pub(crate) fn outbox_depth(&self) -> usize {
+        self.outbox.as_ref().map_or(0, |outbox| outbox.depth())
     }
 
     /// Publishes a message with the specified payload to the specified topic.
-    pub(crate) async fn publish_with_payload(&self, payload: String, topic: String, qos: PublisherQoS) -> Result<(), PublisherError> {
+    pub(crate) async fn publish_with_payload(&self,
+                                             payload: String,
+                                             topic: String,
+                                             qos: PublisherQoS,
+                                             options: PublishOptions) -> Result<Vec<PublisherWarning>, PublisherError> {
+        //
+
+        let Some(outbox) = &self.outbox else {
+            return self.send(payload, topic, qos, options).await;
+        };
+
+        // Queue behind any messages still waiting so that ordering is preserved.
+        if !self.connected.load(Ordering::SeqCst) || outbox.depth() > 0 {
+            trace!("Broker unavailable or Outbox not yet drained. Queueing message.");
+            outbox.enqueue(OutboxMessage { payload, topic, qos, options }).await?;
+            if self.connected.load(Ordering::SeqCst) {
+                self.replay_outbox().await;
+            }
+            return Ok(vec![]);
+        }
+
+        match self.send(payload.clone(), topic.clone(), qos.clone(), options.clone()).await {
+            Err(PublisherError::FailedToMessage) => {
+                outbox.enqueue(OutboxMessage { payload, topic, qos, options }).await?;
+                Ok(vec![])
+            }
+            result => result,
+        }
+    }
+
+    /// Publishes the queued messages, oldest first, until the Outbox is empty or the connection
+    /// is lost.
+    async fn replay_outbox(&self) {
+        //
+
+        let Some(outbox) = &self.outbox else {
+            return; // Early return. Nothing to replay.
+        };
+
+        loop {
+            //
+
+            if self.replaying.swap(true, Ordering::SeqCst) {
+                return; // Early return. Another task is already replaying.
+            }
+
+            trace!("Replaying {} queued message(s)", outbox.depth());
+
+            let mut drained = false;
+            while self.connected.load(Ordering::SeqCst) {
+                let Some(message) = outbox.peek() else {
+                    drained = true;
+                    break;
+                };
+                match self.send(message.payload, message.topic, message.qos, message.options).await {
+                    Ok(_) => outbox.pop(),
+                    Err(PublisherError::PayloadTooLarge) => {
+                        // Retrying can never succeed, so discard the message rather than stall the queue.
+                        warn!("Dropping queued message that exceeds the broker's maximum packet size");
+                        outbox.pop();
+                    }
+                    Err(_) => break,
+                }
+            }
+
+            self.replaying.store(false, Ordering::SeqCst);
+
+            // A message queued after the Outbox was found empty, but before the flag was cleared,
+            // left its replay to this one. Go around again rather than strand it.
+            if !drained || !self.connected.load(Ordering::SeqCst) || outbox.depth() == 0 {
+                break;
+            }
+        }
+    }
+
+    /// Hands a message to whichever version of the client has been configured.
+    async fn send(&self, payload: String, topic: String, qos: PublisherQoS, options: PublishOptions) -> Result<Vec<PublisherWarning>, PublisherError> {
         if let Some(client_v3) = self.client_v3.clone() {
-            match client_v3.publish(topic, qos.clone().into(), false, payload.clone()).await {
+            match client_v3.publish(topic, qos.clone().into(), options.retain, payload.clone()).await {
                 Ok(_) => {
                     trace!("Message published over v3 protocol");
-                    Ok(())
+                    Ok(vec![])
                 }
                 Err(error) => {
                     error!("{}" ,error.to_string());
+                    self.healthy.store(false, Ordering::SeqCst);
                     // TODO: JD: return PublisherError::DuplicateMessage if this is a dupe
                     Err(PublisherError::FailedToMessage)
                 }
             }
         } else if let Some(client_v5) = self.client_v5.clone() {
-            match client_v5.publish(topic, qos.clone().into(), false, payload.clone()).await {
+            //
+
+            // Honor the maximum QoS advertised by the broker.
+            let mut warnings: Vec<PublisherWarning> = vec![];
+            let granted_qos = qos.capped_at(self.max_qos.load(Ordering::SeqCst));
+            if granted_qos != qos {
+                warn!("Broker does not support {:?}. Publishing with {:?} instead.", qos, granted_qos);
+                warnings.push(PublisherWarning::QoSDowngraded { requested: qos, granted: granted_qos.clone() });
+            }
+
+            let properties = options.to_v5_properties();
+            // Sending a packet larger than the broker accepts would cost the connection.
+            let mut packet_size = PublishV5::new(topic.clone(), granted_qos.clone().into(), payload.clone(), Some(properties.clone())).size();
+            if granted_qos != PublisherQoS::AtMostOnce {
+                packet_size += 2; // Packet identifier
+            }
+            if packet_size > self.max_packet_size.load(Ordering::SeqCst) as usize {
+                error!("Message of {packet_size} bytes exceeds the broker's maximum packet size");
+                return Err(PublisherError::PayloadTooLarge);
+            }
+
+            match client_v5.publish_with_properties(topic, granted_qos.into(), options.retain, payload.clone(), properties).await {
                 Ok(_) => {
                     trace!("Message published over v5 protocol");
-                    Ok(())
+                    Ok(warnings)
                 }
                 Err(error) => {
                     error!("{}" ,error.to_string());
+                    self.healthy.store(false, Ordering::SeqCst);
                     // TODO: JD: return PublisherError::DuplicateMessage if this is a dupe
                     Err(PublisherError::FailedToMessage)
                 }
@@ -133,4 +419,4 @@ impl AsyncMqttClient {
             Err(PublisherError::ClientNotConfigured)
         }
     }
-}
\ No newline at end of file
+}
@@ -6,17 +6,44 @@
 
 use std::time::Duration;
 
+use crate::outbox::OutboxConfig;
+use crate::publisher_qos::PublisherQoS;
+
 /// Enumerates the MQTT protocols supported by this library.
-#[derive(Clone, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum MqttProtocolVersion { V3, V5 }
 
+/// Defines the message the broker publishes on the client's behalf should the client disconnect
+/// unexpectedly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LastWill {
+    pub(crate) topic: String,
+    pub(crate) payload: String,
+    pub(crate) qos: PublisherQoS,
+    pub(crate) retain: bool,
+}
+
+impl LastWill {
+    /// Creates a new LastWill instance.
+    pub fn new(topic: String, payload: String, qos: PublisherQoS, retain: bool) -> Self {
+        Self {
+            topic,
+            payload,
+            qos,
+            retain,
+        }
+    }
+}
+
 /// Defines the parameters required to configure a Publisher.
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BrokerInfo {
     pub(crate) capacity: usize,
     pub(crate) broker_address: String,
     pub(crate) broker_port: u16,
     pub(crate) keep_alive: Duration,
+    pub(crate) last_will: Option<LastWill>,
+    pub(crate) outbox: Option<OutboxConfig>,
     pub(crate) protocol_version: MqttProtocolVersion,
 }
 
@@ -32,7 +59,22 @@ impl BrokerInfo {
             broker_address,
             broker_port,
             keep_alive,
+            last_will: None,
+            outbox: None,
             protocol_version,
         }
     }
+
+    /// Registers a message for the broker to publish should this client disconnect unexpectedly.
+    pub fn with_last_will(mut self, last_will: LastWill) -> Self {
+        self.last_will = Some(last_will);
+        self
+    }
+
+    /// Enables an Outbox that queues messages while the broker is unreachable and replays them,
+    /// in order, once the connection is re-established.
+    pub fn with_outbox(mut self, outbox: OutboxConfig) -> Self {
+        self.outbox = Some(outbox);
+        self
+    }
 }
@@ -6,6 +6,14 @@
 
 mod async_mqtt_client;
 pub mod broker_info;
+pub mod outbox;
+pub mod publish_options;
 pub mod publisher;
 pub mod publisher_error;
 pub mod publisher_qos;
+pub mod publisher_trait;
+pub mod publisher_warning;
+pub mod simulcast_policy;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+mod tests;
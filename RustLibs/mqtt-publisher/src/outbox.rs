@@ -0,0 +1,272 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+use crate::publish_options::PublishOptions;
+use crate::publisher_error::PublisherError;
+use crate::publisher_qos::PublisherQoS;
+
+/// The number of messages removed from an on-disk Outbox after which its file is rewritten to hold
+/// only the messages still queued.
+const COMPACTION_THRESHOLD: usize = 64;
+
+/// Enumerates what the Outbox does with a new message when it is already full.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OverflowPolicy {
+    /// Discards the oldest queued message to make room for the new one.
+    DropOldest,
+    /// Discards the new message, leaving the queued messages untouched.
+    DropNewest,
+    /// Waits until the queued messages have been replayed and room is available.
+    ///
+    /// NOTE: The wait is unbounded. While the broker is unreachable, publishing to it does not
+    /// return, which also holds up a simulcast via other brokers. Wrap the publish in
+    /// tokio::time::timeout if the caller must not stall.
+    Block,
+}
+
+/// Enumerates where the Outbox keeps its queued messages.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OutboxStorage {
+    /// Messages are kept in memory and are lost when the process exits.
+    InMemory,
+    /// Messages are also written to the specified file so that they survive a restart.
+    OnDisk(PathBuf),
+}
+
+/// Defines the parameters required to configure an Outbox.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OutboxConfig {
+    pub(crate) capacity: usize,
+    pub(crate) overflow_policy: OverflowPolicy,
+    pub(crate) storage: OutboxStorage,
+}
+
+impl OutboxConfig {
+    /// Creates a new OutboxConfig instance.
+    pub fn new(capacity: usize, overflow_policy: OverflowPolicy, storage: OutboxStorage) -> Self {
+        Self {
+            capacity,
+            overflow_policy,
+            storage,
+        }
+    }
+}
+
+/// Models a message waiting in the Outbox to be published.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct OutboxMessage {
+    pub(crate) payload: String,
+    pub(crate) topic: String,
+    pub(crate) qos: PublisherQoS,
+    #[serde(default)]
+    pub(crate) options: PublishOptions,
+}
+
+/// Models an entry in the file of an on-disk Outbox. The file is an append-only log: each queued
+/// message is appended as it arrives and each removal is recorded after it, until the file is
+/// compacted.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum OutboxLogEntry {
+    /// The oldest message was removed from the queue.
+    Removed { removed: usize },
+    Queued(OutboxMessage),
+}
+
+/// Bounded queue that holds messages while the client is disconnected from its broker so that they
+/// can be replayed, in order, once the connection is re-established.
+pub(crate) struct Outbox {
+    config: OutboxConfig,
+    messages: Mutex<VecDeque<OutboxMessage>>,
+    /// The number of removals appended to the file since it was last compacted.
+    removals_since_compaction: AtomicUsize,
+    space_available: Notify,
+}
+
+impl Outbox {
+    //
+
+    /// Constructs a new Outbox instance, restoring any messages previously persisted to disk.
+    pub(crate) fn new(config: OutboxConfig) -> Self {
+        //
+
+        let messages = match &config.storage {
+            OutboxStorage::InMemory => VecDeque::new(),
+            OutboxStorage::OnDisk(path) => Self::load(path, config.capacity),
+        };
+
+        let outbox = Self {
+            config,
+            messages: Mutex::new(messages),
+            removals_since_compaction: AtomicUsize::new(0),
+            space_available: Notify::new(),
+        };
+
+        // Start the log afresh, without the removals recorded by the previous run.
+        outbox.compact(&outbox.messages.lock().unwrap());
+
+        outbox
+    }
+
+    /// Returns the number of messages waiting to be published.
+    pub(crate) fn depth(&self) -> usize {
+        self.messages.lock().unwrap().len()
+    }
+
+    /// Adds a message to the back of the queue, applying the overflow policy if the queue is full.
+    pub(crate) async fn enqueue(&self, message: OutboxMessage) -> Result<(), PublisherError> {
+        //
+
+        loop {
+            // Register interest before checking for room so that a wake-up cannot be missed.
+            let space_available = self.space_available.notified();
+            {
+                let mut messages = self.messages.lock().unwrap();
+                if messages.len() < self.config.capacity {
+                    self.append(&[OutboxLogEntry::Queued(message.clone())]);
+                    messages.push_back(message);
+                    return Ok(());
+                }
+                match self.config.overflow_policy {
+                    OverflowPolicy::DropOldest => {
+                        warn!("Outbox is full. Dropping the oldest message.");
+                        messages.pop_front();
+                        self.record_removal(&messages);
+                        self.append(&[OutboxLogEntry::Queued(message.clone())]);
+                        messages.push_back(message);
+                        return Ok(());
+                    }
+                    OverflowPolicy::DropNewest => {
+                        warn!("Outbox is full. Dropping the newest message.");
+                        return Err(PublisherError::OutboxFull);
+                    }
+                    OverflowPolicy::Block => {}
+                }
+            }
+            space_available.await;
+        }
+    }
+
+    /// Returns a copy of the oldest message without removing it from the queue.
+    pub(crate) fn peek(&self) -> Option<OutboxMessage> {
+        self.messages.lock().unwrap().front().cloned()
+    }
+
+    /// Removes the oldest message from the queue, typically once it has been published.
+    pub(crate) fn pop(&self) {
+        let mut messages = self.messages.lock().unwrap();
+        if messages.pop_front().is_some() {
+            self.record_removal(&messages);
+            self.space_available.notify_waiters();
+        }
+    }
+}
+
+// Persistence
+impl Outbox {
+    //
+
+    /// Reads previously persisted messages by replaying the file's log, skipping any entries that
+    /// cannot be parsed.
+    fn load(path: &PathBuf, capacity: usize) -> VecDeque<OutboxMessage> {
+        //
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return VecDeque::new(), // Early return. Nothing has been persisted yet.
+        };
+
+        let mut messages: VecDeque<OutboxMessage> = VecDeque::new();
+        for line in contents.lines() {
+            match serde_json::from_str(line) {
+                Ok(OutboxLogEntry::Queued(message)) => messages.push_back(message),
+                Ok(OutboxLogEntry::Removed { removed }) => {
+                    messages.drain(..removed.min(messages.len()));
+                }
+                Err(error) => warn!("Skipping unreadable Outbox entry: {}", error),
+            }
+        }
+
+        // Keep the most recent messages if the capacity has been reduced since the last run.
+        while messages.len() > capacity {
+            messages.pop_front();
+        }
+
+        messages
+    }
+
+    /// Appends the specified entries to the file when on-disk storage has been configured.
+    fn append(&self, entries: &[OutboxLogEntry]) {
+        //
+
+        let OutboxStorage::OnDisk(path) = &self.config.storage else {
+            return; // Early return. Nothing to persist.
+        };
+
+        let contents = Self::serialize(entries);
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()));
+        if let Err(error) = result {
+            error!("Failed to persist the Outbox to {:?}: {}", path, error);
+        }
+    }
+
+    /// Rewrites the file to hold only the specified, still queued, messages when on-disk storage
+    /// has been configured.
+    fn compact(&self, messages: &VecDeque<OutboxMessage>) {
+        //
+
+        let OutboxStorage::OnDisk(path) = &self.config.storage else {
+            return; // Early return. Nothing to persist.
+        };
+
+        self.removals_since_compaction.store(0, Ordering::SeqCst);
+
+        let entries: Vec<OutboxLogEntry> = messages.iter().cloned().map(OutboxLogEntry::Queued).collect();
+        if let Err(error) = fs::write(path, Self::serialize(&entries)) {
+            error!("Failed to persist the Outbox to {:?}: {}", path, error);
+        }
+    }
+
+    /// Records that the oldest message has been removed, compacting the file once the queue is
+    /// empty or enough removals have built up.
+    fn record_removal(&self, messages: &VecDeque<OutboxMessage>) {
+        let removals = self.removals_since_compaction.fetch_add(1, Ordering::SeqCst) + 1;
+        match messages.is_empty() || removals >= COMPACTION_THRESHOLD {
+            true => self.compact(messages),
+            false => self.append(&[OutboxLogEntry::Removed { removed: 1 }]),
+        }
+    }
+
+    /// Serializes the specified entries as JSON lines.
+    fn serialize(entries: &[OutboxLogEntry]) -> String {
+        let mut contents = String::new();
+        for entry in entries {
+            match serde_json::to_string(entry) {
+                Ok(line) => {
+                    contents.push_str(&line);
+                    contents.push('\n');
+                }
+                Err(error) => error!("Failed to serialize Outbox entry: {}", error),
+            }
+        }
+        contents
+    }
+}
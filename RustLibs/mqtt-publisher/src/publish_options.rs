@@ -0,0 +1,89 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use rumqttc::v5::mqttbytes::v5::PublishProperties;
+use serde::{Deserialize, Serialize};
+
+/// Defines the optional settings applied to a published message.
+///
+/// NOTE: Only the retain flag is supported by version 3.1.1 of the MQTT protocol. All other
+/// settings are MQTT v5 properties and are ignored when publishing via a v3 broker.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PublishOptions {
+    pub(crate) retain: bool,
+    pub(crate) message_expiry_interval: Option<u32>,
+    pub(crate) content_type: Option<String>,
+    pub(crate) correlation_data: Option<Vec<u8>>,
+    pub(crate) user_properties: Vec<(String, String)>,
+    pub(crate) response_topic: Option<String>,
+    pub(crate) topic_alias: Option<u16>,
+}
+
+impl PublishOptions {
+    //
+
+    /// Creates a new PublishOptions instance with no options set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the broker to keep the message and deliver it to clients that subscribe later.
+    pub fn retain(mut self, retain: bool) -> Self {
+        self.retain = retain;
+        self
+    }
+
+    /// Sets the number of seconds after which the broker discards the message if undelivered.
+    pub fn message_expiry_interval(mut self, seconds: u32) -> Self {
+        self.message_expiry_interval = Some(seconds);
+        self
+    }
+
+    /// Sets the MIME type of the payload, e.g. `application/json`.
+    pub fn content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Sets the data used by a requester to match a response to its request.
+    pub fn correlation_data(mut self, correlation_data: &[u8]) -> Self {
+        self.correlation_data = Some(correlation_data.to_vec());
+        self
+    }
+
+    /// Adds a name/value pair to the message's user properties. May be called repeatedly.
+    pub fn user_property(mut self, name: &str, value: &str) -> Self {
+        self.user_properties.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the topic to which a receiver should publish its response.
+    pub fn response_topic(mut self, response_topic: &str) -> Self {
+        self.response_topic = Some(response_topic.to_string());
+        self
+    }
+
+    /// Sets the alias that stands in for the topic name on subsequent messages. The alias must
+    /// not exceed the Topic Alias Maximum advertised by the broker.
+    pub fn topic_alias(mut self, topic_alias: u16) -> Self {
+        self.topic_alias = Some(topic_alias);
+        self
+    }
+
+    /// Maps the options onto the MQTT v5 publish properties. The retain flag is not a property and
+    /// is passed separately.
+    pub(crate) fn to_v5_properties(&self) -> PublishProperties {
+        PublishProperties {
+            message_expiry_interval: self.message_expiry_interval,
+            topic_alias: self.topic_alias,
+            response_topic: self.response_topic.clone(),
+            correlation_data: self.correlation_data.clone().map(|data| data.into()),
+            user_properties: self.user_properties.clone(),
+            content_type: self.content_type.clone(),
+            ..Default::default()
+        }
+    }
+}
@@ -4,19 +4,33 @@
 //
 // @author JoelDavisEngineering@Gmail.com
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
 
 use crate::async_mqtt_client::AsyncMqttClient;
 use crate::broker_info::BrokerInfo;
+use crate::publish_options::PublishOptions;
 use crate::publisher_error::PublisherError;
+use crate::publisher_trait::PublisherTrait;
+use crate::publisher_warning::PublisherWarning;
 use crate::publisher_qos::PublisherQoS;
-use log::error;
+use crate::simulcast_policy::SimulcastPolicy;
+use async_trait::async_trait;
+use log::{error, warn};
+use tokio::task::JoinSet;
+
+/// The outcome of publishing a message, keyed by the broker through which it was published.
+pub type BrokerResults = HashMap<BrokerInfo, Result<Vec<PublisherWarning>, PublisherError>>;
 
 /// Provides MQTT message publishing functionality, including simulcast to disparate brokers and
 /// different versions of the MQTT protocol.
+///
+/// NOTE: Clones share the same set of brokers, so brokers added or removed via one clone are seen
+/// by all of them.
 #[derive(Clone)]
 pub struct Publisher {
-    clients: Vec<AsyncMqttClient>,
+    clients: Arc<RwLock<HashMap<BrokerInfo, AsyncMqttClient>>>,
+    policy: SimulcastPolicy,
 }
 
 // Public contract.
@@ -25,44 +39,158 @@ impl Publisher {
 
     /// Constructs a new Publisher instance for broadcasting via a single broker.
     pub fn new(broker: BrokerInfo) -> Self {
-        Self { clients: vec!(AsyncMqttClient::new(broker)) }
+        Self::new_for_simulcast(HashSet::from([broker]))
     }
 
     /// Constructs a new Publisher instance for simulcasting via multiple brokers.
     pub fn new_for_simulcast(brokers: HashSet<BrokerInfo>) -> Self {
-        let mut clients: Vec<AsyncMqttClient> = vec!();
+        let mut clients: HashMap<BrokerInfo, AsyncMqttClient> = HashMap::new();
         for config in brokers {
-            clients.push(AsyncMqttClient::new(config))
+            clients.insert(config.clone(), AsyncMqttClient::new(config));
         }
         Self {
-            clients,
+            clients: Arc::new(RwLock::new(clients)),
+            policy: SimulcastPolicy::default(),
+        }
+    }
+
+    /// Sets the rule that determines whether a simulcast message counts as successfully published.
+    /// Defaults to SimulcastPolicy::All. Returns PublisherError::InvalidPolicy if the policy cannot
+    /// be satisfied by the brokers, e.g. a Quorum of zero or one larger than the number of brokers.
+    pub fn with_policy(mut self, policy: SimulcastPolicy) -> Result<Self, PublisherError> {
+        policy.validate(self.clients.read().unwrap().len())?;
+        self.policy = policy;
+        Ok(self)
+    }
+
+    /// Adds a broker to the simulcast. Has no effect if the broker is already part of it.
+    pub fn add_broker(&self, broker: BrokerInfo) {
+        let mut clients = self.clients.write().unwrap();
+        if !clients.contains_key(&broker) {
+            clients.insert(broker.clone(), AsyncMqttClient::new(broker));
         }
     }
 
+    /// Removes a broker from the simulcast, disconnecting from it. Returns false if the broker was
+    /// not part of it, or PublisherError::InvalidPolicy if too few brokers would remain to satisfy
+    /// the Publisher's SimulcastPolicy.
+    pub async fn remove_broker(&self, broker: &BrokerInfo) -> Result<bool, PublisherError> {
+        //
+
+        let removed = {
+            let mut clients = self.clients.write().unwrap();
+            if !clients.contains_key(broker) {
+                return Ok(false); // Early return. Nothing to remove.
+            }
+            self.policy.validate(clients.len() - 1)?;
+            clients.remove(broker)
+        };
+
+        if let Some(client) = removed {
+            client.disconnect().await;
+        }
+        Ok(true)
+    }
+
+    /// Returns the brokers currently part of the simulcast.
+    pub fn brokers(&self) -> Vec<BrokerInfo> {
+        self.clients.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Returns the brokers that are currently believed to be unreachable. Messages are not routed
+    /// to these brokers, unless they have an Outbox, until they reconnect.
+    pub fn unhealthy_brokers(&self) -> Vec<BrokerInfo> {
+        self.clients.read().unwrap()
+            .iter()
+            .filter(|(_, client)| !client.is_healthy())
+            .map(|(broker, _)| broker.clone())
+            .collect()
+    }
+
+    /// Returns the total number of messages waiting in the Outboxes of all brokers. Always zero when
+    /// no Outbox has been configured.
+    pub fn outbox_depth(&self) -> usize {
+        self.clients.read().unwrap().values().map(|client| client.outbox_depth()).sum()
+    }
+
     /// Publishes an empty message to the specified topic.
-    pub async fn publish(&self, topic: &str, qos: PublisherQoS) -> Result<(), Vec<PublisherError>> {
+    pub async fn publish(&self, topic: &str, qos: PublisherQoS) -> Result<BrokerResults, BrokerResults> {
         self.publish_with_payload("", topic, qos).await
     }
 
     /// Publishes a message with the specified payload to the specified topic.
-    pub async fn publish_with_payload(&self, payload: &str, topic: &str, qos: PublisherQoS) -> Result<(), Vec<PublisherError>> {
+    pub async fn publish_with_payload(&self, payload: &str, topic: &str, qos: PublisherQoS) -> Result<BrokerResults, BrokerResults> {
+        self.publish_with_options(payload, topic, qos, PublishOptions::new()).await
+    }
+
+    /// Publishes a message with the specified payload to the specified topic, applying the
+    /// specified options, e.g. retain and MQTT v5 properties.
+    ///
+    /// The message is published via all brokers concurrently. The per-broker results are returned
+    /// as Ok if they satisfy the Publisher's SimulcastPolicy, otherwise as Err.
+    pub async fn publish_with_options(&self,
+                                      payload: &str,
+                                      topic: &str,
+                                      qos: PublisherQoS,
+                                      options: PublishOptions) -> Result<BrokerResults, BrokerResults> {
         //
 
-        let mut failed_results: Vec<PublisherError> = vec!();
+        let clients: Vec<(BrokerInfo, AsyncMqttClient)> = self.clients.read().unwrap()
+            .iter()
+            .map(|(broker, client)| (broker.clone(), client.clone()))
+            .collect();
+        let broker_count = clients.len();
 
-        for client in self.clients.clone() {
-            match client.publish_with_payload(payload.to_string(), topic.to_string(), qos.clone()).await {
-                Ok(_) => {}
-                Err(error) => {
-                    error!("Failed to publish message: {}", error.to_string());
-                    failed_results.push(error)
+        let mut results: BrokerResults = HashMap::new();
+        let mut pending_results = JoinSet::new();
+
+        for (broker, client) in clients {
+            // Route around brokers that are known to be unreachable, unless they can buffer.
+            if !client.is_healthy() && !client.has_outbox() {
+                warn!("Skipping unhealthy broker {}:{}", broker.broker_address, broker.broker_port);
+                results.insert(broker, Err(PublisherError::BrokerUnhealthy));
+                continue;
+            }
+            let payload = payload.to_string();
+            let topic = topic.to_string();
+            let qos = qos.clone();
+            let options = options.clone();
+            pending_results.spawn(async move {
+                let result = client.publish_with_payload(payload, topic, qos, options).await;
+                (broker, result)
+            });
+        }
+
+        while let Some(joined) = pending_results.join_next().await {
+            match joined {
+                Ok((broker, result)) => {
+                    match &result {
+                        Ok(warnings) => warnings.iter().for_each(|warning| warn!("Published with warning: {}", warning)),
+                        Err(error) => error!("Failed to publish message: {}", error.to_string()),
+                    }
+                    results.insert(broker, result);
                 }
+                Err(error) => error!("Publishing task failed: {}", error.to_string()),
             }
         }
 
-        match failed_results.is_empty() {
-            true => Ok(()),
-            false => Err(failed_results)
+        let successes = results.values().filter(|result| result.is_ok()).count();
+        match self.policy.is_satisfied(successes, broker_count) {
+            true => Ok(results),
+            false => Err(results)
         }
     }
 }
+
+#[async_trait]
+impl PublisherTrait for Publisher {
+    //
+
+    async fn publish_with_options(&self,
+                                  payload: &str,
+                                  topic: &str,
+                                  qos: PublisherQoS,
+                                  options: PublishOptions) -> Result<BrokerResults, BrokerResults> {
+        Publisher::publish_with_options(self, payload, topic, qos, options).await
+    }
+}
@@ -9,6 +9,10 @@ use derive_more::{Display, Error};
 /// Defines the errors used throughout the library.
 #[derive(Clone, Debug, Display, Error, PartialEq)]
 pub enum PublisherError {
+    BrokerUnhealthy,
     ClientNotConfigured,
     FailedToMessage,
+    InvalidPolicy,
+    OutboxFull,
+    PayloadTooLarge,
 }
@@ -6,21 +6,35 @@
 
 use rumqttc::v5::mqttbytes::QoS as QoSV5;
 use rumqttc::QoS as QoSV3;
+use serde::{Deserialize, Serialize};
 
 /// Enumerates the Quality of Service options for message publishing. These options correspond
 /// 1-to-1 with those of MQTT. IMPORTANT NOTE: This redundant enum exists so that users of this
 /// library are not tightly-coupled to whichever MQTT implementation this library utilizes.
-#[derive(Clone)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum PublisherQoS {
     AtMostOnce = 0,
     AtLeastOnce = 1,
     ExactlyOnce = 2,
 }
 
+impl PublisherQoS {
+    //
+
+    /// Returns this QoS, or the specified maximum QoS level if this QoS exceeds it.
+    pub(crate) fn capped_at(&self, max_level: u8) -> Self {
+        match max_level {
+            0 => PublisherQoS::AtMostOnce,
+            1 if *self == PublisherQoS::ExactlyOnce => PublisherQoS::AtLeastOnce,
+            _ => self.clone(),
+        }
+    }
+}
+
 impl From<PublisherQoS> for QoSV3 {
     fn from(value: PublisherQoS) -> Self {
         match value {
-            PublisherQoS::AtMostOnce => QoSV3::AtLeastOnce,
+            PublisherQoS::AtMostOnce => QoSV3::AtMostOnce,
             PublisherQoS::AtLeastOnce => QoSV3::AtLeastOnce,
             PublisherQoS::ExactlyOnce => QoSV3::ExactlyOnce,
         }
@@ -30,7 +44,7 @@ impl From<PublisherQoS> for QoSV3 {
 impl From<PublisherQoS> for QoSV5 {
     fn from(value: PublisherQoS) -> Self {
         match value {
-            PublisherQoS::AtMostOnce => QoSV5::AtLeastOnce,
+            PublisherQoS::AtMostOnce => QoSV5::AtMostOnce,
             PublisherQoS::AtLeastOnce => QoSV5::AtLeastOnce,
             PublisherQoS::ExactlyOnce => QoSV5::ExactlyOnce,
         }
@@ -0,0 +1,36 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use async_trait::async_trait;
+
+use crate::publish_options::PublishOptions;
+use crate::publisher::BrokerResults;
+use crate::publisher_qos::PublisherQoS;
+
+/// Defines the behavior of a message Publisher. Depending on this trait, rather than on Publisher
+/// itself, allows a stand-in, such as the RecordingPublisher, to be used in tests.
+#[async_trait]
+pub trait PublisherTrait: Send + Sync {
+    //
+
+    /// Publishes a message with the specified payload to the specified topic, applying the
+    /// specified options.
+    async fn publish_with_options(&self,
+                                  payload: &str,
+                                  topic: &str,
+                                  qos: PublisherQoS,
+                                  options: PublishOptions) -> Result<BrokerResults, BrokerResults>;
+
+    /// Publishes an empty message to the specified topic.
+    async fn publish(&self, topic: &str, qos: PublisherQoS) -> Result<BrokerResults, BrokerResults> {
+        self.publish_with_options("", topic, qos, PublishOptions::new()).await
+    }
+
+    /// Publishes a message with the specified payload to the specified topic.
+    async fn publish_with_payload(&self, payload: &str, topic: &str, qos: PublisherQoS) -> Result<BrokerResults, BrokerResults> {
+        self.publish_with_options(payload, topic, qos, PublishOptions::new()).await
+    }
+}
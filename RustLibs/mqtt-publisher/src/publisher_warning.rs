@@ -0,0 +1,18 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use derive_more::Display;
+
+use crate::publisher_qos::PublisherQoS;
+
+/// Enumerates the conditions under which a message was published, but not exactly as requested.
+#[derive(Clone, Debug, Display, PartialEq)]
+pub enum PublisherWarning {
+    /// The broker does not support the requested QoS, so the message was published with the
+    /// broker's maximum QoS instead.
+    #[display("QoS downgraded from {requested:?} to {granted:?}")]
+    QoSDowngraded { requested: PublisherQoS, granted: PublisherQoS },
+}
@@ -0,0 +1,42 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use crate::publisher_error::PublisherError;
+
+/// Enumerates the rules that determine whether a simulcast message counts as successfully
+/// published.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum SimulcastPolicy {
+    /// Every broker must accept the message.
+    #[default]
+    All,
+    /// At least one broker must accept the message.
+    Any,
+    /// At least the specified number of brokers must accept the message.
+    Quorum(usize),
+}
+
+impl SimulcastPolicy {
+    //
+
+    /// Determines whether the number of brokers that accepted a message satisfies this policy.
+    pub fn is_satisfied(&self, successes: usize, broker_count: usize) -> bool {
+        match self {
+            SimulcastPolicy::All => successes == broker_count,
+            SimulcastPolicy::Any => successes > 0,
+            SimulcastPolicy::Quorum(quorum) => successes >= *quorum,
+        }
+    }
+
+    /// Checks that this policy can be satisfied by the specified number of brokers. A Quorum of
+    /// zero would be satisfied by every publish, and one larger than the number of brokers by none.
+    pub fn validate(&self, broker_count: usize) -> Result<(), PublisherError> {
+        match self {
+            SimulcastPolicy::Quorum(quorum) if *quorum == 0 || *quorum > broker_count => Err(PublisherError::InvalidPolicy),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::publish_options::PublishOptions;
+use crate::publisher::BrokerResults;
+use crate::publisher_qos::PublisherQoS;
+use crate::publisher_trait::PublisherTrait;
+
+/// Models a message captured by the RecordingPublisher.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedMessage {
+    pub payload: String,
+    pub topic: String,
+    pub qos: PublisherQoS,
+    pub options: PublishOptions,
+}
+
+/// Stands in for a Publisher, recording each message instead of sending it to a broker.
+///
+/// NOTE: Clones share the same recording, so a clone can be handed to the code under test while
+/// the original is used for the assertions.
+#[derive(Clone, Default)]
+pub struct RecordingPublisher {
+    messages: Arc<Mutex<Vec<RecordedMessage>>>,
+}
+
+impl RecordingPublisher {
+    //
+
+    /// Creates a new RecordingPublisher instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discards all recorded messages.
+    pub fn clear(&self) {
+        self.messages.lock().unwrap().clear();
+    }
+
+    /// Returns the recorded messages in the order in which they were published.
+    pub fn messages(&self) -> Vec<RecordedMessage> {
+        self.messages.lock().unwrap().clone()
+    }
+
+    /// Returns the recorded messages that were published to the specified topic.
+    pub fn messages_for_topic(&self, topic: &str) -> Vec<RecordedMessage> {
+        self.messages().into_iter().filter(|message| message.topic == topic).collect()
+    }
+
+    /// Returns the topics of the recorded messages in the order in which they were published.
+    pub fn topics(&self) -> Vec<String> {
+        self.messages().into_iter().map(|message| message.topic).collect()
+    }
+}
+
+// Assertion helpers
+impl RecordingPublisher {
+    //
+
+    /// Panics unless at least one message was published to the specified topic.
+    pub fn assert_published(&self, topic: &str) {
+        assert!(!self.messages_for_topic(topic).is_empty(),
+                "Expected a message on topic '{}'. Published topics: {:?}", topic, self.topics());
+    }
+
+    /// Panics unless a message with the specified payload was published to the specified topic.
+    pub fn assert_published_with_payload(&self, topic: &str, payload: &str) {
+        assert!(self.messages_for_topic(topic).iter().any(|message| message.payload == payload),
+                "Expected payload '{}' on topic '{}'. Published: {:?}", payload, topic, self.messages());
+    }
+
+    /// Panics unless a message was published to the specified topic with the specified QoS.
+    pub fn assert_published_with_qos(&self, topic: &str, qos: PublisherQoS) {
+        assert!(self.messages_for_topic(topic).iter().any(|message| message.qos == qos),
+                "Expected a message with {:?} on topic '{}'. Published: {:?}", qos, topic, self.messages());
+    }
+
+    /// Panics unless a retained message was published to the specified topic.
+    pub fn assert_retained(&self, topic: &str) {
+        assert!(self.messages_for_topic(topic).iter().any(|message| message.options.retain),
+                "Expected a retained message on topic '{}'. Published: {:?}", topic, self.messages());
+    }
+
+    /// Panics if any message was published to the specified topic.
+    pub fn assert_not_published(&self, topic: &str) {
+        assert!(self.messages_for_topic(topic).is_empty(),
+                "Expected no message on topic '{}'. Published topics: {:?}", topic, self.topics());
+    }
+
+    /// Panics unless exactly the specified topics were published, in the specified order.
+    pub fn assert_topics(&self, topics: &[&str]) {
+        assert_eq!(self.topics(), topics);
+    }
+}
+
+#[async_trait]
+impl PublisherTrait for RecordingPublisher {
+    //
+
+    async fn publish_with_options(&self,
+                                  payload: &str,
+                                  topic: &str,
+                                  qos: PublisherQoS,
+                                  options: PublishOptions) -> Result<BrokerResults, BrokerResults> {
+        self.messages.lock().unwrap().push(RecordedMessage {
+            payload: payload.to_string(),
+            topic: topic.to_string(),
+            qos,
+            options,
+        });
+        Ok(HashMap::new())
+    }
+}
@@ -0,0 +1,553 @@
+// MQTT Publisher
+//
+// © 2024 Rust Made Easy. All rights reserved.
+//
+// @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod test_broker {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::task::JoinHandle;
+
+    /// The CONNACK of an MQTT v3.1.1 broker that accepts the connection.
+    pub(crate) const CONNACK_V3: &[u8] = &[0x20, 0x02, 0x00, 0x00];
+
+    /// Stands in for an MQTT broker. Accepts one connection at a time and records the topics of the
+    /// messages published to it.
+    pub(crate) struct TestBroker {
+        connection: Option<JoinHandle<()>>,
+        listener: TcpListener,
+        topics: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl TestBroker {
+        //
+
+        /// Listens on a free local port. Clients can connect straight away, but are only
+        /// acknowledged once the connection has been accepted.
+        pub(crate) async fn bind() -> Self {
+            Self {
+                connection: None,
+                listener: TcpListener::bind("127.0.0.1:0").await.unwrap(),
+                topics: Arc::new(Mutex::new(vec![])),
+            }
+        }
+
+        pub(crate) fn port(&self) -> u16 {
+            self.listener.local_addr().unwrap().port()
+        }
+
+        /// Accepts the next connection, acknowledging the CONNECT with the specified CONNACK.
+        pub(crate) async fn accept(&mut self, connack: &'static [u8]) {
+            //
+
+            let (mut stream, _) = self.listener.accept().await.unwrap();
+            read_packet(&mut stream).await.unwrap();
+            stream.write_all(connack).await.unwrap();
+
+            let topics = self.topics.clone();
+            self.connection = Some(tokio::spawn(async move {
+                while let Some((header, body)) = read_packet(&mut stream).await {
+                    let reply = match header >> 4 {
+                        3 => on_publish(header, &body, &topics),
+                        12 => Some(vec![0xD0, 0x00]), // PINGREQ
+                        _ => None,
+                    };
+                    if let Some(reply) = reply {
+                        let _ = stream.write_all(&reply).await;
+                    }
+                }
+            }));
+        }
+
+        /// Drops the current connection, as an unreachable broker would.
+        pub(crate) fn drop_connection(&mut self) {
+            if let Some(connection) = self.connection.take() {
+                connection.abort();
+            }
+        }
+
+        /// Returns the topics of the messages received so far, in the order received.
+        pub(crate) fn topics(&self) -> Vec<String> {
+            self.topics.lock().unwrap().clone()
+        }
+    }
+
+    impl Drop for TestBroker {
+        fn drop(&mut self) {
+            self.drop_connection();
+        }
+    }
+
+    /// Records the topic of a PUBLISH and returns the PUBACK owed for QoS 1.
+    fn on_publish(header: u8, body: &[u8], topics: &Mutex<Vec<String>>) -> Option<Vec<u8>> {
+        //
+
+        let topic_length = u16::from_be_bytes([body[0], body[1]]) as usize;
+        topics.lock().unwrap().push(String::from_utf8_lossy(&body[2..2 + topic_length]).to_string());
+
+        match (header >> 1) & 0x03 {
+            0 => None,
+            _ => Some(vec![0x40, 0x02, body[2 + topic_length], body[3 + topic_length]]),
+        }
+    }
+
+    /// Reads one packet, returning its fixed header byte and its body, or None once the connection
+    /// has been closed.
+    async fn read_packet(stream: &mut TcpStream) -> Option<(u8, Vec<u8>)> {
+        //
+
+        let header = stream.read_u8().await.ok()?;
+        let mut remaining_length = 0_usize;
+        for shift in (0..28).step_by(7) {
+            let byte = stream.read_u8().await.ok()?;
+            remaining_length |= ((byte & 0x7F) as usize) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        let mut body = vec![0; remaining_length];
+        stream.read_exact(&mut body).await.ok()?;
+        Some((header, body))
+    }
+
+    /// Waits up to five seconds for the specified condition to hold.
+    pub(crate) async fn wait_until(condition: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "timed out waiting for the condition");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod outbox_tests {
+    use crate::async_mqtt_client::AsyncMqttClient;
+    use crate::broker_info::{BrokerInfo, MqttProtocolVersion};
+    use crate::outbox::{Outbox, OutboxConfig, OutboxMessage, OutboxStorage, OverflowPolicy};
+    use crate::publish_options::PublishOptions;
+    use crate::publisher_error::PublisherError;
+    use crate::publisher_qos::PublisherQoS;
+    use crate::tests::test_broker::{wait_until, TestBroker, CONNACK_V3};
+    use std::fs;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn message(topic: &str) -> OutboxMessage {
+        OutboxMessage { payload: String::new(), topic: topic.to_string(), qos: PublisherQoS::AtLeastOnce, options: PublishOptions::new() }
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest() {
+        //
+
+        let outbox = Outbox::new(OutboxConfig::new(2, OverflowPolicy::DropOldest, OutboxStorage::InMemory));
+        outbox.enqueue(message("one")).await.unwrap();
+        outbox.enqueue(message("two")).await.unwrap();
+        outbox.enqueue(message("three")).await.unwrap();
+
+        assert_eq!(outbox.depth(), 2);
+        assert_eq!(outbox.peek().unwrap().topic, "two");
+        outbox.pop();
+        assert_eq!(outbox.peek().unwrap().topic, "three");
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest() {
+        //
+
+        let outbox = Outbox::new(OutboxConfig::new(2, OverflowPolicy::DropNewest, OutboxStorage::InMemory));
+        outbox.enqueue(message("one")).await.unwrap();
+        outbox.enqueue(message("two")).await.unwrap();
+
+        assert_eq!(outbox.enqueue(message("three")).await, Err(PublisherError::OutboxFull));
+        assert_eq!(outbox.depth(), 2);
+        assert_eq!(outbox.peek().unwrap().topic, "one");
+    }
+
+    #[tokio::test]
+    async fn test_block_until_room_is_available() {
+        //
+
+        let outbox = Arc::new(Outbox::new(OutboxConfig::new(1, OverflowPolicy::Block, OutboxStorage::InMemory)));
+        outbox.enqueue(message("one")).await.unwrap();
+
+        let blocked_outbox = outbox.clone();
+        let blocked = tokio::spawn(async move { blocked_outbox.enqueue(message("two")).await });
+
+        // The second message must wait for the first to leave the queue.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!blocked.is_finished());
+
+        outbox.pop();
+        blocked.await.unwrap().unwrap();
+        assert_eq!(outbox.peek().unwrap().topic, "two");
+    }
+
+    #[tokio::test]
+    async fn test_on_disk_storage_survives_restart() {
+        //
+
+        let path = std::env::temp_dir().join(format!("outbox-{}.jsonl", Uuid::new_v4()));
+        let config = OutboxConfig::new(10, OverflowPolicy::DropOldest, OutboxStorage::OnDisk(path.clone()));
+
+        let outbox = Outbox::new(config.clone());
+        outbox.enqueue(message("one")).await.unwrap();
+        outbox.enqueue(message("two")).await.unwrap();
+        drop(outbox);
+
+        // Simulate a partially written entry.
+        let mut contents = fs::read_to_string(&path).unwrap();
+        contents.push_str("{\"payload\":\n");
+        fs::write(&path, contents).unwrap();
+
+        let restored = Outbox::new(config);
+        assert_eq!(restored.depth(), 2);
+        assert_eq!(restored.peek().unwrap(), message("one"));
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn test_on_disk_storage_survives_restart_after_pop() {
+        //
+
+        let path = std::env::temp_dir().join(format!("outbox-{}.jsonl", Uuid::new_v4()));
+        let config = OutboxConfig::new(10, OverflowPolicy::DropOldest, OutboxStorage::OnDisk(path.clone()));
+
+        let outbox = Outbox::new(config.clone());
+        outbox.enqueue(message("one")).await.unwrap();
+        outbox.enqueue(message("two")).await.unwrap();
+        outbox.enqueue(message("three")).await.unwrap();
+        outbox.pop();
+        drop(outbox);
+
+        let restored = Outbox::new(config.clone());
+        assert_eq!(restored.depth(), 2);
+        assert_eq!(restored.peek().unwrap(), message("two"));
+
+        // Emptying the queue compacts the file.
+        restored.pop();
+        restored.pop();
+        drop(restored);
+        assert!(fs::read_to_string(&path).unwrap().is_empty());
+        assert_eq!(Outbox::new(config).depth(), 0);
+
+        let _ = fs::remove_file(path);
+    }
+
+    fn client_with_outbox(broker: &TestBroker, overflow_policy: OverflowPolicy) -> AsyncMqttClient {
+        let outbox = OutboxConfig::new(1, overflow_policy, OutboxStorage::InMemory);
+        let broker_info = BrokerInfo::new("127.0.0.1".to_string(), 10, broker.port(), Duration::from_secs(60), MqttProtocolVersion::V3);
+        AsyncMqttClient::new(broker_info.with_outbox(outbox))
+    }
+
+    async fn publish(client: &AsyncMqttClient, topic: &str) -> Result<(), PublisherError> {
+        client.publish_with_payload(String::new(), topic.to_string(), PublisherQoS::AtLeastOnce, PublishOptions::new()).await.map(|_| ())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_messages_are_replayed_after_reconnecting() {
+        //
+
+        let mut broker = TestBroker::bind().await;
+        let client = client_with_outbox(&broker, OverflowPolicy::DropNewest);
+        broker.accept(CONNACK_V3).await;
+        wait_until(|| client.is_connected()).await;
+
+        publish(&client, "zero").await.unwrap();
+        wait_until(|| broker.topics() == ["zero"]).await;
+
+        // The broker goes away. Messages are held until it is back.
+        broker.drop_connection();
+        wait_until(|| !client.is_connected()).await;
+        publish(&client, "one").await.unwrap();
+        assert_eq!(publish(&client, "two").await, Err(PublisherError::OutboxFull));
+        assert_eq!(client.outbox_depth(), 1);
+
+        broker.accept(CONNACK_V3).await;
+        wait_until(|| client.outbox_depth() == 0).await;
+        wait_until(|| broker.topics() == ["zero", "one"]).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_waits_for_the_replay() {
+        //
+
+        let mut broker = TestBroker::bind().await;
+        let client = client_with_outbox(&broker, OverflowPolicy::Block);
+        publish(&client, "one").await.unwrap();
+
+        // With the Outbox full, the publisher waits for as long as the broker is unreachable.
+        let blocked_client = client.clone();
+        let blocked = tokio::spawn(async move { publish(&blocked_client, "two").await });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!blocked.is_finished());
+
+        broker.accept(CONNACK_V3).await;
+        blocked.await.unwrap().unwrap();
+        wait_until(|| broker.topics() == ["one", "two"]).await;
+        assert_eq!(client.outbox_depth(), 0);
+    }
+}
+
+#[cfg(test)]
+mod publish_options_tests {
+    use crate::outbox::OutboxMessage;
+    use crate::publish_options::PublishOptions;
+    use crate::publisher_qos::PublisherQoS;
+
+    #[test]
+    fn test_builder() {
+        //
+
+        let options = PublishOptions::new()
+            .retain(true)
+            .message_expiry_interval(60)
+            .content_type("application/json")
+            .correlation_data(b"request-1")
+            .user_property("game", "tic-tac-toe")
+            .user_property("version", "1")
+            .response_topic("responses")
+            .topic_alias(3);
+
+        assert!(options.retain);
+        assert_eq!(options.message_expiry_interval, Some(60));
+        assert_eq!(options.content_type.as_deref(), Some("application/json"));
+        assert_eq!(options.correlation_data.as_deref(), Some(&b"request-1"[..]));
+        assert_eq!(options.user_properties.len(), 2);
+        assert_eq!(options.response_topic.as_deref(), Some("responses"));
+        assert_eq!(options.topic_alias, Some(3));
+    }
+
+    #[test]
+    fn test_v5_properties() {
+        //
+
+        let properties = PublishOptions::new()
+            .retain(true)
+            .message_expiry_interval(3600)
+            .content_type("application/json")
+            .correlation_data(b"request-1")
+            .user_property("game", "tic-tac-toe")
+            .response_topic("responses")
+            .topic_alias(3)
+            .to_v5_properties();
+
+        assert_eq!(properties.message_expiry_interval, Some(3600));
+        assert_eq!(properties.content_type.as_deref(), Some("application/json"));
+        assert_eq!(properties.correlation_data.as_deref(), Some(&b"request-1"[..]));
+        assert_eq!(properties.user_properties, vec![("game".to_string(), "tic-tac-toe".to_string())]);
+        assert_eq!(properties.response_topic.as_deref(), Some("responses"));
+        assert_eq!(properties.topic_alias, Some(3));
+        assert_eq!(properties.payload_format_indicator, None);
+
+        // Unset options are left out of the packet.
+        assert_eq!(PublishOptions::new().to_v5_properties(), Default::default());
+    }
+
+    #[test]
+    fn test_outbox_entries_without_options_are_readable() {
+        //
+
+        // Entries persisted before publish options existed.
+        let message: OutboxMessage = serde_json::from_str(r#"{"payload":"","topic":"a/b","qos":"AtLeastOnce"}"#).unwrap();
+        assert_eq!(message.qos, PublisherQoS::AtLeastOnce);
+        assert_eq!(message.options, PublishOptions::new());
+    }
+}
+
+#[cfg(test)]
+mod simulcast_tests {
+    use crate::async_mqtt_client::AsyncMqttClient;
+    use crate::broker_info::{BrokerInfo, MqttProtocolVersion};
+    use crate::publisher::Publisher;
+    use crate::publisher_error::PublisherError;
+    use crate::publisher_qos::PublisherQoS;
+    use crate::simulcast_policy::SimulcastPolicy;
+    use crate::tests::test_broker::{wait_until, TestBroker, CONNACK_V3};
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    fn broker(port: u16) -> BrokerInfo {
+        BrokerInfo::new("localhost".to_string(), 10, port, Duration::from_secs(60), MqttProtocolVersion::V5)
+    }
+
+    #[test]
+    fn test_policies() {
+        //
+
+        assert!(SimulcastPolicy::All.is_satisfied(3, 3));
+        assert!(!SimulcastPolicy::All.is_satisfied(2, 3));
+
+        assert!(SimulcastPolicy::Any.is_satisfied(1, 3));
+        assert!(!SimulcastPolicy::Any.is_satisfied(0, 3));
+
+        assert!(SimulcastPolicy::Quorum(2).is_satisfied(2, 3));
+        assert!(!SimulcastPolicy::Quorum(2).is_satisfied(1, 3));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_quorums_are_rejected() {
+        //
+
+        let brokers = HashSet::from([broker(1), broker(2)]);
+
+        let result = Publisher::new_for_simulcast(brokers.clone()).with_policy(SimulcastPolicy::Quorum(0));
+        assert_eq!(result.err(), Some(PublisherError::InvalidPolicy));
+
+        let result = Publisher::new_for_simulcast(brokers.clone()).with_policy(SimulcastPolicy::Quorum(3));
+        assert_eq!(result.err(), Some(PublisherError::InvalidPolicy));
+
+        // Removing a broker must not leave the Quorum out of reach.
+        let publisher = Publisher::new_for_simulcast(brokers).with_policy(SimulcastPolicy::Quorum(2)).unwrap();
+        assert_eq!(publisher.remove_broker(&broker(1)).await, Err(PublisherError::InvalidPolicy));
+        assert_eq!(publisher.brokers().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_brokers() {
+        //
+
+        let publisher = Publisher::new_for_simulcast(HashSet::from([broker(1), broker(2)]));
+        let clone = publisher.clone();
+
+        publisher.add_broker(broker(3));
+        publisher.add_broker(broker(3));
+        assert_eq!(clone.brokers().len(), 3);
+
+        assert_eq!(clone.remove_broker(&broker(1)).await, Ok(true));
+        assert_eq!(clone.remove_broker(&broker(1)).await, Ok(false));
+        assert_eq!(publisher.brokers().len(), 2);
+        assert!(!publisher.brokers().contains(&broker(1)));
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_ends_event_loop() {
+        //
+
+        // Nothing listens on the port, so the event loop keeps failing to connect.
+        let client = AsyncMqttClient::new(broker(1));
+        assert!(client.is_event_loop_running());
+
+        client.disconnect().await;
+
+        assert!(!client.is_event_loop_running());
+        assert!(!client.is_healthy());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unhealthy_brokers_are_routed_around() {
+        //
+
+        let mut healthy_broker = TestBroker::bind().await;
+        let healthy = BrokerInfo::new("127.0.0.1".to_string(), 10, healthy_broker.port(), Duration::from_secs(60), MqttProtocolVersion::V3);
+        // Nothing listens on the port once the broker has gone.
+        let unhealthy = broker(TestBroker::bind().await.port());
+
+        let publisher = Publisher::new_for_simulcast(HashSet::from([healthy.clone(), unhealthy.clone()]))
+            .with_policy(SimulcastPolicy::Any)
+            .unwrap();
+        healthy_broker.accept(CONNACK_V3).await;
+        wait_until(|| publisher.unhealthy_brokers() == [unhealthy.clone()]).await;
+
+        let results = publisher.publish("game/1", PublisherQoS::AtLeastOnce).await.unwrap();
+        assert!(results[&healthy].is_ok());
+        assert_eq!(results[&unhealthy], Err(PublisherError::BrokerUnhealthy));
+        wait_until(|| healthy_broker.topics() == ["game/1"]).await;
+
+        // With no healthy broker left, the policy cannot be satisfied.
+        assert_eq!(publisher.remove_broker(&healthy).await, Ok(true));
+        let results = publisher.publish("game/2", PublisherQoS::AtLeastOnce).await.unwrap_err();
+        assert_eq!(results[&unhealthy], Err(PublisherError::BrokerUnhealthy));
+    }
+}
+
+#[cfg(test)]
+mod qos_tests {
+    use crate::async_mqtt_client::AsyncMqttClient;
+    use crate::broker_info::{BrokerInfo, MqttProtocolVersion};
+    use crate::publish_options::PublishOptions;
+    use crate::publisher_error::PublisherError;
+    use crate::publisher_qos::PublisherQoS;
+    use crate::publisher_warning::PublisherWarning;
+    use crate::tests::test_broker::{wait_until, TestBroker};
+    use rumqttc::v5::mqttbytes::QoS as QoSV5;
+    use rumqttc::QoS as QoSV3;
+    use std::time::Duration;
+
+    /// The CONNACK of an MQTT v5 broker that supports up to QoS 1 and packets of up to 1 KiB.
+    const CONNACK_V5_WITH_LIMITS: &[u8] = &[0x20, 0x0A, 0x00, 0x00, 0x07, 0x24, 0x01, 0x27, 0x00, 0x00, 0x04, 0x00];
+
+    async fn connect_v5_client(broker: &mut TestBroker) -> AsyncMqttClient {
+        //
+
+        let broker_info = BrokerInfo::new("127.0.0.1".to_string(), 10, broker.port(), Duration::from_secs(60), MqttProtocolVersion::V5);
+        let client = AsyncMqttClient::new(broker_info);
+        broker.accept(CONNACK_V5_WITH_LIMITS).await;
+        wait_until(|| client.is_connected()).await;
+        client
+    }
+
+    #[test]
+    fn test_mapping() {
+        //
+
+        assert_eq!(QoSV3::from(PublisherQoS::AtMostOnce), QoSV3::AtMostOnce);
+        assert_eq!(QoSV3::from(PublisherQoS::AtLeastOnce), QoSV3::AtLeastOnce);
+        assert_eq!(QoSV3::from(PublisherQoS::ExactlyOnce), QoSV3::ExactlyOnce);
+
+        assert_eq!(QoSV5::from(PublisherQoS::AtMostOnce), QoSV5::AtMostOnce);
+        assert_eq!(QoSV5::from(PublisherQoS::AtLeastOnce), QoSV5::AtLeastOnce);
+        assert_eq!(QoSV5::from(PublisherQoS::ExactlyOnce), QoSV5::ExactlyOnce);
+    }
+
+    #[test]
+    fn test_downgrade() {
+        //
+
+        assert_eq!(PublisherQoS::ExactlyOnce.capped_at(2), PublisherQoS::ExactlyOnce);
+        assert_eq!(PublisherQoS::ExactlyOnce.capped_at(1), PublisherQoS::AtLeastOnce);
+        assert_eq!(PublisherQoS::ExactlyOnce.capped_at(0), PublisherQoS::AtMostOnce);
+        assert_eq!(PublisherQoS::AtLeastOnce.capped_at(1), PublisherQoS::AtLeastOnce);
+        assert_eq!(PublisherQoS::AtLeastOnce.capped_at(0), PublisherQoS::AtMostOnce);
+        assert_eq!(PublisherQoS::AtMostOnce.capped_at(1), PublisherQoS::AtMostOnce);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_qos_above_broker_maximum_is_downgraded() {
+        //
+
+        let mut broker = TestBroker::bind().await;
+        let client = connect_v5_client(&mut broker).await;
+
+        let warnings = client.publish_with_payload(String::new(), "a/b".to_string(), PublisherQoS::ExactlyOnce, PublishOptions::new()).await.unwrap();
+        assert_eq!(warnings, vec![PublisherWarning::QoSDowngraded { requested: PublisherQoS::ExactlyOnce, granted: PublisherQoS::AtLeastOnce }]);
+
+        let warnings = client.publish_with_payload(String::new(), "a/c".to_string(), PublisherQoS::AtLeastOnce, PublishOptions::new()).await.unwrap();
+        assert!(warnings.is_empty());
+        wait_until(|| broker.topics() == ["a/b", "a/c"]).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_oversize_payload_is_rejected() {
+        //
+
+        let mut broker = TestBroker::bind().await;
+        let client = connect_v5_client(&mut broker).await;
+
+        let result = client.publish_with_payload("x".repeat(1024), "a/b".to_string(), PublisherQoS::AtLeastOnce, PublishOptions::new()).await;
+        assert_eq!(result, Err(PublisherError::PayloadTooLarge));
+
+        // The connection survives, so smaller messages still get through.
+        client.publish_with_payload("x".repeat(512), "a/c".to_string(), PublisherQoS::AtLeastOnce, PublishOptions::new()).await.unwrap();
+        wait_until(|| broker.topics() == ["a/c"]).await;
+        assert!(client.is_connected());
+    }
+}
@@ -0,0 +1,114 @@
+# Tic-Tac-Toe Bevy Client App - English
+#
+# Messages may refer to the following variables:
+#   $name     - a Player's display name
+#   $code     - the Invitation Code
+#   $count    - a number, used to choose the plural form
+#   $wins     - the number of Games won by the local Player
+#   $losses   - the number of Games won by the other Player
+#   $min      - the smallest accepted value
+#   $max      - the largest accepted value
+#   $server   - the address of the Tic-Tac-Toe service
+#   $status   - an HTTP status
+#   $context  - what was being attempted when an error occurred
+#   $reason   - why the error occurred
+#   $path     - a file location
+#   $error    - the error reported by the operating system
+
+## Start Screen
+
+start-instructions = Please type your name:
+start-name-placeholder = Your name
+start-two-player-game = Two-Player Game
+start-single-player-game = Single-Player Game
+start-accept-invitation = Accept An Invitation
+start-settings = Settings
+start-name-needed = Please enter your name.
+
+## Invitation Screen
+
+invitation-instructions = Please enter the Invitation Code:
+invitation-code-placeholder = 6-digit code
+invitation-join-game = Join Game
+invitation-code-needed = Please ask the other player for the 6-digit Invitation Code.
+
+## Settings Screen
+
+settings-instructions = Click a setting, or press Tab, to edit it:
+settings-server-url = Server URL:
+settings-poll-interval = Polling Interval (ms):
+settings-display-name = Your Name:
+settings-language = Language:
+settings-test-connection = Test Connection
+settings-save = Save
+settings-saved = Settings saved.
+settings-connected = Connected to the server at { $server }.
+settings-invalid-server-url = The Server URL must begin with http:// or https://
+settings-invalid-poll-interval = The Polling Interval must be between { $min } and { $max } ms.
+settings-poll-interval-needed = Please enter the Polling Interval in milliseconds.
+settings-no-config-directory = Unable to locate the configuration directory.
+settings-unable-to-write = Unable to write { $path }: { $error }
+
+## Shared
+
+go-back = Go Back
+
+## Game Play Screen
+
+game-end-game = End Game
+game-play-again = Play Again
+game-switch-sides = Switch Sides
+game-accept-rematch = Accept Rematch
+game-decline-rematch = Decline Rematch
+game-send-invitation = Please send the Invitation Code to another player so they can join the game...
+game-greeting-with-invitation = Hi { $name }. Please send the Invitation Code: { $code } to another player so they can join...
+game-waiting-for-player = Waiting for another player to join game. Please send out the invitation code.
+game-waiting-for-rematch = Waiting for { $name } to accept the rematch...
+game-already-ended = Game has already ended.
+game-location-occupied = This location is already occupied. Please choose another location.
+game-wait-for-turn = Please wait for { $name } to take their turn.
+game-player-joined = { $name } has joined! Let the game begin!
+game-rematch-accepted-by = { $name } has accepted the rematch! Let the game begin!
+game-begin = Let the game begin!
+game-closed = The Game has been closed.
+game-player-left = { $name } has left the game.
+game-stalemate = This game has ended in a stalemate.
+game-you-won = You won!
+game-won-by = { $name } won. Better luck next time.
+game-series-score = Series: You { $wins } - { $losses } { $name }
+game-series-stalemates = { $count ->
+        [one] (1 stalemate)
+       *[other] ({ $count } stalemates)
+    }
+
+## Rematches
+
+rematch-offered = { $name } would like a rematch. Do you accept?
+rematch-declined-by = { $name } has declined the rematch.
+rematch-withdrawn-by = { $name } has withdrawn the rematch.
+rematch-starting = Starting a new game...
+rematch-accepted = Rematch accepted. Let the game begin!
+rematch-declined = Rematch declined.
+rematch-unavailable = The rematch is no longer available.
+
+## Service Errors
+
+error-create-session = Unable to create the Gaming Session
+error-join-session = Unable to join the Gaming Session
+error-session-not-found = Gaming Session not found.
+error-create-game = Unable to create the Game
+error-join-game = Unable to join the Game
+error-game-not-started = The Game has not started yet.
+error-retrieve-game = Unable to retrieve the Game
+error-take-turn = Unable to take the turn
+error-start-rematch = Unable to start the rematch
+error-join-rematch = Unable to join the rematch
+error-decline-rematch = Unable to decline the rematch
+error-not-found = { $context }. Not found.
+error-internal = { $context }. Internal server error.
+error-unexpected-response = { $context }. An unexpected error was returned from the TicTacToe server.
+error-communication = { $context }. Problem communicating with the TicTacToe server.
+error-unreachable-context = { $context }. { $reason }
+error-server-not-responding = The server at { $server } is not responding. Please check the Settings.
+error-server-unreachable = Unable to reach the server at { $server }. Please check the Settings.
+error-server-status = The server at { $server } responded with { $status }.
@@ -0,0 +1,102 @@
+# Tic-Tac-Toe Bevy Client App - Spanish
+#
+# See en-US/tic-tac-toe.ftl for the variables that the messages may refer to. Messages missing
+# from this file are shown in English.
+
+## Start Screen
+
+start-instructions = Por favor, escribe tu nombre:
+start-name-placeholder = Tu nombre
+start-two-player-game = Partida de dos jugadores
+start-single-player-game = Partida de un jugador
+start-accept-invitation = Aceptar una invitación
+start-settings = Ajustes
+start-name-needed = Por favor, introduce tu nombre.
+
+## Invitation Screen
+
+invitation-instructions = Por favor, introduce el código de invitación:
+invitation-code-placeholder = Código de 6 dígitos
+invitation-join-game = Unirse a la partida
+invitation-code-needed = Por favor, pide al otro jugador el código de invitación de 6 dígitos.
+
+## Settings Screen
+
+settings-instructions = Haz clic en un ajuste, o pulsa Tab, para editarlo:
+settings-server-url = URL del servidor:
+settings-poll-interval = Intervalo de sondeo (ms):
+settings-display-name = Tu nombre:
+settings-language = Idioma:
+settings-test-connection = Probar la conexión
+settings-save = Guardar
+settings-saved = Ajustes guardados.
+settings-connected = Conectado al servidor en { $server }.
+settings-invalid-server-url = La URL del servidor debe comenzar con http:// o https://
+settings-invalid-poll-interval = El intervalo de sondeo debe estar entre { $min } y { $max } ms.
+settings-poll-interval-needed = Por favor, introduce el intervalo de sondeo en milisegundos.
+settings-no-config-directory = No se encuentra el directorio de configuración.
+settings-unable-to-write = No se puede escribir { $path }: { $error }
+
+## Shared
+
+go-back = Volver
+
+## Game Play Screen
+
+game-end-game = Terminar la partida
+game-play-again = Jugar de nuevo
+game-switch-sides = Cambiar de lado
+game-accept-rematch = Aceptar la revancha
+game-decline-rematch = Rechazar la revancha
+game-send-invitation = Por favor, envía el código de invitación a otro jugador para que pueda unirse a la partida...
+game-greeting-with-invitation = Hola, { $name }. Por favor, envía el código de invitación { $code } a otro jugador para que pueda unirse...
+game-waiting-for-player = Esperando a que otro jugador se una a la partida. Por favor, envía el código de invitación.
+game-waiting-for-rematch = Esperando a que { $name } acepte la revancha...
+game-already-ended = La partida ya ha terminado.
+game-location-occupied = Esta casilla ya está ocupada. Por favor, elige otra casilla.
+game-wait-for-turn = Por favor, espera a que { $name } juegue su turno.
+game-player-joined = ¡{ $name } se ha unido! ¡Que empiece la partida!
+game-rematch-accepted-by = ¡{ $name } ha aceptado la revancha! ¡Que empiece la partida!
+game-begin = ¡Que empiece la partida!
+game-closed = La partida se ha cerrado.
+game-player-left = { $name } ha abandonado la partida.
+game-stalemate = Esta partida ha terminado en empate.
+game-you-won = ¡Has ganado!
+game-won-by = { $name } ha ganado. Más suerte la próxima vez.
+game-series-score = Serie: Tú { $wins } - { $losses } { $name }
+game-series-stalemates = { $count ->
+        [one] (1 empate)
+       *[other] ({ $count } empates)
+    }
+
+## Rematches
+
+rematch-offered = { $name } quiere la revancha. ¿Aceptas?
+rematch-declined-by = { $name } ha rechazado la revancha.
+rematch-withdrawn-by = { $name } ha retirado la revancha.
+rematch-starting = Empezando una nueva partida...
+rematch-accepted = Revancha aceptada. ¡Que empiece la partida!
+rematch-declined = Revancha rechazada.
+rematch-unavailable = La revancha ya no está disponible.
+
+## Service Errors
+
+error-create-session = No se puede crear la sesión de juego
+error-join-session = No se puede unir a la sesión de juego
+error-session-not-found = No se ha encontrado la sesión de juego.
+error-create-game = No se puede crear la partida
+error-join-game = No se puede unir a la partida
+error-game-not-started = La partida aún no ha empezado.
+error-retrieve-game = No se puede obtener la partida
+error-take-turn = No se puede jugar el turno
+error-start-rematch = No se puede empezar la revancha
+error-join-rematch = No se puede unir a la revancha
+error-decline-rematch = No se puede rechazar la revancha
+error-not-found = { $context }. No encontrado.
+error-internal = { $context }. Error interno del servidor.
+error-unexpected-response = { $context }. El servidor de TicTacToe ha devuelto un error inesperado.
+error-communication = { $context }. Problema al comunicarse con el servidor de TicTacToe.
+error-unreachable-context = { $context }. { $reason }
+error-server-not-responding = El servidor en { $server } no responde. Por favor, revisa los ajustes.
+error-server-unreachable = No se puede conectar con el servidor en { $server }. Por favor, revisa los ajustes.
+error-server-status = El servidor en { $server } ha respondido con { $status }.
@@ -0,0 +1,102 @@
+# Tic-Tac-Toe Bevy Client App - French
+#
+# See en-US/tic-tac-toe.ftl for the variables that the messages may refer to. Messages missing
+# from this file are shown in English.
+
+## Start Screen
+
+start-instructions = Veuillez saisir votre nom :
+start-name-placeholder = Votre nom
+start-two-player-game = Partie à deux joueurs
+start-single-player-game = Partie à un joueur
+start-accept-invitation = Accepter une invitation
+start-settings = Paramètres
+start-name-needed = Veuillez saisir votre nom.
+
+## Invitation Screen
+
+invitation-instructions = Veuillez saisir le code d'invitation :
+invitation-code-placeholder = Code à 6 chiffres
+invitation-join-game = Rejoindre la partie
+invitation-code-needed = Veuillez demander à l'autre joueur le code d'invitation à 6 chiffres.
+
+## Settings Screen
+
+settings-instructions = Cliquez sur un paramètre, ou appuyez sur Tab, pour le modifier :
+settings-server-url = URL du serveur :
+settings-poll-interval = Intervalle d'interrogation (ms) :
+settings-display-name = Votre nom :
+settings-language = Langue :
+settings-test-connection = Tester la connexion
+settings-save = Enregistrer
+settings-saved = Paramètres enregistrés.
+settings-connected = Connecté au serveur { $server }.
+settings-invalid-server-url = L'URL du serveur doit commencer par http:// ou https://
+settings-invalid-poll-interval = L'intervalle d'interrogation doit être compris entre { $min } et { $max } ms.
+settings-poll-interval-needed = Veuillez saisir l'intervalle d'interrogation en millisecondes.
+settings-no-config-directory = Impossible de trouver le répertoire de configuration.
+settings-unable-to-write = Impossible d'écrire { $path } : { $error }
+
+## Shared
+
+go-back = Retour
+
+## Game Play Screen
+
+game-end-game = Terminer la partie
+game-play-again = Rejouer
+game-switch-sides = Changer de camp
+game-accept-rematch = Accepter la revanche
+game-decline-rematch = Refuser la revanche
+game-send-invitation = Veuillez envoyer le code d'invitation à un autre joueur pour qu'il puisse rejoindre la partie...
+game-greeting-with-invitation = Bonjour { $name }. Veuillez envoyer le code d'invitation { $code } à un autre joueur pour qu'il puisse rejoindre la partie...
+game-waiting-for-player = En attente d'un autre joueur. Veuillez envoyer le code d'invitation.
+game-waiting-for-rematch = En attente de l'acceptation de la revanche par { $name }...
+game-already-ended = La partie est déjà terminée.
+game-location-occupied = Cette case est déjà occupée. Veuillez en choisir une autre.
+game-wait-for-turn = Veuillez attendre que { $name } joue son tour.
+game-player-joined = { $name } a rejoint la partie ! Que la partie commence !
+game-rematch-accepted-by = { $name } a accepté la revanche ! Que la partie commence !
+game-begin = Que la partie commence !
+game-closed = La partie a été fermée.
+game-player-left = { $name } a quitté la partie.
+game-stalemate = Cette partie s'est terminée par un match nul.
+game-you-won = Vous avez gagné !
+game-won-by = { $name } a gagné. Plus de chance la prochaine fois.
+game-series-score = Série : Vous { $wins } - { $losses } { $name }
+game-series-stalemates = { $count ->
+        [one] (1 match nul)
+       *[other] ({ $count } matchs nuls)
+    }
+
+## Rematches
+
+rematch-offered = { $name } propose une revanche. Acceptez-vous ?
+rematch-declined-by = { $name } a refusé la revanche.
+rematch-withdrawn-by = { $name } a retiré sa proposition de revanche.
+rematch-starting = Une nouvelle partie commence...
+rematch-accepted = Revanche acceptée. Que la partie commence !
+rematch-declined = Revanche refusée.
+rematch-unavailable = La revanche n'est plus disponible.
+
+## Service Errors
+
+error-create-session = Impossible de créer la session de jeu
+error-join-session = Impossible de rejoindre la session de jeu
+error-session-not-found = Session de jeu introuvable.
+error-create-game = Impossible de créer la partie
+error-join-game = Impossible de rejoindre la partie
+error-game-not-started = La partie n'a pas encore commencé.
+error-retrieve-game = Impossible de récupérer la partie
+error-take-turn = Impossible de jouer le tour
+error-start-rematch = Impossible de lancer la revanche
+error-join-rematch = Impossible de rejoindre la revanche
+error-decline-rematch = Impossible de refuser la revanche
+error-not-found = { $context }. Introuvable.
+error-internal = { $context }. Erreur interne du serveur.
+error-unexpected-response = { $context }. Le serveur TicTacToe a renvoyé une erreur inattendue.
+error-communication = { $context }. Problème de communication avec le serveur TicTacToe.
+error-unreachable-context = { $context }. { $reason }
+error-server-not-responding = Le serveur { $server } ne répond pas. Veuillez vérifier les paramètres.
+error-server-unreachable = Impossible de joindre le serveur { $server }. Veuillez vérifier les paramètres.
+error-server-status = Le serveur { $server } a répondu { $status }.
@@ -0,0 +1,100 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bevy::app::App;
+use bevy::prelude::{in_state, resource_exists, Commands, EventWriter, IntoSystemConfigs, OnExit, Plugin, Res, Resource, Update};
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+use tic_tac_toe_rust_client_sdk::game_client_event::GameClientEvent;
+use tic_tac_toe_rust_client_sdk::game_event_listener::GameEventListener;
+use tic_tac_toe_rust_client_sdk::models::{EventPlaneConfig, GameState};
+
+use crate::game_play_screen::{GameChangedEvent, GameCreatedEvent};
+use crate::shared::app_mode::AppMode;
+
+/// Forwards the SDK's GameClientEvents into Bevy as GameChangedEvents and GameCreatedEvents.
+///
+/// Listening begins when a GameEventsListenerResource is inserted and ends when the Gaming Session
+/// is deleted or the app leaves the Game Play screen. See the SDK's GameEventListener.
+pub(super) struct GameEventsPlugin;
+
+impl Plugin for GameEventsPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .add_event::<GameChangedEvent>()
+            .add_event::<GameCreatedEvent>()
+            .add_systems(
+                Update,
+                Self::forward_game_changes
+                    .run_if(in_state(AppMode::GamePlay))
+                    .run_if(resource_exists::<GameEventsListenerResource>),
+            )
+            .add_systems(OnExit(AppMode::GamePlay), Self::stop_listening);
+    }
+}
+
+impl GameEventsPlugin {
+    //
+
+    /// Sends the events reported by the listener as GameChangedEvents and GameCreatedEvents.
+    fn forward_game_changes(
+        listener: Res<GameEventsListenerResource>,
+        mut game_changed_writer: EventWriter<GameChangedEvent>,
+        mut game_created_writer: EventWriter<GameCreatedEvent>,
+    ) {
+        let listener = listener.listener.lock().unwrap();
+        while let Some(event) = listener.try_next_event() {
+            match event {
+                GameClientEvent::GameCreated(game) => {
+                    game_created_writer.send(GameCreatedEvent { game: *game });
+                }
+                event => {
+                    game_changed_writer.send(GameChangedEvent { event });
+                }
+            }
+        }
+    }
+
+    /// Shuts down the background listener.
+    fn stop_listening(mut commands: Commands) {
+        commands.remove_resource::<GameEventsListenerResource>();
+    }
+}
+
+/// Owns the SDK's GameEventListener, which watches the Gaming Session's Games from a background
+/// thread. Dropping the resource stops the listener.
+#[derive(Resource)]
+pub(super) struct GameEventsListenerResource {
+    listener: Mutex<GameEventListener>,
+}
+
+impl GameEventsListenerResource {
+    //
+
+    /// Begins listening for changes to the specified Game and for any Games that follow it in the
+    /// Gaming Session. The poll_interval sets how often the service is polled while the event
+    /// plane broker is unreachable or while waiting for a new Game.
+    pub(super) fn start(configuration: Configuration,
+                        event_plane_config: EventPlaneConfig,
+                        session_id: &str,
+                        game_id: &str,
+                        local_player_id: &str,
+                        game_state: GameState,
+                        poll_interval: Duration) -> Self {
+        let listener = GameEventListener::start(configuration,
+                                                event_plane_config,
+                                                session_id,
+                                                game_id,
+                                                local_player_id,
+                                                game_state,
+                                                poll_interval);
+        Self { listener: Mutex::new(listener) }
+    }
+}
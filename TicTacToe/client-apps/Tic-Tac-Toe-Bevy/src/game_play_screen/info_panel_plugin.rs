@@ -8,11 +8,13 @@ use bevy::prelude::{
 };
 
 use crate::game_play_screen::info_panel_plugin::functionality::{
-    button_interaction, set_status_text, update_info_panel_bottom, update_info_panel_top,
+    button_interaction, set_status_text, update_buttons, update_info_panel_bottom,
+    update_info_panel_top, update_series_score,
 };
 use crate::game_play_screen::info_panel_plugin::spawn_ui::{
-    spawn_end_game_button, spawn_info_panel_entities,
+    spawn_game_buttons, spawn_info_panel_entities,
 };
+use crate::game_play_screen::ButtonPressedEvent;
 use crate::shared::app_mode::AppMode;
 
 /// Displays relevant Game info along the top of the screen.
@@ -22,21 +24,22 @@ impl Plugin for InfoPanelPlugin {
     /// Composes the plugin.
     fn build(&self, app: &mut App) {
         app //
+            .add_event::<ButtonPressedEvent>()
             .add_systems(
                 OnEnter(AppMode::GamePlay),
                 (
                     set_status_text,
-                    spawn_end_game_button,
+                    spawn_game_buttons,
                     spawn_info_panel_entities,
                 ),
             )
             .add_systems(
                 Update,
-                (button_interaction,).run_if(in_state(AppMode::GamePlay)),
+                (button_interaction, update_buttons).run_if(in_state(AppMode::GamePlay)),
             )
             .add_systems(
                 FixedUpdate,
-                (update_info_panel_bottom, update_info_panel_top)
+                (update_info_panel_bottom, update_info_panel_top, update_series_score)
                     .run_if(in_state(AppMode::GamePlay)),
             );
     }
@@ -52,29 +55,35 @@ pub(super) struct InfoPanelUiComponentTop;
 #[derive(Component)]
 pub(super) struct InfoPanelUiComponentBottom;
 
+/// Marker for the series score text, shown once the first Game of the Gaming Session has ended.
+#[derive(Component)]
+pub(super) struct InfoPanelUiComponentSeries;
+
 mod functionality {
     use std::time::Duration;
 
-    use bevy::prelude::{BackgroundColor, Button, Changed, DetectChanges, EventWriter, Interaction, NextState, Query, Res, ResMut, Text, TextStyle, Window, With};
+    use bevy::prelude::{BackgroundColor, Button, Changed, DetectChanges, Display, EventWriter, Interaction, Query, Res, Style, Text, TextStyle, Window, With};
     use bevy::window::PrimaryWindow;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
     use helpers_for_bevy::status_text::events::SetStatusTextEvent;
 
-    use crate::game_play_screen::info_panel_plugin::{InfoPanelUiComponentBottom, InfoPanelUiComponentTop, INFO_PANEL_FONT_SIZE_LABEL};
+    use crate::game_play_screen::info_panel_plugin::{InfoPanelUiComponentBottom, InfoPanelUiComponentSeries, InfoPanelUiComponentTop, INFO_PANEL_FONT_SIZE_LABEL};
+    use crate::game_play_screen::{ButtonPressedEvent, ButtonPurpose};
+    use crate::localization::localization_resource::LocalizationResource;
     use crate::shared::api_helpers::GamePieceHelper;
-    use crate::shared::app_mode::AppMode;
     use crate::shared::app_state_resource::AppStateResource;
     use crate::shared::{BUTTON_COLOR_HOVERED, BUTTON_COLOR_NORMAL, BUTTON_COLOR_PRESSED, FOREGROUND_COLOR};
 
     /// Provides button functionality, including state changes as well as response to presses.
     #[allow(clippy::type_complexity)] // The query is complex by necessity.
     pub(super) fn button_interaction(
+        mut event_writer: EventWriter<ButtonPressedEvent>,
         mut interactions: Query<
-            (&Interaction, &mut BackgroundColor),
+            (&Interaction, &mut BackgroundColor, &EntityInfoComponent<ButtonPurpose>),
             (Changed<Interaction>, With<Button>),
         >,
-        mut next_state: ResMut<NextState<AppMode>>,
     ) {
-        for (interaction, mut color) in &mut interactions {
+        for (interaction, mut color, button_info) in &mut interactions {
             match *interaction {
                 Interaction::Hovered => {
                     *color = BackgroundColor(*BUTTON_COLOR_HOVERED);
@@ -84,21 +93,51 @@ mod functionality {
                 }
                 Interaction::Pressed => {
                     *color = BackgroundColor(*BUTTON_COLOR_PRESSED);
-                    next_state.set(AppMode::StartMenu);
+                    event_writer.send(ButtonPressedEvent { purpose: button_info.get_purpose() });
                 }
             }
         }
     }
 
+    /// Shows only the buttons that apply to the current state of the Game. The rematch buttons
+    /// appear once the Game has ended.
+    pub(super) fn update_buttons(
+        app_state: Res<AppStateResource>,
+        mut buttons: Query<(&mut Style, &EntityInfoComponent<ButtonPurpose>), With<Button>>,
+    ) {
+        //
+
+        if !app_state.is_changed() {
+            return;
+        }
+
+        let can_start_rematch = app_state.has_game_ended && app_state.rematch_offer.is_none();
+        let has_rematch_offer = app_state.rematch_offer.is_some();
+
+        for (mut style, button_info) in &mut buttons {
+            let is_visible = match button_info.get_purpose() {
+                ButtonPurpose::AcceptRematch | ButtonPurpose::DeclineRematch => has_rematch_offer,
+                ButtonPurpose::EndGame => true,
+                ButtonPurpose::PlayAgain => can_start_rematch,
+                ButtonPurpose::SwitchSides => can_start_rematch && app_state.is_two_player_game,
+            };
+            style.display = match is_visible {
+                true => Display::Flex,
+                false => Display::None,
+            };
+        }
+    }
+
     /// Sets the Status text with instructions for sharing the Invitation Code.
     pub(super) fn set_status_text(
         mut event_writer: EventWriter<SetStatusTextEvent>,
         app_state: Res<AppStateResource>,
+        localization: Res<LocalizationResource>,
         _window_query: Query<&Window, With<PrimaryWindow>>,
     ) {
         if app_state.local_player_initiated_gaming_session && app_state.is_two_player_game {
             let event = SetStatusTextEvent::new_with_duration(
-                "Please send the Invitation Code to another player so they can join the game...",
+                localization.text("game-send-invitation"),
                 Duration::from_secs(30),
             );
             event_writer.send(event);
@@ -108,16 +147,21 @@ mod functionality {
     /// Sets the text of the info panel's top row, based on the current state of the Game.
     pub(super) fn update_info_panel_top(
         app_state: Res<AppStateResource>,
+        localization: Res<LocalizationResource>,
         mut top_text_query: Query<&mut Text, With<InfoPanelUiComponentTop>>,
     ) {
-        if app_state.is_changed() {
+        if app_state.is_changed() || localization.is_changed() {
             if let Ok(mut text_sections) = top_text_query.get_single_mut() {
-                // Show the Invitation Code instructions until the Game has started
-                if app_state.local_player_initiated_gaming_session && !app_state.has_game_started {
-                    text_sections.sections[0].value = format!(
-                        "Hi {}. Please send the Invitation Code: {} to another player so they can join...",
-                        app_state.local_player.display_name, app_state.invitation_code.clone()
-                    );
+                // Show the Invitation Code instructions until the first Game has started
+                if app_state.local_player_initiated_gaming_session && !app_state.has_game_started && app_state.series_score.games_played() == 0 {
+                    text_sections.sections[0].value = localization.format("game-greeting-with-invitation", [
+                        ("name", app_state.local_player.display_name.as_str().into()),
+                        ("code", app_state.invitation_code.as_str().into()),
+                    ]);
+                } else if app_state.is_awaiting_rematch() {
+                    text_sections.sections[0].value = localization.format("game-waiting-for-rematch", [
+                        ("name", app_state.other_player.clone().unwrap_or_default().display_name.into()),
+                    ]);
                 } else {
                     text_sections.sections[0].value = format!("{} {}", app_state.local_player.display_name, GamePieceHelper::display_name(app_state.local_player.game_piece));
                     if app_state.has_game_started && app_state.current_player.is_some() {
@@ -168,23 +212,60 @@ mod functionality {
             }
         }
     }
+
+    /// Sets the series score text, e.g. "Series: You 2 - 1 Alice (1 stalemate)".
+    pub(super) fn update_series_score(
+        app_state: Res<AppStateResource>,
+        localization: Res<LocalizationResource>,
+        mut series_text_query: Query<&mut Text, With<InfoPanelUiComponentSeries>>,
+    ) {
+        //
+
+        if !app_state.is_changed() && !localization.is_changed() {
+            return;
+        }
+
+        if let Ok(mut text_sections) = series_text_query.get_single_mut() {
+            let score = &app_state.series_score;
+            text_sections.sections[0].value = match score.games_played() {
+                0 => "".to_string(),
+                _ => {
+                    let series = localization.format("game-series-score", [
+                        ("wins", score.local_player_wins.into()),
+                        ("losses", score.other_player_wins.into()),
+                        ("name", app_state.other_player.clone().unwrap_or_default().display_name.into()),
+                    ]);
+                    match score.stalemates {
+                        0 => series,
+                        count => format!("{} {}", series, localization.format("game-series-stalemates", [("count", count.into())])),
+                    }
+                }
+            };
+        }
+    }
 }
 
 mod spawn_ui {
     use bevy::hierarchy::BuildChildren;
     use bevy::prelude::{
         AlignItems, BackgroundColor, BorderColor, ButtonBundle, Color, Commands, FlexDirection,
-        JustifyContent, JustifySelf, NodeBundle, Query, TextBundle, TextSection, Window, With,
+        JustifyContent, JustifySelf, NodeBundle, Query, Res, TextBundle, TextSection, Window, With,
     };
     use bevy::text::TextStyle;
     use bevy::ui::{Style, UiRect, Val};
     use bevy::utils::default;
     use bevy::window::PrimaryWindow;
 
+    use bevy::prelude::Display;
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+
     use crate::game_play_screen::info_panel_plugin::{
-        InfoPanelUiComponentBottom, InfoPanelUiComponentTop, INFO_PANEL_FONT_SIZE_LABEL,
+        InfoPanelUiComponentBottom, InfoPanelUiComponentSeries, InfoPanelUiComponentTop,
+        INFO_PANEL_FONT_SIZE_LABEL,
     };
-    use crate::game_play_screen::OnGamePlayScreen;
+    use crate::game_play_screen::{ButtonPurpose, OnGamePlayScreen};
+    use crate::localization::localization_resource::LocalizationResource;
+    use crate::localization::LocalizedTextComponent;
     use crate::shared::{BUTTON_COLOR_NORMAL, FONT_SIZE, FOREGROUND_COLOR, TEXT_COLOR};
 
     /// Sets up and creates the UI entities in the information panel area.
@@ -221,7 +302,18 @@ mod spawn_ui {
                 font_size: INFO_PANEL_FONT_SIZE_LABEL,
             },
         )];
-        let text_bundle2 = TextBundle::from_sections(sections).with_style(style);
+        let text_bundle2 = TextBundle::from_sections(sections).with_style(style.clone());
+
+        // Series score
+        let sections = [TextSection::new(
+            "",
+            TextStyle {
+                color: *TEXT_COLOR,
+                font: Default::default(),
+                font_size: FONT_SIZE,
+            },
+        )];
+        let text_bundle3 = TextBundle::from_sections(sections).with_style(style);
 
         commands
             .spawn((
@@ -241,18 +333,27 @@ mod spawn_ui {
             .with_children(|parent| {
                 parent.spawn((text_bundle1, InfoPanelUiComponentTop, OnGamePlayScreen));
                 parent.spawn((text_bundle2, InfoPanelUiComponentBottom, OnGamePlayScreen));
+                parent.spawn((text_bundle3, InfoPanelUiComponentSeries, OnGamePlayScreen));
             });
     }
 
-    /// Sets up and creates the End Game button.
-    pub(super) fn spawn_end_game_button(
+    /// Sets up and creates the End Game button as well as the rematch buttons, which remain
+    /// hidden until the Game has ended.
+    pub(super) fn spawn_game_buttons(
         mut commands: Commands,
+        localization: Res<LocalizationResource>,
         _window_query: Query<&Window, With<PrimaryWindow>>,
     ) {
         //
 
-        // Start Over Button
-        let title_end_game = "End Game";
+        let buttons = [
+            (ButtonPurpose::EndGame, "game-end-game"),
+            (ButtonPurpose::PlayAgain, "game-play-again"),
+            (ButtonPurpose::SwitchSides, "game-switch-sides"),
+            (ButtonPurpose::AcceptRematch, "game-accept-rematch"),
+            (ButtonPurpose::DeclineRematch, "game-decline-rematch"),
+        ];
+
         let button_bundle = ButtonBundle {
             style: Style {
                 align_items: AlignItems::Center,
@@ -275,6 +376,8 @@ mod spawn_ui {
             .spawn((
                 NodeBundle {
                     style: Style {
+                        column_gap: Val::Px(10.),
+                        flex_direction: FlexDirection::Row,
                         padding: UiRect::all(Val::Px(12.)),
                         ..default()
                     },
@@ -283,14 +386,21 @@ mod spawn_ui {
                 OnGamePlayScreen,
             ))
             .with_children(|parent| {
-                parent
-                    .spawn((button_bundle, OnGamePlayScreen))
-                    .with_children(|parent| {
-                        parent.spawn((
-                            TextBundle::from_section(title_end_game, button_text_style),
-                            OnGamePlayScreen,
-                        ));
-                    });
+                for (purpose, title) in buttons {
+                    let mut button_bundle = button_bundle.clone();
+                    if purpose != ButtonPurpose::EndGame {
+                        button_bundle.style.display = Display::None;
+                    }
+                    parent
+                        .spawn((button_bundle, EntityInfoComponent::new(purpose), OnGamePlayScreen))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(localization.text(title), button_text_style.clone()),
+                                LocalizedTextComponent(title),
+                                OnGamePlayScreen,
+                            ));
+                        });
+                }
             });
     }
 }
@@ -5,23 +5,26 @@
 
 use std::time::Duration;
 
-use crate::game_play_screen::{OnGamePlayScreen, TilePressedEvent};
-use crate::shared::api_helpers::{GameStateCache, SDK_CONFIG};
+use crate::game_play_screen::game_events_plugin::GameEventsListenerResource;
+use crate::game_play_screen::{ButtonPressedEvent, ButtonPurpose, GameChangedEvent, GameCreatedEvent, OnGamePlayScreen, TilePressedEvent};
+use crate::localization::localization_resource::LocalizationResource;
+use crate::shared::api_helpers::ServiceErrorHelper;
 use crate::shared::app_mode::AppMode;
+use crate::shared::app_settings_resource::AppSettingsResource;
 use crate::shared::app_state_resource::AppStateResource;
 use crate::shared::despawn;
 use bevy::app::App;
 use bevy::log::error;
-use bevy::prelude::{in_state, EventReader, EventWriter, FixedUpdate, IntoSystemConfigs, NextState, OnEnter, OnExit, Plugin, ResMut, Update};
-use bevy::time::common_conditions::on_timer;
+use bevy::prelude::{in_state, Commands, EventReader, EventWriter, IntoSystemConfigs, NextState, OnEnter, OnExit, Plugin, Res, ResMut, Update};
 use helpers_for_bevy::status_text::events::SetStatusTextEvent;
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
 use tic_tac_toe_rust_client_sdk::apis::{tic_tac_toe_api, Error};
-use tic_tac_toe_rust_client_sdk::models::{AutomaticPlayerSkillLevel, GameCreationResponse, GamePiece, GameTurnParams, GamingSessionCreationResponse, JoinSessionParams, NewGamingSessionParams, NewSinglePlayerGameParams, PlayStatus};
+use tic_tac_toe_rust_client_sdk::game_client_event::GameClientEvent;
+use tic_tac_toe_rust_client_sdk::models::{AutomaticPlayerSkillLevel, EndGameParams, EndGamingSessionParams, GameCreationResponse, GamePiece, GameTurnParams, GamingSessionCreationResponse, JoinSessionParams, NewGamingSessionParams, NewSinglePlayerGameParams, NewTwoPlayerGameParams, PlayStatus};
 
 /// Provides the local, client-side logic that works with our TicTacToe Game Service.
 pub(super) struct LocalGamePlayPlugin;
 
-pub(super) const STATE_UPDATE_INTERVAL_IN_MS: u64 = 500;
 
 impl Plugin for LocalGamePlayPlugin {
     //
@@ -32,13 +35,14 @@ impl Plugin for LocalGamePlayPlugin {
             .add_systems(OnEnter(AppMode::GamePlay), Self::join_or_begin_new_game)
             .add_systems(
                 Update,
-                Self::handle_tile_pressed.run_if(in_state(AppMode::GamePlay)),
-            )
-            .add_systems(
-                FixedUpdate,
-                LocalGamePlayPlugin::refresh_game_state
-                    .run_if(in_state(AppMode::GamePlay))
-                    .run_if(on_timer(Duration::from_millis(STATE_UPDATE_INTERVAL_IN_MS))),
+                (
+                    Self::handle_tile_pressed,
+                    Self::handle_button_pressed,
+                    Self::handle_game_created,
+                    Self::handle_game_changes,
+                )
+                    .chain()
+                    .run_if(in_state(AppMode::GamePlay)),
             )
             .add_systems(OnExit(AppMode::GamePlay), despawn::<OnGamePlayScreen>);
     }
@@ -50,6 +54,8 @@ impl LocalGamePlayPlugin {
     /// Handles the Tile Pressed event.
     fn handle_tile_pressed(
         mut app_state: ResMut<AppStateResource>,
+        localization: Res<LocalizationResource>,
+        settings: Res<AppSettingsResource>,
         mut event_reader: EventReader<TilePressedEvent>,
         mut event_writer: EventWriter<SetStatusTextEvent>,
     ) {
@@ -60,17 +66,20 @@ impl LocalGamePlayPlugin {
 
             // Ignore clicks if the Game has yet to begin.
             if !app_state.has_game_started {
-                event_writer.send(SetStatusTextEvent::new_with_duration(
-                    "Waiting for another player to join game. Please send out the invitation code.",
-                    Duration::from_secs(10),
-                ));
+                let message = match app_state.series_score.games_played() {
+                    0 => localization.text("game-waiting-for-player"),
+                    _ => localization.format("game-waiting-for-rematch", [
+                        ("name", app_state.other_player.clone().unwrap_or_default().display_name.into()),
+                    ]),
+                };
+                event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(10)));
                 return;
             }
 
             // Ignore clicks if the Game has already ended.
             if app_state.has_game_ended {
                 event_writer.send(SetStatusTextEvent::new_with_duration(
-                    "Game has already ended.",
+                    localization.text("game-already-ended"),
                     Duration::from_secs(30),
                 ));
                 return;
@@ -81,16 +90,15 @@ impl LocalGamePlayPlugin {
                 != GamePiece::Unselected
             {
                 event_writer.send(SetStatusTextEvent::new_with_duration(
-                    "This location is already occupied. Please choose another location.",
+                    localization.text("game-location-occupied"),
                     Duration::from_secs(5),
                 ));
                 return;
             }
 
-            let not_local_player_turn = format!(
-                "Please wait for {} to take their turn.",
-                app_state.other_player.clone().unwrap_or_default().display_name
-            );
+            let not_local_player_turn = localization.format("game-wait-for-turn", [
+                ("name", app_state.other_player.clone().unwrap_or_default().display_name.into()),
+            ]);
 
             // Ignore clicks if it is not the local Player's turn.
             if let Some(current_player) = app_state.current_player.clone() {
@@ -120,20 +128,14 @@ impl LocalGamePlayPlugin {
                 session_id: app_state.gaming_session_id.clone(),
             };
             match tic_tac_toe_api::take_turn(
-                &SDK_CONFIG,
+                settings.sdk_config(),
                 &app_state.game_id,
                 params) {
                 Ok(_) => {}
                 Err(error) => {
                     let message = match error {
-                        Error::ResponseError(error) => {
-                            if error.status == 405 {
-                                not_local_player_turn
-                            } else {
-                                "Problem contacting the TicTacToe server.".to_string()
-                            }
-                        }
-                        _ => "Problem communicating with the TicTacToe server.".to_string(),
+                        Error::ResponseError(error) if error.status == 405 => not_local_player_turn,
+                        error => ServiceErrorHelper::describe(&localization, "error-take-turn", settings.sdk_config(), &error),
                     };
                     event_writer.send(SetStatusTextEvent::new_with_duration(
                         message,
@@ -148,46 +150,30 @@ impl LocalGamePlayPlugin {
 impl LocalGamePlayPlugin {
     //
 
-    fn create_two_player_game(app_state: &AppStateResource) -> Option<GameCreationResponse> {
-        //
-
-        let new_game_state = match tic_tac_toe_api::create_two_player_game(&SDK_CONFIG, &app_state.gaming_session_id) {
-            Ok(new_game_state) => new_game_state,
-            Err(error) => {
-                // TODO: JD: finish
-                error!("Error joining gaming session: {:?}", error);
-                return None;
-            }
-        };
-
-        Some(new_game_state)
+    fn create_two_player_game(localization: &LocalizationResource, configuration: &Configuration, app_state: &AppStateResource) -> Result<GameCreationResponse, String> {
+        tic_tac_toe_api::create_two_player_game(configuration, &app_state.gaming_session_id)
+            .map_err(|error| ServiceErrorHelper::describe(localization, "error-create-game", configuration, &error))
     }
 
-    fn create_single_player_game(app_state: &AppStateResource) -> Option<GameCreationResponse> {
-        //
-
+    fn create_single_player_game(localization: &LocalizationResource, configuration: &Configuration, app_state: &AppStateResource) -> Result<GameCreationResponse, String> {
         let params = NewSinglePlayerGameParams { computer_skill_level: AutomaticPlayerSkillLevel::Beginner };
-        let new_game_state = match tic_tac_toe_api::create_single_player_game(&SDK_CONFIG, &app_state.gaming_session_id, params) {
-            Ok(new_game_state) => new_game_state,
-            Err(error) => {
-                // TODO: JD: finish
-                error!("Error joining gaming session: {:?}", error);
-                return None;
-            }
-        };
-
-        Some(new_game_state)
+        tic_tac_toe_api::create_single_player_game(configuration, &app_state.gaming_session_id, params)
+            .map_err(|error| ServiceErrorHelper::describe(localization, "error-create-game", configuration, &error))
     }
 
     /// Starts a new Game or joins an existing Game - depending upon whether the local Player is
     /// initiating the Game.
     fn join_or_begin_new_game(
+        mut commands: Commands,
         mut app_state: ResMut<AppStateResource>,
+        localization: Res<LocalizationResource>,
+        settings: Res<AppSettingsResource>,
         mut event_writer: EventWriter<SetStatusTextEvent>,
         mut next_state: ResMut<NextState<AppMode>>,
     ) {
         //
 
+        let configuration = settings.sdk_config();
         let gaming_session_info: GamingSessionCreationResponse;
 
         if app_state.local_player_initiated_gaming_session {
@@ -196,12 +182,17 @@ impl LocalGamePlayPlugin {
             // *** Create a new Gaming Session ***
 
             let params = NewGamingSessionParams { session_owner_display_name: app_state.local_player.display_name.clone() };
-            gaming_session_info = match tic_tac_toe_api::create_gaming_session(&SDK_CONFIG, params) {
+            gaming_session_info = match tic_tac_toe_api::create_gaming_session(configuration, params) {
                 Ok(result) => {
                     result
                 }
                 Err(error) => {
                     error!("Error creating gaming session: {:?}", error);
+                    event_writer.send(SetStatusTextEvent::new_with_duration(
+                        ServiceErrorHelper::describe(&localization, "error-create-session", configuration, &error),
+                        Duration::from_secs(10),
+                    ));
+                    next_state.set(AppMode::StartMenu);
                     return;
                 }
             };
@@ -222,7 +213,12 @@ impl LocalGamePlayPlugin {
                 }
                 false => Self::create_single_player_game,
             };
-            let _ = game_creation_function(&app_state);
+            if let Err(message) = game_creation_function(&localization, configuration, &app_state) {
+                error!("{}", message);
+                event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(10)));
+                next_state.set(AppMode::StartMenu);
+                return;
+            }
         } else {
             //
 
@@ -234,20 +230,13 @@ impl LocalGamePlayPlugin {
                 game_invitation_code: app_state.invitation_code.clone(),
                 player_display_name: app_state.local_player.display_name.clone(),
             };
-            gaming_session_info = match tic_tac_toe_api::join_gaming_session(&SDK_CONFIG, params) {
+            gaming_session_info = match tic_tac_toe_api::join_gaming_session(configuration, params) {
                 Ok(gaming_session_info) => gaming_session_info,
                 Err(error) => {
-                    // TODO: JD: localize the text.
                     error!("Error joining Gaming Session: {:?}", error);
                     let message = match error {
-                        Error::ResponseError(error) => {
-                            match error.status {
-                                reqwest::StatusCode::NOT_FOUND => "Gaming Session not found.",
-                                reqwest::StatusCode::INTERNAL_SERVER_ERROR => "Internal server error",
-                                _ => "An unexpected error was returned from the TicTacToe server.",
-                            }
-                        }
-                        _ => "An unexpected error was returned from the TicTacToe server.",
+                        Error::ResponseError(error) if error.status == reqwest::StatusCode::NOT_FOUND => localization.text("error-session-not-found"),
+                        error => ServiceErrorHelper::describe(&localization, "error-join-session", configuration, &error),
                     };
                     event_writer.send(SetStatusTextEvent::new_with_duration(
                         message,
@@ -268,21 +257,13 @@ impl LocalGamePlayPlugin {
 
         // *** Join the Game ***
 
-        let game_creation_response = match tic_tac_toe_api::join_current_game(&SDK_CONFIG, &gaming_session_info.session_id, &app_state.local_player.player_id) {
+        let game_creation_response = match tic_tac_toe_api::join_current_game(configuration, &gaming_session_info.session_id, &app_state.local_player.player_id) {
             Ok(response) => response,
             Err(error) => {
                 error!("Error joining Game: {:?}", error);
-                // TODO: JD: localize the text.
                 let message = match error {
-                    Error::ResponseError(error) => {
-                        match error.status {
-                            reqwest::StatusCode::NOT_FOUND => "Game not found.",
-                            reqwest::StatusCode::BAD_REQUEST => "Bad request - Game not started",
-                            reqwest::StatusCode::INTERNAL_SERVER_ERROR => "Internal server error",
-                            _ => "An unexpected error was returned from the TicTacToe server.",
-                        }
-                    }
-                    _ => "An unexpected error was returned from the TicTacToe server.",
+                    Error::ResponseError(error) if error.status == reqwest::StatusCode::BAD_REQUEST => localization.text("error-game-not-started"),
+                    error => ServiceErrorHelper::describe(&localization, "error-join-game", configuration, &error),
                 };
                 event_writer.send(SetStatusTextEvent::new_with_duration(
                     message,
@@ -305,122 +286,351 @@ impl LocalGamePlayPlugin {
         }
         app_state.current_game_state = game_creation_response.game_info.game_state.clone();
         app_state.has_game_ended = false;
+        app_state.local_player_initiated_game = app_state.local_player_initiated_gaming_session;
 
         // *** Begin listening for Game change events ***
 
-        // TODO: JD: when the Game ends, we need to shut down the auto-update Task.
-
-        // Point the background update thread to the new Game ID.
-        GameStateCache::setup_auto_update(
+        commands.insert_resource(GameEventsListenerResource::start(
+            configuration.clone(),
+            gaming_session_info.event_plane_config,
+            &app_state.gaming_session_id,
             &app_state.game_id,
-            &Duration::from_millis(STATE_UPDATE_INTERVAL_IN_MS / 2),
-        );
+            &app_state.local_player.player_id,
+            app_state.current_game_state.clone(),
+            settings.poll_interval(),
+        ));
     }
 
-    /// Pulls the latest Game state from the GameStateCache.
-    fn refresh_game_state(
+    /// Updates the Game state whenever the event plane reports a change.
+    fn handle_game_changes(
         mut app_state: ResMut<AppStateResource>,
+        localization: Res<LocalizationResource>,
+        settings: Res<AppSettingsResource>,
+        mut event_reader: EventReader<GameChangedEvent>,
         mut event_writer: EventWriter<SetStatusTextEvent>,
+        mut next_state: ResMut<NextState<AppMode>>,
     ) {
         //
 
-        // Exit early if the game is no longer in progress.
-        if app_state.game_id.is_empty() && app_state.has_game_ended {
-            return;
-        }
-
-        let game_started_before_call = app_state.has_game_started;
-
-        if app_state.has_game_started {
-
-            // Grab the latest Turn info
-            let turn_response = match GameStateCache::get_latest_game_turn(&app_state.game_id) {
-                Ok(remote_game_info) => remote_game_info,
-                Err(error) => {
-                    // TODO: JD: localize the text.
-                    let message = match error {
-                        Error::ResponseError(error) => {
-                            match error.status {
-                                reqwest::StatusCode::NOT_FOUND => "Game not found.",
-                                reqwest::StatusCode::BAD_REQUEST => "Bad request - Game not started",
-                                reqwest::StatusCode::INTERNAL_SERVER_ERROR => "Internal server error",
-                                _ => "An unexpected error was returned from the TicTacToe server.",
-                            }
-                        }
-                        _ => "An unexpected error was returned from the TicTacToe server.",
-                    };
-                    event_writer.send(SetStatusTextEvent::new_with_duration(
-                        message,
-                        Duration::from_secs(5),
-                    ));
-                    return;
+        for event in event_reader.read() {
+            match &event.event {
+                GameClientEvent::OpponentJoined(_) | GameClientEvent::TurnTaken(_) | GameClientEvent::GameEnded(_) => {
+                    if !app_state.has_game_started {
+                        Self::load_current_game(&localization, settings.sdk_config(), &mut app_state, &mut event_writer);
+                    }
+                    if app_state.has_game_started {
+                        Self::load_latest_turn(&localization, settings.sdk_config(), &mut app_state, &mut event_writer);
+                    }
                 }
-            };
-
-            app_state.current_game_state = turn_response.new_game_state.clone();
-            app_state.current_player = turn_response.current_player.clone().unwrap_or_default();
-
-            // If the Game has ended, let the user know the results.
-            match app_state.current_game_state.play_status {
-                PlayStatus::EndedInStalemate | PlayStatus::EndedInWin => {
-                    let winning_player_name =
-                        if app_state.current_game_state.play_status == PlayStatus::EndedInWin {
-                            Some(
-                                if app_state.local_player.player_id
-                                    == app_state.current_game_state.id_of_player_who_made_move.clone()
-                                {
-                                    app_state.local_player.display_name.clone()
-                                } else {
-                                    app_state.other_player.clone().unwrap_or_default().display_name
-                                },
-                            )
-                        } else {
-                            None
-                        };
-                    let game_results = app_state.generate_results_text(
-                        &turn_response,
-                        &app_state.local_player.display_name,
-                        &winning_player_name,
-                    );
-                    if !game_results.is_empty() {
+                GameClientEvent::GameDeleted => {
+                    let other_player_name = app_state.other_player.clone().unwrap_or_default().display_name;
+                    if app_state.is_awaiting_rematch() {
+                        // The other Player has turned down the local Player's rematch.
+                        app_state.has_game_ended = true;
                         event_writer.send(SetStatusTextEvent::new_with_duration(
-                            game_results,
+                            localization.format("rematch-declined-by", [("name", other_player_name.into())]),
+                            Duration::from_secs(10),
+                        ));
+                    } else if app_state.rematch_offer.take().is_some() {
+                        // The other Player has withdrawn their rematch.
+                        event_writer.send(SetStatusTextEvent::new_with_duration(
+                            localization.format("rematch-withdrawn-by", [("name", other_player_name.into())]),
+                            Duration::from_secs(10),
+                        ));
+                    } else if !app_state.has_game_ended {
+                        event_writer.send(SetStatusTextEvent::new_with_duration(
+                            localization.text("game-closed"),
                             Duration::from_secs(5),
                         ));
+                        next_state.set(AppMode::StartMenu);
+                        return;
                     }
                 }
-                _ => {}
+                GameClientEvent::SessionDeleted => {
+                    let message = match &app_state.other_player {
+                        Some(other_player) if app_state.is_two_player_game => {
+                            localization.format("game-player-left", [("name", other_player.display_name.as_str().into())])
+                        }
+                        _ => localization.text("game-closed"),
+                    };
+                    event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(10)));
+                    next_state.set(AppMode::StartMenu);
+                    return;
+                }
+                // Sent as GameCreatedEvents instead.
+                GameClientEvent::GameCreated(_) => {}
             }
-        } else {
-            //
+        }
+    }
 
-            if GameStateCache::get_latest_game_readiness(&app_state.game_id).unwrap_or_default() {
+    /// Retrieves the latest Turn, i.e. the Game state, the current Player and the outcome.
+    fn load_latest_turn(
+        localization: &LocalizationResource,
+        configuration: &Configuration,
+        app_state: &mut AppStateResource,
+        event_writer: &mut EventWriter<SetStatusTextEvent>,
+    ) {
+        //
+
+        // Grab the latest Turn info
+        let turn_response = match tic_tac_toe_api::get_latest_game_turn(configuration, &app_state.game_id) {
+            Ok(remote_game_info) => remote_game_info,
+            Err(error) => {
+                let message = match error {
+                    Error::ResponseError(error) if error.status == reqwest::StatusCode::BAD_REQUEST => localization.text("error-game-not-started"),
+                    error => ServiceErrorHelper::describe(localization, "error-retrieve-game", configuration, &error),
+                };
+                event_writer.send(SetStatusTextEvent::new_with_duration(
+                    message,
+                    Duration::from_secs(5),
+                ));
+                return;
+            }
+        };
+
+        app_state.current_game_state = turn_response.new_game_state.clone();
+        app_state.current_player = turn_response.current_player.clone().unwrap_or_default();
+        app_state.winning_locations = turn_response.winning_locations.clone().unwrap_or_default();
+
+        // If the Game has ended, let the user know the results.
+        match app_state.current_game_state.play_status {
+            PlayStatus::EndedInStalemate | PlayStatus::EndedInWin => {
                 //
 
-                if let Ok(game_creation_response) = tic_tac_toe_api::get_session_current_game(&SDK_CONFIG, &app_state.gaming_session_id) {
-                    if app_state.local_player_initiated_gaming_session {
-                        app_state.other_player = game_creation_response.other_player.unwrap_or_default();
-                    }
-                    app_state.current_player = game_creation_response.game_info.current_player.unwrap_or_default();
-                    app_state.current_game_state = game_creation_response.game_info.game_state.clone();
-                    app_state.has_game_started = true;
+                // Count each Game just once toward the series.
+                if !app_state.has_game_ended {
+                    let play_status = app_state.current_game_state.play_status;
+                    let local_player_won = app_state.local_player.player_id == app_state.current_game_state.id_of_player_who_made_move;
+                    app_state.series_score.record(play_status, local_player_won);
                 }
 
-                // The other Player has just joined. So, note their info and also inform the local Player.
-                if app_state.local_player_initiated_gaming_session
-                    && !game_started_before_call
-                    && app_state.has_game_started
-                {
-                    let message = format!(
-                        "{} has joined! Let the game begin!",
-                        app_state.other_player.clone().unwrap_or_default().display_name
-                    );
+                app_state.has_game_ended = true;
+                let winning_player_name =
+                    if app_state.current_game_state.play_status == PlayStatus::EndedInWin {
+                        Some(
+                            if app_state.local_player.player_id
+                                == app_state.current_game_state.id_of_player_who_made_move.clone()
+                            {
+                                app_state.local_player.display_name.clone()
+                            } else {
+                                app_state.other_player.clone().unwrap_or_default().display_name
+                            },
+                        )
+                    } else {
+                        None
+                    };
+                let game_results = app_state.generate_results_text(
+                    localization,
+                    &turn_response,
+                    &app_state.local_player.display_name,
+                    &winning_player_name,
+                );
+                if !game_results.is_empty() {
                     event_writer.send(SetStatusTextEvent::new_with_duration(
-                        message,
+                        game_results,
                         Duration::from_secs(5),
                     ));
                 }
             }
+            _ => {}
+        }
+    }
+
+    /// Retrieves the Gaming Session's current Game once all Players are ready.
+    fn load_current_game(
+        localization: &LocalizationResource,
+        configuration: &Configuration,
+        app_state: &mut AppStateResource,
+        event_writer: &mut EventWriter<SetStatusTextEvent>,
+    ) {
+        //
+
+        if let Ok(game_creation_response) = tic_tac_toe_api::get_session_current_game(configuration, &app_state.gaming_session_id) {
+            //
+
+            // Note both Players' info, as the Game Pieces are assigned anew for each Game.
+            let players = [Some(game_creation_response.initiating_player), game_creation_response.other_player.flatten()];
+            for player in players.into_iter().flatten() {
+                if player.player_id == app_state.local_player.player_id {
+                    app_state.local_player = player;
+                } else {
+                    app_state.other_player = Some(player);
+                }
+            }
+
+            app_state.current_player = game_creation_response.game_info.current_player.unwrap_or_default();
+            app_state.current_game_state = game_creation_response.game_info.game_state.clone();
+            app_state.has_game_started = true;
+        }
+
+        // The other Player has just joined. So, inform the local Player.
+        if app_state.local_player_initiated_game && app_state.has_game_started {
+            let other_player_name = app_state.other_player.clone().unwrap_or_default().display_name;
+            let message = match (app_state.series_score.games_played(), app_state.is_two_player_game) {
+                (0, _) => localization.format("game-player-joined", [("name", other_player_name.into())]),
+                (_, true) => localization.format("game-rematch-accepted-by", [("name", other_player_name.into())]),
+                (_, false) => localization.text("game-begin"),
+            };
+            event_writer.send(SetStatusTextEvent::new_with_duration(
+                message,
+                Duration::from_secs(5),
+            ));
+        }
+    }
+}
+
+// Rematches
+impl LocalGamePlayPlugin {
+    //
+
+    /// Handles the Game Play Screen's buttons.
+    fn handle_button_pressed(
+        mut app_state: ResMut<AppStateResource>,
+        localization: Res<LocalizationResource>,
+        settings: Res<AppSettingsResource>,
+        mut event_reader: EventReader<ButtonPressedEvent>,
+        mut event_writer: EventWriter<SetStatusTextEvent>,
+        mut next_state: ResMut<NextState<AppMode>>,
+    ) {
+        //
+
+        let configuration = settings.sdk_config();
+
+        for event in event_reader.read() {
+            let result = match event.purpose {
+                ButtonPurpose::AcceptRematch => Self::accept_rematch(&localization, configuration, &mut app_state),
+                ButtonPurpose::DeclineRematch => Self::decline_rematch(&localization, configuration, &mut app_state),
+                ButtonPurpose::EndGame => {
+                    Self::leave_gaming_session(configuration, &app_state);
+                    next_state.set(AppMode::StartMenu);
+                    return;
+                }
+                ButtonPurpose::PlayAgain => Self::start_rematch(&localization, configuration, &mut app_state, false),
+                ButtonPurpose::SwitchSides => Self::start_rematch(&localization, configuration, &mut app_state, true),
+            };
+            let message = match result {
+                Ok(message) => message,
+                Err(message) => {
+                    error!("{}", message);
+                    message
+                }
+            };
+            event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(10)));
+        }
+    }
+
+    /// Notes a rematch started by the other Player so that the local Player can accept or
+    /// decline it. Rematches started by the local Player are already known.
+    fn handle_game_created(
+        mut app_state: ResMut<AppStateResource>,
+        localization: Res<LocalizationResource>,
+        mut event_reader: EventReader<GameCreatedEvent>,
+        mut event_writer: EventWriter<SetStatusTextEvent>,
+    ) {
+        //
+
+        for event in event_reader.read() {
+            //
+
+            if event.game.game_info.game_id == app_state.game_id {
+                continue;
+            }
+
+            let message = localization.format("rematch-offered", [
+                ("name", app_state.other_player.clone().unwrap_or_default().display_name.into()),
+            ]);
+            event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(30)));
+
+            app_state.rematch_offer = Some(event.game.clone());
+        }
+    }
+
+    /// Starts a new Game against the same opponent, within the same Gaming Session. The Players
+    /// keep their Game Pieces unless switch_sides is set. In a Two-Player Game, the other Player
+    /// must then accept the rematch.
+    fn start_rematch(
+        localization: &LocalizationResource,
+        configuration: &Configuration,
+        app_state: &mut AppStateResource,
+        switch_sides: bool,
+    ) -> Result<String, String> {
+        //
+
+        let game_creation_response = match app_state.is_two_player_game {
+            true => {
+                // By convention, X starts first.
+                let local_player_starts = (app_state.local_player.game_piece == GamePiece::X) != switch_sides;
+                let starting_player_id = match local_player_starts {
+                    true => app_state.local_player.player_id.clone(),
+                    false => app_state.other_player.clone().unwrap_or_default().player_id,
+                };
+                let params = NewTwoPlayerGameParams { starting_player_id: Some(Some(starting_player_id)) };
+                tic_tac_toe_api::create_two_player_game_with_params(configuration, &app_state.gaming_session_id, Some(params))
+                    .map_err(|error| ServiceErrorHelper::describe(localization, "error-start-rematch", configuration, &error))?
+            }
+            false => Self::create_single_player_game(localization, configuration, app_state)?,
+        };
+
+        app_state.begin_next_game(&game_creation_response, true);
+
+        tic_tac_toe_api::join_current_game(configuration, &app_state.gaming_session_id, &app_state.local_player.player_id)
+            .map_err(|error| ServiceErrorHelper::describe(localization, "error-join-rematch", configuration, &error))?;
+
+        Ok(match app_state.is_two_player_game {
+            true => localization.format("game-waiting-for-rematch", [
+                ("name", app_state.other_player.clone().unwrap_or_default().display_name.into()),
+            ]),
+            false => localization.text("rematch-starting"),
+        })
+    }
+
+    /// Joins the rematch offered by the other Player.
+    fn accept_rematch(localization: &LocalizationResource, configuration: &Configuration, app_state: &mut AppStateResource) -> Result<String, String> {
+        //
+
+        let Some(game_creation_response) = app_state.rematch_offer.clone() else {
+            return Err(localization.text("rematch-unavailable"));
+        };
+
+        app_state.begin_next_game(&game_creation_response, false);
+
+        // Once the local Player has joined, the Game is ready and the usual Game change events follow.
+        tic_tac_toe_api::join_current_game(configuration, &app_state.gaming_session_id, &app_state.local_player.player_id)
+            .map_err(|error| ServiceErrorHelper::describe(localization, "error-join-rematch", configuration, &error))?;
+
+        Ok(localization.text("rematch-accepted"))
+    }
+
+    /// Turns down the rematch offered by the other Player, closing the offered Game.
+    fn decline_rematch(localization: &LocalizationResource, configuration: &Configuration, app_state: &mut AppStateResource) -> Result<String, String> {
+        //
+
+        let Some(game_creation_response) = app_state.rematch_offer.take() else {
+            return Err(localization.text("rematch-unavailable"));
+        };
+
+        let params = EndGameParams {
+            player_id: app_state.local_player.player_id.clone(),
+            session_id: app_state.gaming_session_id.clone(),
+        };
+        tic_tac_toe_api::end_game(configuration, &game_creation_response.game_info.game_id, params)
+            .map_err(|error| ServiceErrorHelper::describe(localization, "error-decline-rematch", configuration, &error))?;
+
+        Ok(localization.text("rematch-declined"))
+    }
+
+    /// Ends the Gaming Session so that the other Player learns that the local Player has left.
+    fn leave_gaming_session(configuration: &Configuration, app_state: &AppStateResource) {
+        //
+
+        if app_state.gaming_session_id.is_empty() {
+            return;
+        }
+
+        let params = EndGamingSessionParams { player_id: app_state.local_player.player_id.clone() };
+        if let Err(error) = tic_tac_toe_api::end_gaming_session(configuration, &app_state.gaming_session_id, params) {
+            // The Gaming Session may already be gone, e.g. the other Player left first.
+            error!("Error encountered calling end_gaming_session(): {:?}", error);
         }
     }
 }
@@ -3,14 +3,17 @@
 //  © 2024 Rust Made Easy. All rights reserved.
 //  @author JoelDavisEngineering@Gmail.com
 
+use crate::game_play_screen::game_events_plugin::GameEventsPlugin;
 use crate::game_play_screen::info_panel_plugin::InfoPanelPlugin;
 use crate::game_play_screen::local_game_play_plugin::LocalGamePlayPlugin;
 use crate::game_play_screen::tiles_plugin::TilesPlugin;
 use bevy::app::PluginGroupBuilder;
 use bevy::math::Vec2;
 use bevy::prelude::{Component, Event, PluginGroup};
-use tic_tac_toe_rust_client_sdk::models::BoardPosition;
+use tic_tac_toe_rust_client_sdk::game_client_event::GameClientEvent;
+use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GameCreationResponse};
 
+pub(super) mod game_events_plugin;
 pub(super) mod info_panel_plugin;
 pub(super) mod local_game_play_plugin;
 mod tile_component;
@@ -26,12 +29,23 @@ impl PluginGroup for GamePlayPluginGroup {
     /// Composes the plugin group.
     fn build(self) -> PluginGroupBuilder {
         PluginGroupBuilder::start::<Self>()
+            .add(GameEventsPlugin)
             .add(LocalGamePlayPlugin)
             .add(InfoPanelPlugin)
             .add(TilesPlugin)
     }
 }
 
+/// Defines the purposes of the Game Play Screen buttons.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum ButtonPurpose {
+    AcceptRematch,
+    DeclineRematch,
+    EndGame,
+    PlayAgain,
+    SwitchSides,
+}
+
 /// Marker to indicate that an entity was spawned on the Game Play Screen.
 #[derive(Component)]
 struct OnGamePlayScreen;
@@ -71,3 +85,24 @@ pub(super) struct TilePressedEvent {
     /// Indicates the tile's location on the game board.
     pub(super) grid_position: BoardPosition,
 }
+
+/// Event that is fired when the Game's listener reports a change to the current Game.
+#[derive(Event)]
+pub(super) struct GameChangedEvent {
+    /// The event describing the change.
+    pub(super) event: GameClientEvent,
+}
+
+/// Event that is fired when one of the Game Play Screen's buttons is pressed.
+#[derive(Event)]
+pub(super) struct ButtonPressedEvent {
+    /// Indicates which button was pressed.
+    pub(super) purpose: ButtonPurpose,
+}
+
+/// Event that is fired when a new Game, e.g. a rematch, has been created in the Gaming Session.
+#[derive(Event)]
+pub(super) struct GameCreatedEvent {
+    /// The new Game.
+    pub(super) game: GameCreationResponse,
+}
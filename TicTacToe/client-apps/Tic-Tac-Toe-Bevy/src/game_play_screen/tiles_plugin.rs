@@ -20,7 +20,7 @@ use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
 use bevy::text::{Text, Text2dBundle};
 use bevy::utils::default;
 use bevy::window::PrimaryWindow;
-use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, PlayStatus};
+use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece};
 
 const TILE_FONT_SIZE: f32 = 44_f32;
 const TILE_SIDE: f32 = 100_f32;
@@ -115,12 +115,12 @@ impl TilesPlugin {
             return;
         }
 
-        if let Some(winning_locations) = &app_state.winning_locations {
-            for (mut visibility, tile_info) in tile_highlights.iter_mut() {
-                if winning_locations.contains(&tile_info.grid_position) {
-                    *visibility = Visibility::Visible;
-                }
-            }
+        // Hide the highlights again when a new Game, e.g. a rematch, begins.
+        for (mut visibility, tile_info) in tile_highlights.iter_mut() {
+            *visibility = match &app_state.winning_locations {
+                Some(winning_locations) if winning_locations.contains(&tile_info.grid_position) => Visibility::Visible,
+                _ => Visibility::Hidden,
+            };
         }
     }
 
@@ -131,7 +131,7 @@ impl TilesPlugin {
     ) {
         //
 
-        if !app_state.is_changed() || app_state.game_id.is_empty() || app_state.current_game_state.play_status == PlayStatus::NotStarted {
+        if !app_state.is_changed() || app_state.game_id.is_empty() || app_state.current_game_state.game_board.is_empty() {
             return;
         }
 
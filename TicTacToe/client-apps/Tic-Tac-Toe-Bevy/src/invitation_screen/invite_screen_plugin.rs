@@ -1,7 +1,7 @@
-use bevy::prelude::{in_state, App, Component, IntoSystemConfigs, OnEnter, OnExit, Plugin, Update};
+use bevy::prelude::{in_state, App, IntoSystemConfigs, OnEnter, OnExit, Plugin, Update};
 
 use crate::invitation_screen::invite_screen_plugin::functionality::{
-    button_interaction, text_input,
+    button_interaction, join_on_submit, update_invitation_code,
 };
 use crate::invitation_screen::invite_screen_plugin::ui::spawn_ui;
 use crate::invitation_screen::OnInvitationScreen;
@@ -25,7 +25,7 @@ impl Plugin for InvitationScreenPlugin {
             .add_systems(OnEnter(AppMode::EnterInvitation), spawn_ui)
             .add_systems(
                 Update,
-                (button_interaction, text_input).run_if(in_state(AppMode::EnterInvitation)),
+                (button_interaction, update_invitation_code, join_on_submit).run_if(in_state(AppMode::EnterInvitation)),
             )
             .add_systems(
                 OnExit(AppMode::EnterInvitation),
@@ -34,24 +34,20 @@ impl Plugin for InvitationScreenPlugin {
     }
 }
 
-/// Marker for invitation code text edit control.
-#[derive(Component)]
-struct InvitationCodeLabelComponent;
-
 mod functionality {
     use std::time::Duration;
 
     use bevy::prelude::{
-        BackgroundColor, Button, ButtonInput, Changed, EventReader, EventWriter, Interaction,
-        KeyCode, NextState, Query, ReceivedCharacter, Res, ResMut, Text, With,
+        BackgroundColor, Button, Changed, EventReader, EventWriter, Interaction, NextState, Query,
+        Res, ResMut, With,
     };
     use helpers_for_bevy::entity_info_component::EntityInfoComponent;
     use helpers_for_bevy::status_text::events::SetStatusTextEvent;
+    use helpers_for_bevy::text_input::events::{TextInputChangedEvent, TextInputSubmittedEvent};
 
-    use crate::invitation_screen::invite_screen_plugin::{
-        InvitationCodeLabelComponent, INVITATION_CODE_LENGTH,
-    };
+    use crate::invitation_screen::invite_screen_plugin::INVITATION_CODE_LENGTH;
     use crate::invitation_screen::ButtonPurpose;
+    use crate::localization::localization_resource::LocalizationResource;
     use crate::shared::app_mode::AppMode;
     use crate::shared::app_state_resource::AppStateResource;
     use crate::shared::{BUTTON_COLOR_HOVERED, BUTTON_COLOR_NORMAL, BUTTON_COLOR_PRESSED};
@@ -69,6 +65,7 @@ mod functionality {
             ),
             (Changed<Interaction>, With<Button>),
         >,
+        localization: Res<LocalizationResource>,
         mut next_state: ResMut<NextState<AppMode>>,
     ) {
         for (interaction, mut color, button_info) in &mut interactions {
@@ -87,17 +84,7 @@ mod functionality {
                     match button_info.get_purpose() {
                         ButtonPurpose::BackToStartScreen => next_state.set(AppMode::StartMenu),
                         ButtonPurpose::BeginGame => {
-                            if app_state.invitation_code.trim().len()
-                                == INVITATION_CODE_LENGTH
-                            {
-                                next_state.set(AppMode::GamePlay);
-                            } else {
-                                let event = SetStatusTextEvent::new_with_duration(
-                                    "Please ask the other player for the 6-digit Invitation Code.",
-                                    Duration::from_secs(10),
-                                );
-                                event_writer.send(event);
-                            }
+                            join_game(&app_state, &mut event_writer, &localization, &mut next_state);
                         }
                     }
                 }
@@ -105,47 +92,47 @@ mod functionality {
         }
     }
 
-    pub(super) fn text_input(
-        keyboard_input: Res<ButtonInput<KeyCode>>,
-        mut evr_char: EventReader<ReceivedCharacter>,
-        mut app_state: ResMut<AppStateResource>,
-        mut invitation_code_label_text: Query<&mut Text, With<InvitationCodeLabelComponent>>,
+    /// Joins the Game when the user presses Enter in the Invitation Code text field.
+    pub(super) fn join_on_submit(
+        app_state: Res<AppStateResource>,
+        mut event_reader: EventReader<TextInputSubmittedEvent>,
+        mut event_writer: EventWriter<SetStatusTextEvent>,
+        localization: Res<LocalizationResource>,
+        mut next_state: ResMut<NextState<AppMode>>,
     ) {
-        let mut skip_character_capture = false;
-
-        // Use the back-space to delete from the end.
-        if keyboard_input.just_pressed(KeyCode::Backspace)
-            || keyboard_input.just_pressed(KeyCode::Delete)
-        {
-            let len = app_state.invitation_code.len();
-            if len > 0 {
-                app_state.invitation_code.pop();
-                skip_character_capture = true;
-            }
+        for _ in event_reader.read() {
+            join_game(&app_state, &mut event_writer, &localization, &mut next_state);
         }
+    }
 
-        if !skip_character_capture {
-            // Capture only numeric characters.
-            for received_char in evr_char.read() {
-                let received_char = received_char
-                    .char
-                    .to_string()
-                    .chars()
-                    .next()
-                    .unwrap_or_default();
-                if app_state.invitation_code.len() < INVITATION_CODE_LENGTH
-                    && received_char.is_numeric()
-                {
-                    app_state.invitation_code.push(received_char);
-                } else {
-                    return;
-                }
-            }
+    /// Keeps the Invitation Code in step with its text field.
+    pub(super) fn update_invitation_code(
+        mut app_state: ResMut<AppStateResource>,
+        mut event_reader: EventReader<TextInputChangedEvent>,
+    ) {
+        for event in event_reader.read() {
+            app_state.invitation_code = event.value.clone();
         }
+    }
 
-        // Update the UI
-        if let Ok(mut label) = invitation_code_label_text.get_single_mut() {
-            label.sections[0].value = app_state.invitation_code.clone();
+    /// Begins Game play if a complete Invitation Code has been entered. Otherwise, asks the user for
+    /// the code.
+    fn join_game(
+        app_state: &AppStateResource,
+        event_writer: &mut EventWriter<SetStatusTextEvent>,
+        localization: &LocalizationResource,
+        next_state: &mut NextState<AppMode>,
+    ) {
+        //
+
+        if app_state.invitation_code.trim().len() == INVITATION_CODE_LENGTH {
+            next_state.set(AppMode::GamePlay);
+        } else {
+            let event = SetStatusTextEvent::new_with_duration(
+                localization.text("invitation-code-needed"),
+                Duration::from_secs(10),
+            );
+            event_writer.send(event);
         }
     }
 }
@@ -153,19 +140,23 @@ mod functionality {
 mod ui {
     use bevy::prelude::{
         default, AlignItems, BackgroundColor, BorderColor, BuildChildren, ButtonBundle, Color,
-        Commands, FlexDirection, JustifyContent, JustifySelf, NodeBundle, ResMut, Style,
+        Commands, FlexDirection, JustifyContent, JustifySelf, NodeBundle, Res, ResMut, Style,
         TextBundle, TextSection, TextStyle, UiRect, Val,
     };
     use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+    use helpers_for_bevy::text_input::text_input_component::{TextInputBundle, TextInputComponent, TextInputFilter};
 
-    use crate::invitation_screen::invite_screen_plugin::InvitationCodeLabelComponent;
+    use crate::invitation_screen::invite_screen_plugin::INVITATION_CODE_LENGTH;
     use crate::invitation_screen::{ButtonPurpose, OnInvitationScreen};
+    use crate::localization::localization_resource::LocalizationResource;
+    use crate::localization::LocalizedTextComponent;
     use crate::shared::app_state_resource::AppStateResource;
     use crate::shared::{BUTTON_COLOR_NORMAL, FONT_SIZE, TEXT_COLOR};
 
     pub(super) fn spawn_ui(
         mut commands: Commands,
         mut app_state: ResMut<AppStateResource>,
+        localization: Res<LocalizationResource>,
     ) {
         //
 
@@ -173,10 +164,9 @@ mod ui {
         app_state.local_player_initiated_gaming_session = false;
         app_state.invitation_code = "".to_string();
 
-        // TODO: JD: localize the text
-        let title_back = "Go Back";
-        let title_start = "Join Game";
-        let title_instructions = "Please enter the Invitation Code: ";
+        let title_back = "go-back";
+        let title_start = "invitation-join-game";
+        let title_instructions = "invitation-instructions";
 
         let text_style = TextStyle {
             color: *TEXT_COLOR,
@@ -185,7 +175,7 @@ mod ui {
         };
 
         // Instructions Label
-        let sections = [TextSection::new(title_instructions, text_style.clone())];
+        let sections = [TextSection::new(localization.text(title_instructions), text_style.clone())];
         let label_style = Style {
             display: Default::default(),
             justify_self: JustifySelf::Center,
@@ -195,15 +185,18 @@ mod ui {
             TextBundle::from_sections(sections.clone()).with_style(label_style);
 
         // Invitation Code
-        let sections = [TextSection::new("", text_style.clone())];
+        let invitation_code = TextInputComponent::new(text_style.clone())
+            .with_filter(TextInputFilter::Digits)
+            .with_focus()
+            .with_max_length(INVITATION_CODE_LENGTH)
+            .with_placeholder(localization.text("invitation-code-placeholder"));
         let label_style = Style {
             border: UiRect::all(Val::Px(2.)),
             display: Default::default(),
             justify_self: JustifySelf::Center,
             ..default()
         };
-        let invitation_code_text_bundle =
-            TextBundle::from_sections(sections.clone()).with_style(label_style);
+        let invitation_code_bundle = TextInputBundle::new(invitation_code).with_style(label_style);
 
         // Button template
         let button_bundle = ButtonBundle {
@@ -247,13 +240,9 @@ mod ui {
             .with_children(|parent| {
                 //
 
-                parent.spawn((instructions_label_bundle, OnInvitationScreen));
+                parent.spawn((instructions_label_bundle, LocalizedTextComponent(title_instructions), OnInvitationScreen));
 
-                parent.spawn((
-                    invitation_code_text_bundle,
-                    OnInvitationScreen,
-                    InvitationCodeLabelComponent,
-                ));
+                parent.spawn((invitation_code_bundle, OnInvitationScreen));
 
                 parent
                     .spawn((
@@ -285,9 +274,10 @@ mod ui {
                             .with_children(|parent| {
                                 parent.spawn((
                                     TextBundle::from_section(
-                                        title_start,
+                                        localization.text(title_start),
                                         button_text_style.clone(),
                                     ),
+                                    LocalizedTextComponent(title_start),
                                     OnInvitationScreen,
                                 ));
                             });
@@ -300,7 +290,8 @@ mod ui {
                             ))
                             .with_children(|parent| {
                                 parent.spawn((
-                                    TextBundle::from_section(title_back, button_text_style.clone()),
+                                    TextBundle::from_section(localization.text(title_back), button_text_style.clone()),
+                                    LocalizedTextComponent(title_back),
                                     OnInvitationScreen,
                                 ));
                             });
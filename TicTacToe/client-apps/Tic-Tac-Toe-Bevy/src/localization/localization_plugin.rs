@@ -0,0 +1,133 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::sync::Arc;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetApp, AssetLoader, AsyncReadExt, Handle, LoadContext};
+use bevy::log::warn;
+use bevy::prelude::{App, IntoSystemConfigs, Plugin, Resource, Startup, Update};
+use bevy::reflect::TypePath;
+use bevy::utils::BoxedFuture;
+use fluent_bundle::FluentResource;
+
+/// Loads each language's messages file from the assets folder and keeps the texts marked with
+/// the LocalizedTextComponent in the current language.
+pub(crate) struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    //
+
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .init_asset::<FluentResourceAsset>()
+            .init_asset_loader::<FluentResourceLoader>()
+            .add_systems(Startup, functionality::load_messages)
+            .add_systems(
+                Update,
+                (functionality::add_loaded_messages, functionality::update_localized_text).chain(),
+            );
+    }
+}
+
+/// A Fluent messages (.ftl) file.
+#[derive(Asset, TypePath)]
+pub(crate) struct FluentResourceAsset(Arc<FluentResource>);
+
+/// Parses Fluent messages files. Messages with syntax errors are skipped, so that the rest of the
+/// file can still be used.
+#[derive(Default)]
+struct FluentResourceLoader;
+
+impl AssetLoader for FluentResourceLoader {
+    //
+
+    type Asset = FluentResourceAsset;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            //
+
+            let mut source = String::new();
+            reader.read_to_string(&mut source).await?;
+
+            let resource = FluentResource::try_new(source).unwrap_or_else(|(resource, errors)| {
+                warn!("Errors in {}: {:?}", load_context.path().display(), errors);
+                resource
+            });
+
+            Ok(FluentResourceAsset(Arc::new(resource)))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+/// Keeps the messages files loaded. Each handle is paired with its language.
+#[derive(Resource)]
+struct MessagesFilesResource(Vec<(&'static str, Handle<FluentResourceAsset>)>);
+
+mod functionality {
+    use bevy::asset::{AssetEvent, AssetServer, Assets};
+    use bevy::prelude::{Commands, DetectChanges, EventReader, Query, Res, ResMut, Text};
+
+    use crate::localization::localization_plugin::{FluentResourceAsset, MessagesFilesResource};
+    use crate::localization::localization_resource::LocalizationResource;
+    use crate::localization::{messages_file_path, LocalizedTextComponent, SUPPORTED_LANGUAGES};
+
+    /// Begins loading the messages of every supported language.
+    pub(super) fn load_messages(asset_server: Res<AssetServer>, mut commands: Commands) {
+        let handles = SUPPORTED_LANGUAGES
+            .iter()
+            .map(|(language, _)| (*language, asset_server.load(messages_file_path(language))))
+            .collect();
+        commands.insert_resource(MessagesFilesResource(handles));
+    }
+
+    /// Hands the messages to the LocalizationResource as each file finishes loading, or changes.
+    pub(super) fn add_loaded_messages(
+        assets: Res<Assets<FluentResourceAsset>>,
+        mut event_reader: EventReader<AssetEvent<FluentResourceAsset>>,
+        messages_files: Res<MessagesFilesResource>,
+        mut localization: ResMut<LocalizationResource>,
+    ) {
+        for event in event_reader.read() {
+            for (language, handle) in &messages_files.0 {
+                if event.is_loaded_with_dependencies(handle) || event.is_modified(handle) {
+                    if let Some(asset) = assets.get(handle) {
+                        localization.add_messages(language, asset.0.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Shows the marked texts in the current language whenever the language, or its messages,
+    /// change.
+    pub(super) fn update_localized_text(
+        localization: Res<LocalizationResource>,
+        mut texts: Query<(&mut Text, &LocalizedTextComponent)>,
+    ) {
+        //
+
+        if !localization.is_changed() {
+            return;
+        }
+
+        for (mut text, localized_text) in &mut texts {
+            text.sections[0].value = localization.text(localized_text.0);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bevy::log::warn;
+use bevy::prelude::Resource;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+
+use crate::localization::{negotiate_language, FALLBACK_LANGUAGE};
+
+/// The English messages. These are built into the app so that there is always text to show, even
+/// before, or without, the message files in the assets folder.
+const FALLBACK_MESSAGES: &str = include_str!("../../assets/locales/en-US/tic-tac-toe.ftl");
+
+/// Provides the app's text in the user's language. Messages that have yet to be translated are
+/// shown in English.
+#[derive(Resource)]
+pub(crate) struct LocalizationResource {
+    //
+
+    /// The messages of each language that has been loaded, keyed by language.
+    bundles: HashMap<&'static str, FluentBundle<Arc<FluentResource>>>,
+
+    /// The language in which text is shown.
+    language: &'static str,
+}
+
+impl LocalizationResource {
+    //
+
+    /// Creates a new instance showing text in the specified language.
+    pub(crate) fn new(language: &str) -> Self {
+        //
+
+        let fallback_messages = FluentResource::try_new(FALLBACK_MESSAGES.to_string())
+            .unwrap_or_else(|(resource, errors)| {
+                warn!("Errors in the built-in English messages: {:?}", errors);
+                resource
+            });
+
+        let mut instance = Self {
+            bundles: HashMap::new(),
+            language: negotiate_language(language),
+        };
+        instance.add_messages(FALLBACK_LANGUAGE, Arc::new(fallback_messages));
+        instance
+    }
+
+    /// Adds, or replaces, the messages of the specified language.
+    pub(crate) fn add_messages(&mut self, language: &'static str, messages: Arc<FluentResource>) {
+        //
+
+        let Ok(language_id) = language.parse() else {
+            warn!("Ignoring the messages of unknown language {language}");
+            return;
+        };
+
+        let mut bundle = FluentBundle::new_concurrent(vec![language_id]);
+
+        // Bevy's fonts cannot render the Unicode isolation marks placed around arguments.
+        bundle.set_use_isolating(false);
+
+        if let Err(errors) = bundle.add_resource(messages) {
+            warn!("Errors adding the {language} messages: {:?}", errors);
+        }
+
+        self.bundles.insert(language, bundle);
+    }
+
+    /// Changes the language in which text is shown.
+    pub(crate) fn set_language(&mut self, language: &str) {
+        self.language = negotiate_language(language);
+    }
+
+    /// Returns the text of the specified message.
+    pub(crate) fn text(&self, message_id: &str) -> String {
+        self.format(message_id, std::iter::empty())
+    }
+
+    /// Returns the text of the specified message, filling in the specified arguments, e.g.
+    /// `format("game-won-by", [("name", name.into())])`.
+    pub(crate) fn format<'a>(
+        &self,
+        message_id: &str,
+        args: impl IntoIterator<Item = (&'a str, FluentValue<'a>)>,
+    ) -> String {
+        //
+
+        let args = FluentArgs::from_iter(args);
+
+        // Try the user's language first, then English.
+        let found = [self.language, FALLBACK_LANGUAGE]
+            .iter()
+            .filter_map(|language| self.bundles.get(language))
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(message_id)?.value()?;
+                Some((bundle, pattern))
+            });
+
+        // Early return.
+        let Some((bundle, pattern)) = found else {
+            warn!("Message {message_id} was not found");
+            return message_id.to_string();
+        };
+
+        let mut errors = vec![];
+        let text = bundle.format_pattern(pattern, Some(&args), &mut errors);
+        if !errors.is_empty() {
+            warn!("Errors formatting message {message_id}: {:?}", errors);
+        }
+
+        text.into_owned()
+    }
+}
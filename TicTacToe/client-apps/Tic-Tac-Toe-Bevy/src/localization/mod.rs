@@ -0,0 +1,68 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Component;
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use unic_langid::LanguageIdentifier;
+
+pub(crate) mod localization_plugin;
+pub(crate) mod localization_resource;
+pub(crate) mod string_catalog;
+
+/// The languages into which the app has been translated, each with its name in that language.
+pub(crate) const SUPPORTED_LANGUAGES: [(&str, &str); 3] = [
+    ("en-US", "English"),
+    ("es-ES", "Español"),
+    ("fr-FR", "Français"),
+];
+
+/// The language used for any text that has yet to be translated.
+pub(crate) const FALLBACK_LANGUAGE: &str = "en-US";
+
+/// Name of the Fluent file, within each language's folder, that holds the app's text.
+const MESSAGES_FILE_NAME: &str = "tic-tac-toe.ftl";
+
+/// Marker for text that is shown anew whenever the language changes. Holds the ID of the
+/// message to show.
+#[derive(Component)]
+pub(crate) struct LocalizedTextComponent(pub(crate) &'static str);
+
+/// Returns the path, relative to the assets folder, of the specified language's messages file.
+fn messages_file_path(language: &str) -> String {
+    format!("locales/{language}/{MESSAGES_FILE_NAME}")
+}
+
+/// Picks the supported language that best matches the requested one, e.g. "es", "es-MX" or the
+/// POSIX style "es_MX.UTF-8" yields "es-ES". Falls back to English.
+pub(crate) fn negotiate_language(requested: &str) -> &'static str {
+    //
+
+    // Drop any POSIX encoding or modifier.
+    let requested = requested.split(['.', '@']).next().unwrap_or_default().replace('_', "-");
+    let requested: Vec<LanguageIdentifier> = requested.parse().into_iter().collect();
+    let available: Vec<LanguageIdentifier> = SUPPORTED_LANGUAGES
+        .iter()
+        .filter_map(|(language, _)| language.parse().ok())
+        .collect();
+    let default: LanguageIdentifier = FALLBACK_LANGUAGE.parse().unwrap_or_default();
+
+    let negotiated = negotiate_languages(&requested, &available, Some(&default), NegotiationStrategy::Lookup);
+    let negotiated = negotiated.first().map(|language| language.to_string()).unwrap_or_default();
+
+    SUPPORTED_LANGUAGES
+        .iter()
+        .map(|(language, _)| *language)
+        .find(|language| *language == negotiated)
+        .unwrap_or(FALLBACK_LANGUAGE)
+}
+
+/// Returns the name of the specified language, in that language.
+pub(crate) fn language_name(language: &str) -> &'static str {
+    SUPPORTED_LANGUAGES
+        .iter()
+        .find(|(id, _)| *id == language)
+        .map(|(_, name)| *name)
+        .unwrap_or_default()
+}
@@ -0,0 +1,295 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::asset::io::file::FileAssetReader;
+use fluent_syntax::ast::{Entry, Expression, InlineExpression, Pattern, PatternElement, Variant, VariantKey};
+use fluent_syntax::parser;
+use serde_json::{json, Map, Value};
+
+use crate::localization::{messages_file_path, FALLBACK_LANGUAGE, SUPPORTED_LANGUAGES};
+
+/// The plural categories that a String Catalog accepts.
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Exports the app's messages as an Xcode String Catalog (Localizable.xcstrings) so that the
+/// Swift app can share the translations.
+///
+/// Swift looks up its text by the English text, with each interpolated value replaced by a format
+/// specifier, e.g. `String(localized: "\(name) won. Better luck next time.")` is found under
+/// "%@ won. Better luck next time.". The catalog is therefore keyed by the English text of each
+/// message, so any Swift text that matches it is translated. Variables used to choose a plural
+/// form become %lld and all other variables become %@.
+///
+/// Returns the number of exported strings.
+pub(crate) fn export(path: &Path) -> Result<usize, String> {
+    //
+
+    let sources = SUPPORTED_LANGUAGES
+        .iter()
+        .map(|(language, _)| read_messages(language).map(|source| (*language, source)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let sources = sources.iter().map(|(language, source)| (*language, source.as_str())).collect::<Vec<_>>();
+
+    let catalog = build_catalog(&sources)?;
+    let count = catalog["strings"].as_object().map(|strings| strings.len()).unwrap_or_default();
+    let contents = serde_json::to_string_pretty(&catalog).map_err(|error| error.to_string())?;
+    fs::write(path, contents).map_err(|error| format!("Unable to write {}: {}", path.display(), error))?;
+
+    Ok(count)
+}
+
+/// Builds the String Catalog from the messages files of the specified languages, which must
+/// include English.
+pub(crate) fn build_catalog(sources: &[(&str, &str)]) -> Result<Value, String> {
+    //
+
+    let english_source = sources
+        .iter()
+        .find(|(language, _)| *language == FALLBACK_LANGUAGE)
+        .map(|(_, source)| *source)
+        .ok_or(format!("The {FALLBACK_LANGUAGE} messages are missing"))?;
+    let english = parse(english_source)?;
+
+    let translations = sources
+        .iter()
+        .map(|(language, source)| parse(source).map(|messages| (*language, messages)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut strings = Map::new();
+
+    for (message_id, english_pattern) in &english {
+        //
+
+        let arguments = collect_arguments(english_pattern);
+        let key = match render(english_pattern, &arguments, None, false) {
+            Ok(key) => key,
+            Err(error) => {
+                eprintln!("Skipping {message_id}: {error}");
+                continue;
+            }
+        };
+
+        let mut localizations = Map::new();
+        for (language, messages) in &translations {
+            //
+
+            // Xcode falls back to the English text for any missing translation.
+            let Some((_, pattern)) = messages.iter().find(|(id, _)| id == message_id) else {
+                continue;
+            };
+
+            // The English text is the key. So, it only needs listing for its plural forms.
+            if *language == FALLBACK_LANGUAGE && !has_plural_forms(pattern) {
+                continue;
+            }
+
+            match localize(pattern, &arguments) {
+                Ok(localization) => {
+                    localizations.insert(apple_language(language), localization);
+                }
+                Err(error) => eprintln!("Skipping the {language} text of {message_id}: {error}"),
+            }
+        }
+
+        strings.insert(key, json!({
+            "comment": message_id,
+            "extractionState": "manual",
+            "localizations": localizations,
+        }));
+    }
+
+    Ok(json!({
+        "sourceLanguage": apple_language(FALLBACK_LANGUAGE),
+        "strings": strings,
+        "version": "1.0",
+    }))
+}
+
+/// A message variable along with whether it is used to choose a plural form.
+struct Argument<'s> {
+    is_number: bool,
+    name: &'s str,
+}
+
+/// Returns the location of the specified language's messages file.
+pub(crate) fn messages_file(language: &str) -> PathBuf {
+    FileAssetReader::get_base_path().join("assets").join(messages_file_path(language))
+}
+
+fn read_messages(language: &str) -> Result<String, String> {
+    let path = messages_file(language);
+    fs::read_to_string(&path).map_err(|error| format!("Unable to read {}: {}", path.display(), error))
+}
+
+/// Parses a messages file into its messages, in the order in which they appear.
+fn parse(source: &str) -> Result<Vec<(&str, Pattern<&str>)>, String> {
+    //
+
+    let resource = parser::parse(source).map_err(|(_, errors)| format!("{:?}", errors))?;
+
+    Ok(resource
+        .body
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => message.value.map(|pattern| (message.id.name, pattern)),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Converts the language identifiers used by Fluent to those used by Xcode, e.g. "es-ES" to
+/// "es". The app's translations are not specific to a region.
+fn apple_language(language: &str) -> String {
+    language.split('-').next().unwrap_or(language).to_string()
+}
+
+/// Lists the variables of the specified English message, in the order in which they appear in
+/// its text.
+fn collect_arguments<'s>(pattern: &Pattern<&'s str>) -> Vec<Argument<'s>> {
+    //
+
+    let mut arguments: Vec<Argument> = vec![];
+    let mut add = |name: &'s str, is_number: bool| match arguments.iter_mut().find(|argument| argument.name == name) {
+        Some(argument) => argument.is_number |= is_number,
+        None => arguments.push(Argument { is_number, name }),
+    };
+
+    for element in &pattern.elements {
+        match element {
+            PatternElement::Placeable { expression: Expression::Inline(InlineExpression::VariableReference { id }) } => {
+                add(id.name, false);
+            }
+            PatternElement::Placeable { expression: Expression::Select { selector, variants } } => {
+                if let Some(default) = variants.iter().find(|variant| variant.default) {
+                    for argument in collect_arguments(&default.value) {
+                        add(argument.name, argument.is_number);
+                    }
+                }
+                if let InlineExpression::VariableReference { id } = selector {
+                    add(id.name, true);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    arguments
+}
+
+fn has_plural_forms(pattern: &Pattern<&str>) -> bool {
+    pattern.elements.iter().any(|element| matches!(element, PatternElement::Placeable { expression: Expression::Select { .. } }))
+}
+
+/// Builds the String Catalog localization of the specified translation.
+fn localize(pattern: &Pattern<&str>, arguments: &[Argument]) -> Result<Value, String> {
+    //
+
+    let positional = arguments.len() > 1;
+
+    // Early return.
+    if !has_plural_forms(pattern) {
+        let value = render(pattern, arguments, None, positional)?;
+        return Ok(json!({ "stringUnit": { "state": "translated", "value": value } }));
+    }
+
+    let mut plural = Map::new();
+    for category in PLURAL_CATEGORIES {
+        if let Some(value) = render_plural_form(pattern, arguments, category, positional)? {
+            plural.insert(category.to_string(), json!({ "stringUnit": { "state": "translated", "value": value } }));
+        }
+    }
+
+    Ok(json!({ "variations": { "plural": plural } }))
+}
+
+/// Renders the specified plural form, if the message has one. The "other" form, which every
+/// String Catalog plural requires, falls back to the message's default variant.
+fn render_plural_form(pattern: &Pattern<&str>, arguments: &[Argument], category: &str, positional: bool) -> Result<Option<String>, String> {
+    //
+
+    let has_variant = pattern.elements.iter().any(|element| match element {
+        PatternElement::Placeable { expression: Expression::Select { variants, .. } } => {
+            variants.iter().any(|variant| plural_category(&variant.key) == Some(category))
+        }
+        _ => false,
+    });
+
+    match has_variant || category == "other" {
+        true => render(pattern, arguments, Some(category), positional).map(Some),
+        false => Ok(None),
+    }
+}
+
+/// Maps a variant key to its plural category. A key of 0 is taken to mean "zero".
+fn plural_category<'s>(key: &VariantKey<&'s str>) -> Option<&'s str> {
+    match key {
+        VariantKey::Identifier { name } => PLURAL_CATEGORIES.contains(name).then_some(*name),
+        VariantKey::NumberLiteral { value } => (*value == "0").then_some("zero"),
+    }
+}
+
+/// Renders a message as a format string. The variant for the specified plural category is chosen
+/// from any select expression, falling back to the default variant. Positional specifiers, e.g.
+/// %2$@, let a translation order the values differently than the English text does.
+fn render(pattern: &Pattern<&str>, arguments: &[Argument], category: Option<&str>, positional: bool) -> Result<String, String> {
+    //
+
+    let mut text = String::new();
+
+    for element in &pattern.elements {
+        match element {
+            PatternElement::TextElement { value } => text.push_str(&value.replace('%', "%%")),
+            PatternElement::Placeable { expression: Expression::Inline(expression) } => {
+                text.push_str(&render_inline(expression, arguments, positional)?);
+            }
+            PatternElement::Placeable { expression: Expression::Select { variants, .. } } => {
+                let variant = variants
+                    .iter()
+                    .find(|variant| category.is_some() && plural_category(&variant.key) == category)
+                    .or_else(|| variants.iter().find(|variant| variant.default))
+                    .ok_or("A select expression has no default variant")?;
+                text.push_str(&render_variant(variant, arguments, positional)?);
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+fn render_variant(variant: &Variant<&str>, arguments: &[Argument], positional: bool) -> Result<String, String> {
+    match has_plural_forms(&variant.value) {
+        true => Err("Nested select expressions are not supported".to_string()),
+        false => render(&variant.value, arguments, None, positional),
+    }
+}
+
+fn render_inline(expression: &InlineExpression<&str>, arguments: &[Argument], positional: bool) -> Result<String, String> {
+    match expression {
+        InlineExpression::StringLiteral { value } | InlineExpression::NumberLiteral { value } => Ok(value.replace('%', "%%")),
+        InlineExpression::VariableReference { id } => {
+            let (index, argument) = arguments
+                .iter()
+                .enumerate()
+                .find(|(_, argument)| argument.name == id.name)
+                .ok_or(format!("${} is not used by the English text", id.name))?;
+            let specifier = match argument.is_number {
+                true => "lld",
+                false => "@",
+            };
+            Ok(match positional {
+                true => format!("%{}${specifier}", index + 1),
+                false => format!("%{specifier}"),
+            })
+        }
+        InlineExpression::Placeable { expression } => match expression.as_ref() {
+            Expression::Inline(expression) => render_inline(expression, arguments, positional),
+            Expression::Select { .. } => Err("Nested select expressions are not supported".to_string()),
+        },
+        _ => Err("Only text, literals and variables are supported".to_string()),
+    }
+}
@@ -1,19 +1,30 @@
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
+use clap::Parser;
 use helpers_for_bevy::status_text::status_text_plugin::StatusTextPlugin;
+use helpers_for_bevy::text_input::text_input_plugin::TextInputPlugin;
 
 use crate::camera_plugin::CameraPlugin;
 use crate::game_play_screen::GamePlayPluginGroup;
 use crate::invitation_screen::invite_screen_plugin::InvitationScreenPlugin;
+use crate::localization::localization_plugin::LocalizationPlugin;
+use crate::localization::localization_resource::LocalizationResource;
+use crate::localization::string_catalog;
+use crate::settings_screen::settings_screen_plugin::SettingsScreenPlugin;
 use crate::shared::app_mode::AppMode;
+use crate::shared::app_settings_resource::AppSettingsResource;
 use crate::shared::app_state_resource::AppStateResource;
+use crate::shared::cli_args::CliArgs;
 use crate::start_screen::start_screen_plugin::StartScreenPlugin;
 
 pub(crate) mod camera_plugin;
 mod game_play_screen;
 mod invitation_screen;
+pub(crate) mod localization;
+mod settings_screen;
 pub(crate) mod shared;
 mod start_screen;
+mod tests;
 
 //  Tic-Tac-Toe Bevy Client App
 //
@@ -24,6 +35,26 @@ mod start_screen;
 fn main() {
     //
 
+    let cli_args = CliArgs::parse();
+
+    // Export the translations for the Swift app, if asked to, instead of running the app.
+    if let Some(path) = &cli_args.export_string_catalog {
+        match string_catalog::export(path) {
+            Ok(count) => println!("Exported {} strings to {}", count, path.display()),
+            Err(error) => {
+                eprintln!("Unable to export the String Catalog: {}", error);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // Settings come from the settings file, overridden by any command line arguments.
+    let settings = AppSettingsResource::load(&cli_args);
+    let localization = LocalizationResource::new(settings.language());
+    let mut app_state = AppStateResource::default();
+    app_state.local_player.display_name = settings.display_name().to_string();
+
     // Set the window title and its initial size
     let window_plugin = WindowPlugin {
         primary_window: Some(Window {
@@ -35,12 +66,17 @@ fn main() {
     };
 
     App::new()
-        .insert_resource(AppStateResource::default())
+        .insert_resource(app_state)
+        .insert_resource(settings)
+        .insert_resource(localization)
         .add_plugins(DefaultPlugins.set(window_plugin))
         .add_plugins(StatusTextPlugin::default())
+        .add_plugins(TextInputPlugin::default())
+        .add_plugins(LocalizationPlugin)
         .add_plugins(GamePlayPluginGroup)
         .add_plugins(StartScreenPlugin)
         .add_plugins(InvitationScreenPlugin)
+        .add_plugins(SettingsScreenPlugin)
         .add_plugins(CameraPlugin)
         .add_systems(FixedUpdate, bevy::window::close_on_esc) // Close the app when the Escape button is pressed
         .insert_state(AppMode::StartMenu) // Set the initial state
@@ -0,0 +1,30 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use bevy::prelude::Component;
+
+pub(crate) mod settings_screen_plugin;
+
+/// Marker to indicate that an entity was spawned on the Settings Screen.
+#[derive(Component)]
+struct OnSettingsScreen;
+
+/// Defines the purposes of the Settings Screen buttons.
+#[derive(Clone)]
+pub(super) enum ButtonPurpose {
+    BackToStartScreen,
+    EditField(SettingsField),
+    Save,
+    TestConnection,
+}
+
+/// Enumerates the editable settings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum SettingsField {
+    DisplayName,
+    Language,
+    PollInterval,
+    ServerUrl,
+}
@@ -0,0 +1,503 @@
+use bevy::prelude::{in_state, App, Component, IntoSystemConfigs, OnEnter, OnExit, Plugin, Resource, Update};
+
+use crate::localization::localization_resource::LocalizationResource;
+use crate::localization::{negotiate_language, SUPPORTED_LANGUAGES};
+use crate::settings_screen::{OnSettingsScreen, SettingsField};
+use crate::shared::app_mode::AppMode;
+use crate::shared::app_settings_resource::AppSettingsResource;
+use crate::shared::despawn;
+
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// The editable settings, in the order in which they are shown, with the IDs of their labels.
+const SETTINGS_FIELDS: [(SettingsField, &str); 4] = [
+    (SettingsField::ServerUrl, "settings-server-url"),
+    (SettingsField::PollInterval, "settings-poll-interval"),
+    (SettingsField::DisplayName, "settings-display-name"),
+    (SettingsField::Language, "settings-language"),
+];
+
+/// The maximum number of digits accepted for the polling interval.
+const POLL_INTERVAL_MAX_DIGITS: usize = 5;
+
+/// Implements the Settings Screen, on which the user sets the server address, the polling interval,
+/// their display name and the language, and tests the connection to the server.
+pub(crate) struct SettingsScreenPlugin;
+
+impl Plugin for SettingsScreenPlugin {
+    /// Composes the plugin.
+    fn build(&self, app: &mut App) {
+        app //
+            .add_systems(
+                OnEnter(AppMode::Settings),
+                (functionality::begin_editing, ui::spawn_ui).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    functionality::button_interaction,
+                    functionality::keyboard_input,
+                    functionality::update_draft,
+                    functionality::follow_text_input_focus,
+                    functionality::apply_focus,
+                    functionality::update_language_label,
+                )
+                    .chain()
+                    .run_if(in_state(AppMode::Settings)),
+            )
+            .add_systems(
+                OnExit(AppMode::Settings),
+                (despawn::<OnSettingsScreen>, functionality::end_editing),
+            );
+    }
+}
+
+/// Marker for the text field of a typed, rather than picked, setting.
+#[derive(Component)]
+struct SettingsFieldComponent(SettingsField);
+
+/// Marker for the text of the Language field.
+#[derive(Component)]
+struct LanguageLabelComponent;
+
+/// Holds the settings while they are being edited. The AppSettingsResource is only updated when
+/// the user saves.
+#[derive(Resource)]
+struct SettingsDraftResource {
+    display_name: String,
+    focused_field: SettingsField,
+    language: &'static str,
+    poll_interval_ms: String,
+    server_url: String,
+}
+
+impl SettingsDraftResource {
+    //
+
+    fn new(settings: &AppSettingsResource) -> Self {
+        Self {
+            display_name: settings.display_name().to_string(),
+            focused_field: SettingsField::ServerUrl,
+            language: negotiate_language(settings.language()),
+            poll_interval_ms: settings.poll_interval_ms().to_string(),
+            server_url: settings.server_url().to_string(),
+        }
+    }
+
+    /// Returns the text of the specified field for editing. The Language is picked rather than typed.
+    fn value_mut(&mut self, field: SettingsField) -> Option<&mut String> {
+        match field {
+            SettingsField::DisplayName => Some(&mut self.display_name),
+            SettingsField::Language => None,
+            SettingsField::PollInterval => Some(&mut self.poll_interval_ms),
+            SettingsField::ServerUrl => Some(&mut self.server_url),
+        }
+    }
+
+    /// Moves the focus to the next field, wrapping around after the last.
+    fn focus_next_field(&mut self) {
+        let index = SETTINGS_FIELDS.iter().position(|(field, _)| *field == self.focused_field).unwrap_or_default();
+        self.focused_field = SETTINGS_FIELDS[(index + 1) % SETTINGS_FIELDS.len()].0;
+    }
+
+    /// Picks the next, or with a negative step the previous, supported language, wrapping around.
+    fn pick_language(&mut self, step: isize) {
+        let index = SUPPORTED_LANGUAGES.iter().position(|(language, _)| *language == self.language).unwrap_or_default();
+        let index = (index as isize + step).rem_euclid(SUPPORTED_LANGUAGES.len() as isize) as usize;
+        self.language = SUPPORTED_LANGUAGES[index].0;
+    }
+
+    /// Builds validated settings from the draft.
+    fn to_settings(&self, localization: &LocalizationResource) -> Result<AppSettingsResource, String> {
+        let poll_interval_ms = self.poll_interval_ms.parse::<u64>()
+            .map_err(|_| localization.text("settings-poll-interval-needed"))?;
+        let settings = AppSettingsResource::new(self.server_url.trim(), poll_interval_ms, &self.display_name, self.language);
+        settings.validate(localization)?;
+        Ok(settings)
+    }
+}
+
+mod functionality {
+    use std::time::Duration;
+
+    use bevy::prelude::{
+        BackgroundColor, Button, ButtonInput, Changed, Commands, DetectChanges, EventReader, EventWriter,
+        Interaction, KeyCode, NextState, Query, Res, ResMut, Text, With,
+    };
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+    use helpers_for_bevy::status_text::events::SetStatusTextEvent;
+    use helpers_for_bevy::text_input::events::TextInputChangedEvent;
+    use helpers_for_bevy::text_input::text_input_component::TextInputComponent;
+
+    use crate::localization::language_name;
+    use crate::localization::localization_resource::LocalizationResource;
+    use crate::settings_screen::settings_screen_plugin::{LanguageLabelComponent, SettingsDraftResource, SettingsFieldComponent};
+    use crate::settings_screen::{ButtonPurpose, SettingsField};
+    use crate::shared::api_helpers::ServiceHealthHelper;
+    use crate::shared::app_mode::AppMode;
+    use crate::shared::app_settings_resource::AppSettingsResource;
+    use crate::shared::app_state_resource::AppStateResource;
+    use crate::shared::{BUTTON_COLOR_HOVERED, BUTTON_COLOR_NORMAL, BUTTON_COLOR_PRESSED, FOREGROUND_COLOR, TEXT_COLOR};
+
+    /// Copies the current settings into a draft for editing.
+    pub(super) fn begin_editing(mut commands: Commands, settings: Res<AppSettingsResource>) {
+        commands.insert_resource(SettingsDraftResource::new(&settings));
+    }
+
+    /// Discards the draft.
+    pub(super) fn end_editing(mut commands: Commands) {
+        commands.remove_resource::<SettingsDraftResource>();
+    }
+
+    /// Provides button functionality, including state changes as well as response when clicked.
+    #[allow(clippy::type_complexity)] // The query is complex by necessity.
+    pub(super) fn button_interaction(
+        mut app_state: ResMut<AppStateResource>,
+        mut draft: ResMut<SettingsDraftResource>,
+        mut event_writer: EventWriter<SetStatusTextEvent>,
+        mut interactions: Query<
+            (
+                &Interaction,
+                &mut BackgroundColor,
+                &EntityInfoComponent<ButtonPurpose>,
+            ),
+            (Changed<Interaction>, With<Button>),
+        >,
+        mut localization: ResMut<LocalizationResource>,
+        mut next_state: ResMut<NextState<AppMode>>,
+        mut settings: ResMut<AppSettingsResource>,
+    ) {
+        for (interaction, mut color, button_info) in &mut interactions {
+            match *interaction {
+                Interaction::Hovered => {
+                    *color = BackgroundColor(*BUTTON_COLOR_HOVERED);
+                }
+                Interaction::None => {
+                    *color = BackgroundColor(*BUTTON_COLOR_NORMAL);
+                }
+                Interaction::Pressed => {
+                    //
+
+                    *color = BackgroundColor(*BUTTON_COLOR_PRESSED);
+
+                    match button_info.get_purpose() {
+                        ButtonPurpose::BackToStartScreen => next_state.set(AppMode::StartMenu),
+                        ButtonPurpose::EditField(SettingsField::Language) => {
+                            // Each click picks the next language.
+                            draft.focused_field = SettingsField::Language;
+                            draft.pick_language(1);
+                        }
+                        ButtonPurpose::EditField(field) => draft.focused_field = field,
+                        ButtonPurpose::Save => {
+                            let result = draft.to_settings(&localization)
+                                .and_then(|new_settings| new_settings.save(&localization).map(|_| new_settings));
+                            match result {
+                                Ok(new_settings) => {
+                                    app_state.local_player.display_name = new_settings.display_name().to_string();
+                                    localization.set_language(new_settings.language());
+                                    *settings = new_settings;
+                                    event_writer.send(SetStatusTextEvent::new_with_duration(
+                                        localization.text("settings-saved"),
+                                        Duration::from_secs(5),
+                                    ));
+                                    next_state.set(AppMode::StartMenu);
+                                }
+                                Err(message) => {
+                                    event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(10)));
+                                }
+                            }
+                        }
+                        ButtonPurpose::TestConnection => {
+                            let message = match draft.to_settings(&localization)
+                                .and_then(|new_settings| ServiceHealthHelper::check(&localization, new_settings.sdk_config()).map(|_| new_settings)) {
+                                Ok(new_settings) => localization.format("settings-connected", [("server", new_settings.server_url().into())]),
+                                Err(message) => message,
+                            };
+                            event_writer.send(SetStatusTextEvent::new_with_duration(message, Duration::from_secs(10)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the focus to the next field when the Tab key is pressed. While the Language is focused,
+    /// the arrow keys pick it. The text fields handle the rest of the keyboard input.
+    pub(super) fn keyboard_input(mut draft: ResMut<SettingsDraftResource>, keyboard_input: Res<ButtonInput<KeyCode>>) {
+        //
+
+        if keyboard_input.just_pressed(KeyCode::Tab) {
+            draft.focus_next_field();
+            return;
+        }
+
+        if draft.focused_field == SettingsField::Language {
+            if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+                draft.pick_language(1);
+            } else if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+                draft.pick_language(-1);
+            }
+        }
+    }
+
+    /// Copies the user's edits into the draft.
+    pub(super) fn update_draft(
+        mut draft: ResMut<SettingsDraftResource>,
+        mut event_reader: EventReader<TextInputChangedEvent>,
+        fields: Query<&SettingsFieldComponent>,
+    ) {
+        for event in event_reader.read() {
+            if let Ok(SettingsFieldComponent(field)) = fields.get(event.entity) {
+                if let Some(value) = draft.value_mut(*field) {
+                    *value = event.value.clone();
+                }
+            }
+        }
+    }
+
+    /// Notes the text field that the user has clicked as the focused field.
+    pub(super) fn follow_text_input_focus(
+        mut draft: ResMut<SettingsDraftResource>,
+        text_inputs: Query<(&TextInputComponent, &SettingsFieldComponent), Changed<TextInputComponent>>,
+    ) {
+        for (text_input, SettingsFieldComponent(field)) in &text_inputs {
+            if text_input.is_focused() && draft.focused_field != *field {
+                draft.focused_field = *field;
+            }
+        }
+    }
+
+    /// Gives the keyboard focus to the focused field's text field, if it has one.
+    pub(super) fn apply_focus(
+        draft: Res<SettingsDraftResource>,
+        mut text_inputs: Query<(&mut TextInputComponent, &SettingsFieldComponent)>,
+    ) {
+        //
+
+        if !draft.is_changed() {
+            return;
+        }
+
+        for (mut text_input, SettingsFieldComponent(field)) in &mut text_inputs {
+            let focused = *field == draft.focused_field;
+            if text_input.is_focused() != focused {
+                text_input.set_focus(focused);
+            }
+        }
+    }
+
+    /// Shows the picked Language, highlighting it while it is focused.
+    pub(super) fn update_language_label(
+        draft: Res<SettingsDraftResource>,
+        mut labels: Query<&mut Text, With<LanguageLabelComponent>>,
+    ) {
+        //
+
+        if !draft.is_changed() {
+            return;
+        }
+
+        let is_focused = draft.focused_field == SettingsField::Language;
+        for mut text in &mut labels {
+            text.sections[0].value = match is_focused {
+                true => format!("< {} >", language_name(draft.language)),
+                false => language_name(draft.language).to_string(),
+            };
+            text.sections[0].style.color = match is_focused {
+                true => *FOREGROUND_COLOR,
+                false => *TEXT_COLOR,
+            };
+        }
+    }
+}
+
+mod ui {
+    use bevy::prelude::{
+        default, AlignItems, BackgroundColor, BorderColor, BuildChildren, ButtonBundle, Color,
+        Commands, FlexDirection, JustifyContent, NodeBundle, Res, Style, TextBundle, TextStyle, UiRect, Val,
+    };
+    use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+    use helpers_for_bevy::text_input::text_input_component::{TextInputBundle, TextInputComponent, TextInputFilter};
+
+    use crate::localization::localization_resource::LocalizationResource;
+    use crate::localization::LocalizedTextComponent;
+
+    use crate::settings_screen::settings_screen_plugin::{LanguageLabelComponent, SettingsFieldComponent, POLL_INTERVAL_MAX_DIGITS, SETTINGS_FIELDS};
+    use crate::settings_screen::{ButtonPurpose, OnSettingsScreen, SettingsField};
+    use crate::shared::app_settings_resource::AppSettingsResource;
+    use crate::shared::{BUTTON_COLOR_NORMAL, FONT_SIZE, PLAYER_NAME_MAX_LENGTH, TEXT_COLOR};
+
+    /// Sets up and then spawns the Settings Screen UI widgets.
+    pub(super) fn spawn_ui(mut commands: Commands, localization: Res<LocalizationResource>, settings: Res<AppSettingsResource>) {
+        //
+
+        let title_back = "go-back";
+        let title_save = "settings-save";
+        let title_test = "settings-test-connection";
+        let title_instructions = "settings-instructions";
+
+        let text_style = TextStyle {
+            color: *TEXT_COLOR,
+            font: Default::default(),
+            font_size: FONT_SIZE,
+        };
+
+        // Button template
+        let button_bundle = ButtonBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.)),
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::Px(16.)),
+                ..default()
+            },
+            border_color: BorderColor(Color::GRAY),
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        };
+
+        // Field template. The Language field is a button so that it can be clicked to pick the
+        // next Language. The other fields are text fields.
+        let field_style = Style {
+            border: UiRect::all(Val::Px(1.)),
+            min_width: Val::Px(300.),
+            padding: UiRect::all(Val::Px(8.)),
+            ..default()
+        };
+        let field_bundle = ButtonBundle {
+            style: field_style.clone(),
+            border_color: BorderColor(Color::GRAY),
+            background_color: BackgroundColor(*BUTTON_COLOR_NORMAL),
+            ..default()
+        };
+
+        // Row template
+        let row_bundle = NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ..default()
+        };
+
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10.),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                OnSettingsScreen,
+            ))
+            .with_children(|parent| {
+                //
+
+                parent.spawn((
+                    TextBundle::from_section(localization.text(title_instructions), text_style.clone()),
+                    LocalizedTextComponent(title_instructions),
+                    OnSettingsScreen,
+                ));
+
+                // The Fields
+                for (field, title) in SETTINGS_FIELDS {
+                    parent
+                        .spawn((row_bundle.clone(), OnSettingsScreen))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(localization.text(title), text_style.clone()).with_style(Style {
+                                    min_width: Val::Px(180.),
+                                    ..default()
+                                }),
+                                LocalizedTextComponent(title),
+                                OnSettingsScreen,
+                            ));
+                            match new_text_input(field, &settings, &text_style) {
+                                Some(text_input) => {
+                                    parent.spawn((
+                                        TextInputBundle::new(text_input).with_style(field_style.clone()),
+                                        BorderColor(Color::GRAY),
+                                        SettingsFieldComponent(field),
+                                        OnSettingsScreen,
+                                    ));
+                                }
+                                None => {
+                                    parent
+                                        .spawn((
+                                            field_bundle.clone(),
+                                            EntityInfoComponent::new(ButtonPurpose::EditField(field)),
+                                            OnSettingsScreen,
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section("", text_style.clone()),
+                                                LanguageLabelComponent,
+                                                OnSettingsScreen,
+                                            ));
+                                        });
+                                }
+                            }
+                        });
+                }
+
+                // The Buttons
+                parent
+                    .spawn((row_bundle.clone(), OnSettingsScreen))
+                    .with_children(|parent| {
+                        for (title, purpose) in [
+                            (title_test, ButtonPurpose::TestConnection),
+                            (title_save, ButtonPurpose::Save),
+                            (title_back, ButtonPurpose::BackToStartScreen),
+                        ] {
+                            parent
+                                .spawn((
+                                    button_bundle.clone(),
+                                    EntityInfoComponent::new(purpose),
+                                    OnSettingsScreen,
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(localization.text(title), text_style.clone()),
+                                        LocalizedTextComponent(title),
+                                        OnSettingsScreen,
+                                    ));
+                                });
+                        }
+                    });
+            });
+    }
+
+    /// Creates the text field for the specified setting, holding its current value. The Language
+    /// is picked rather than typed. So, it has none.
+    fn new_text_input(field: SettingsField, settings: &AppSettingsResource, text_style: &TextStyle) -> Option<TextInputComponent> {
+        let text_input = TextInputComponent::new(text_style.clone());
+        match field {
+            SettingsField::DisplayName => Some(text_input
+                .with_filter(TextInputFilter::Alphanumeric)
+                .with_max_length(PLAYER_NAME_MAX_LENGTH)
+                .with_value(settings.display_name())),
+            SettingsField::Language => None,
+            SettingsField::PollInterval => Some(text_input
+                .with_filter(TextInputFilter::Digits)
+                .with_max_length(POLL_INTERVAL_MAX_DIGITS)
+                .with_value(settings.poll_interval_ms().to_string())),
+            SettingsField::ServerUrl => Some(text_input
+                .with_filter(TextInputFilter::Custom(|char| !char.is_whitespace()))
+                .with_focus()
+                .with_value(settings.server_url())),
+        }
+    }
+}
@@ -3,183 +3,86 @@
 //  © 2024 Rust Made Easy. All rights reserved.
 //  @author JoelDavisEngineering@Gmail.com
 
-use bevy::log::error;
-use function_name::named;
-use std::sync::{LazyLock, Mutex};
-use std::time::Duration;
 use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
-use tic_tac_toe_rust_client_sdk::apis::tic_tac_toe_api::{GetLatestGameTurnError, GetPlayersReadinessError};
-use tic_tac_toe_rust_client_sdk::apis::{tic_tac_toe_api, Error};
-use tic_tac_toe_rust_client_sdk::models::{GamePiece, TurnResponse};
+use tic_tac_toe_rust_client_sdk::apis::Error;
+use tic_tac_toe_rust_client_sdk::models::GamePiece;
 
-/// An auto-refreshing Game State info cache.
-pub(crate) struct GameStateCache;
+use crate::localization::localization_resource::LocalizationResource;
 
-impl GameStateCache {
+/// Checks that the Tic-Tac-Toe service can be reached.
+pub(crate) struct ServiceHealthHelper;
+impl ServiceHealthHelper {
     //
 
-    /// Returns Game state of a specified Game. NOTE: This info is cached for immediate access if
-    /// setup_auto_update() is called whenever a new Game is started or joined.
-    pub(crate) fn get_latest_game_turn(game_id: &str) -> Result<TurnResponse, Error<GetLatestGameTurnError>> {
-
-        // Try the mem cache
-        let info_mutex = AUTO_UPDATE_INFO.lock().unwrap();
-        let result = info_mutex.latest_results.clone();
-        drop(info_mutex);
+    /// Calls the service's health endpoint, describing the problem if the service does not
+    /// respond as expected.
+    pub(crate) fn check(localization: &LocalizationResource, configuration: &Configuration) -> Result<(), String> {
+        //
 
-        match result {
-            None => {
-                match Self::load_and_cache_latest_game_turn(game_id) {
-                    Ok(response) => Ok(response),
-                    Err(error) => Err(error),
-                }
-            }
-            Some(result) => {
-                // Cache hit, wooohooo!
-                Ok(result)
-            }
+        let url = format!("{}/v1/health", configuration.base_path);
+        let mut request = configuration.client.get(&url);
+        if let Some(user_agent) = &configuration.user_agent {
+            request = request.header(reqwest::header::USER_AGENT, user_agent);
         }
-    }
-
-    /// Returns the Game readiness of a specified Game. NOTE: This info is cached for immediate
-    /// access if setup_auto_update() is called whenever a new Game is started or joined.
-    pub(crate) fn get_latest_game_readiness(game_id: &str) -> Result<bool, Error<GetPlayersReadinessError>> {
-
-        // Try the mem cache
-        let info_mutex = AUTO_UPDATE_INFO.lock().unwrap();
-        let result = info_mutex.players_ready;
-        drop(info_mutex);
-
-        match result {
-            None => {
-                match Self::load_and_cache_players_readiness(game_id) {
-                    Ok(response) => Ok(response),
-                    Err(error) => Err(error),
-                }
-            }
-            Some(result) => {
-                // Cache hit, wooohooo!
-                Ok(result)
-            }
+        if let Some(timeout) = configuration.timeout {
+            request = request.timeout(timeout);
         }
-    }
 
-    fn load_and_cache_latest_game_turn(game_id: &str) -> Result<TurnResponse, Error<GetLatestGameTurnError>> {
-        match tic_tac_toe_api::get_latest_game_turn(&SDK_CONFIG, game_id) {
-            Ok(response) => {
-                // Cache the result. We lock the mutex for only as long as it takes to write to it.
-                let mut info_mutex = AUTO_UPDATE_INFO.lock().unwrap();
-                info_mutex.latest_results = Some(response.clone());
-                drop(info_mutex);
-                Ok(response)
-            }
-            Err(error) => Err(error),
+        match request.send() {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) => Err(localization.format("error-server-status", [
+                ("server", configuration.base_path.as_str().into()),
+                ("status", response.status().to_string().into()),
+            ])),
+            Err(error) => Err(ServiceErrorHelper::describe_unreachable(localization, &configuration.base_path, &error)),
         }
     }
+}
 
-    fn load_and_cache_players_readiness(game_id: &str) -> Result<bool, Error<GetPlayersReadinessError>> {
-        match tic_tac_toe_api::get_players_readiness(&SDK_CONFIG, game_id) {
-            Ok(response) => {
-                // Cache the result. We lock the mutex for only as long as it takes to write to it.
-                let mut info_mutex = AUTO_UPDATE_INFO.lock().unwrap();
-                info_mutex.players_ready = Some(response.all_players_are_ready);
-                drop(info_mutex);
-                Ok(response.all_players_are_ready)
-            }
-            Err(error) => Err(error),
-        }
-    }
+/// Turns SDK errors into status text.
+pub(crate) struct ServiceErrorHelper;
+impl ServiceErrorHelper {
+    //
 
-    /// Sets Game ID to use for Game Info auto-updating. The interval parameter should be set to
-    /// 1/2 the interval at which the system calls get_game_info(), i.e. twice as frequent.
-    pub(crate) fn setup_auto_update(new_game_id: &str, interval: &Duration) {
+    /// Describes the specified error. The context is the ID of the message that describes what
+    /// was being attempted, e.g. "error-join-game".
+    pub(crate) fn describe<T>(
+        localization: &LocalizationResource,
+        context: &str,
+        configuration: &Configuration,
+        error: &Error<T>,
+    ) -> String {
         //
 
-        let mut info_mutex = AUTO_UPDATE_INFO.lock().unwrap();
+        let context = localization.text(context);
+        let describe = |message_id: &str| localization.format(message_id, [("context", context.as_str().into())]);
 
-        *info_mutex = Default::default();
-        info_mutex.game_id = new_game_id.to_string();
-        info_mutex.interval = *interval;
-        let is_already_running = info_mutex.is_running;
-        drop(info_mutex);
-
-        // If the thread is not already running then let's get it started.
-        if !is_already_running {
-            Self::start_auto_game_info_update();
+        match error {
+            Error::Reqwest(error) if error.is_connect() || error.is_timeout() => {
+                let reason = Self::describe_unreachable(localization, &configuration.base_path, error);
+                localization.format("error-unreachable-context", [
+                    ("context", context.as_str().into()),
+                    ("reason", reason.into()),
+                ])
+            }
+            Error::ResponseError(response) => match response.status {
+                reqwest::StatusCode::NOT_FOUND => describe("error-not-found"),
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR => describe("error-internal"),
+                _ => describe("error-unexpected-response"),
+            },
+            _ => describe("error-communication"),
         }
     }
 
-    /// Begins the background thread that frequently retrieves and caches the Game Info.
-    #[named]
-    fn start_auto_game_info_update() {
-        //
-
-        let mut info_mutex = AUTO_UPDATE_INFO.lock().unwrap();
-
-        // Exit if we are already running.
-        if info_mutex.is_running {
-            drop(info_mutex);
-            return;
-        }
-
-        // Indicate that the thread is running
-        info_mutex.is_running = true;
-
-        drop(info_mutex);
-
-        std::thread::spawn(|| {
-            //
-
-            loop {
-                // Get a local snapshot of the info to work from. We do this per loop so that
-                // these settings can be changed dynamically. NOTE: We don't want to keep the mutex
-                // locked for the duration of this call.
-                let auto_update_info = AUTO_UPDATE_INFO.lock().unwrap();
-                let cloned_info = auto_update_info.clone();
-                drop(auto_update_info);
-
-                // Call the server for the latest state of the specified Game.
-                if cloned_info.players_ready.is_none() || !cloned_info.players_ready.unwrap() {
-                    match GameStateCache::load_and_cache_players_readiness(&cloned_info.game_id) {
-                        Ok(_) => {}
-                        Err(error) => {
-                            error!("{} - Error encountered calling load_and_cache_players_readiness(): {:?}", function_name!(), error);
-                        }
-                    }
-                } else {
-                    match GameStateCache::load_and_cache_latest_game_turn(&cloned_info.game_id) {
-                        Ok(_) => {}
-                        Err(error) => {
-                            error!("{} - Error encountered calling load_and_cache_latest_game_turn(): {:?}", function_name!(), error);
-                        }
-                    }
-                }
-
-                // Sleep for the interval.
-                std::thread::sleep(cloned_info.interval);
-            }
-        });
+    fn describe_unreachable(localization: &LocalizationResource, base_path: &str, error: &reqwest::Error) -> String {
+        let message_id = match error.is_timeout() {
+            true => "error-server-not-responding",
+            false => "error-server-unreachable",
+        };
+        localization.format(message_id, [("server", base_path.into())])
     }
 }
 
-pub(crate) static SDK_CONFIG: LazyLock<Configuration> = LazyLock::new(|| Configuration {
-    base_path: "http://127.0.0.1:50020".to_string(), // TODO: JD: set this to the address of the load balancer.
-    user_agent: Some("Tic-Tac-Toe Rust Client".to_string()),
-    ..Default::default()
-});
-
-pub(crate) static AUTO_UPDATE_INFO: LazyLock<Mutex<AutoUpdateInfo>> = LazyLock::new(|| { Mutex::new(AutoUpdateInfo::default()) });
-
-/// Models the data need to perform and cache background updates for Game Info.
-#[derive(Clone, Default)]
-pub(crate) struct AutoUpdateInfo {
-    pub(crate) game_id: String,
-    pub(crate) interval: Duration,
-    pub(crate) is_running: bool,
-    pub(crate) latest_results: Option<TurnResponse>,
-    pub(crate) players_ready: Option<bool>,
-}
-
 pub(crate) struct GamePieceHelper;
 impl GamePieceHelper {
     pub(crate) fn display_name(game_piece: GamePiece) -> String {
@@ -10,5 +10,6 @@ use bevy::prelude::States;
 pub(crate) enum AppMode {
     EnterInvitation,
     GamePlay,
+    Settings,
     StartMenu,
 }
@@ -0,0 +1,210 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+
+use crate::localization::localization_resource::LocalizationResource;
+use crate::localization::negotiate_language;
+use crate::shared::cli_args::CliArgs;
+
+/// Address of the Tic-Tac-Toe service used when none has been configured.
+const DEFAULT_SERVER_URL: &str = "http://127.0.0.1:50020";
+
+/// Polling interval, in milliseconds, used when none has been configured.
+const DEFAULT_POLL_INTERVAL_MS: u64 = 500;
+
+/// The range of accepted polling intervals, in milliseconds.
+pub(crate) const POLL_INTERVAL_RANGE_MS: std::ops::RangeInclusive<u64> = 100..=60_000;
+
+/// How long to wait for the service to respond before giving up.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Houses the user's connection and language settings. The settings are persisted to a JSON file in the
+/// user's configuration directory and can be overridden via command line arguments.
+#[derive(Clone, Debug, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub(crate) struct AppSettingsResource {
+    //
+
+    /// Name with which the local Player is greeted on the Start Screen.
+    display_name: String,
+
+    /// Language in which the app's text is shown, e.g. "es-ES".
+    language: String,
+
+    /// How often, in milliseconds, the service is polled while the event plane is unreachable.
+    poll_interval_ms: u64,
+
+    /// Base URL of the Tic-Tac-Toe service.
+    server_url: String,
+
+    /// SDK configuration for the server_url. Built once as it owns the HTTP connection pool.
+    #[serde(skip)]
+    sdk_config: Configuration,
+}
+
+impl Default for AppSettingsResource {
+    fn default() -> Self {
+        // Show the app in the user's language, if it has been translated into it.
+        let language = sys_locale::get_locale().unwrap_or_default();
+        Self::new(DEFAULT_SERVER_URL, DEFAULT_POLL_INTERVAL_MS, "", &language)
+    }
+}
+
+impl AppSettingsResource {
+    //
+
+    /// Creates a new instance with the specified settings.
+    pub(crate) fn new(server_url: &str, poll_interval_ms: u64, display_name: &str, language: &str) -> Self {
+        Self {
+            display_name: display_name.to_string(),
+            language: negotiate_language(language).to_string(),
+            poll_interval_ms,
+            server_url: server_url.trim_end_matches('/').to_string(),
+            sdk_config: Self::build_sdk_config(server_url),
+        }
+    }
+
+    /// Loads the persisted settings, if any, and applies the command line arguments on top. Invalid
+    /// persisted settings are replaced by their defaults. The command line arguments have already
+    /// been validated by CliArgs.
+    pub(crate) fn load(cli_args: &CliArgs) -> Self {
+        //
+
+        let persisted = Self::file_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| match serde_json::from_str::<Self>(&contents) {
+                Ok(settings) => Some(settings),
+                Err(error) => {
+                    // Bevy's logging is not yet available at this point.
+                    eprintln!("Ignoring the unreadable settings file: {}", error);
+                    None
+                }
+            })
+            .map(Self::with_defaults_for_invalid_values)
+            .unwrap_or_default();
+
+        Self::new(
+            cli_args.server.as_deref().unwrap_or(&persisted.server_url),
+            cli_args.poll_interval_ms.unwrap_or(persisted.poll_interval_ms),
+            cli_args.name.as_deref().unwrap_or(&persisted.display_name),
+            cli_args.language.as_deref().unwrap_or(&persisted.language),
+        )
+    }
+
+    /// Persists the settings to the settings file.
+    pub(crate) fn save(&self, localization: &LocalizationResource) -> Result<(), String> {
+        //
+
+        let path = Self::file_path().ok_or(localization.text("settings-no-config-directory"))?;
+        let unable_to_write = |path: &std::path::Path, error: std::io::Error| {
+            localization.format("settings-unable-to-write", [
+                ("path", path.display().to_string().into()),
+                ("error", error.to_string().into()),
+            ])
+        };
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|error| unable_to_write(directory, error))?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        fs::write(&path, contents).map_err(|error| unable_to_write(&path, error))
+    }
+
+    /// Checks the settings, describing the first problem found.
+    pub(crate) fn validate(&self, localization: &LocalizationResource) -> Result<(), String> {
+        //
+
+        if !Self::is_valid_server_url(&self.server_url) {
+            return Err(localization.text("settings-invalid-server-url"));
+        }
+
+        if !POLL_INTERVAL_RANGE_MS.contains(&self.poll_interval_ms) {
+            return Err(localization.format("settings-invalid-poll-interval", [
+                ("min", (*POLL_INTERVAL_RANGE_MS.start()).into()),
+                ("max", (*POLL_INTERVAL_RANGE_MS.end()).into()),
+            ]));
+        }
+
+        Ok(())
+    }
+
+    /// Indicates whether the server URL is an absolute http:// or https:// URL with a host.
+    pub(crate) fn is_valid_server_url(server_url: &str) -> bool {
+        matches!(reqwest::Url::parse(server_url), Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host())
+    }
+
+    /// Replaces any invalid settings, e.g. those edited by hand in the settings file, with their
+    /// defaults.
+    pub(crate) fn with_defaults_for_invalid_values(self) -> Self {
+        //
+
+        let server_url = match Self::is_valid_server_url(&self.server_url) {
+            true => self.server_url.as_str(),
+            false => {
+                eprintln!("Ignoring the invalid server URL in the settings file: {}", self.server_url);
+                DEFAULT_SERVER_URL
+            }
+        };
+
+        let poll_interval_ms = match POLL_INTERVAL_RANGE_MS.contains(&self.poll_interval_ms) {
+            true => self.poll_interval_ms,
+            false => {
+                eprintln!("Ignoring the invalid polling interval in the settings file: {} ms", self.poll_interval_ms);
+                DEFAULT_POLL_INTERVAL_MS
+            }
+        };
+
+        Self::new(server_url, poll_interval_ms, &self.display_name, &self.language)
+    }
+
+    /// Returns the location of the settings file.
+    fn file_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|directory| directory.join("tic-tac-toe-bevy").join("settings.json"))
+    }
+
+    fn build_sdk_config(server_url: &str) -> Configuration {
+        Configuration {
+            base_path: server_url.trim_end_matches('/').to_string(),
+            user_agent: Some("Tic-Tac-Toe Rust Client".to_string()),
+            ..Default::default()
+        }.with_timeout(REQUEST_TIMEOUT)
+    }
+}
+
+// Accessors
+impl AppSettingsResource {
+    //
+
+    pub(crate) fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub(crate) fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub(crate) fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub(crate) fn poll_interval_ms(&self) -> u64 {
+        self.poll_interval_ms
+    }
+
+    /// Returns the SDK configuration with which to call the Tic-Tac-Toe service.
+    pub(crate) fn sdk_config(&self) -> &Configuration {
+        &self.sdk_config
+    }
+
+    pub(crate) fn server_url(&self) -> &str {
+        &self.server_url
+    }
+}
@@ -5,7 +5,9 @@
 
 use bevy::prelude::Resource;
 use tic_tac_toe_rust_client_sdk::models;
-use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, PlayStatus, PlayerInfo, TurnResponse};
+use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GameCreationResponse, GamePiece, PlayStatus, PlayerInfo, TurnResponse};
+
+use crate::localization::localization_resource::LocalizationResource;
 
 /// Houses the application state variables.
 #[derive(Default, Resource)]
@@ -18,10 +20,13 @@ pub(crate) struct AppStateResource {
     /// This is the code used to invite a new Player to the Game.
     pub(crate) invitation_code: String,
 
-    // TODO: JD: we also need localPlayerInitiatedGame for when we support rematch within the same Gaming Session.
     /// Indicates that this client app instance is the one that started the Gaming Session.
     pub(crate) local_player_initiated_gaming_session: bool,
 
+    /// Indicates that this client app instance is the one that started the current Game. After a
+    /// rematch, this can differ from local_player_initiated_gaming_session.
+    pub(crate) local_player_initiated_game: bool,
+
     /// The local Player, i.e. the Player using this app instance.
     pub(crate) local_player: PlayerInfo,
 
@@ -46,6 +51,12 @@ pub(crate) struct AppStateResource {
     /// Indicates that this is a Two-Player Game
     pub(crate) is_two_player_game: bool,
 
+    /// A rematch started by the other Player that the local Player has yet to accept or decline
+    pub(crate) rematch_offer: Option<GameCreationResponse>,
+
+    /// The results of the Games played so far in the Gaming Session
+    pub(crate) series_score: SeriesScore,
+
     /// If/when the Game has been won, winningLocations lists the locations of the winning Game pieces
     pub(crate) winning_locations: Option<Vec<BoardPosition>>,
 
@@ -64,25 +75,44 @@ impl AppStateResource {
     /// Generates Game completion text.
     pub(crate) fn generate_results_text(
         &self,
+        localization: &LocalizationResource,
         turn_info: &TurnResponse,
         local_player_name: &String,
         winning_player_name: &Option<String>,
     ) -> String {
-        // TODO: JD: localize the text
         match turn_info.new_game_state.play_status {
-            PlayStatus::EndedInStalemate => "This game has ended in a stalemate.".to_string(),
+            PlayStatus::EndedInStalemate => localization.text("game-stalemate"),
             PlayStatus::EndedInWin => {
                 let winning_player_name = winning_player_name.clone().unwrap_or_default();
                 if *local_player_name == winning_player_name {
-                    "You won!".to_string()
+                    localization.text("game-you-won")
                 } else {
-                    format!("{winning_player_name} won. Better luck next time.")
+                    localization.format("game-won-by", [("name", winning_player_name.into())])
                 }
             }
             _ => "".to_string(),
         }
     }
 
+    /// Indicates whether the local Player is waiting for the other Player to accept a rematch.
+    pub(crate) fn is_awaiting_rematch(&self) -> bool {
+        self.local_player_initiated_game && !self.has_game_started && !self.has_game_ended && self.series_score.games_played() > 0
+    }
+
+    /// Prepares for the next Game in the Gaming Session, keeping the Players and the series score.
+    pub(crate) fn begin_next_game(&mut self, game: &GameCreationResponse, local_player_initiated_game: bool) {
+        //
+
+        self.current_game_state = game.game_info.game_state.clone();
+        self.current_player = None;
+        self.game_id = game.game_info.game_id.clone();
+        self.has_game_ended = false;
+        self.has_game_started = false;
+        self.local_player_initiated_game = local_player_initiated_game;
+        self.rematch_offer = None;
+        self.winning_locations = None;
+    }
+
     /// Clears all fields of this instance.
     pub(crate) fn reset(&mut self) {
         //
@@ -99,3 +129,32 @@ impl AppStateResource {
         self.local_player.display_name = name;
     }
 }
+
+/// Tallies the outcomes of the Games played in a Gaming Session.
+#[derive(Clone, Default)]
+pub(crate) struct SeriesScore {
+    /// Number of Games won by the local Player
+    pub(crate) local_player_wins: u32,
+    /// Number of Games won by the other Player
+    pub(crate) other_player_wins: u32,
+    /// Number of Games that ended in a stalemate
+    pub(crate) stalemates: u32,
+}
+
+impl SeriesScore {
+    //
+
+    pub(crate) fn games_played(&self) -> u32 {
+        self.local_player_wins + self.other_player_wins + self.stalemates
+    }
+
+    /// Records the outcome of a Game. The local_player_won flag is ignored for stalemates.
+    pub(crate) fn record(&mut self, play_status: PlayStatus, local_player_won: bool) {
+        match play_status {
+            PlayStatus::EndedInStalemate => self.stalemates += 1,
+            PlayStatus::EndedInWin if local_player_won => self.local_player_wins += 1,
+            PlayStatus::EndedInWin => self.other_player_wins += 1,
+            PlayStatus::InProgress | PlayStatus::NotStarted => {}
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use clap::Parser;
+
+use crate::shared::app_settings_resource::{AppSettingsResource, POLL_INTERVAL_RANGE_MS};
+
+/// Plays Tic-Tac-Toe. Arguments override the settings saved via the Settings Screen.
+#[derive(Parser)]
+#[command(name = "tic_tac_toe_bevy", version, about)]
+pub(crate) struct CliArgs {
+    //
+
+    /// Base URL of the Tic-Tac-Toe service, e.g. http://127.0.0.1:50020
+    #[arg(long, env = "TIC_TAC_TOE_SERVER", value_parser = parse_server_url)]
+    pub(crate) server: Option<String>,
+
+    /// How often, in milliseconds, the service is polled while the event plane is unreachable.
+    #[arg(long, value_parser = parse_poll_interval_ms)]
+    pub(crate) poll_interval_ms: Option<u64>,
+
+    /// Display name of the local Player.
+    #[arg(long)]
+    pub(crate) name: Option<String>,
+
+    /// Language in which to show the app's text, e.g. en, es or fr.
+    #[arg(long)]
+    pub(crate) language: Option<String>,
+
+    /// Writes the app's translations to the specified Xcode String Catalog, e.g.
+    /// Localizable.xcstrings, for use by the Swift app, and then exits.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) export_string_catalog: Option<std::path::PathBuf>,
+}
+
+fn parse_server_url(value: &str) -> Result<String, String> {
+    match AppSettingsResource::is_valid_server_url(value) {
+        true => Ok(value.to_string()),
+        false => Err("expected an http:// or https:// URL, e.g. http://127.0.0.1:50020".to_string()),
+    }
+}
+
+fn parse_poll_interval_ms(value: &str) -> Result<u64, String> {
+    let out_of_range = || format!("expected between {} and {} ms", POLL_INTERVAL_RANGE_MS.start(), POLL_INTERVAL_RANGE_MS.end());
+    let poll_interval_ms = value.parse::<u64>().map_err(|_| out_of_range())?;
+    match POLL_INTERVAL_RANGE_MS.contains(&poll_interval_ms) {
+        true => Ok(poll_interval_ms),
+        false => Err(out_of_range()),
+    }
+}
@@ -6,9 +6,11 @@
 use bevy::prelude::{Color, Commands, Component, Entity, Query, With};
 use std::sync::LazyLock;
 
+pub(crate) mod api_helpers;
 pub(crate) mod app_mode;
+pub(crate) mod app_settings_resource;
 pub(crate) mod app_state_resource;
-pub(crate) mod api_helpers;
+pub(crate) mod cli_args;
 
 pub(crate) static BACKGROUND_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("521c93").unwrap() });
 pub(crate) static FOREGROUND_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::hex("ff7e79").unwrap() });
@@ -19,6 +21,9 @@ pub(crate) static TEXT_COLOR: LazyLock<Color> = LazyLock::new(|| { Color::WHITE
 
 pub(crate) const FONT_SIZE: f32 = 15.0;
 
+/// The longest Player display name that the service accepts.
+pub(crate) const PLAYER_NAME_MAX_LENGTH: usize = 40;
+
 /// Helper function to de-spawn all components of the specified type.
 pub(crate) fn despawn<T: Component>(components: Query<Entity, With<T>>, mut commands: Commands) {
     for component in &components {
@@ -12,6 +12,7 @@ use crate::shared::despawn;
 #[derive(Clone)]
 enum ButtonPurpose {
     AcceptInvitation,
+    OpenSettings,
     StartTwoPlayerGame,
     StartSinglePlayerGame,
 }
@@ -20,10 +21,6 @@ enum ButtonPurpose {
 #[derive(Component)]
 struct OnStartScreen;
 
-/// Companion component for Startup Screen Player name display text.
-#[derive(Component)]
-struct PlayerNameLabelComponent;
-
 /// Provides UI and functionality for the Game's Startup Screen.
 pub(crate) struct StartScreenPlugin;
 
@@ -39,7 +36,7 @@ impl Plugin for StartScreenPlugin {
             )
             .add_systems(
                 Update,
-                (functionality::button_interaction, functionality::text_input)
+                (functionality::button_interaction, functionality::update_player_name)
                     .run_if(in_state(AppMode::StartMenu)),
             )
             .add_systems(OnExit(AppMode::StartMenu), despawn::<OnStartScreen>);
@@ -50,16 +47,18 @@ mod functionality {
     use std::time::Duration;
 
     use bevy::prelude::{
-        BackgroundColor, Button, ButtonInput, Changed, EventReader, EventWriter, Interaction,
-        KeyCode, NextState, Query, ReceivedCharacter, Res, ResMut, Text, With,
+        BackgroundColor, Button, Changed, EventReader, EventWriter, Interaction, NextState, Query,
+        Res, ResMut, With,
     };
     use helpers_for_bevy::entity_info_component::EntityInfoComponent;
     use helpers_for_bevy::status_text::events::SetStatusTextEvent;
+    use helpers_for_bevy::text_input::events::TextInputChangedEvent;
 
+    use crate::localization::localization_resource::LocalizationResource;
     use crate::shared::app_mode::AppMode;
     use crate::shared::app_state_resource::AppStateResource;
     use crate::shared::{BUTTON_COLOR_HOVERED, BUTTON_COLOR_NORMAL, BUTTON_COLOR_PRESSED};
-    use crate::start_screen::start_screen_plugin::{ButtonPurpose, PlayerNameLabelComponent};
+    use crate::start_screen::start_screen_plugin::ButtonPurpose;
 
     /// Provides button functionality, including state changes as well as response when clicked.
     #[allow(clippy::type_complexity)] // The query is complex by necessity.
@@ -74,6 +73,7 @@ mod functionality {
             ),
             (Changed<Interaction>, With<Button>),
         >,
+        localization: Res<LocalizationResource>,
         mut next_state: ResMut<NextState<AppMode>>,
     ) {
         for (interaction, mut color, button_info) in &mut interactions {
@@ -89,6 +89,12 @@ mod functionality {
 
                     *color = BackgroundColor(*BUTTON_COLOR_PRESSED);
 
+                    // The Settings can be changed without a Player Name.
+                    if let ButtonPurpose::OpenSettings = button_info.get_purpose() {
+                        next_state.set(AppMode::Settings);
+                        continue;
+                    }
+
                     // Validate the Player Name entry...
                     if !app_state.local_player.display_name.trim().is_empty() {
                         // Which button was pressed?
@@ -97,6 +103,7 @@ mod functionality {
                                 app_state.local_player_initiated_gaming_session = false;
                                 next_state.set(AppMode::EnterInvitation);
                             }
+                            ButtonPurpose::OpenSettings => {}
                             ButtonPurpose::StartTwoPlayerGame => {
                                 // Reflect the fact that this local Player is the one who initiated the Game.
                                 app_state.local_player_initiated_gaming_session = true;
@@ -113,7 +120,7 @@ mod functionality {
                     } else {
                         // Ask the user to enter their display name.
                         let event = SetStatusTextEvent::new_with_duration(
-                            "Please enter your name.",
+                            localization.text("start-name-needed"),
                             Duration::from_secs(10),
                         );
                         event_writer.send(event);
@@ -138,44 +145,13 @@ mod functionality {
         app_state.reset();
     }
 
-    /// Provides keyboard input and rudimentary editing for the Player display name text field.
-    pub(super) fn text_input(
+    /// Keeps the local Player's display name in step with the Player name text field.
+    pub(super) fn update_player_name(
         mut app_state: ResMut<AppStateResource>,
-        keyboard_input: Res<ButtonInput<KeyCode>>,
-        mut event_reader: EventReader<ReceivedCharacter>,
-        mut player_name_label_text: Query<&mut Text, With<PlayerNameLabelComponent>>,
+        mut event_reader: EventReader<TextInputChangedEvent>,
     ) {
-        //
-
-        if keyboard_input.just_pressed(KeyCode::Backspace)
-            || keyboard_input.just_pressed(KeyCode::Delete)
-        {
-            // Character removal
-            let len = app_state.local_player.display_name.len();
-            if len > 0 {
-                app_state.local_player.display_name.pop();
-            }
-        } else {
-            //
-
-            // Add the characters, ignoring non-alphanumeric characters.
-            for received_char in event_reader.read() {
-                let char = received_char
-                    .char
-                    .to_string()
-                    .chars()
-                    .next()
-                    .unwrap_or_default();
-
-                if char.is_alphanumeric() {
-                    app_state.local_player.display_name.push(char);
-                }
-            }
-        }
-
-        // Update the UI with the latest edits.
-        if let Ok(mut label) = player_name_label_text.get_single_mut() {
-            label.sections[0].value = app_state.local_player.display_name.clone();
+        for event in event_reader.read() {
+            app_state.local_player.display_name = event.value.clone();
         }
     }
 }
@@ -183,24 +159,33 @@ mod functionality {
 mod ui {
     use bevy::prelude::{
         default, AlignItems, BackgroundColor, BorderColor, BuildChildren, ButtonBundle, Color,
-        Commands, FlexDirection, JustifyContent, JustifySelf, NodeBundle, Style, TextBundle,
+        Commands, FlexDirection, JustifyContent, JustifySelf, NodeBundle, Res, Style, TextBundle,
         TextSection, TextStyle, UiRect, Val,
     };
     use helpers_for_bevy::entity_info_component::EntityInfoComponent;
+    use helpers_for_bevy::text_input::text_input_component::{TextInputBundle, TextInputComponent, TextInputFilter};
+
+    use crate::localization::localization_resource::LocalizationResource;
+    use crate::localization::LocalizedTextComponent;
 
-    use crate::shared::{BUTTON_COLOR_NORMAL, FONT_SIZE, TEXT_COLOR};
-    use crate::start_screen::start_screen_plugin::ButtonPurpose::{AcceptInvitation, StartSinglePlayerGame, StartTwoPlayerGame};
-    use crate::start_screen::start_screen_plugin::{OnStartScreen, PlayerNameLabelComponent};
+    use crate::shared::app_state_resource::AppStateResource;
+    use crate::shared::{BUTTON_COLOR_NORMAL, FONT_SIZE, PLAYER_NAME_MAX_LENGTH, TEXT_COLOR};
+    use crate::start_screen::start_screen_plugin::ButtonPurpose::{AcceptInvitation, OpenSettings, StartSinglePlayerGame, StartTwoPlayerGame};
+    use crate::start_screen::start_screen_plugin::OnStartScreen;
 
     /// Sets up and then spawns the Start Screen UI widgets.
-    pub(super) fn spawn_buttons(mut commands: Commands) {
+    pub(super) fn spawn_buttons(
+        app_state: Res<AppStateResource>,
+        mut commands: Commands,
+        localization: Res<LocalizationResource>,
+    ) {
         //
 
-        // TODO: JD: localize this text
-        let title_start_two_player = "Two-Player Game";
-        let title_start_single_player = "Single-Player Game";
-        let title_invitation = "Accept An Invitation";
-        let title_instructions = "Please type your name:";
+        let title_start_two_player = "start-two-player-game";
+        let title_start_single_player = "start-single-player-game";
+        let title_invitation = "start-accept-invitation";
+        let title_settings = "start-settings";
+        let title_instructions = "start-instructions";
 
         let text_style = TextStyle {
             font: default(),
@@ -209,7 +194,7 @@ mod ui {
         };
 
         // Instructions Label
-        let sections = [TextSection::new(title_instructions, text_style.clone())];
+        let sections = [TextSection::new(localization.text(title_instructions), text_style.clone())];
         let label_style = Style {
             display: Default::default(),
             justify_self: JustifySelf::Center,
@@ -219,15 +204,19 @@ mod ui {
             TextBundle::from_sections(sections.clone()).with_style(label_style);
 
         // Player Name
-        let sections2 = [TextSection::new("", text_style.clone())];
+        let player_name = TextInputComponent::new(text_style.clone())
+            .with_filter(TextInputFilter::Alphanumeric)
+            .with_focus()
+            .with_max_length(PLAYER_NAME_MAX_LENGTH)
+            .with_placeholder(localization.text("start-name-placeholder"))
+            .with_value(&app_state.local_player.display_name);
         let label_style = Style {
             border: UiRect::all(Val::Px(2.)),
             display: Default::default(),
             justify_self: JustifySelf::Center,
             ..default()
         };
-        let player_name_bundle =
-            TextBundle::from_sections(sections2.clone()).with_style(label_style);
+        let player_name_bundle = TextInputBundle::new(player_name).with_style(label_style);
 
         // Button style
         let button_bundle = ButtonBundle {
@@ -278,8 +267,8 @@ mod ui {
                         OnStartScreen,
                     ))
                     .with_children(|parent| {
-                        parent.spawn((instructions_label_bundle, OnStartScreen));
-                        parent.spawn((player_name_bundle, OnStartScreen, PlayerNameLabelComponent));
+                        parent.spawn((instructions_label_bundle, LocalizedTextComponent(title_instructions), OnStartScreen));
+                        parent.spawn((player_name_bundle, OnStartScreen));
                     });
                 parent
                     // The Buttons
@@ -307,7 +296,8 @@ mod ui {
                             ))
                             .with_children(|parent| {
                                 parent.spawn((
-                                    TextBundle::from_section(title_start_two_player, text_style.clone()),
+                                    TextBundle::from_section(localization.text(title_start_two_player), text_style.clone()),
+                                    LocalizedTextComponent(title_start_two_player),
                                     OnStartScreen,
                                 ));
                             });
@@ -319,19 +309,34 @@ mod ui {
                             ))
                             .with_children(|parent| {
                                 parent.spawn((
-                                    TextBundle::from_section(title_start_single_player, text_style.clone()),
+                                    TextBundle::from_section(localization.text(title_start_single_player), text_style.clone()),
+                                    LocalizedTextComponent(title_start_single_player),
                                     OnStartScreen,
                                 ));
                             });
                         parent
                             .spawn((
-                                button_bundle,
+                                button_bundle.clone(),
                                 EntityInfoComponent::new(AcceptInvitation),
                                 OnStartScreen,
                             ))
                             .with_children(|parent| {
                                 parent.spawn((
-                                    TextBundle::from_section(title_invitation, text_style.clone()),
+                                    TextBundle::from_section(localization.text(title_invitation), text_style.clone()),
+                                    LocalizedTextComponent(title_invitation),
+                                    OnStartScreen,
+                                ));
+                            });
+                        parent
+                            .spawn((
+                                button_bundle,
+                                EntityInfoComponent::new(OpenSettings),
+                                OnStartScreen,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    TextBundle::from_section(localization.text(title_settings), text_style.clone()),
+                                    LocalizedTextComponent(title_settings),
                                     OnStartScreen,
                                 ));
                             });
@@ -0,0 +1,263 @@
+//  Tic-Tac-Toe Bevy Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod app_settings_tests {
+    use crate::shared::app_settings_resource::AppSettingsResource;
+
+    #[test]
+    fn test_invalid_persisted_values_fall_back_to_defaults() {
+        //
+
+        let settings = AppSettingsResource::new("localhost:50020", 0, "Joel", "en-US").with_defaults_for_invalid_values();
+        assert_eq!(settings.server_url(), "http://127.0.0.1:50020");
+        assert_eq!(settings.poll_interval_ms(), 500);
+        assert_eq!(settings.display_name(), "Joel");
+
+        let settings = AppSettingsResource::new("https://example.com/", 250, "", "en-US").with_defaults_for_invalid_values();
+        assert_eq!(settings.server_url(), "https://example.com");
+        assert_eq!(settings.poll_interval_ms(), 250);
+    }
+
+    #[test]
+    fn test_server_urls() {
+        //
+
+        assert!(AppSettingsResource::is_valid_server_url("http://127.0.0.1:50020"));
+        assert!(AppSettingsResource::is_valid_server_url("https://example.com"));
+
+        assert!(!AppSettingsResource::is_valid_server_url(""));
+        assert!(!AppSettingsResource::is_valid_server_url("http://"));
+        assert!(!AppSettingsResource::is_valid_server_url("127.0.0.1:50020"));
+        assert!(!AppSettingsResource::is_valid_server_url("ftp://example.com"));
+    }
+}
+
+#[cfg(test)]
+mod cli_args_tests {
+    use crate::shared::cli_args::CliArgs;
+    use clap::Parser;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, clap::Error> {
+        CliArgs::try_parse_from(["tic_tac_toe_bevy"].iter().chain(args))
+    }
+
+    #[test]
+    fn test_valid_arguments() {
+        //
+
+        let cli_args = parse(&["--server", "http://example.com:50020", "--poll-interval-ms", "100"]).unwrap();
+        assert_eq!(cli_args.server.as_deref(), Some("http://example.com:50020"));
+        assert_eq!(cli_args.poll_interval_ms, Some(100));
+    }
+
+    #[test]
+    fn test_invalid_arguments_are_rejected() {
+        //
+
+        assert!(parse(&["--poll-interval-ms", "0"]).is_err());
+        assert!(parse(&["--poll-interval-ms", "60001"]).is_err());
+        assert!(parse(&["--poll-interval-ms", "fast"]).is_err());
+        assert!(parse(&["--server", "example.com"]).is_err());
+        assert!(parse(&["--server", "http://"]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod series_score_tests {
+    use crate::shared::app_state_resource::SeriesScore;
+    use tic_tac_toe_rust_client_sdk::models::PlayStatus;
+
+    #[test]
+    fn test_record() {
+        //
+
+        let mut score = SeriesScore::default();
+        assert_eq!(score.games_played(), 0);
+
+        score.record(PlayStatus::EndedInWin, true);
+        score.record(PlayStatus::EndedInWin, false);
+        score.record(PlayStatus::EndedInWin, false);
+        score.record(PlayStatus::EndedInStalemate, true);
+
+        assert_eq!(score.local_player_wins, 1);
+        assert_eq!(score.other_player_wins, 2);
+        assert_eq!(score.stalemates, 1);
+        assert_eq!(score.games_played(), 4);
+    }
+
+    #[test]
+    fn test_unfinished_games_are_not_recorded() {
+        //
+
+        let mut score = SeriesScore::default();
+        score.record(PlayStatus::InProgress, true);
+        score.record(PlayStatus::NotStarted, false);
+
+        assert_eq!(score.games_played(), 0);
+    }
+}
+
+#[cfg(test)]
+mod localization_tests {
+    use crate::localization::{language_name, negotiate_language};
+
+    #[test]
+    fn test_negotiate_language() {
+        //
+
+        assert_eq!(negotiate_language("es"), "es-ES");
+        assert_eq!(negotiate_language("es-MX"), "es-ES");
+        assert_eq!(negotiate_language("fr-CA"), "fr-FR");
+        assert_eq!(negotiate_language("en-GB"), "en-US");
+    }
+
+    #[test]
+    fn test_negotiate_posix_language() {
+        //
+
+        assert_eq!(negotiate_language("es_MX.UTF-8"), "es-ES");
+        assert_eq!(negotiate_language("fr_FR@euro"), "fr-FR");
+        assert_eq!(negotiate_language("C"), "en-US");
+    }
+
+    #[test]
+    fn test_unsupported_languages_fall_back_to_english() {
+        //
+
+        assert_eq!(negotiate_language("de-DE"), "en-US");
+        assert_eq!(negotiate_language(""), "en-US");
+        assert_eq!(negotiate_language("not a language"), "en-US");
+        assert_eq!(language_name("es-ES"), "Español");
+    }
+}
+
+#[cfg(test)]
+mod string_catalog_tests {
+    use crate::localization::string_catalog::{build_catalog, export, messages_file};
+    use crate::localization::SUPPORTED_LANGUAGES;
+    use fluent_syntax::ast::Entry;
+    use fluent_syntax::parser;
+    use serde_json::{json, Value};
+    use std::fs;
+
+    const ENGLISH: &str = r#"
+game-won-by = { $name } won. Better luck next time.
+game-score = { $winner } beat { $loser }
+game-series-stalemates = { $count ->
+        [one] (1 stalemate)
+       *[other] ({ $count } stalemates)
+    }
+game-discount = 50% off
+"#;
+
+    const SPANISH: &str = r#"
+game-won-by = { $name } ganó. Suerte la próxima vez.
+game-score = { $loser } perdió contra { $winner }
+game-series-stalemates = { $count ->
+        [one] (1 empate)
+       *[other] ({ $count } empates)
+    }
+"#;
+
+    fn build() -> Value {
+        build_catalog(&[("en-US", ENGLISH), ("es-ES", SPANISH)]).unwrap()
+    }
+
+    fn translated(value: &str) -> Value {
+        json!({ "stringUnit": { "state": "translated", "value": value } })
+    }
+
+    #[test]
+    fn test_text_variables_become_object_specifiers() {
+        //
+
+        let catalog = build();
+        let string = &catalog["strings"]["%@ won. Better luck next time."];
+
+        assert_eq!(string["comment"], "game-won-by");
+        assert_eq!(string["localizations"], json!({ "es": translated("%@ ganó. Suerte la próxima vez.") }));
+    }
+
+    #[test]
+    fn test_several_variables_become_positional_specifiers() {
+        //
+
+        let catalog = build();
+        let localizations = &catalog["strings"]["%@ beat %@"]["localizations"];
+
+        assert_eq!(localizations["es"], translated("%2$@ perdió contra %1$@"));
+    }
+
+    #[test]
+    fn test_plurals() {
+        //
+
+        let catalog = build();
+        let localizations = &catalog["strings"]["(%lld stalemates)"]["localizations"];
+
+        // The English text is listed for its plural forms.
+        assert_eq!(localizations["en"]["variations"]["plural"], json!({
+            "one": translated("(1 stalemate)"),
+            "other": translated("(%lld stalemates)"),
+        }));
+        assert_eq!(localizations["es"]["variations"]["plural"], json!({
+            "one": translated("(1 empate)"),
+            "other": translated("(%lld empates)"),
+        }));
+    }
+
+    #[test]
+    fn test_percent_signs_are_escaped() {
+        //
+
+        let catalog = build();
+
+        // Untranslated text falls back to the English text.
+        assert_eq!(catalog["strings"]["50%% off"]["localizations"], json!({}));
+        assert_eq!(catalog["sourceLanguage"], "en");
+    }
+
+    #[test]
+    fn test_every_message_is_translated() {
+        //
+
+        let message_ids = |language: &str| -> Vec<String> {
+            let source = fs::read_to_string(messages_file(language)).unwrap();
+            parser::parse(source.as_str())
+                .unwrap()
+                .body
+                .into_iter()
+                .filter_map(|entry| match entry {
+                    Entry::Message(message) => Some(message.id.name.to_string()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let english = message_ids("en-US");
+        assert!(!english.is_empty());
+
+        for (language, _) in SUPPORTED_LANGUAGES {
+            let translated = message_ids(language);
+            let missing: Vec<_> = english.iter().filter(|id| !translated.contains(id)).collect();
+            assert!(missing.is_empty(), "{language} is missing {missing:?}");
+        }
+    }
+
+    #[test]
+    fn test_export() {
+        //
+
+        let path = std::env::temp_dir().join(format!("tic-tac-toe-{}.xcstrings", std::process::id()));
+
+        let count = export(&path).unwrap();
+        let catalog: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(catalog["strings"].as_object().unwrap().len(), count);
+        assert!(count > 0);
+    }
+}
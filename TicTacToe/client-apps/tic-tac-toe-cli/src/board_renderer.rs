@@ -0,0 +1,50 @@
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, GameState};
+
+/// Renders Game Boards as text.
+pub(crate) struct BoardRenderer;
+
+impl BoardRenderer {
+    //
+
+    /// Renders the Game Board of the specified Game State, with row and column numbers. Pieces on
+    /// the winning line, if any, are shown in brackets.
+    pub(crate) fn render(game_state: &GameState, winning_locations: &[BoardPosition]) -> String {
+        //
+
+        let column_count = game_state.game_board.first().map(|row| row.len()).unwrap_or_default();
+
+        let mut lines = vec![format!("   {}", (0..column_count).map(|column| format!(" {column} ")).collect::<Vec<_>>().join(" "))];
+
+        for (row_index, row) in game_state.game_board.iter().enumerate() {
+            if row_index > 0 {
+                lines.push(format!("   {}", vec!["---"; column_count].join("+")));
+            }
+            let cells: Vec<String> = row.iter().enumerate().map(|(column_index, game_piece)| {
+                let is_winning = winning_locations.iter().any(|location| {
+                    location.row == row_index as i32 && location.column == column_index as i32
+                });
+                let symbol = Self::symbol(game_piece);
+                match is_winning {
+                    true => format!("[{symbol}]"),
+                    false => format!(" {symbol} "),
+                }
+            }).collect();
+            lines.push(format!("{row_index}  {}", cells.join("|")));
+        }
+
+        lines.join("\n")
+    }
+
+    fn symbol(game_piece: &GamePiece) -> &'static str {
+        match game_piece {
+            GamePiece::Unselected => " ",
+            GamePiece::X => "X",
+            GamePiece::O => "O",
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use tic_tac_toe_rust_client_sdk::models::AutomaticPlayerSkillLevel;
+
+/// Plays, watches and inspects Tic-Tac-Toe Games from the command line.
+#[derive(Parser)]
+#[command(name = "tic-tac-toe-cli", version, about)]
+pub(crate) struct CliArgs {
+    //
+
+    /// Base URL of the Tic-Tac-Toe service.
+    #[arg(long, env = "TIC_TAC_TOE_SERVER", default_value = "http://127.0.0.1:50020", global = true)]
+    pub(crate) server: String,
+
+    #[command(subcommand)]
+    pub(crate) command: CliCommand,
+}
+
+/// Enumerates the CLI's commands.
+#[derive(Subcommand)]
+pub(crate) enum CliCommand {
+    //
+
+    /// Creates a new Gaming Session and plays its first Game.
+    Create {
+        /// Display name of the local Player.
+        #[arg(long)]
+        name: String,
+
+        /// Plays against the computer, at the specified skill level, instead of an invited Player.
+        #[arg(long, value_enum)]
+        computer: Option<SkillLevel>,
+
+        /// Takes the moves from the specified file instead of prompting for them.
+        #[arg(long)]
+        moves: Option<PathBuf>,
+    },
+
+    /// Joins a Gaming Session via its invitation code and plays its current Game.
+    Join {
+        /// Invitation code shared by the Player who created the Gaming Session.
+        #[arg(long)]
+        code: String,
+
+        /// Display name of the local Player.
+        #[arg(long)]
+        name: String,
+
+        /// Takes the moves from the specified file instead of prompting for them.
+        #[arg(long)]
+        moves: Option<PathBuf>,
+    },
+
+    /// Watches a Gaming Session's current Game live.
+    Watch {
+        /// ID of the Gaming Session to watch.
+        #[arg(long)]
+        session_id: String,
+
+        /// Topic prefix of the Gaming Session's event plane. The service is polled when omitted.
+        #[arg(long, requires = "broker_address")]
+        topic_prefix: Option<String>,
+
+        /// Address of the event plane's MQTT broker.
+        #[arg(long, requires = "topic_prefix")]
+        broker_address: Option<String>,
+
+        /// Port of the event plane's MQTT broker.
+        #[arg(long, default_value_t = 1883)]
+        broker_port: u16,
+    },
+
+    /// Prints the history of a Game's states as JSON.
+    History {
+        /// ID of the Game.
+        #[arg(long)]
+        game_id: String,
+    },
+}
+
+/// Mirrors the SDK's AutomaticPlayerSkillLevel so that it can be parsed from the command line.
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum SkillLevel {
+    Beginner,
+    Intermediate,
+    Expert,
+    Master,
+}
+
+impl From<SkillLevel> for AutomaticPlayerSkillLevel {
+    fn from(skill_level: SkillLevel) -> Self {
+        match skill_level {
+            SkillLevel::Beginner => AutomaticPlayerSkillLevel::Beginner,
+            SkillLevel::Intermediate => AutomaticPlayerSkillLevel::Intermediate,
+            SkillLevel::Expert => AutomaticPlayerSkillLevel::Expert,
+            SkillLevel::Master => AutomaticPlayerSkillLevel::Master,
+        }
+    }
+}
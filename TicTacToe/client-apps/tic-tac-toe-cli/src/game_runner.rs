@@ -0,0 +1,204 @@
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+use tic_tac_toe_rust_client_sdk::apis::tic_tac_toe_api;
+use tic_tac_toe_rust_client_sdk::game_client::GameClient;
+use tic_tac_toe_rust_client_sdk::game_client_event::GameClientEvent;
+use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, GameState, PlayStatus, PlayerInfo, TurnResponse};
+
+use crate::board_renderer::BoardRenderer;
+use crate::move_script::MoveScript;
+
+/// How long to wait for a Game change before checking again.
+const EVENT_WAIT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Plays a Game to its end, taking the local Player's moves from a MoveScript or, when there is
+/// none, from the terminal.
+pub(crate) struct GameRunner {
+    client: GameClient,
+    configuration: Configuration,
+    current_player: Option<PlayerInfo>,
+    game_state: GameState,
+    move_script: Option<MoveScript>,
+    winning_locations: Vec<BoardPosition>,
+    winning_player: Option<PlayerInfo>,
+}
+
+impl GameRunner {
+    //
+
+    /// Creates a new instance for the GameClient's current Game.
+    pub(crate) fn new(client: GameClient, configuration: Configuration, move_script: Option<MoveScript>) -> Self {
+        Self {
+            client,
+            configuration,
+            current_player: None,
+            game_state: GameState::default(),
+            move_script,
+            winning_locations: vec![],
+            winning_player: None,
+        }
+    }
+
+    /// Plays the Game until it ends.
+    pub(crate) fn run(mut self) -> Result<(), String> {
+        //
+
+        self.wait_for_opponent()?;
+        self.load_latest_turn()?;
+
+        loop {
+            println!("\n{}\n", BoardRenderer::render(&self.game_state, &self.winning_locations));
+
+            match self.game_state.play_status {
+                PlayStatus::EndedInWin | PlayStatus::EndedInStalemate => {
+                    println!("{}", self.outcome_text());
+                    return Ok(());
+                }
+                PlayStatus::InProgress | PlayStatus::NotStarted => {}
+            }
+
+            if self.is_local_players_turn() {
+                self.take_turn()?;
+            } else {
+                if let Some(current_player) = &self.current_player {
+                    println!("Waiting for {} to play...", current_player.display_name);
+                }
+                self.wait_for_turn()?;
+            }
+        }
+    }
+
+    fn wait_for_opponent(&mut self) -> Result<(), String> {
+        //
+
+        if self.client.opponent().is_some() {
+            return Ok(()); // Early return. The other Player is already in the Game.
+        }
+
+        println!("Waiting for the other Player to join...");
+        loop {
+            match self.client.next_event(EVENT_WAIT_INTERVAL) {
+                Some(GameClientEvent::OpponentJoined(opponent)) => {
+                    println!("{} has joined! Let the game begin!", opponent.display_name);
+                    return Ok(());
+                }
+                Some(GameClientEvent::GameDeleted) => return Err("The Game has been deleted.".to_string()),
+                Some(GameClientEvent::SessionDeleted) => return Err("The Gaming Session has been deleted.".to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    fn wait_for_turn(&mut self) -> Result<(), String> {
+        loop {
+            match self.client.next_event(EVENT_WAIT_INTERVAL) {
+                // Events reported before the latest Turn was loaded are stale and are skipped.
+                Some(GameClientEvent::TurnTaken(turn)) | Some(GameClientEvent::GameEnded(turn))
+                if Self::piece_count(&turn.new_game_state) > Self::piece_count(&self.game_state) => {
+                    self.apply_turn(turn);
+                    return Ok(());
+                }
+                Some(GameClientEvent::GameDeleted) => return Err("The Game has been deleted.".to_string()),
+                Some(GameClientEvent::SessionDeleted) => return Err("The Gaming Session has been deleted.".to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    fn take_turn(&mut self) -> Result<(), String> {
+        loop {
+            let position = self.next_move()?;
+            match self.client.play(position.row, position.column) {
+                Ok(turn) => {
+                    self.apply_turn(turn);
+                    return Ok(());
+                }
+                Err(error) => {
+                    let message = error.describe_rejected_move();
+                    // A scripted Game cannot recover from a bad move.
+                    if self.move_script.is_some() {
+                        return Err(message);
+                    }
+                    println!("{}", message);
+                }
+            }
+        }
+    }
+
+    fn next_move(&mut self) -> Result<BoardPosition, String> {
+        //
+
+        if let Some(move_script) = &mut self.move_script {
+            return move_script.next_move().ok_or_else(|| "The move file has run out of moves.".to_string());
+        }
+
+        let local_player = self.client.local_player();
+        loop {
+            print!("Your move, {} ({}). Enter row and column: ", local_player.display_name, local_player.game_piece);
+            let _ = io::stdout().flush();
+
+            let mut line = String::new();
+            match io::stdin().lock().read_line(&mut line) {
+                Ok(0) => return Err("Input closed before the Game ended.".to_string()),
+                Ok(_) => {}
+                Err(error) => return Err(format!("Unable to read the move: {}", error)),
+            }
+
+            match MoveScript::parse(&line).map(|mut moves| moves.next_move()) {
+                Ok(Some(position)) => return Ok(position),
+                Ok(None) => {}
+                Err(_) => println!("Please enter the row and column, e.g. 1 2"),
+            }
+        }
+    }
+
+    fn load_latest_turn(&mut self) -> Result<(), String> {
+        let game_id = self.client.game_id().ok_or("No Game has been created or joined.")?;
+        let turn = tic_tac_toe_api::get_latest_game_turn(&self.configuration, game_id)
+            .map_err(|error| format!("Unable to retrieve the Game: {}", error))?;
+        self.apply_turn(turn);
+        Ok(())
+    }
+
+    fn apply_turn(&mut self, turn: TurnResponse) {
+        self.current_player = turn.current_player.flatten();
+        self.game_state = turn.new_game_state;
+        self.winning_locations = turn.winning_locations.flatten().unwrap_or_default();
+        self.winning_player = turn.winning_player.flatten();
+    }
+
+    fn piece_count(game_state: &GameState) -> usize {
+        game_state.game_board.iter().flatten().filter(|game_piece| **game_piece != GamePiece::Unselected).count()
+    }
+
+    fn is_local_players_turn(&self) -> bool {
+        self.current_player.as_ref()
+            .is_some_and(|current_player| current_player.player_id == self.client.local_player().player_id)
+    }
+
+    fn outcome_text(&self) -> String {
+        //
+
+        if self.game_state.play_status == PlayStatus::EndedInStalemate {
+            return "The Game has ended in a stalemate.".to_string();
+        }
+
+        let local_player = self.client.local_player();
+        let winner_id = self.winning_player.as_ref()
+            .map(|winner| winner.player_id.clone())
+            .unwrap_or_else(|| self.game_state.id_of_player_who_made_move.clone());
+        if winner_id == local_player.player_id {
+            "You won!".to_string()
+        } else {
+            let winner_name = self.client.opponent().map(|opponent| opponent.display_name.clone()).unwrap_or_default();
+            format!("{} won!", winner_name)
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+use tic_tac_toe_rust_client_sdk::apis::tic_tac_toe_api;
+use tic_tac_toe_rust_client_sdk::game_client::GameClient;
+use tic_tac_toe_rust_client_sdk::models::EventPlaneConfig;
+
+use crate::cli_args::{CliArgs, CliCommand};
+use crate::game_runner::GameRunner;
+use crate::move_script::MoveScript;
+use crate::spectator::Spectator;
+
+mod board_renderer;
+mod cli_args;
+mod game_runner;
+mod move_script;
+mod spectator;
+mod tests;
+
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// App entry point.
+fn main() -> ExitCode {
+    //
+
+    let args = CliArgs::parse();
+
+    let configuration = Configuration {
+        base_path: args.server.clone(),
+        user_agent: Some("Tic-Tac-Toe CLI".to_string()),
+        ..Default::default()
+    };
+
+    match run(args.command, configuration) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: CliCommand, configuration: Configuration) -> Result<(), String> {
+    match command {
+        CliCommand::Create { name, computer, moves } => {
+            //
+
+            let move_script = moves.map(|path| MoveScript::from_file(&path)).transpose()?;
+
+            let mut client = GameClient::create_session(configuration.clone(), &name)
+                .map_err(|error| format!("Unable to create the Gaming Session: {}", error))?;
+
+            match computer {
+                Some(skill_level) => client.start_single_player_game(skill_level.into()),
+                None => client.start_two_player_game(),
+            }.map_err(|error| format!("Unable to start the Game: {}", error))?;
+
+            if computer.is_none() {
+                println!("Invitation code: {}", client.invitation_code());
+                print_watch_command(client.session_id(), client.event_plane_config());
+            }
+
+            GameRunner::new(client, configuration, move_script).run()
+        }
+        CliCommand::Join { code, name, moves } => {
+            //
+
+            let move_script = moves.map(|path| MoveScript::from_file(&path)).transpose()?;
+
+            let mut client = GameClient::join_session(configuration.clone(), &code, &name)
+                .map_err(|error| format!("Unable to join the Gaming Session: {}", error))?;
+            client.join_current_game()
+                .map_err(|error| format!("Unable to join the Game: {}", error))?;
+
+            GameRunner::new(client, configuration, move_script).run()
+        }
+        CliCommand::Watch { session_id, topic_prefix, broker_address, broker_port } => {
+            //
+
+            let event_plane_config = match (topic_prefix, broker_address) {
+                (Some(topic_prefix), Some(broker_address)) => {
+                    Some(EventPlaneConfig::new(broker_address, broker_port.into(), String::new(), topic_prefix))
+                }
+                _ => None,
+            };
+
+            Spectator::watch(&configuration, &session_id, event_plane_config)
+        }
+        CliCommand::History { game_id } => {
+            //
+
+            let history = tic_tac_toe_api::get_game_history(&configuration, &game_id)
+                .map_err(|error| format!("Unable to retrieve the Game history: {}", error))?;
+            let json = serde_json::to_string_pretty(&history)
+                .map_err(|error| format!("Unable to format the Game history: {}", error))?;
+            println!("{}", json);
+            Ok(())
+        }
+    }
+}
+
+/// Tells the user how others can follow the Game live.
+fn print_watch_command(session_id: &str, event_plane_config: &EventPlaneConfig) {
+    println!("Spectators can watch with: tic-tac-toe-cli watch --session-id {} --topic-prefix {} --broker-address {} --broker-port {}",
+             session_id,
+             event_plane_config.topic_prefix,
+             event_plane_config.broker_address,
+             event_plane_config.broker_port);
+}
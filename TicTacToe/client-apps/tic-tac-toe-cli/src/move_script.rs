@@ -0,0 +1,61 @@
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+use tic_tac_toe_rust_client_sdk::models::BoardPosition;
+
+/// A scripted sequence of moves, played in order, one per turn of the local Player.
+///
+/// Each line of a move file holds one move as `row column` or `row,column`. Blank lines and lines
+/// starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub(crate) struct MoveScript {
+    moves: VecDeque<BoardPosition>,
+}
+
+impl MoveScript {
+    //
+
+    /// Reads a move file.
+    pub(crate) fn from_file(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("Unable to read the move file {}: {}", path.display(), error))?;
+        Self::parse(&contents)
+    }
+
+    /// Parses the contents of a move file.
+    pub(crate) fn parse(contents: &str) -> Result<Self, String> {
+        //
+
+        let mut moves = VecDeque::new();
+
+        for (line_index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let coordinates: Vec<&str> = line.split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .collect();
+            let [row, column] = coordinates.as_slice() else {
+                return Err(format!("Line {}: expected 'row column', found '{}'", line_index + 1, line));
+            };
+            let (Ok(row), Ok(column)) = (row.parse::<i32>(), column.parse::<i32>()) else {
+                return Err(format!("Line {}: row and column must be numbers, found '{}'", line_index + 1, line));
+            };
+            moves.push_back(BoardPosition::new(column, row));
+        }
+
+        Ok(Self { moves })
+    }
+
+    /// Removes and returns the next move. Returns None once all moves have been played.
+    pub(crate) fn next_move(&mut self) -> Option<BoardPosition> {
+        self.moves.pop_front()
+    }
+}
@@ -0,0 +1,129 @@
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::thread;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use rumqttc::{Client, Event, MqttOptions, Packet, QoS};
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+use tic_tac_toe_rust_client_sdk::apis::{tic_tac_toe_api, Error};
+use tic_tac_toe_rust_client_sdk::event_plane_message::EventPlaneMessage;
+use tic_tac_toe_rust_client_sdk::models::{EventPlaneConfig, GameState, PlayStatus};
+
+use crate::board_renderer::BoardRenderer;
+
+/// How often the service is polled when the event plane is not available.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Follows a Game live, printing the board after every turn.
+pub(crate) struct Spectator;
+
+impl Spectator {
+    //
+
+    /// Watches the Gaming Session's current Game until it ends. Updates are received via the
+    /// event plane, when configured, otherwise by polling the service.
+    pub(crate) fn watch(configuration: &Configuration, session_id: &str, event_plane_config: Option<EventPlaneConfig>) -> Result<(), String> {
+        //
+
+        let current_game = tic_tac_toe_api::get_session_current_game(configuration, session_id)
+            .map_err(|error| format!("Unable to retrieve the Gaming Session's current Game: {}", error))?;
+        let game_id = current_game.game_info.game_id;
+        println!("Watching Game {}", game_id);
+
+        match event_plane_config {
+            Some(event_plane_config) => Self::watch_event_plane(configuration, &game_id, &event_plane_config),
+            None => Self::poll(configuration, &game_id),
+        }
+    }
+
+    fn watch_event_plane(configuration: &Configuration, game_id: &str, event_plane_config: &EventPlaneConfig) -> Result<(), String> {
+        //
+
+        let broker_port = u16::try_from(event_plane_config.broker_port).unwrap_or_default();
+        let options = MqttOptions::new(format!("tic-tac-toe-cli-{}", std::process::id()), event_plane_config.broker_address.clone(), broker_port);
+        let (client, mut connection) = Client::new(options, 10);
+
+        for notification in connection.iter() {
+            match notification {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    // The broker delivers the retained Game State, i.e. the current board, on subscription.
+                    let _ = client.try_subscribe(event_plane_config.topic_filter(), QoS::AtLeastOnce);
+                }
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    match event_plane_config.decode_message(&publish.topic, &publish.payload) {
+                        Ok(EventPlaneMessage::TurnTaken(game_state)) => Self::print_board(&game_state),
+                        Ok(EventPlaneMessage::GameEndedInWin(game_state)) | Ok(EventPlaneMessage::GameEndedInStalemate(game_state)) => {
+                            Self::print_board(&game_state);
+                            Self::print_outcome(&game_state, None);
+                            return Ok(());
+                        }
+                        Ok(EventPlaneMessage::AllPlayersReady) => println!("All Players are ready."),
+                        Ok(EventPlaneMessage::GameDeleted) => return Err("The Game has been deleted.".to_string()),
+                        Ok(EventPlaneMessage::SessionDeleted) => return Err("The Gaming Session has been deleted.".to_string()),
+                        Ok(_) => {}
+                        Err(error) => eprintln!("Ignoring message on {}: {}", publish.topic, error),
+                    }
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    eprintln!("The event plane is unreachable ({}). Polling the service instead.", error);
+                    return Self::poll(configuration, game_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn poll(configuration: &Configuration, game_id: &str) -> Result<(), String> {
+        //
+
+        let mut last_game_state: Option<GameState> = None;
+
+        loop {
+            let turn = match tic_tac_toe_api::get_latest_game_turn(configuration, game_id) {
+                Ok(turn) => turn,
+                Err(Error::ResponseError(response)) if response.status == StatusCode::BAD_REQUEST => {
+                    // The Game has not started yet.
+                    thread::sleep(POLL_INTERVAL);
+                    continue;
+                }
+                Err(error) => return Err(format!("Unable to retrieve the Game: {}", error)),
+            };
+
+            if last_game_state.as_ref() != Some(&turn.new_game_state) {
+                let winning_locations = turn.winning_locations.clone().flatten().unwrap_or_default();
+                println!("\n{}\n", BoardRenderer::render(&turn.new_game_state, &winning_locations));
+                match turn.new_game_state.play_status {
+                    PlayStatus::EndedInWin | PlayStatus::EndedInStalemate => {
+                        let winner_name = turn.winning_player.flatten().map(|winner| winner.display_name);
+                        Self::print_outcome(&turn.new_game_state, winner_name);
+                        return Ok(());
+                    }
+                    PlayStatus::InProgress | PlayStatus::NotStarted => {}
+                }
+                last_game_state = Some(turn.new_game_state);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn print_board(game_state: &GameState) {
+        println!("\n{}\n", BoardRenderer::render(game_state, &[]));
+    }
+
+    /// Announces the end of the Game. The event plane's Game State only identifies the winner by
+    /// their Player ID, so the winner's name is shown when it is known.
+    fn print_outcome(game_state: &GameState, winner_name: Option<String>) {
+        match (&game_state.play_status, winner_name) {
+            (PlayStatus::EndedInWin, Some(winner_name)) => println!("{} won!", winner_name),
+            (PlayStatus::EndedInWin, None) => println!("The Game has been won by Player {}.", game_state.id_of_player_who_made_move),
+            _ => println!("The Game has ended in a stalemate."),
+        }
+    }
+}
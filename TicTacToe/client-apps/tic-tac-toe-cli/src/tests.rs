@@ -0,0 +1,89 @@
+//  Tic-Tac-Toe CLI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod board_renderer_tests {
+    use crate::board_renderer::BoardRenderer;
+    use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, GameState, PlayStatus};
+
+    fn new_game_state() -> GameState {
+        let mut game_board = vec![vec![GamePiece::Unselected; 3]; 3];
+        game_board[0][0] = GamePiece::X;
+        game_board[1][1] = GamePiece::X;
+        game_board[2][2] = GamePiece::X;
+        game_board[0][2] = GamePiece::O;
+        GameState::new(game_board, "player-1".to_string(), PlayStatus::EndedInWin)
+    }
+
+    #[test]
+    fn test_render_board() {
+        let expected = [
+            "    0   1   2 ",
+            "0   X |   | O ",
+            "   ---+---+---",
+            "1     | X |   ",
+            "   ---+---+---",
+            "2     |   | X ",
+        ].join("\n");
+        assert_eq!(BoardRenderer::render(&new_game_state(), &[]), expected);
+    }
+
+    #[test]
+    fn test_render_winning_line() {
+        let winning_locations = [BoardPosition::new(0, 0), BoardPosition::new(1, 1), BoardPosition::new(2, 2)];
+        let rendered = BoardRenderer::render(&new_game_state(), &winning_locations);
+        assert!(rendered.contains("0  [X]|   | O "));
+        assert!(rendered.contains("1     |[X]|   "));
+        assert!(rendered.contains("2     |   |[X]"));
+    }
+}
+
+#[cfg(test)]
+mod move_script_tests {
+    use crate::move_script::MoveScript;
+    use tic_tac_toe_rust_client_sdk::models::BoardPosition;
+
+    #[test]
+    fn test_parse_moves() {
+        let mut move_script = MoveScript::parse("# Opening\n1 1\n\n0,2\n  2 , 0  \n").unwrap();
+        assert_eq!(move_script.next_move(), Some(BoardPosition::new(1, 1)));
+        assert_eq!(move_script.next_move(), Some(BoardPosition::new(2, 0)));
+        assert_eq!(move_script.next_move(), Some(BoardPosition::new(0, 2)));
+        assert_eq!(move_script.next_move(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_moves() {
+        assert!(MoveScript::parse("1").unwrap_err().starts_with("Line 1:"));
+        assert!(MoveScript::parse("1 1\n1 2 3").unwrap_err().starts_with("Line 2:"));
+        assert!(MoveScript::parse("a b").unwrap_err().starts_with("Line 1:"));
+    }
+}
+
+#[cfg(test)]
+mod cli_args_tests {
+    use crate::cli_args::{CliArgs, CliCommand};
+    use clap::Parser;
+
+    fn parse_watch(args: &[&str]) -> Result<(Option<String>, Option<String>), clap::Error> {
+        let args = ["tic-tac-toe-cli", "watch", "--session-id", "session-1"].iter().chain(args);
+        match CliArgs::try_parse_from(args)?.command {
+            CliCommand::Watch { topic_prefix, broker_address, .. } => Ok((topic_prefix, broker_address)),
+            _ => panic!("Expected the watch command"),
+        }
+    }
+
+    #[test]
+    fn test_watch_event_plane_arguments_go_together() {
+        //
+
+        assert_eq!(parse_watch(&[]).unwrap(), (None, None));
+        assert_eq!(parse_watch(&["--topic-prefix", "prefix", "--broker-address", "localhost"]).unwrap(),
+                   (Some("prefix".to_string()), Some("localhost".to_string())));
+
+        assert!(parse_watch(&["--topic-prefix", "prefix"]).is_err());
+        assert!(parse_watch(&["--broker-address", "localhost"]).is_err());
+    }
+}
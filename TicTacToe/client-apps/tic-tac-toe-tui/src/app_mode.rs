@@ -0,0 +1,13 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// Indicates the operating mode of the app, i.e. which screen is shown.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum AppMode {
+    EnterInvitation,
+    GamePlay,
+    #[default]
+    StartMenu,
+}
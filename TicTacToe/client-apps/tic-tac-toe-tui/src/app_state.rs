@@ -0,0 +1,151 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, GameState, PlayStatus, PlayerInfo, TurnResponse};
+
+use crate::app_mode::AppMode;
+use crate::board_cursor::BoardCursor;
+
+/// Houses the application state variables.
+#[derive(Default)]
+pub(crate) struct AppState {
+    //
+
+    /// The screen currently shown.
+    pub(crate) mode: AppMode,
+
+    /// The location on the Game Board selected by the local Player.
+    pub(crate) board_cursor: BoardCursor,
+
+    /// The current state of the Game
+    pub(crate) current_game_state: GameState,
+
+    /// The Player who can take the next turn
+    pub(crate) current_player: Option<PlayerInfo>,
+
+    /// Indicates whether the Game has ended
+    pub(crate) has_game_ended: bool,
+
+    /// Indicates whether the Game has been started
+    pub(crate) has_game_started: bool,
+
+    /// This is the code used to invite a new Player to the Game.
+    pub(crate) invitation_code: String,
+
+    /// Indicates that this is a Two-Player Game
+    pub(crate) is_two_player_game: bool,
+
+    /// Indicates that this client app instance is the one that started the Gaming Session.
+    pub(crate) local_player_initiated_gaming_session: bool,
+
+    /// The local Player, i.e. the Player using this app instance.
+    pub(crate) local_player: PlayerInfo,
+
+    /// The other Player, i.e. the local Player's opponent.
+    pub(crate) other_player: Option<PlayerInfo>,
+
+    /// Index of the highlighted Start Menu item.
+    pub(crate) start_menu_selection: usize,
+
+    /// Tells the user what is going on, e.g. whose turn it is or why a move was rejected.
+    pub(crate) status_text: String,
+
+    /// Set when the user asks to leave the app.
+    pub(crate) should_quit: bool,
+
+    /// If/when the Game has been won, winning_locations lists the locations of the winning Game pieces
+    pub(crate) winning_locations: Vec<BoardPosition>,
+
+    /// If/when the Game has been won, the Player who won it.
+    pub(crate) winning_player: Option<PlayerInfo>,
+}
+
+impl AppState {
+    //
+
+    /// Takes the Game state from the specified Turn. Returns false, leaving the state unchanged,
+    /// if the Turn is older than the Game state already shown, e.g. an event reported before the
+    /// local Player's own move was applied.
+    pub(crate) fn apply_turn(&mut self, turn: TurnResponse) -> bool {
+        //
+
+        if Self::piece_count(&turn.new_game_state) < Self::piece_count(&self.current_game_state) {
+            return false; // Early return. The Turn is stale.
+        }
+
+        self.current_player = turn.current_player.flatten();
+        self.has_game_ended = matches!(turn.new_game_state.play_status, PlayStatus::EndedInWin | PlayStatus::EndedInStalemate);
+        self.current_game_state = turn.new_game_state;
+        self.winning_locations = turn.winning_locations.flatten().unwrap_or_default();
+        self.winning_player = turn.winning_player.flatten();
+
+        if self.has_game_ended {
+            self.status_text = self.generate_results_text();
+        }
+
+        true
+    }
+
+    /// Generates Game completion text.
+    pub(crate) fn generate_results_text(&self) -> String {
+        match self.current_game_state.play_status {
+            PlayStatus::EndedInStalemate => "This game has ended in a stalemate.".to_string(),
+            PlayStatus::EndedInWin => {
+                let winning_player = self.winning_player.clone().unwrap_or_default();
+                if winning_player.player_id == self.local_player.player_id {
+                    "You won!".to_string()
+                } else {
+                    format!("{} won. Better luck next time.", winning_player.display_name)
+                }
+            }
+            _ => "".to_string(),
+        }
+    }
+
+    /// Indicates whether the local Player can take the next turn.
+    pub(crate) fn is_local_players_turn(&self) -> bool {
+        !self.has_game_ended
+            && self.current_player.as_ref()
+            .is_some_and(|current_player| current_player.player_id == self.local_player.player_id)
+    }
+
+    /// Indicates whether the specified location is part of the winning line.
+    pub(crate) fn is_winning_location(&self, row: usize, column: usize) -> bool {
+        self.winning_locations.iter().any(|location| location.row == row as i32 && location.column == column as i32)
+    }
+
+    /// Sets the other Player. The local Player's Game Piece is only known once both Players have
+    /// joined, so it is taken to be the other one of the pair.
+    pub(crate) fn set_other_player(&mut self, other_player: PlayerInfo) {
+        if self.local_player.game_piece == GamePiece::Unselected {
+            self.local_player.game_piece = match other_player.game_piece {
+                GamePiece::O => GamePiece::X,
+                GamePiece::X => GamePiece::O,
+                GamePiece::Unselected => GamePiece::Unselected,
+            };
+        }
+        self.other_player = Some(other_player);
+    }
+
+    /// Clears all fields of this instance and returns to the Start Menu.
+    pub(crate) fn reset(&mut self) {
+        //
+
+        // Grab the name
+        let name = self.local_player.display_name.clone();
+
+        *self = Self::default();
+
+        // Set our board state to Not Started instead of the SDK default of EndedInStalemate.
+        self.current_game_state.play_status = PlayStatus::NotStarted;
+
+        // Preserve the name
+        self.local_player.display_name = name;
+    }
+
+    fn piece_count(game_state: &GameState) -> usize {
+        game_state.game_board.iter().flatten().filter(|game_piece| **game_piece != GamePiece::Unselected).count()
+    }
+}
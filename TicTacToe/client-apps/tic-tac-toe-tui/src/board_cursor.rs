@@ -0,0 +1,54 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use tic_tac_toe_rust_client_sdk::models::BoardPosition;
+
+/// The number of rows and columns on the Game Board.
+pub(crate) const BOARD_SIZE: i32 = 3;
+
+/// Tracks the board location selected via the keyboard. The cursor stops at the edges of the
+/// board.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct BoardCursor {
+    pub(crate) row: i32,
+    pub(crate) column: i32,
+}
+
+impl Default for BoardCursor {
+    /// Starts the cursor at the center of the board.
+    fn default() -> Self {
+        Self { row: BOARD_SIZE / 2, column: BOARD_SIZE / 2 }
+    }
+}
+
+impl BoardCursor {
+    //
+
+    pub(crate) fn move_up(&mut self) {
+        self.row = (self.row - 1).max(0);
+    }
+
+    pub(crate) fn move_down(&mut self) {
+        self.row = (self.row + 1).min(BOARD_SIZE - 1);
+    }
+
+    pub(crate) fn move_left(&mut self) {
+        self.column = (self.column - 1).max(0);
+    }
+
+    pub(crate) fn move_right(&mut self) {
+        self.column = (self.column + 1).min(BOARD_SIZE - 1);
+    }
+
+    /// Returns the selected location as a BoardPosition.
+    pub(crate) fn position(&self) -> BoardPosition {
+        BoardPosition::new(self.column, self.row)
+    }
+
+    /// Indicates whether the cursor is at the specified location.
+    pub(crate) fn is_at(&self, row: usize, column: usize) -> bool {
+        self.row == row as i32 && self.column == column as i32
+    }
+}
@@ -0,0 +1,42 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use clap::{Parser, ValueEnum};
+use tic_tac_toe_rust_client_sdk::models::AutomaticPlayerSkillLevel;
+
+/// Plays Tic-Tac-Toe in the terminal.
+#[derive(Parser)]
+#[command(name = "tic-tac-toe-tui", version, about)]
+pub(crate) struct CliArgs {
+    //
+
+    /// Base URL of the Tic-Tac-Toe service.
+    #[arg(long, env = "TIC_TAC_TOE_SERVER", default_value = "http://127.0.0.1:50020")]
+    pub(crate) server: String,
+
+    /// Skill level of the computer in Single-Player Games.
+    #[arg(long, value_enum, default_value = "beginner")]
+    pub(crate) computer_skill_level: SkillLevel,
+}
+
+/// Mirrors the SDK's AutomaticPlayerSkillLevel so that it can be parsed from the command line.
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum SkillLevel {
+    Beginner,
+    Intermediate,
+    Expert,
+    Master,
+}
+
+impl From<SkillLevel> for AutomaticPlayerSkillLevel {
+    fn from(skill_level: SkillLevel) -> Self {
+        match skill_level {
+            SkillLevel::Beginner => AutomaticPlayerSkillLevel::Beginner,
+            SkillLevel::Intermediate => AutomaticPlayerSkillLevel::Intermediate,
+            SkillLevel::Expert => AutomaticPlayerSkillLevel::Expert,
+            SkillLevel::Master => AutomaticPlayerSkillLevel::Master,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+use tic_tac_toe_rust_client_sdk::models::GamePiece;
+
+use crate::app_state::AppState;
+use crate::board_cursor::BOARD_SIZE;
+use crate::info_panel::InfoPanel;
+use crate::screen_layout::ScreenLayout;
+use crate::user_action::UserAction;
+
+/// Width, in characters, of each board cell.
+const CELL_WIDTH: usize = 7;
+
+/// Shows the Game Board and the info panel. The local Player moves a cursor over the board and
+/// places their Game Piece with Enter.
+pub(crate) struct GamePlayScreen;
+
+impl GamePlayScreen {
+    //
+
+    /// Moves the board cursor and places Game Pieces. Returns the action chosen, if any.
+    pub(crate) fn handle_key(app_state: &mut AppState, key: KeyEvent) -> Option<UserAction> {
+        match key.code {
+            KeyCode::Esc => Some(UserAction::EndGame),
+            KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('k') => {
+                app_state.board_cursor.move_up();
+                None
+            }
+            KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('j') => {
+                app_state.board_cursor.move_down();
+                None
+            }
+            KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('h') => {
+                app_state.board_cursor.move_left();
+                None
+            }
+            KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('l') => {
+                app_state.board_cursor.move_right();
+                None
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => Self::place_game_piece(app_state),
+            _ => None,
+        }
+    }
+
+    fn place_game_piece(app_state: &mut AppState) -> Option<UserAction> {
+        //
+
+        if app_state.has_game_ended {
+            return None; // Early return. Only End Game is possible now.
+        }
+
+        if !app_state.is_local_players_turn() {
+            if let Some(other_player) = &app_state.other_player {
+                app_state.status_text = format!("Please wait for {} to play.", other_player.display_name);
+            }
+            return None; // Early return. The service would reject the move anyway.
+        }
+
+        Some(UserAction::PlaceGamePiece(app_state.board_cursor.position()))
+    }
+
+    pub(crate) fn draw(frame: &mut Frame, app_state: &AppState) {
+        //
+
+        let board_width = (CELL_WIDTH * BOARD_SIZE as usize + BOARD_SIZE as usize - 1) as u16;
+        let board_height = (BOARD_SIZE * 2 - 1) as u16;
+        let key_help = match app_state.has_game_ended {
+            true => "Esc: back to the Start Menu",
+            false => "Arrows/WASD: move · Enter: place piece · Esc: end game",
+        };
+        let area = ScreenLayout::draw_frame(frame, 72, board_height + 2 + InfoPanel::HEIGHT + 1, &app_state.status_text, key_help);
+
+        let [board_area, _, info_panel_area] = Layout::vertical([
+            Constraint::Length(board_height + 2),
+            Constraint::Length(1),
+            Constraint::Length(InfoPanel::HEIGHT),
+        ]).areas(area);
+
+        let board_area = ScreenLayout::centered(board_area, board_width + 2, board_height + 2);
+        frame.render_widget(Paragraph::new(Self::board_lines(app_state)).block(Block::bordered()), board_area);
+
+        InfoPanel::draw(frame, info_panel_area, app_state);
+    }
+
+    /// Builds the Game Board's lines. The cursor is shown in reverse video and the winning line in
+    /// bold green, with brackets for terminals without color.
+    fn board_lines(app_state: &AppState) -> Vec<Line<'static>> {
+        //
+
+        let separator = vec!["─".repeat(CELL_WIDTH); BOARD_SIZE as usize].join("┼");
+        let show_cursor = !app_state.has_game_ended;
+
+        let mut lines = vec![];
+        for row in 0..BOARD_SIZE as usize {
+            if row > 0 {
+                lines.push(Line::from(separator.clone()));
+            }
+            let mut spans = vec![];
+            for column in 0..BOARD_SIZE as usize {
+                if column > 0 {
+                    spans.push(Span::raw("│"));
+                }
+                let game_piece = app_state.current_game_state.game_board.get(row)
+                    .and_then(|board_row| board_row.get(column))
+                    .copied()
+                    .unwrap_or_default();
+                let is_winning = app_state.is_winning_location(row, column);
+                let symbol = Self::symbol(game_piece);
+                let text = match is_winning {
+                    true => format!("[{symbol}]"),
+                    false => format!(" {symbol} "),
+                };
+                let mut style = Style::default();
+                if is_winning {
+                    style = style.fg(Color::Green).add_modifier(Modifier::BOLD);
+                }
+                if show_cursor && app_state.board_cursor.is_at(row, column) {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                spans.push(Span::styled(format!("{text:^CELL_WIDTH$}"), style));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines
+    }
+
+    fn symbol(game_piece: GamePiece) -> &'static str {
+        match game_piece {
+            GamePiece::Unselected => " ",
+            GamePiece::X => "X",
+            GamePiece::O => "O",
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::Frame;
+use tic_tac_toe_rust_client_sdk::models::{GamePiece, PlayerInfo};
+
+use crate::app_state::AppState;
+
+/// Shows the Players, highlighting the one who can take the next turn, or the Invitation Code
+/// until the other Player has joined.
+pub(crate) struct InfoPanel;
+
+impl InfoPanel {
+    //
+
+    /// Height of the info panel, including its border.
+    pub(crate) const HEIGHT: u16 = 4;
+
+    pub(crate) fn draw(frame: &mut Frame, area: Rect, app_state: &AppState) {
+        frame.render_widget(Paragraph::new(vec![Self::top_line(app_state), Self::bottom_line(app_state)])
+                                .wrap(Wrap { trim: false })
+                                .block(Block::bordered()), area);
+    }
+
+    /// Returns the text of the info panel's top row, based on the current state of the Game.
+    pub(crate) fn top_line(app_state: &AppState) -> Line<'static> {
+        //
+
+        // Show the Invitation Code instructions until the Game has started
+        if app_state.local_player_initiated_gaming_session && !app_state.has_game_started {
+            return Line::from(format!(
+                "Hi {}. Please send the Invitation Code: {} to another player so they can join...",
+                app_state.local_player.display_name, app_state.invitation_code
+            ));
+        }
+
+        Self::player_line(app_state, &app_state.local_player)
+    }
+
+    /// Returns the text of the info panel's bottom row, based on the current state of the Game.
+    pub(crate) fn bottom_line(app_state: &AppState) -> Line<'static> {
+        //
+
+        // Hide the other Player's section until the Game has started
+        match (&app_state.other_player, app_state.has_game_started) {
+            (Some(other_player), true) => Self::player_line(app_state, other_player),
+            _ => Line::default(),
+        }
+    }
+
+    /// Labels the specified Player with their Game Piece, marking them when it is their turn.
+    fn player_line(app_state: &AppState, player: &PlayerInfo) -> Line<'static> {
+        //
+
+        let label = format!("{} {}", player.display_name, Self::display_name(player.game_piece));
+
+        let is_current_player = !app_state.has_game_ended
+            && app_state.current_player.as_ref().is_some_and(|current_player| current_player.player_id == player.player_id);
+        match is_current_player {
+            true => Line::from(format!("> {label}")).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            false => Line::from(format!("  {label}")),
+        }
+    }
+
+    fn display_name(game_piece: GamePiece) -> &'static str {
+        match game_piece {
+            GamePiece::Unselected => "",
+            GamePiece::X => "(X)",
+            GamePiece::O => "(O)",
+        }
+    }
+}
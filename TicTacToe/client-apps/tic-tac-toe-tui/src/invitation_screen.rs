@@ -0,0 +1,74 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+
+use crate::app_mode::AppMode;
+use crate::app_state::AppState;
+use crate::screen_layout::ScreenLayout;
+use crate::user_action::UserAction;
+
+pub(crate) const INVITATION_CODE_LENGTH: usize = 6;
+
+/// Shows the screen on which the invited Player enters the Invitation Code.
+pub(crate) struct InvitationScreen;
+
+impl InvitationScreen {
+    //
+
+    /// Edits the Invitation Code. Returns UserAction::JoinGame once a complete code has been
+    /// confirmed.
+    pub(crate) fn handle_key(app_state: &mut AppState, key: KeyEvent) -> Option<UserAction> {
+        match key.code {
+            KeyCode::Esc => {
+                app_state.invitation_code.clear();
+                app_state.status_text.clear();
+                app_state.mode = AppMode::StartMenu;
+                None
+            }
+            KeyCode::Backspace | KeyCode::Delete => {
+                app_state.invitation_code.pop();
+                None
+            }
+            // Capture only numeric characters.
+            KeyCode::Char(char) if char.is_numeric() && app_state.invitation_code.len() < INVITATION_CODE_LENGTH => {
+                app_state.invitation_code.push(char);
+                None
+            }
+            KeyCode::Enter if app_state.invitation_code.trim().len() == INVITATION_CODE_LENGTH => Some(UserAction::JoinGame),
+            KeyCode::Enter => {
+                app_state.status_text = "Please ask the other player for the 6-digit Invitation Code.".to_string();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn draw(frame: &mut Frame, app_state: &AppState) {
+        //
+
+        let area = ScreenLayout::draw_frame(frame, 40, 7, &app_state.status_text,
+                                            "Type the code · Enter: join game · Esc: go back");
+
+        let block = Block::bordered().title(Line::from(" Accept An Invitation ").centered());
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [instructions_area, code_area] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ]).margin(1).areas(inner_area);
+
+        let code = format!("{:_<width$}", app_state.invitation_code, width = INVITATION_CODE_LENGTH);
+        frame.render_widget(Paragraph::new(Line::from("Please enter the Invitation Code:").centered()), instructions_area);
+        frame.render_widget(Paragraph::new(Line::from(code).centered())
+                                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)), code_area);
+    }
+}
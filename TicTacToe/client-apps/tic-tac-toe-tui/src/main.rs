@@ -0,0 +1,51 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+
+use crate::cli_args::CliArgs;
+use crate::tui_app::TuiApp;
+
+mod app_mode;
+mod app_state;
+mod board_cursor;
+mod cli_args;
+mod game_play_screen;
+mod info_panel;
+mod invitation_screen;
+mod screen_layout;
+mod start_screen;
+mod tests;
+mod tui_app;
+mod user_action;
+
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+/// App entry point.
+fn main() -> ExitCode {
+    //
+
+    let args = CliArgs::parse();
+
+    let configuration = Configuration {
+        base_path: args.server.clone(),
+        user_agent: Some("Tic-Tac-Toe TUI".to_string()),
+        ..Default::default()
+    };
+
+    // Restores the terminal on the way out, including after a panic.
+    let mut terminal = ratatui::init();
+    let result = TuiApp::new(configuration, args.computer_skill_level.into()).run(&mut terminal);
+    ratatui::restore();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Terminal error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
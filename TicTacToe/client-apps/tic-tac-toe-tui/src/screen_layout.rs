@@ -0,0 +1,45 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+/// Lays out the parts shared by all screens.
+///
+/// Highlights use text attributes, e.g. reverse video, in addition to color so that the screens
+/// remain readable on monochrome and limited-color terminals, as is common over SSH.
+pub(crate) struct ScreenLayout;
+
+impl ScreenLayout {
+    //
+
+    /// Splits the frame into the screen's content area, centered and no larger than the
+    /// specified size, and draws the status and key help lines beneath it.
+    pub(crate) fn draw_frame(frame: &mut Frame, content_width: u16, content_height: u16, status_text: &str, key_help: &str) -> Rect {
+        //
+
+        let [content_area, status_area, help_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ]).areas(frame.area());
+
+        frame.render_widget(Paragraph::new(Line::from(status_text.to_string()).centered()), status_area);
+        frame.render_widget(Paragraph::new(Line::from(key_help.to_string()).centered())
+                                .style(Style::default().add_modifier(Modifier::DIM)), help_area);
+
+        Self::centered(content_area, content_width, content_height)
+    }
+
+    /// Returns an area of the specified size centered within the specified area.
+    pub(crate) fn centered(area: Rect, width: u16, height: u16) -> Rect {
+        let [area] = Layout::horizontal([Constraint::Length(width)]).flex(Flex::Center).areas(area);
+        let [area] = Layout::vertical([Constraint::Length(height)]).flex(Flex::Center).areas(area);
+        area
+    }
+}
@@ -0,0 +1,102 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+
+use crate::app_mode::AppMode;
+use crate::app_state::AppState;
+use crate::screen_layout::ScreenLayout;
+use crate::user_action::UserAction;
+
+// TODO: JD: localize this text
+const MENU_ITEMS: [&str; 3] = ["Two-Player Game", "Single-Player Game", "Accept An Invitation"];
+
+/// Shows the Start Menu, where the local Player enters their name and chooses how to play.
+pub(crate) struct StartScreen;
+
+impl StartScreen {
+    //
+
+    /// Edits the Player's name and moves through the menu. Returns the action chosen, if any.
+    pub(crate) fn handle_key(app_state: &mut AppState, key: KeyEvent) -> Option<UserAction> {
+        match key.code {
+            KeyCode::Esc => Some(UserAction::Quit),
+            KeyCode::Up | KeyCode::BackTab => {
+                app_state.start_menu_selection = (app_state.start_menu_selection + MENU_ITEMS.len() - 1) % MENU_ITEMS.len();
+                None
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                app_state.start_menu_selection = (app_state.start_menu_selection + 1) % MENU_ITEMS.len();
+                None
+            }
+            KeyCode::Backspace | KeyCode::Delete => {
+                app_state.local_player.display_name.pop();
+                None
+            }
+            // Add the characters, ignoring non-alphanumeric characters.
+            KeyCode::Char(char) if char.is_alphanumeric() => {
+                app_state.local_player.display_name.push(char);
+                None
+            }
+            KeyCode::Enter => Self::choose_menu_item(app_state),
+            _ => None,
+        }
+    }
+
+    fn choose_menu_item(app_state: &mut AppState) -> Option<UserAction> {
+        //
+
+        if app_state.local_player.display_name.trim().is_empty() {
+            app_state.status_text = "Please type your name first.".to_string();
+            return None; // Early return. Every Player needs a name.
+        }
+
+        match app_state.start_menu_selection {
+            0 => Some(UserAction::StartTwoPlayerGame),
+            1 => Some(UserAction::StartSinglePlayerGame),
+            _ => {
+                app_state.status_text.clear();
+                app_state.mode = AppMode::EnterInvitation;
+                None
+            }
+        }
+    }
+
+    pub(crate) fn draw(frame: &mut Frame, app_state: &AppState) {
+        //
+
+        let area = ScreenLayout::draw_frame(frame, 40, 12, &app_state.status_text,
+                                            "Type your name · Up/Down: select · Enter: start · Esc: quit");
+
+        let block = Block::bordered().title(Line::from(" Tic-Tac-Toe ").centered());
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [instructions_area, name_area, _, menu_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(MENU_ITEMS.len() as u16 * 2),
+        ]).margin(1).areas(inner_area);
+
+        frame.render_widget(Paragraph::new(Line::from("Please type your name:").centered()), instructions_area);
+        frame.render_widget(Paragraph::new(Line::from(format!("{}_", app_state.local_player.display_name)).centered())
+                                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)), name_area);
+
+        let menu_lines: Vec<Line> = MENU_ITEMS.iter().enumerate().flat_map(|(index, title)| {
+            let line = match index == app_state.start_menu_selection {
+                true => Line::from(format!("> {title} <")).style(Style::default().add_modifier(Modifier::REVERSED)),
+                false => Line::from(title.to_string()),
+            };
+            [line.centered(), Line::default()]
+        }).collect();
+        frame.render_widget(Paragraph::new(menu_lines), menu_area);
+    }
+}
@@ -0,0 +1,181 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod board_cursor_tests {
+    use crate::board_cursor::BoardCursor;
+
+    #[test]
+    fn test_cursor_starts_at_center() {
+        let cursor = BoardCursor::default();
+        assert_eq!((cursor.row, cursor.column), (1, 1));
+    }
+
+    #[test]
+    fn test_cursor_stops_at_edges() {
+        let mut cursor = BoardCursor::default();
+        for _ in 0..5 {
+            cursor.move_up();
+            cursor.move_left();
+        }
+        assert_eq!((cursor.row, cursor.column), (0, 0));
+        for _ in 0..5 {
+            cursor.move_down();
+            cursor.move_right();
+        }
+        assert_eq!((cursor.row, cursor.column), (2, 2));
+    }
+
+    #[test]
+    fn test_cursor_position() {
+        let mut cursor = BoardCursor::default();
+        cursor.move_up();
+        let position = cursor.position();
+        assert_eq!((position.row, position.column), (0, 1));
+    }
+}
+
+#[cfg(test)]
+mod screen_input_tests {
+    use ratatui::crossterm::event::{KeyCode, KeyEvent};
+    use tic_tac_toe_rust_client_sdk::models::{BoardPosition, PlayerInfo};
+
+    use crate::app_mode::AppMode;
+    use crate::app_state::AppState;
+    use crate::game_play_screen::GamePlayScreen;
+    use crate::invitation_screen::{InvitationScreen, INVITATION_CODE_LENGTH};
+    use crate::start_screen::StartScreen;
+    use crate::user_action::UserAction;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::from(code)
+    }
+
+    #[test]
+    fn test_start_screen_requires_name() {
+        let mut app_state = AppState::default();
+        assert_eq!(StartScreen::handle_key(&mut app_state, key(KeyCode::Enter)), None);
+        assert!(!app_state.status_text.is_empty());
+
+        for char in "Al-ice".chars() {
+            StartScreen::handle_key(&mut app_state, key(KeyCode::Char(char)));
+        }
+        assert_eq!(app_state.local_player.display_name, "Alice");
+        assert_eq!(StartScreen::handle_key(&mut app_state, key(KeyCode::Enter)), Some(UserAction::StartTwoPlayerGame));
+    }
+
+    #[test]
+    fn test_start_screen_menu() {
+        let mut app_state = AppState::default();
+        app_state.local_player.display_name = "Bob".to_string();
+
+        StartScreen::handle_key(&mut app_state, key(KeyCode::Down));
+        assert_eq!(StartScreen::handle_key(&mut app_state, key(KeyCode::Enter)), Some(UserAction::StartSinglePlayerGame));
+
+        StartScreen::handle_key(&mut app_state, key(KeyCode::Down));
+        assert_eq!(StartScreen::handle_key(&mut app_state, key(KeyCode::Enter)), None);
+        assert_eq!(app_state.mode, AppMode::EnterInvitation);
+
+        // Wraps around to the first item.
+        StartScreen::handle_key(&mut app_state, key(KeyCode::Down));
+        assert_eq!(app_state.start_menu_selection, 0);
+    }
+
+    #[test]
+    fn test_invitation_code_entry() {
+        let mut app_state = AppState { mode: AppMode::EnterInvitation, ..Default::default() };
+
+        for char in "12a3456789".chars() {
+            InvitationScreen::handle_key(&mut app_state, key(KeyCode::Char(char)));
+        }
+        assert_eq!(app_state.invitation_code, "123456");
+        assert_eq!(app_state.invitation_code.len(), INVITATION_CODE_LENGTH);
+        assert_eq!(InvitationScreen::handle_key(&mut app_state, key(KeyCode::Enter)), Some(UserAction::JoinGame));
+
+        InvitationScreen::handle_key(&mut app_state, key(KeyCode::Backspace));
+        assert_eq!(InvitationScreen::handle_key(&mut app_state, key(KeyCode::Enter)), None);
+
+        InvitationScreen::handle_key(&mut app_state, key(KeyCode::Esc));
+        assert_eq!(app_state.mode, AppMode::StartMenu);
+        assert!(app_state.invitation_code.is_empty());
+    }
+
+    #[test]
+    fn test_place_game_piece_only_on_local_players_turn() {
+        let local_player = PlayerInfo { player_id: "player-1".to_string(), ..Default::default() };
+        let other_player = PlayerInfo { player_id: "player-2".to_string(), display_name: "Bob".to_string(), ..Default::default() };
+        let mut app_state = AppState {
+            mode: AppMode::GamePlay,
+            local_player: local_player.clone(),
+            other_player: Some(other_player.clone()),
+            current_player: Some(other_player),
+            ..Default::default()
+        };
+
+        assert_eq!(GamePlayScreen::handle_key(&mut app_state, key(KeyCode::Enter)), None);
+        assert_eq!(app_state.status_text, "Please wait for Bob to play.");
+
+        app_state.current_player = Some(local_player);
+        GamePlayScreen::handle_key(&mut app_state, key(KeyCode::Left));
+        assert_eq!(GamePlayScreen::handle_key(&mut app_state, key(KeyCode::Enter)),
+                   Some(UserAction::PlaceGamePiece(BoardPosition::new(0, 1))));
+        assert_eq!(GamePlayScreen::handle_key(&mut app_state, key(KeyCode::Esc)), Some(UserAction::EndGame));
+    }
+}
+
+#[cfg(test)]
+mod app_state_tests {
+    use tic_tac_toe_rust_client_sdk::models::{BoardPosition, GamePiece, GameState, PlayStatus, PlayerInfo, TurnResponse};
+
+    use crate::app_state::AppState;
+
+    fn new_player(player_id: &str, display_name: &str) -> PlayerInfo {
+        PlayerInfo { player_id: player_id.to_string(), display_name: display_name.to_string(), ..Default::default() }
+    }
+
+    fn new_turn(pieces: &[(usize, usize, GamePiece)], play_status: PlayStatus) -> TurnResponse {
+        let mut game_board = vec![vec![GamePiece::Unselected; 3]; 3];
+        for (row, column, game_piece) in pieces {
+            game_board[*row][*column] = *game_piece;
+        }
+        TurnResponse {
+            new_game_state: GameState::new(game_board, "player-1".to_string(), play_status),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stale_turn_is_ignored() {
+        let mut app_state = AppState::default();
+        assert!(app_state.apply_turn(new_turn(&[(1, 1, GamePiece::X), (0, 0, GamePiece::O)], PlayStatus::InProgress)));
+        assert!(!app_state.apply_turn(new_turn(&[(1, 1, GamePiece::X)], PlayStatus::InProgress)));
+        assert_eq!(app_state.current_game_state.game_board[0][0], GamePiece::O);
+    }
+
+    #[test]
+    fn test_win_is_reported() {
+        let mut app_state = AppState { local_player: new_player("player-2", "Bob"), ..Default::default() };
+
+        let mut turn = new_turn(&[(0, 0, GamePiece::X), (1, 1, GamePiece::X), (2, 2, GamePiece::X)], PlayStatus::EndedInWin);
+        turn.winning_player = Some(Some(new_player("player-1", "Alice")));
+        turn.winning_locations = Some(Some(vec![BoardPosition::new(0, 0), BoardPosition::new(1, 1), BoardPosition::new(2, 2)]));
+        app_state.apply_turn(turn);
+
+        assert!(app_state.has_game_ended);
+        assert!(app_state.is_winning_location(1, 1));
+        assert!(!app_state.is_winning_location(0, 2));
+        assert_eq!(app_state.status_text, "Alice won. Better luck next time.");
+    }
+
+    #[test]
+    fn test_reset_preserves_name() {
+        let mut app_state = AppState { local_player: new_player("player-1", "Alice"), ..Default::default() };
+        app_state.invitation_code = "123456".to_string();
+        app_state.reset();
+        assert_eq!(app_state.local_player.display_name, "Alice");
+        assert!(app_state.invitation_code.is_empty());
+        assert_eq!(app_state.current_game_state.play_status, PlayStatus::NotStarted);
+    }
+}
@@ -0,0 +1,284 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use std::io;
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{DefaultTerminal, Frame};
+use tic_tac_toe_rust_client_sdk::apis::configuration::Configuration;
+use tic_tac_toe_rust_client_sdk::apis::tic_tac_toe_api;
+use tic_tac_toe_rust_client_sdk::game_client::GameClient;
+use tic_tac_toe_rust_client_sdk::game_client_event::GameClientEvent;
+use tic_tac_toe_rust_client_sdk::models::{AutomaticPlayerSkillLevel, BoardPosition, GameInfoResponse};
+
+use crate::app_mode::AppMode;
+use crate::app_state::AppState;
+use crate::game_play_screen::GamePlayScreen;
+use crate::invitation_screen::InvitationScreen;
+use crate::start_screen::StartScreen;
+use crate::user_action::UserAction;
+
+/// How long to wait for a key press before checking for Game changes.
+const TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Runs the app: draws the current screen, routes key presses to it and applies the Game changes
+/// reported by the GameClient.
+pub(crate) struct TuiApp {
+    app_state: AppState,
+    client: Option<GameClient>,
+    computer_skill_level: AutomaticPlayerSkillLevel,
+    configuration: Configuration,
+}
+
+impl TuiApp {
+    //
+
+    pub(crate) fn new(configuration: Configuration, computer_skill_level: AutomaticPlayerSkillLevel) -> Self {
+        let mut app_state = AppState::default();
+        app_state.reset();
+        Self {
+            app_state,
+            client: None,
+            computer_skill_level,
+            configuration,
+        }
+    }
+
+    /// Runs until the user quits.
+    pub(crate) fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        //
+
+        while !self.app_state.should_quit {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if event::poll(TICK_INTERVAL)? {
+                if let Event::Key(key) = event::read()? {
+                    // Some terminals also report key releases.
+                    if key.kind == KeyEventKind::Press {
+                        self.handle_key(key);
+                    }
+                }
+            }
+
+            self.process_game_events();
+        }
+
+        self.leave_gaming_session();
+        Ok(())
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        match self.app_state.mode {
+            AppMode::EnterInvitation => InvitationScreen::draw(frame, &self.app_state),
+            AppMode::GamePlay => GamePlayScreen::draw(frame, &self.app_state),
+            AppMode::StartMenu => StartScreen::draw(frame, &self.app_state),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        //
+
+        // The terminal is in raw mode, so Ctrl+C arrives as a key press rather than a signal.
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.app_state.should_quit = true;
+            return; // Early return. The Gaming Session is closed down on the way out.
+        }
+
+        let action = match self.app_state.mode {
+            AppMode::EnterInvitation => InvitationScreen::handle_key(&mut self.app_state, key),
+            AppMode::GamePlay => GamePlayScreen::handle_key(&mut self.app_state, key),
+            AppMode::StartMenu => StartScreen::handle_key(&mut self.app_state, key),
+        };
+
+        if let Some(action) = action {
+            self.perform(action);
+        }
+    }
+
+    fn perform(&mut self, action: UserAction) {
+        match action {
+            UserAction::EndGame => {
+                self.leave_gaming_session();
+                self.app_state.reset();
+            }
+            UserAction::JoinGame => self.join_game(),
+            UserAction::PlaceGamePiece(position) => self.place_game_piece(position),
+            UserAction::Quit => self.app_state.should_quit = true,
+            UserAction::StartSinglePlayerGame => self.start_game(false),
+            UserAction::StartTwoPlayerGame => self.start_game(true),
+        }
+    }
+}
+
+// Games
+impl TuiApp {
+    //
+
+    fn start_game(&mut self, is_two_player_game: bool) {
+        //
+
+        let result = GameClient::create_session(self.configuration.clone(), &self.app_state.local_player.display_name)
+            .and_then(|mut client| {
+                let game_info = match is_two_player_game {
+                    true => client.start_two_player_game(),
+                    false => client.start_single_player_game(self.computer_skill_level),
+                }?;
+                Ok((client, game_info))
+            });
+
+        match result {
+            Ok((client, game_info)) => {
+                self.app_state.is_two_player_game = is_two_player_game;
+                self.app_state.local_player_initiated_gaming_session = true;
+                self.enter_game(client, game_info);
+                if is_two_player_game {
+                    self.app_state.status_text = "Please send the Invitation Code to another player so they can join the game...".to_string();
+                }
+            }
+            Err(error) => self.app_state.status_text = format!("Unable to start the Game: {}", error),
+        }
+    }
+
+    fn join_game(&mut self) {
+        //
+
+        let result = GameClient::join_session(self.configuration.clone(), &self.app_state.invitation_code, &self.app_state.local_player.display_name)
+            .and_then(|mut client| {
+                let game_info = client.join_current_game()?;
+                Ok((client, game_info))
+            });
+
+        match result {
+            Ok((client, game_info)) => {
+                self.app_state.is_two_player_game = true;
+                self.enter_game(client, game_info);
+            }
+            Err(error) => self.app_state.status_text = format!("Unable to join the Game: {}", error),
+        }
+    }
+
+    /// Switches to the Game Play screen for the GameClient's current Game.
+    fn enter_game(&mut self, client: GameClient, game_info: GameInfoResponse) {
+        //
+
+        self.app_state.current_game_state = game_info.game_state;
+        self.app_state.invitation_code = client.invitation_code().to_string();
+        self.app_state.local_player = client.local_player().clone();
+        if let Some(other_player) = client.opponent() {
+            self.app_state.set_other_player(other_player.clone());
+        }
+        self.app_state.status_text.clear();
+        self.app_state.mode = AppMode::GamePlay;
+        self.client = Some(client);
+
+        if self.app_state.other_player.is_some() {
+            self.app_state.has_game_started = true;
+            self.load_latest_turn();
+        }
+    }
+
+    fn load_latest_turn(&mut self) {
+        //
+
+        let Some(game_id) = self.client.as_ref().and_then(|client| client.game_id()) else {
+            return; // Early return. There is no Game to load.
+        };
+
+        match tic_tac_toe_api::get_latest_game_turn(&self.configuration, game_id) {
+            Ok(turn) => {
+                self.app_state.apply_turn(turn);
+                self.update_turn_status();
+            }
+            Err(error) => self.app_state.status_text = format!("Unable to retrieve the Game: {}", error),
+        }
+    }
+
+    fn place_game_piece(&mut self, position: BoardPosition) {
+        //
+
+        let Some(client) = &self.client else {
+            return; // Early return. There is no Game to play.
+        };
+
+        match client.play(position.row, position.column) {
+            Ok(turn) => {
+                self.app_state.apply_turn(turn);
+                self.update_turn_status();
+            }
+            Err(error) => self.app_state.status_text = error.describe_rejected_move(),
+        }
+    }
+
+    /// Closes down the Game and the Gaming Session, if any. Failures are ignored as the service
+    /// cleans up abandoned sessions on its own.
+    fn leave_gaming_session(&mut self) {
+        if let Some(mut client) = self.client.take() {
+            let _ = client.end_game();
+            let _ = client.end_session();
+        }
+    }
+
+    fn update_turn_status(&mut self) {
+        //
+
+        if self.app_state.has_game_ended {
+            return; // Early return. The results text is already shown.
+        }
+
+        self.app_state.status_text = match (self.app_state.is_local_players_turn(), &self.app_state.current_player) {
+            (true, _) => "It's your turn.".to_string(),
+            (false, Some(current_player)) => format!("Waiting for {} to play...", current_player.display_name),
+            (false, None) => "".to_string(),
+        };
+    }
+}
+
+// Events
+impl TuiApp {
+    //
+
+    /// Applies the Game changes reported since the last tick.
+    fn process_game_events(&mut self) {
+        //
+
+        let Some(client) = &mut self.client else {
+            return; // Early return. There is no Game to follow.
+        };
+
+        let events: Vec<GameClientEvent> = std::iter::from_fn(|| client.try_next_event()).collect();
+
+        for event in events {
+            match event {
+                GameClientEvent::OpponentJoined(other_player) => {
+                    //
+
+                    if self.app_state.has_game_started {
+                        continue; // The invited Player already knows who they are playing against.
+                    }
+
+                    let message = format!("{} has joined! Let the game begin!", other_player.display_name);
+                    self.app_state.set_other_player(other_player);
+                    self.app_state.has_game_started = true;
+                    self.load_latest_turn();
+                    self.app_state.status_text = message;
+                }
+                GameClientEvent::TurnTaken(turn) | GameClientEvent::GameEnded(turn) => {
+                    if self.app_state.apply_turn(turn) {
+                        self.update_turn_status();
+                    }
+                }
+                GameClientEvent::GameDeleted | GameClientEvent::SessionDeleted => {
+                    self.leave_gaming_session();
+                    self.app_state.reset();
+                    self.app_state.status_text = "The Game has been closed by the other player.".to_string();
+                    return;
+                }
+                // Rematches are not offered. A new Game starts from the start screen.
+                GameClientEvent::GameCreated(_) => {}
+            }
+        }
+    }
+}
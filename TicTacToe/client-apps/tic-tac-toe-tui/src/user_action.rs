@@ -0,0 +1,18 @@
+//  Tic-Tac-Toe TUI Client App
+//
+//  © 2024 Rust Made Easy. All rights reserved.
+//  @author JoelDavisEngineering@Gmail.com
+
+use tic_tac_toe_rust_client_sdk::models::BoardPosition;
+
+/// Enumerates the requests, made by the user via the keyboard, that require the Tic-Tac-Toe
+/// service.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum UserAction {
+    EndGame,
+    JoinGame,
+    PlaceGamePiece(BoardPosition),
+    Quit,
+    StartSinglePlayerGame,
+    StartTwoPlayerGame,
+}
@@ -9,12 +9,21 @@
  */
 
 
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Configuration {
     pub base_path: String,
     pub user_agent: Option<String>,
     pub client: reqwest::blocking::Client,
+    #[cfg(feature = "async")]
+    pub async_client: reqwest::Client,
+    /// Maximum time allowed for each request attempt. None means no limit.
+    pub timeout: Option<Duration>,
+    /// Number of times a failed request is re-sent before its error is returned.
+    pub max_retries: u32,
+    /// Time to wait between request attempts.
+    pub retry_delay: Duration,
     pub basic_auth: Option<BasicAuth>,
     pub oauth_access_token: Option<String>,
     pub bearer_access_token: Option<String>,
@@ -34,6 +43,23 @@ impl Configuration {
     pub fn new() -> Configuration {
         Configuration::default()
     }
+
+    /// Sets the maximum time allowed for each request attempt.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Re-sends failed requests up to the specified number of times, waiting the specified delay
+    /// between attempts.
+    ///
+    /// NOTE: Requests that never reached the service are always retried. Timeouts and server
+    /// errors are only retried for idempotent requests so that, e.g., a Turn is never taken twice.
+    pub fn with_retries(mut self, max_retries: u32, retry_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_delay = retry_delay;
+        self
+    }
 }
 
 impl Default for Configuration {
@@ -42,6 +68,11 @@ impl Default for Configuration {
             base_path: "http://localhost".to_owned(),
             user_agent: Some("OpenAPI-Generator/0.4.0/rust".to_owned()),
             client: reqwest::blocking::Client::new(),
+            #[cfg(feature = "async")]
+            async_client: reqwest::Client::new(),
+            timeout: None,
+            max_retries: 0,
+            retry_delay: Duration::from_millis(500),
             basic_auth: None,
             oauth_access_token: None,
             bearer_access_token: None,
@@ -1,6 +1,8 @@
 use std::error;
 use std::fmt;
 
+use crate::models;
+
 #[derive(Debug, Clone)]
 pub struct ResponseContent<T> {
     pub status: reqwest::StatusCode,
@@ -39,6 +41,28 @@ impl <T: fmt::Debug> error::Error for Error<T> {
     }
 }
 
+impl <T> Error<T> {
+    /// Indicates whether the service reported that the requested Game or Gaming Session does not
+    /// exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::ResponseError(response) if response.status == reqwest::StatusCode::NOT_FOUND)
+    }
+
+    /// Indicates whether the service reported that the Gaming Session does not exist, e.g. because
+    /// the other Player has left.
+    pub fn is_session_not_found(&self) -> bool {
+        self.game_error() == Some(models::GameError::GamingSessionNotFound)
+    }
+
+    /// Returns the error reported by the service, if any, as identified by the ErrorResponse body.
+    pub fn game_error(&self) -> Option<models::GameError> {
+        match self {
+            Error::ResponseError(response) => serde_json::from_str::<models::ErrorResponse>(&response.content).ok().map(|it| it.code),
+            _ => None,
+        }
+    }
+}
+
 impl <T> From<reqwest::Error> for Error<T> {
     fn from(e: reqwest::Error) -> Self {
         Error::Reqwest(e)
@@ -57,6 +81,59 @@ impl <T> From<std::io::Error> for Error<T> {
     }
 }
 
+/// Sends a request via the blocking client, applying the timeout and retries specified by the
+/// Configuration.
+pub(crate) fn execute_blocking(configuration: &configuration::Configuration, mut request: reqwest::blocking::Request) -> Result<reqwest::blocking::Response, reqwest::Error> {
+    if configuration.timeout.is_some() {
+        *request.timeout_mut() = configuration.timeout;
+    }
+    let mut retries_remaining = configuration.max_retries;
+    loop {
+        let next_request = if retries_remaining > 0 { request.try_clone() } else { None };
+        let result = configuration.client.execute(request);
+        match next_request {
+            Some(next_request) if should_retry(next_request.method(), result.as_ref().map(|response| response.status())) => {
+                retries_remaining -= 1;
+                std::thread::sleep(configuration.retry_delay);
+                request = next_request;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// Sends a request via the async client, applying the timeout and retries specified by the
+/// Configuration.
+#[cfg(feature = "async")]
+pub(crate) async fn execute_async(configuration: &configuration::Configuration, mut request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+    if configuration.timeout.is_some() {
+        *request.timeout_mut() = configuration.timeout;
+    }
+    let mut retries_remaining = configuration.max_retries;
+    loop {
+        let next_request = if retries_remaining > 0 { request.try_clone() } else { None };
+        let result = configuration.async_client.execute(request).await;
+        match next_request {
+            Some(next_request) if should_retry(next_request.method(), result.as_ref().map(|response| response.status())) => {
+                retries_remaining -= 1;
+                tokio::time::sleep(configuration.retry_delay).await;
+                request = next_request;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// Determines whether a request may be re-sent after the specified outcome. Requests that never
+/// reached the service are always safe to re-send. Timeouts and server errors may have been
+/// applied by the service, so they are only retried for idempotent methods.
+pub(crate) fn should_retry(method: &reqwest::Method, outcome: Result<reqwest::StatusCode, &reqwest::Error>) -> bool {
+    match outcome {
+        Ok(status) => status.is_server_error() && method.is_idempotent(),
+        Err(error) => error.is_connect() || (error.is_timeout() && method.is_idempotent()),
+    }
+}
+
 pub fn urlencode<T: AsRef<str>>(s: T) -> String {
     ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
@@ -91,5 +168,7 @@ pub fn parse_deep_object(prefix: &str, value: &serde_json::Value) -> Vec<(String
 }
 
 pub mod tic_tac_toe_api;
+#[cfg(feature = "async")]
+pub mod tic_tac_toe_async_api;
 
 pub mod configuration;
@@ -147,7 +147,7 @@ pub fn create_gaming_session(configuration: &configuration::Configuration, new_g
     local_var_req_builder = local_var_req_builder.json(&new_gaming_session_params);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -175,7 +175,7 @@ pub fn create_single_player_game(configuration: &configuration::Configuration, s
     local_var_req_builder = local_var_req_builder.json(&new_single_player_game_params);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -190,6 +190,12 @@ pub fn create_single_player_game(configuration: &configuration::Configuration, s
 }
 
 pub fn create_two_player_game(configuration: &configuration::Configuration, session_id: &str) -> Result<models::GameCreationResponse, Error<CreateTwoPlayerGameError>> {
+    create_two_player_game_with_params(configuration, session_id, None)
+}
+
+/// Like create_two_player_game, but sends the specified NewTwoPlayerGameParams, if any, as the
+/// request body. The body is omitted when there are none.
+pub fn create_two_player_game_with_params(configuration: &configuration::Configuration, session_id: &str, new_two_player_game_params: Option<models::NewTwoPlayerGameParams>) -> Result<models::GameCreationResponse, Error<CreateTwoPlayerGameError>> {
     let local_var_configuration = configuration;
 
     let local_var_client = &local_var_configuration.client;
@@ -200,9 +206,12 @@ pub fn create_two_player_game(configuration: &configuration::Configuration, sess
     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
     }
+    if let Some(ref local_var_params) = new_two_player_game_params {
+        local_var_req_builder = local_var_req_builder.json(local_var_params);
+    }
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -230,7 +239,7 @@ pub fn end_game(configuration: &configuration::Configuration, game_id: &str, end
     local_var_req_builder = local_var_req_builder.json(&end_game_params);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -258,7 +267,7 @@ pub fn end_gaming_session(configuration: &configuration::Configuration, session_
     local_var_req_builder = local_var_req_builder.json(&end_gaming_session_params);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -285,7 +294,7 @@ pub fn get_game_history(configuration: &configuration::Configuration, game_id: &
     }
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -312,7 +321,7 @@ pub fn get_latest_game_turn(configuration: &configuration::Configuration, game_i
     }
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -339,7 +348,7 @@ pub fn get_players_readiness(configuration: &configuration::Configuration, game_
     }
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -366,7 +375,7 @@ pub fn get_session_current_game(configuration: &configuration::Configuration, se
     }
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -393,7 +402,7 @@ pub fn join_current_game(configuration: &configuration::Configuration, session_i
     }
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -421,7 +430,7 @@ pub fn join_gaming_session(configuration: &configuration::Configuration, join_se
     local_var_req_builder = local_var_req_builder.json(&join_session_params);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
@@ -449,7 +458,7 @@ pub fn take_turn(configuration: &configuration::Configuration, game_id: &str, ga
     local_var_req_builder = local_var_req_builder.json(&game_turn_params);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req)?;
+    let local_var_resp = crate::apis::execute_blocking(local_var_configuration, local_var_req)?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text()?;
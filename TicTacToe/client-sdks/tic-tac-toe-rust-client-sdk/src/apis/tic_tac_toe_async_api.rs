@@ -0,0 +1,371 @@
+/*
+ * Tic-Tac-Toe Service
+ *
+ * Tic-Tac-Toe Game Service
+ *
+ * The version of the OpenAPI document: 0.4.0
+ * Contact: JoelDavisEngineering@Gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+use reqwest;
+use crate::{apis::ResponseContent, models};
+use super::{Error, configuration};
+
+// The typed errors are shared with the blocking API.
+pub use super::tic_tac_toe_api::{
+    CreateGamingSessionError,
+    CreateSinglePlayerGameError,
+    CreateTwoPlayerGameError,
+    EndGameError,
+    EndGamingSessionError,
+    GetGameHistoryError,
+    GetLatestGameTurnError,
+    GetPlayersReadinessError,
+    GetSessionCurrentGameError,
+    JoinCurrentGameError,
+    JoinGamingSessionError,
+    TakeTurnError,
+};
+
+
+pub async fn create_gaming_session(configuration: &configuration::Configuration, new_gaming_session_params: models::NewGamingSessionParams) -> Result<models::GamingSessionCreationResponse, Error<CreateGamingSessionError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-sessions", local_var_configuration.base_path);
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    local_var_req_builder = local_var_req_builder.json(&new_gaming_session_params);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<CreateGamingSessionError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn create_single_player_game(configuration: &configuration::Configuration, session_id: &str, new_single_player_game_params: models::NewSinglePlayerGameParams) -> Result<models::GameCreationResponse, Error<CreateSinglePlayerGameError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-sessions/{session_id}/games", local_var_configuration.base_path, session_id=crate::apis::urlencode(session_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    local_var_req_builder = local_var_req_builder.json(&new_single_player_game_params);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<CreateSinglePlayerGameError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn create_two_player_game(configuration: &configuration::Configuration, session_id: &str) -> Result<models::GameCreationResponse, Error<CreateTwoPlayerGameError>> {
+    create_two_player_game_with_params(configuration, session_id, None).await
+}
+
+/// Like create_two_player_game, but sends the specified NewTwoPlayerGameParams, if any, as the
+/// request body. The body is omitted when there are none.
+pub async fn create_two_player_game_with_params(configuration: &configuration::Configuration, session_id: &str, new_two_player_game_params: Option<models::NewTwoPlayerGameParams>) -> Result<models::GameCreationResponse, Error<CreateTwoPlayerGameError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-session/{session_id}/two-player-games", local_var_configuration.base_path, session_id=crate::apis::urlencode(session_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_params) = new_two_player_game_params {
+        local_var_req_builder = local_var_req_builder.json(local_var_params);
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<CreateTwoPlayerGameError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn end_game(configuration: &configuration::Configuration, game_id: &str, end_game_params: models::EndGameParams) -> Result<(), Error<EndGameError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/games/{game_id}", local_var_configuration.base_path, game_id=crate::apis::urlencode(game_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    local_var_req_builder = local_var_req_builder.json(&end_game_params);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        Ok(())
+    } else {
+        let local_var_entity: Option<EndGameError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn end_gaming_session(configuration: &configuration::Configuration, session_id: &str, end_gaming_session_params: models::EndGamingSessionParams) -> Result<(), Error<EndGamingSessionError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-sessions/{session_id}", local_var_configuration.base_path, session_id=crate::apis::urlencode(session_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    local_var_req_builder = local_var_req_builder.json(&end_gaming_session_params);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        Ok(())
+    } else {
+        let local_var_entity: Option<EndGamingSessionError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_game_history(configuration: &configuration::Configuration, game_id: &str) -> Result<Vec<models::GameState>, Error<GetGameHistoryError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/games/{game_id}/turns", local_var_configuration.base_path, game_id=crate::apis::urlencode(game_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetGameHistoryError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_latest_game_turn(configuration: &configuration::Configuration, game_id: &str) -> Result<models::TurnResponse, Error<GetLatestGameTurnError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/games/{game_id}/turns/latest", local_var_configuration.base_path, game_id=crate::apis::urlencode(game_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetLatestGameTurnError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_players_readiness(configuration: &configuration::Configuration, game_id: &str) -> Result<models::PlayersReadinessResponse, Error<GetPlayersReadinessError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/games/{game_id}/players/readiness", local_var_configuration.base_path, game_id=crate::apis::urlencode(game_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetPlayersReadinessError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_session_current_game(configuration: &configuration::Configuration, session_id: &str) -> Result<models::GameCreationResponse, Error<GetSessionCurrentGameError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-sessions/{session_id}/current-game", local_var_configuration.base_path, session_id=crate::apis::urlencode(session_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetSessionCurrentGameError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn join_current_game(configuration: &configuration::Configuration, session_id: &str, player_id: &str) -> Result<models::GameCreationResponse, Error<JoinCurrentGameError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-sessions/{session_id}/current_game/players/{player_id}", local_var_configuration.base_path, session_id=crate::apis::urlencode(session_id), player_id=crate::apis::urlencode(player_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<JoinCurrentGameError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn join_gaming_session(configuration: &configuration::Configuration, join_session_params: models::JoinSessionParams) -> Result<models::GamingSessionCreationResponse, Error<JoinGamingSessionError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/gaming-sessions/players", local_var_configuration.base_path);
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    local_var_req_builder = local_var_req_builder.json(&join_session_params);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<JoinGamingSessionError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn take_turn(configuration: &configuration::Configuration, game_id: &str, game_turn_params: models::GameTurnParams) -> Result<models::TurnResponse, Error<TakeTurnError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.async_client;
+
+    let local_var_uri_str = format!("{}/v1/games/{game_id}/turns", local_var_configuration.base_path, game_id=crate::apis::urlencode(game_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    local_var_req_builder = local_var_req_builder.json(&game_turn_params);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute_async(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<TakeTurnError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
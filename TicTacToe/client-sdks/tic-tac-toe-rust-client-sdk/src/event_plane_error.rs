@@ -0,0 +1,27 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use std::error;
+use std::fmt;
+
+/// Enumerates the errors encountered while decoding event plane messages.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventPlaneError {
+    /// The message's payload could not be decoded.
+    InvalidPayload(String),
+    /// The message was received on a topic that is not part of the event plane.
+    UnknownTopic(String),
+}
+
+impl fmt::Display for EventPlaneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventPlaneError::InvalidPayload(message) => write!(f, "invalid payload: {}", message),
+            EventPlaneError::UnknownTopic(topic) => write!(f, "unknown topic: {}", topic),
+        }
+    }
+}
+
+impl error::Error for EventPlaneError {}
@@ -0,0 +1,70 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use crate::event_plane_error::EventPlaneError;
+use crate::models::{EventPlaneTopicNames, GameState};
+
+/// Models a message received via the real-time messaging event plane.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventPlaneMessage {
+    /// All Players are ready to begin the Game.
+    AllPlayersReady,
+    /// The Game has been deleted from the platform.
+    GameDeleted,
+    /// The Game has ended in a stalemate. Carries the final Game State.
+    GameEndedInStalemate(GameState),
+    /// The Game has ended in a win. Carries the final Game State.
+    GameEndedInWin(GameState),
+    /// The Game has started.
+    GameStarted,
+    /// The retained Game State on the specified topic has been removed, e.g. because a new Game
+    /// has begun.
+    GameStateCleared(EventPlaneTopicNames),
+    /// The Gaming Session has been deleted from the platform.
+    SessionDeleted,
+    /// A Player has taken a new turn. Carries the resulting Game State.
+    TurnTaken(GameState),
+}
+
+impl EventPlaneMessage {
+    //
+
+    /// Decodes the payload of a message received on the specified topic.
+    pub fn decode(topic_name: EventPlaneTopicNames, payload: &[u8]) -> Result<Self, EventPlaneError> {
+        //
+
+        let game_state = || -> Result<GameState, EventPlaneError> {
+            serde_json::from_slice(payload).map_err(|error| EventPlaneError::InvalidPayload(error.to_string()))
+        };
+
+        let message = match topic_name {
+            EventPlaneTopicNames::AllPlayersReady => Self::AllPlayersReady,
+            EventPlaneTopicNames::GameDeleted => Self::GameDeleted,
+            EventPlaneTopicNames::GameStarted => Self::GameStarted,
+            EventPlaneTopicNames::SessionDeleted => Self::SessionDeleted,
+            // Per the MQTT spec, an empty retained message removes the previously retained one.
+            _ if payload.is_empty() => Self::GameStateCleared(topic_name),
+            EventPlaneTopicNames::GameEndedInStalemate => Self::GameEndedInStalemate(game_state()?),
+            EventPlaneTopicNames::GameEndedInWin => Self::GameEndedInWin(game_state()?),
+            EventPlaneTopicNames::TurnTaken => Self::TurnTaken(game_state()?),
+        };
+
+        Ok(message)
+    }
+
+    /// Returns the name of the topic on which this message is published.
+    pub fn topic_name(&self) -> EventPlaneTopicNames {
+        match self {
+            Self::AllPlayersReady => EventPlaneTopicNames::AllPlayersReady,
+            Self::GameDeleted => EventPlaneTopicNames::GameDeleted,
+            Self::GameEndedInStalemate(_) => EventPlaneTopicNames::GameEndedInStalemate,
+            Self::GameEndedInWin(_) => EventPlaneTopicNames::GameEndedInWin,
+            Self::GameStarted => EventPlaneTopicNames::GameStarted,
+            Self::GameStateCleared(topic_name) => *topic_name,
+            Self::SessionDeleted => EventPlaneTopicNames::SessionDeleted,
+            Self::TurnTaken(_) => EventPlaneTopicNames::TurnTaken,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use crate::event_plane_error::EventPlaneError;
+use crate::event_plane_message::EventPlaneMessage;
+use crate::models::{EventPlaneConfig, EventPlaneTopicNames};
+
+impl EventPlaneTopicNames {
+    //
+
+    /// All the topic names of the event plane.
+    pub const ALL: [EventPlaneTopicNames; 7] = [
+        EventPlaneTopicNames::AllPlayersReady,
+        EventPlaneTopicNames::GameDeleted,
+        EventPlaneTopicNames::GameEndedInStalemate,
+        EventPlaneTopicNames::GameEndedInWin,
+        EventPlaneTopicNames::GameStarted,
+        EventPlaneTopicNames::SessionDeleted,
+        EventPlaneTopicNames::TurnTaken,
+    ];
+
+    /// Constructs the full topic, i.e. `[topic_prefix]/[event topic name]`.
+    pub fn build(&self, topic_prefix: &str) -> String {
+        format!("{topic_prefix}/{self}")
+    }
+
+    /// Parses a full topic back into its topic name. Returns None if the topic does not start
+    /// with the specified prefix or does not name an event.
+    pub fn parse(topic_prefix: &str, topic: &str) -> Option<Self> {
+        let name = topic.strip_prefix(topic_prefix)?.strip_prefix('/')?;
+        Self::ALL.into_iter().find(|topic_name| topic_name.to_string() == name)
+    }
+}
+
+impl EventPlaneConfig {
+    //
+
+    /// Returns the full topic on which the specified event is published.
+    pub fn topic(&self, topic_name: EventPlaneTopicNames) -> String {
+        topic_name.build(&self.topic_prefix)
+    }
+
+    /// Returns the topic filter that subscribes to all events of the Gaming Session.
+    pub fn topic_filter(&self) -> String {
+        format!("{}/#", self.topic_prefix)
+    }
+
+    /// Returns the topic filters that subscribe to the specified events only.
+    pub fn topic_filters(&self, topic_names: &[EventPlaneTopicNames]) -> Vec<String> {
+        topic_names.iter().map(|topic_name| self.topic(*topic_name)).collect()
+    }
+
+    /// Parses a received topic back into its topic name. Returns None if the topic is not part
+    /// of this Gaming Session's event plane.
+    pub fn parse_topic(&self, topic: &str) -> Option<EventPlaneTopicNames> {
+        EventPlaneTopicNames::parse(&self.topic_prefix, topic)
+    }
+
+    /// Decodes a message received on the specified topic.
+    pub fn decode_message(&self, topic: &str, payload: &[u8]) -> Result<EventPlaneMessage, EventPlaneError> {
+        let topic_name = self.parse_topic(topic).ok_or_else(|| EventPlaneError::UnknownTopic(topic.to_string()))?;
+        EventPlaneMessage::decode(topic_name, payload)
+    }
+}
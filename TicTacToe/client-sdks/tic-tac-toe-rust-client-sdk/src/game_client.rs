@@ -0,0 +1,256 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use std::time::Duration;
+
+use crate::apis::configuration::Configuration;
+use crate::apis::tic_tac_toe_api;
+use crate::game_client_error::GameClientError;
+use crate::game_client_event::GameClientEvent;
+use crate::game_event_listener::GameEventListener;
+use crate::models::{AutomaticPlayerSkillLevel, BoardPosition, EndGameParams, EndGamingSessionParams,
+                    EventPlaneConfig, GameInfoResponse, GameState, GameTurnParams, JoinSessionParams,
+                    NewGamingSessionParams, NewSinglePlayerGameParams, PlayerInfo, TurnResponse};
+
+/// How often the service is polled while the event plane broker is unreachable.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Stateful facade over the Tic-Tac-Toe API. Owns the Gaming Session, Player and Game IDs and
+/// reports Game changes as GameClientEvents.
+///
+/// Game changes are detected via the real-time event plane described by the Gaming Session's
+/// EventPlaneConfig. While the event plane broker is unreachable, the service is polled instead.
+pub struct GameClient {
+    configuration: Configuration,
+    event_plane_config: EventPlaneConfig,
+    game_id: Option<String>,
+    invitation_code: String,
+    is_initiator: bool,
+    latest_turn: Option<TurnResponse>,
+    listener: Option<GameEventListener>,
+    local_player: PlayerInfo,
+    opponent: Option<PlayerInfo>,
+    poll_interval: Duration,
+    session_id: String,
+}
+
+// Gaming Sessions
+impl GameClient {
+    //
+
+    /// Creates a new Gaming Session owned by the local Player. Share the invitation_code() with
+    /// the other Player so that they can join.
+    pub fn create_session(configuration: Configuration, display_name: &str) -> Result<Self, GameClientError> {
+        let params = NewGamingSessionParams::new(display_name.to_string());
+        let response = tic_tac_toe_api::create_gaming_session(&configuration, params)?;
+        Ok(Self::new(configuration,
+                     response.session_id,
+                     response.invitation_code,
+                     response.event_plane_config,
+                     response.initiating_player,
+                     true))
+    }
+
+    /// Joins the Gaming Session to which the local Player has been invited.
+    pub fn join_session(configuration: Configuration, invitation_code: &str, display_name: &str) -> Result<Self, GameClientError> {
+        let params = JoinSessionParams::new(invitation_code.to_string(), display_name.to_string());
+        let response = tic_tac_toe_api::join_gaming_session(&configuration, params)?;
+        let local_player = response.other_player.flatten().unwrap_or_default();
+        Ok(Self::new(configuration,
+                     response.session_id,
+                     String::new(),
+                     response.event_plane_config,
+                     local_player,
+                     false))
+    }
+
+    /// Sets how often the service is polled while the event plane broker is unreachable.
+    /// Defaults to one second.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Closes down the Gaming Session, including its current Game.
+    pub fn end_session(mut self) -> Result<(), GameClientError> {
+        self.stop_listening();
+        let params = EndGamingSessionParams::new(self.local_player.player_id.clone());
+        tic_tac_toe_api::end_gaming_session(&self.configuration, &self.session_id, params)?;
+        Ok(())
+    }
+
+    fn new(configuration: Configuration,
+           session_id: String,
+           invitation_code: String,
+           event_plane_config: EventPlaneConfig,
+           local_player: PlayerInfo,
+           is_initiator: bool) -> Self {
+        Self {
+            configuration,
+            event_plane_config,
+            game_id: None,
+            invitation_code,
+            is_initiator,
+            latest_turn: None,
+            listener: None,
+            local_player,
+            opponent: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            session_id,
+        }
+    }
+}
+
+// Games
+impl GameClient {
+    //
+
+    /// Starts a Two-Player Game in the Gaming Session. GameClientEvent::OpponentJoined is reported
+    /// once the invited Player has joined.
+    pub fn start_two_player_game(&mut self) -> Result<GameInfoResponse, GameClientError> {
+        tic_tac_toe_api::create_two_player_game(&self.configuration, &self.session_id)?;
+        self.join_current_game()
+    }
+
+    /// Starts a Single-Player Game against the computer, at the specified skill level.
+    pub fn start_single_player_game(&mut self, skill_level: AutomaticPlayerSkillLevel) -> Result<GameInfoResponse, GameClientError> {
+        let params = NewSinglePlayerGameParams::new(skill_level);
+        tic_tac_toe_api::create_single_player_game(&self.configuration, &self.session_id, params)?;
+        self.join_current_game()
+    }
+
+    /// Joins the Gaming Session's current Game and begins reporting its changes.
+    pub fn join_current_game(&mut self) -> Result<GameInfoResponse, GameClientError> {
+        //
+
+        let response = tic_tac_toe_api::join_current_game(&self.configuration, &self.session_id, &self.local_player.player_id)?;
+
+        if self.is_initiator {
+            self.local_player = response.initiating_player.clone();
+        } else {
+            if let Some(Some(player)) = response.other_player.clone() {
+                self.local_player = player;
+            }
+            self.opponent = Some(response.initiating_player.clone());
+        }
+        self.game_id = Some(response.game_info.game_id.clone());
+        self.latest_turn = None;
+
+        self.start_listening(&response.game_info.game_id, response.game_info.game_state.clone());
+
+        Ok(response.game_info)
+    }
+
+    /// Places the local Player's Game Piece at the specified location.
+    pub fn play(&self, row: i32, column: i32) -> Result<TurnResponse, GameClientError> {
+        let game_id = self.game_id.as_ref().ok_or(GameClientError::NoCurrentGame)?;
+        let params = GameTurnParams::new(BoardPosition::new(column, row),
+                                         self.local_player.player_id.clone(),
+                                         self.session_id.clone());
+        Ok(tic_tac_toe_api::take_turn(&self.configuration, game_id, params)?)
+    }
+
+    /// Closes down the current Game. The Gaming Session remains open for further Games.
+    pub fn end_game(&mut self) -> Result<(), GameClientError> {
+        let game_id = self.game_id.take().ok_or(GameClientError::NoCurrentGame)?;
+        self.stop_listening();
+        let params = EndGameParams::new(self.local_player.player_id.clone(), self.session_id.clone());
+        tic_tac_toe_api::end_game(&self.configuration, &game_id, params)?;
+        Ok(())
+    }
+}
+
+// Events
+impl GameClient {
+    //
+
+    /// Returns the next Game change, if one has been reported, without waiting.
+    pub fn try_next_event(&mut self) -> Option<GameClientEvent> {
+        let event = self.listener.as_ref()?.try_next_event()?;
+        self.apply(&event);
+        Some(event)
+    }
+
+    /// Waits up to the specified timeout for the next Game change.
+    pub fn next_event(&mut self, timeout: Duration) -> Option<GameClientEvent> {
+        let event = self.listener.as_ref()?.next_event(timeout)?;
+        self.apply(&event);
+        Some(event)
+    }
+
+    /// Keeps the client's state in step with the events handed to the caller.
+    fn apply(&mut self, event: &GameClientEvent) {
+        match event {
+            GameClientEvent::OpponentJoined(player) => self.opponent = Some(player.clone()),
+            GameClientEvent::TurnTaken(turn) | GameClientEvent::GameEnded(turn) => self.latest_turn = Some(turn.clone()),
+            GameClientEvent::GameDeleted | GameClientEvent::SessionDeleted => {
+                self.game_id = None;
+                self.stop_listening();
+            }
+            // The new Game is reported, but only played once it has been joined.
+            GameClientEvent::GameCreated(_) => {}
+        }
+    }
+
+    fn start_listening(&mut self, game_id: &str, initial_game_state: GameState) {
+        //
+
+        self.listener = Some(GameEventListener::start(self.configuration.clone(),
+                                                      self.event_plane_config.clone(),
+                                                      &self.session_id,
+                                                      game_id,
+                                                      &self.local_player.player_id,
+                                                      initial_game_state,
+                                                      self.poll_interval));
+    }
+
+    /// Stops reporting Game changes. Those already reported can still be retrieved.
+    fn stop_listening(&mut self) {
+        if let Some(listener) = &self.listener {
+            listener.stop();
+        }
+    }
+}
+
+// Accessors
+impl GameClient {
+    //
+
+    /// Returns the configuration of the Gaming Session's real-time event plane.
+    pub fn event_plane_config(&self) -> &EventPlaneConfig {
+        &self.event_plane_config
+    }
+
+    /// Returns the ID of the current Game, if one has been created or joined.
+    pub fn game_id(&self) -> Option<&str> {
+        self.game_id.as_deref()
+    }
+
+    /// Returns the code with which another Player can join the Gaming Session. Empty for the
+    /// invited Player.
+    pub fn invitation_code(&self) -> &str {
+        &self.invitation_code
+    }
+
+    /// Returns the most recently reported Turn of the current Game.
+    pub fn latest_turn(&self) -> Option<&TurnResponse> {
+        self.latest_turn.as_ref()
+    }
+
+    /// Returns the local Player.
+    pub fn local_player(&self) -> &PlayerInfo {
+        &self.local_player
+    }
+
+    /// Returns the other Player, once they have joined.
+    pub fn opponent(&self) -> Option<&PlayerInfo> {
+        self.opponent.as_ref()
+    }
+
+    /// Returns the ID of the Gaming Session.
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+}
@@ -0,0 +1,74 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use std::error;
+use std::fmt;
+
+use crate::apis::Error;
+use crate::models::GameError;
+
+/// Enumerates the errors returned by the GameClient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameClientError {
+    /// The Tic-Tac-Toe service could not be reached or returned an unreadable response.
+    Communication(String),
+    /// The requested action requires a Game, but none has been created or joined.
+    NoCurrentGame,
+    /// The Tic-Tac-Toe service rejected the request with the specified HTTP status and, if the
+    /// response identified it, the specified error.
+    Rejected(reqwest::StatusCode, Option<GameError>),
+}
+
+impl GameClientError {
+    //
+
+    /// Describes, for the Player, why GameClient::play() did not place their Game Piece.
+    pub fn describe_rejected_move(&self) -> String {
+        match self {
+            GameClientError::Rejected(_, Some(code)) => match code {
+                GameError::BoardLocationAlreadyOccupied => "That location is already taken.".to_string(),
+                GameError::GameHasAlreadyEnded => "The Game has already ended.".to_string(),
+                GameError::GameNotFound => "The Game no longer exists.".to_string(),
+                GameError::GameNotStarted => "The Game has not started as yet.".to_string(),
+                GameError::InvalidBoardPosition => "That location is not on the board.".to_string(),
+                GameError::PlayerGamePieceNotSelected => "Your Game Piece has not been selected as yet.".to_string(),
+                GameError::WrongPlayerTakingTurn => "It is not your turn.".to_string(),
+                _ => format!("The move was rejected: {}", self),
+            },
+            // Services that predate ErrorResponse only identify the error by its status.
+            GameClientError::Rejected(status, None) => match status.as_u16() {
+                405 => "It is not your turn.".to_string(),
+                406 => "The Game has already ended.".to_string(),
+                409 => "That location is already taken.".to_string(),
+                _ => format!("The move was rejected: {}", self),
+            },
+            GameClientError::NoCurrentGame => "There is no Game to play.".to_string(),
+            GameClientError::Communication(_) => format!("Problem communicating with the Tic-Tac-Toe service: {}", self),
+        }
+    }
+}
+
+impl fmt::Display for GameClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameClientError::Communication(message) => write!(f, "communication error: {}", message),
+            GameClientError::NoCurrentGame => write!(f, "no Game has been created or joined"),
+            GameClientError::Rejected(status, None) => write!(f, "request rejected with status code {}", status),
+            GameClientError::Rejected(status, Some(code)) => write!(f, "request rejected with status code {} ({})", status, code),
+        }
+    }
+}
+
+impl error::Error for GameClientError {}
+
+impl <T> From<Error<T>> for GameClientError {
+    fn from(e: Error<T>) -> Self {
+        let code = e.game_error();
+        match e {
+            Error::ResponseError(response) => GameClientError::Rejected(response.status, code),
+            other => GameClientError::Communication(other.to_string()),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use crate::models::{GameCreationResponse, PlayerInfo, TurnResponse};
+
+/// Enumerates the Game changes reported by the GameClient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameClientEvent {
+    /// The other Player has joined and the Game can begin.
+    OpponentJoined(PlayerInfo),
+    /// A Player has taken a turn and the Game is still in progress.
+    TurnTaken(TurnResponse),
+    /// The Game has ended in a win or a stalemate.
+    GameEnded(TurnResponse),
+    /// The Game has been deleted from the service.
+    GameDeleted,
+    /// The Gaming Session has been deleted from the service.
+    SessionDeleted,
+    /// A new Game, e.g. a rematch, has replaced the ended Game in the Gaming Session.
+    GameCreated(Box<GameCreationResponse>),
+}
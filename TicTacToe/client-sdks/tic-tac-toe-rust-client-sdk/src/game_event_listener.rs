@@ -0,0 +1,262 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use rumqttc::{Client, Event, MqttOptions, Packet, QoS, RecvTimeoutError};
+use uuid::Uuid;
+
+use crate::apis::configuration::Configuration;
+use crate::apis::tic_tac_toe_api;
+use crate::event_plane_error::EventPlaneError;
+use crate::event_plane_message::EventPlaneMessage;
+use crate::game_client_event::GameClientEvent;
+use crate::models::{EventPlaneConfig, GameCreationResponse, GameState, PlayStatus, PlayerInfo};
+
+/// Watches a Gaming Session's Games from a background thread and reports their changes as
+/// GameClientEvents. GameClient listens via this type. Apps that make their own API calls can use
+/// it directly.
+///
+/// Event plane messages are used as a cue to fetch the latest state from the service. While the
+/// broker is unreachable, the latest state is fetched every poll interval instead. Once a Game has
+/// ended, the Gaming Session is watched for a new Game, e.g. a rematch, which is reported as
+/// GameClientEvent::GameCreated and then followed in turn. Dropping the listener stops the thread.
+pub struct GameEventListener {
+    events: Receiver<GameClientEvent>,
+    is_running: Arc<AtomicBool>,
+}
+
+impl GameEventListener {
+    //
+
+    /// Begins watching the specified Game, whose state is currently initial_game_state, on behalf
+    /// of the specified local Player.
+    pub fn start(configuration: Configuration,
+                 event_plane_config: EventPlaneConfig,
+                 session_id: &str,
+                 game_id: &str,
+                 local_player_id: &str,
+                 initial_game_state: GameState,
+                 poll_interval: Duration) -> Self {
+        //
+
+        let is_running = Arc::new(AtomicBool::new(true));
+        let (events_sender, events) = mpsc::channel();
+
+        let watcher = GameWatcher {
+            configuration,
+            event_plane_config,
+            events_sender,
+            game_has_ended: false,
+            game_id: game_id.to_string(),
+            is_running: is_running.clone(),
+            last_game_state: initial_game_state,
+            local_player_id: local_player_id.to_string(),
+            opponent_has_joined: false,
+            poll_interval,
+            session_id: session_id.to_string(),
+        };
+        thread::spawn(move || watcher.run());
+
+        Self { events, is_running }
+    }
+
+    /// Returns the next Game change, if one has been reported, without waiting.
+    pub fn try_next_event(&self) -> Option<GameClientEvent> {
+        self.events.try_recv().ok()
+    }
+
+    /// Waits up to the specified timeout for the next Game change.
+    pub fn next_event(&self, timeout: Duration) -> Option<GameClientEvent> {
+        self.events.recv_timeout(timeout).ok()
+    }
+
+    /// Stops watching. Changes reported before stopping can still be retrieved.
+    pub fn stop(&self) {
+        self.is_running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl Drop for GameEventListener {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Does the GameEventListener's watching on its background thread.
+struct GameWatcher {
+    configuration: Configuration,
+    event_plane_config: EventPlaneConfig,
+    events_sender: Sender<GameClientEvent>,
+    game_has_ended: bool,
+    game_id: String,
+    is_running: Arc<AtomicBool>,
+    last_game_state: GameState,
+    local_player_id: String,
+    opponent_has_joined: bool,
+    poll_interval: Duration,
+    session_id: String,
+}
+
+impl GameWatcher {
+    //
+
+    fn run(mut self) {
+        //
+
+        let broker_port = u16::try_from(self.event_plane_config.broker_port).unwrap_or_default();
+        let mut options = MqttOptions::new(Uuid::new_v4().to_string(), self.event_plane_config.broker_address.clone(), broker_port);
+        options.set_keep_alive(Duration::from_secs(30));
+        let (client, mut connection) = Client::new(options, 10);
+        let topic_filter = self.event_plane_config.topic_filter();
+
+        self.refresh();
+
+        while self.is_running.load(Ordering::Relaxed) {
+            match connection.recv_timeout(self.poll_interval) {
+                Ok(Ok(Event::Incoming(Packet::ConnAck(_)))) => {
+                    // (Re)subscribe and catch up on anything missed while disconnected.
+                    let _ = client.try_subscribe(topic_filter.as_str(), QoS::AtLeastOnce);
+                    self.refresh();
+                }
+                Ok(Ok(Event::Incoming(Packet::Publish(publish)))) => self.on_message(&publish.topic, &publish.payload),
+                Ok(Ok(_)) => {}
+                Ok(Err(_)) => {
+                    // The broker is unreachable. Poll until the connection is re-established.
+                    self.refresh();
+                    thread::sleep(self.poll_interval);
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    // The event plane does not announce new Games. So, check for one periodically.
+                    if self.game_has_ended {
+                        self.check_for_new_game();
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        let _ = client.try_disconnect();
+    }
+
+    /// Translates an event plane message into GameClientEvents.
+    fn on_message(&mut self, topic: &str, payload: &[u8]) {
+        match self.event_plane_config.decode_message(topic, payload) {
+            Ok(EventPlaneMessage::GameDeleted) => self.send(GameClientEvent::GameDeleted),
+            Ok(EventPlaneMessage::SessionDeleted) => self.send(GameClientEvent::SessionDeleted),
+            // The Turn, including the current Player, is fetched from the service.
+            Ok(_) | Err(EventPlaneError::InvalidPayload(_)) => self.refresh(),
+            Err(EventPlaneError::UnknownTopic(_)) => {}
+        }
+    }
+
+    /// Fetches the latest state of the Game and reports any changes since the previous fetch.
+    fn refresh(&mut self) {
+        //
+
+        if self.game_has_ended {
+            self.check_for_new_game();
+            return;
+        }
+
+        if !self.opponent_has_joined {
+            let readiness = match tic_tac_toe_api::get_players_readiness(&self.configuration, &self.game_id) {
+                Ok(readiness) => readiness,
+                Err(error) if error.is_not_found() => {
+                    self.report_missing_game();
+                    return;
+                }
+                Err(_) => return, // Early return. Try again on the next cue.
+            };
+            if !readiness.all_players_are_ready {
+                return; // Early return. Still waiting for the other Player.
+            }
+            let Ok(current_game) = tic_tac_toe_api::get_session_current_game(&self.configuration, &self.session_id) else {
+                return; // Early return. Try again on the next cue.
+            };
+            self.opponent_has_joined = true;
+            if let Some(opponent) = self.find_opponent(current_game) {
+                self.send(GameClientEvent::OpponentJoined(opponent));
+            }
+        }
+
+        let turn = match tic_tac_toe_api::get_latest_game_turn(&self.configuration, &self.game_id) {
+            Ok(turn) => turn,
+            Err(error) if error.is_not_found() => {
+                self.report_missing_game();
+                return;
+            }
+            Err(_) => return, // Early return. Try again on the next cue.
+        };
+        if turn.new_game_state == self.last_game_state {
+            return; // Early return. Nothing has changed.
+        }
+        self.last_game_state = turn.new_game_state.clone();
+
+        match turn.new_game_state.play_status {
+            PlayStatus::EndedInStalemate | PlayStatus::EndedInWin => self.send(GameClientEvent::GameEnded(turn)),
+            PlayStatus::InProgress | PlayStatus::NotStarted => self.send(GameClientEvent::TurnTaken(turn)),
+        }
+    }
+
+    /// Looks for a Game that has replaced the ended Game in the Gaming Session.
+    fn check_for_new_game(&mut self) {
+        //
+
+        let game = match tic_tac_toe_api::get_session_current_game(&self.configuration, &self.session_id) {
+            Ok(game) => game,
+            Err(error) if error.is_session_not_found() => {
+                self.send(GameClientEvent::SessionDeleted);
+                return;
+            }
+            Err(_) => return, // Early return. No new Game as yet, or try again on the next cue.
+        };
+        if game.game_info.game_id == self.game_id {
+            return; // Early return. Still the same Game.
+        }
+
+        // Follow the new Game from here on.
+        self.game_has_ended = false;
+        self.game_id = game.game_info.game_id.clone();
+        self.last_game_state = game.game_info.game_state.clone();
+        self.opponent_has_joined = false;
+
+        self.send(GameClientEvent::GameCreated(Box::new(game)));
+    }
+
+    /// Reports that the Game, or its whole Gaming Session, no longer exists.
+    fn report_missing_game(&mut self) {
+        match tic_tac_toe_api::get_session_current_game(&self.configuration, &self.session_id) {
+            Err(error) if error.is_session_not_found() => self.send(GameClientEvent::SessionDeleted),
+            _ => self.send(GameClientEvent::GameDeleted),
+        }
+    }
+
+    fn find_opponent(&self, current_game: GameCreationResponse) -> Option<PlayerInfo> {
+        std::iter::once(current_game.initiating_player)
+            .chain(current_game.other_player.flatten())
+            .find(|player| player.player_id != self.local_player_id)
+    }
+
+    /// Hands the event to the listener. Watching stops once the Gaming Session is gone.
+    fn send(&mut self, event: GameClientEvent) {
+        //
+
+        match &event {
+            GameClientEvent::GameEnded(_) | GameClientEvent::GameDeleted => self.game_has_ended = true,
+            GameClientEvent::SessionDeleted => self.is_running.store(false, Ordering::Relaxed),
+            _ => {}
+        }
+
+        if self.events_sender.send(event).is_err() {
+            // The GameEventListener has gone away.
+            self.is_running.store(false, Ordering::Relaxed);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(unused_imports)]
 #![allow(clippy::too_many_arguments)]
+#![allow(clippy::derivable_impls)]
 
 extern crate serde_repr;
 extern crate serde;
@@ -8,4 +9,16 @@ extern crate url;
 extern crate reqwest;
 
 pub mod apis;
+pub mod event_plane_error;
+pub mod event_plane_message;
+mod event_plane_topics;
+#[cfg(feature = "game-client")]
+pub mod game_client;
+#[cfg(feature = "game-client")]
+pub mod game_client_error;
+#[cfg(feature = "game-client")]
+pub mod game_event_listener;
+#[cfg(feature = "game-client")]
+pub mod game_client_event;
 pub mod models;
+mod tests;
@@ -0,0 +1,29 @@
+/*
+ * Tic-Tac-Toe Service
+ *
+ * Tic-Tac-Toe Game Service
+ *
+ * The version of the OpenAPI document: 0.4.0
+ * Contact: JoelDavisEngineering@Gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ErrorResponse : Models the body of an error response, identifying the error so that clients need not parse any text
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    #[serde(rename = "code")]
+    pub code: models::GameError,
+}
+
+impl ErrorResponse {
+    /// Models the body of an error response, identifying the error so that clients need not parse any text
+    pub fn new(code: models::GameError) -> ErrorResponse {
+        ErrorResponse {
+            code,
+        }
+    }
+}
+
@@ -0,0 +1,66 @@
+/*
+ * Tic-Tac-Toe Service
+ *
+ * Tic-Tac-Toe Game Service
+ *
+ * The version of the OpenAPI document: 0.4.0
+ * Contact: JoelDavisEngineering@Gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// GameError : Defines the errors used throughout the service
+/// Defines the errors used throughout the service
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum GameError {
+    #[serde(rename = "BoardLocationAlreadyOccupied")]
+    BoardLocationAlreadyOccupied,
+    #[serde(rename = "GameHasMaximumNumberOfPlayers")]
+    GameHasMaximumNumberOfPlayers,
+    #[serde(rename = "GameHasAlreadyEnded")]
+    GameHasAlreadyEnded,
+    #[serde(rename = "GameNotStarted")]
+    GameNotStarted,
+    #[serde(rename = "GameNotFound")]
+    GameNotFound,
+    #[serde(rename = "GamingSessionNotFound")]
+    GamingSessionNotFound,
+    #[serde(rename = "InvalidBoardPosition")]
+    InvalidBoardPosition,
+    #[serde(rename = "InvitationCodeNotFound")]
+    InvitationCodeNotFound,
+    #[serde(rename = "PlayerGamePieceNotSelected")]
+    PlayerGamePieceNotSelected,
+    #[serde(rename = "PlayerNotFound")]
+    PlayerNotFound,
+    #[serde(rename = "WrongPlayerTakingTurn")]
+    WrongPlayerTakingTurn,
+
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BoardLocationAlreadyOccupied => write!(f, "BoardLocationAlreadyOccupied"),
+            Self::GameHasMaximumNumberOfPlayers => write!(f, "GameHasMaximumNumberOfPlayers"),
+            Self::GameHasAlreadyEnded => write!(f, "GameHasAlreadyEnded"),
+            Self::GameNotStarted => write!(f, "GameNotStarted"),
+            Self::GameNotFound => write!(f, "GameNotFound"),
+            Self::GamingSessionNotFound => write!(f, "GamingSessionNotFound"),
+            Self::InvalidBoardPosition => write!(f, "InvalidBoardPosition"),
+            Self::InvitationCodeNotFound => write!(f, "InvitationCodeNotFound"),
+            Self::PlayerGamePieceNotSelected => write!(f, "PlayerGamePieceNotSelected"),
+            Self::PlayerNotFound => write!(f, "PlayerNotFound"),
+            Self::WrongPlayerTakingTurn => write!(f, "WrongPlayerTakingTurn"),
+        }
+    }
+}
+
+impl Default for GameError {
+    fn default() -> GameError {
+        Self::BoardLocationAlreadyOccupied
+    }
+}
+
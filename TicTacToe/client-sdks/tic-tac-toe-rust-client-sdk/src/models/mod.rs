@@ -6,12 +6,16 @@ pub mod end_game_params;
 pub use self::end_game_params::EndGameParams;
 pub mod end_gaming_session_params;
 pub use self::end_gaming_session_params::EndGamingSessionParams;
+pub mod error_response;
+pub use self::error_response::ErrorResponse;
 pub mod event_plane_config;
 pub use self::event_plane_config::EventPlaneConfig;
 pub mod event_plane_topic_names;
 pub use self::event_plane_topic_names::EventPlaneTopicNames;
 pub mod game_creation_response;
 pub use self::game_creation_response::GameCreationResponse;
+pub mod game_error;
+pub use self::game_error::GameError;
 pub mod game_info_response;
 pub use self::game_info_response::GameInfoResponse;
 pub mod game_mode;
@@ -30,6 +34,8 @@ pub mod new_gaming_session_params;
 pub use self::new_gaming_session_params::NewGamingSessionParams;
 pub mod new_single_player_game_params;
 pub use self::new_single_player_game_params::NewSinglePlayerGameParams;
+pub mod new_two_player_game_params;
+pub use self::new_two_player_game_params::NewTwoPlayerGameParams;
 pub mod play_status;
 pub use self::play_status::PlayStatus;
 pub mod player_info;
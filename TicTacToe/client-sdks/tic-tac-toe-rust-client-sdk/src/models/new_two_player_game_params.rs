@@ -0,0 +1,30 @@
+/*
+ * Tic-Tac-Toe Service
+ *
+ * Tic-Tac-Toe Game Service
+ *
+ * The version of the OpenAPI document: 0.4.0
+ * Contact: JoelDavisEngineering@Gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// NewTwoPlayerGameParams : Models info needed to start a new Two-Player Game
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NewTwoPlayerGameParams {
+    /// ID of the Player who is to play X and, so, take the first turn. When omitted, the Game Pieces are assigned at random.
+    #[serde(rename = "starting_player_id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub starting_player_id: Option<Option<String>>,
+}
+
+impl NewTwoPlayerGameParams {
+    /// Models info needed to start a new Two-Player Game
+    pub fn new() -> NewTwoPlayerGameParams {
+        NewTwoPlayerGameParams {
+            starting_player_id: None,
+        }
+    }
+}
+
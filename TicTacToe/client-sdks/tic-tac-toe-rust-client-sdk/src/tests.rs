@@ -0,0 +1,647 @@
+// Tic-Tac-Toe Rust Client SDK
+//
+// © 2024 Rust Made Easy. All rights reserved.
+// @author JoelDavisEngineering@Gmail.com
+
+#[cfg(test)]
+mod event_plane_tests {
+    use crate::event_plane_error::EventPlaneError;
+    use crate::event_plane_message::EventPlaneMessage;
+    use crate::models::{EventPlaneConfig, EventPlaneTopicNames, GamePiece, PlayStatus};
+
+    // NOTE: These fixtures mirror the topics and payloads produced by the Tic-Tac-Toe service.
+    // The service's event_plane_contract_tests pin the same values.
+    const CHANNEL_ID: &str = "a4a42b35-3c4b-4b63-9f3e-3a3a2b1c0d0e";
+    const TOPIC_PREFIX: &str = "RustMadeEasy.com/Channels/a4a42b35-3c4b-4b63-9f3e-3a3a2b1c0d0e";
+    const GAME_STATE_PAYLOAD: &str = r#"{"id_of_player_who_made_move":"player-1","game_board":[["X","Unselected","Unselected"],["Unselected","O","Unselected"],["Unselected","Unselected","Unselected"]],"play_status":"InProgress"}"#;
+
+    fn new_config() -> EventPlaneConfig {
+        EventPlaneConfig::new("localhost".to_string(), 1883, CHANNEL_ID.to_string(), TOPIC_PREFIX.to_string())
+    }
+
+    #[test]
+    fn test_build_topic() {
+        assert_eq!(EventPlaneTopicNames::TurnTaken.build(TOPIC_PREFIX), format!("{TOPIC_PREFIX}/TurnTaken"));
+        assert_eq!(new_config().topic(EventPlaneTopicNames::GameEndedInWin), format!("{TOPIC_PREFIX}/GameEndedInWin"));
+    }
+
+    #[test]
+    fn test_topic_filters() {
+        let config = new_config();
+        assert_eq!(config.topic_filter(), format!("{TOPIC_PREFIX}/#"));
+        assert_eq!(config.topic_filters(&[EventPlaneTopicNames::AllPlayersReady, EventPlaneTopicNames::TurnTaken]),
+                   vec![format!("{TOPIC_PREFIX}/AllPlayersReady"), format!("{TOPIC_PREFIX}/TurnTaken")]);
+    }
+
+    #[test]
+    fn test_parse_topic_round_trip() {
+        let config = new_config();
+        for topic_name in EventPlaneTopicNames::ALL {
+            assert_eq!(config.parse_topic(&config.topic(topic_name)), Some(topic_name));
+        }
+    }
+
+    #[test]
+    fn test_parse_foreign_topic() {
+        let config = new_config();
+        assert_eq!(config.parse_topic("RustMadeEasy.com/Channels/other-channel/TurnTaken"), None);
+        assert_eq!(config.parse_topic(&format!("{TOPIC_PREFIX}/NotAnEvent")), None);
+        assert_eq!(config.parse_topic(&format!("{TOPIC_PREFIX}TurnTaken")), None);
+        assert_eq!(config.parse_topic(TOPIC_PREFIX), None);
+    }
+
+    #[test]
+    fn test_decode_game_state_round_trip() {
+        //
+
+        let config = new_config();
+        let message = config.decode_message(&config.topic(EventPlaneTopicNames::TurnTaken), GAME_STATE_PAYLOAD.as_bytes()).unwrap();
+
+        let EventPlaneMessage::TurnTaken(game_state) = message else {
+            panic!("Expected TurnTaken, got {:?}", message);
+        };
+        assert_eq!(game_state.id_of_player_who_made_move, "player-1");
+        assert_eq!(game_state.play_status, PlayStatus::InProgress);
+        assert_eq!(game_state.game_board[0][0], GamePiece::X);
+        assert_eq!(game_state.game_board[1][1], GamePiece::O);
+
+        // Re-encoding must produce the same JSON that the service published.
+        let expected: serde_json::Value = serde_json::from_str(GAME_STATE_PAYLOAD).unwrap();
+        assert_eq!(serde_json::to_value(&game_state).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_decode_game_ended() {
+        let payload = GAME_STATE_PAYLOAD.replace("InProgress", "EndedInWin");
+        let message = EventPlaneMessage::decode(EventPlaneTopicNames::GameEndedInWin, payload.as_bytes()).unwrap();
+        assert!(matches!(message, EventPlaneMessage::GameEndedInWin(ref game_state) if game_state.play_status == PlayStatus::EndedInWin));
+        assert_eq!(message.topic_name(), EventPlaneTopicNames::GameEndedInWin);
+    }
+
+    #[test]
+    fn test_decode_notifications() {
+        assert_eq!(EventPlaneMessage::decode(EventPlaneTopicNames::AllPlayersReady, b"").unwrap(), EventPlaneMessage::AllPlayersReady);
+        assert_eq!(EventPlaneMessage::decode(EventPlaneTopicNames::GameDeleted, b"").unwrap(), EventPlaneMessage::GameDeleted);
+        assert_eq!(EventPlaneMessage::decode(EventPlaneTopicNames::SessionDeleted, b"").unwrap(), EventPlaneMessage::SessionDeleted);
+    }
+
+    #[test]
+    fn test_decode_cleared_game_state() {
+        let message = EventPlaneMessage::decode(EventPlaneTopicNames::TurnTaken, b"").unwrap();
+        assert_eq!(message, EventPlaneMessage::GameStateCleared(EventPlaneTopicNames::TurnTaken));
+        assert_eq!(message.topic_name(), EventPlaneTopicNames::TurnTaken);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let config = new_config();
+        assert!(matches!(config.decode_message(&config.topic(EventPlaneTopicNames::TurnTaken), b"not json"),
+                         Err(EventPlaneError::InvalidPayload(_))));
+        assert_eq!(config.decode_message("some/other/topic", b""),
+                   Err(EventPlaneError::UnknownTopic("some/other/topic".to_string())));
+    }
+}
+
+/// Stands in for the Tic-Tac-Toe service, handing each request to a handler that returns the
+/// status and body of the response, or None to leave the request unanswered.
+#[cfg(test)]
+pub(crate) mod test_service {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    /// Models a request received by the stand-in service.
+    pub(crate) struct TestRequest {
+        pub(crate) method: String,
+        pub(crate) path: String,
+        pub(crate) body: String,
+    }
+
+    /// Starts the stand-in service and returns its URL.
+    pub(crate) fn serve(handler: impl Fn(&TestRequest) -> Option<(u16, String)> + Send + 'static) -> String {
+        //
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            // Unanswered requests are held open until the test ends.
+            let mut unanswered = vec![];
+            for mut stream in listener.incoming().flatten() {
+                let Some(request) = read_request(&stream) else {
+                    continue;
+                };
+                match handler(&request) {
+                    Some((status, body)) => {
+                        let _ = write!(stream, "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+                    }
+                    None => unanswered.push(stream),
+                }
+            }
+        });
+
+        url
+    }
+
+    /// Returns the URL of a port on which nothing is listening.
+    pub(crate) fn unused_url() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    fn read_request(stream: &TcpStream) -> Option<TestRequest> {
+        //
+
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).ok()?;
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next()?.to_string();
+        let path = parts.next()?.to_string();
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).ok()?;
+            if header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0_u8; content_length];
+        reader.read_exact(&mut body).ok()?;
+
+        Some(TestRequest { method, path, body: String::from_utf8_lossy(&body).to_string() })
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use crate::apis::configuration::Configuration;
+    use crate::apis::{execute_blocking, should_retry};
+    use crate::tests::test_service::{serve, unused_url};
+    use reqwest::{Method, StatusCode};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// Answers with the specified statuses, in order, one per request, repeating the last one once
+    /// they run out. Returns the service's URL and the number of requests received.
+    fn serve_statuses(statuses: &[u16]) -> (String, Arc<AtomicUsize>) {
+        let request_count = Arc::new(AtomicUsize::new(0));
+        let counter = request_count.clone();
+        let statuses = statuses.to_vec();
+        let url = serve(move |_| {
+            let index = counter.fetch_add(1, Ordering::SeqCst);
+            Some((statuses[index.min(statuses.len() - 1)], String::new()))
+        });
+        (url, request_count)
+    }
+
+    /// Leaves every request unanswered. Returns the service's URL and the number of requests
+    /// received.
+    fn serve_nothing() -> (String, Arc<AtomicUsize>) {
+        let request_count = Arc::new(AtomicUsize::new(0));
+        let counter = request_count.clone();
+        let url = serve(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            None
+        });
+        (url, request_count)
+    }
+
+    fn new_configuration(max_retries: u32) -> Configuration {
+        Configuration::new().with_retries(max_retries, Duration::from_millis(1))
+    }
+
+    fn send(configuration: &Configuration, method: Method, url: &str) -> Result<StatusCode, reqwest::Error> {
+        let request = configuration.client.request(method, url).build().unwrap();
+        execute_blocking(configuration, request).map(|response| response.status())
+    }
+
+    #[test]
+    fn test_should_retry_statuses() {
+        //
+
+        for status in [StatusCode::INTERNAL_SERVER_ERROR, StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE] {
+            assert!(should_retry(&Method::GET, Ok(status)));
+            assert!(should_retry(&Method::DELETE, Ok(status)));
+            assert!(!should_retry(&Method::POST, Ok(status)));
+        }
+
+        for status in [StatusCode::OK, StatusCode::BAD_REQUEST, StatusCode::FORBIDDEN, StatusCode::NOT_FOUND, StatusCode::CONFLICT] {
+            assert!(!should_retry(&Method::GET, Ok(status)));
+            assert!(!should_retry(&Method::POST, Ok(status)));
+        }
+    }
+
+    #[test]
+    fn test_should_retry_errors() {
+        //
+
+        // A request that never reached the service is safe to re-send, whatever its method.
+        let connect_error = reqwest::blocking::get(unused_url()).unwrap_err();
+        assert!(connect_error.is_connect());
+        assert!(should_retry(&Method::GET, Err(&connect_error)));
+        assert!(should_retry(&Method::POST, Err(&connect_error)));
+
+        // A request that timed out may have been applied.
+        let client = reqwest::blocking::Client::builder().timeout(Duration::from_millis(50)).build().unwrap();
+        let timeout_error = client.get(serve_nothing().0).send().unwrap_err();
+        assert!(timeout_error.is_timeout());
+        assert!(should_retry(&Method::GET, Err(&timeout_error)));
+        assert!(!should_retry(&Method::POST, Err(&timeout_error)));
+    }
+
+    #[test]
+    fn test_server_errors_are_retried() {
+        let (url, request_count) = serve_statuses(&[503, 500, 200]);
+        assert_eq!(send(&new_configuration(3), Method::GET, &url).unwrap(), StatusCode::OK);
+        assert_eq!(request_count.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_retried_requests_are_resent_unchanged() {
+        //
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        let url = serve(move |request| {
+            let mut requests = recorded.lock().unwrap();
+            requests.push((request.method.clone(), request.path.clone(), request.body.clone()));
+            Some((if requests.len() < 2 { 503 } else { 200 }, String::new()))
+        });
+
+        // Ending a Game is a DELETE with a body.
+        let configuration = new_configuration(3);
+        let request = configuration.client.delete(format!("{url}/v1/games/game-1")).body(r#"{"player_id":"player-1"}"#).build().unwrap();
+        assert_eq!(execute_blocking(&configuration, request).unwrap().status(), StatusCode::OK);
+
+        let expected = ("DELETE".to_string(), "/v1/games/game-1".to_string(), r#"{"player_id":"player-1"}"#.to_string());
+        assert_eq!(*requests.lock().unwrap(), vec![expected.clone(), expected]);
+    }
+
+    #[test]
+    fn test_client_errors_are_not_retried() {
+        let (url, request_count) = serve_statuses(&[404, 200]);
+        assert_eq!(send(&new_configuration(3), Method::GET, &url).unwrap(), StatusCode::NOT_FOUND);
+        assert_eq!(request_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_connection_errors_are_retried() {
+        //
+
+        let configuration = Configuration::new().with_retries(2, Duration::from_millis(100));
+        let url = unused_url();
+
+        // The service starts listening while the request is being retried.
+        let listener_url = url.clone();
+        let server = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            let listener = TcpListener::bind(listener_url.trim_start_matches("http://")).unwrap();
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0_u8; 4096]);
+            let _ = write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        });
+
+        assert_eq!(send(&configuration, Method::POST, &url).unwrap(), StatusCode::OK);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_max_retries_is_respected() {
+        //
+
+        let (url, request_count) = serve_statuses(&[500]);
+        assert_eq!(send(&new_configuration(2), Method::GET, &url).unwrap(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(request_count.load(Ordering::SeqCst), 3);
+
+        // Retries are off by default.
+        let (url, request_count) = serve_statuses(&[500]);
+        assert_eq!(send(&Configuration::new(), Method::GET, &url).unwrap(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(request_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_non_idempotent_requests_are_not_retried_after_server_error() {
+        //
+
+        // Taking a Turn twice would be worse than reporting the error.
+        let (url, request_count) = serve_statuses(&[500, 200]);
+        assert_eq!(send(&new_configuration(3), Method::POST, &url).unwrap(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(request_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_timeouts_are_retried_only_for_idempotent_requests() {
+        //
+
+        let configuration = new_configuration(2).with_timeout(Duration::from_millis(50));
+
+        let (url, request_count) = serve_nothing();
+        assert!(send(&configuration, Method::POST, &url).unwrap_err().is_timeout());
+        assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+        let (url, request_count) = serve_nothing();
+        assert!(send(&configuration, Method::GET, &url).unwrap_err().is_timeout());
+        assert_eq!(request_count.load(Ordering::SeqCst), 3);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_retries() {
+        //
+
+        use crate::apis::execute_async;
+
+        // The Configuration's blocking client must not be dropped within the runtime.
+        let configuration = new_configuration(2);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        runtime.block_on(async {
+            //
+
+            let (url, request_count) = serve_statuses(&[503, 500, 500, 200]);
+            let request = configuration.async_client.get(&url).build().unwrap();
+            let response = execute_async(&configuration, request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(request_count.load(Ordering::SeqCst), 3);
+
+            let (url, request_count) = serve_statuses(&[404, 200]);
+            let request = configuration.async_client.get(&url).build().unwrap();
+            assert_eq!(execute_async(&configuration, request).await.unwrap().status(), StatusCode::NOT_FOUND);
+            assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+            let (url, request_count) = serve_statuses(&[500, 200]);
+            let request = configuration.async_client.post(&url).build().unwrap();
+            assert_eq!(execute_async(&configuration, request).await.unwrap().status(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(request_count.load(Ordering::SeqCst), 1);
+        });
+    }
+}
+
+#[cfg(all(test, feature = "game-client"))]
+mod game_client_tests {
+    use crate::apis::configuration::Configuration;
+    use crate::game_client::GameClient;
+    use crate::game_client_error::GameClientError;
+    use crate::game_client_event::GameClientEvent;
+    use crate::models::{ErrorResponse, EventPlaneConfig, GameCreationResponse, GameError, GameInfoResponse,
+                        GamePiece, GameState, GameTurnParams, GamingSessionCreationResponse, PlayStatus,
+                        PlayerInfo, PlayersReadinessResponse, TurnResponse};
+    use crate::tests::test_service::{serve, unused_url};
+    use reqwest::StatusCode;
+    use serde::Serialize;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    const GAME_ID: &str = "game-1";
+    const INVITATION_CODE: &str = "ABC123";
+    const SESSION_ID: &str = "session-1";
+
+    /// Long enough for the listener to poll the service several times.
+    const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+    /// Long enough to be sure that no event is coming.
+    const QUIET_TIMEOUT: Duration = Duration::from_millis(200);
+
+    /// Models the state of the stand-in Tic-Tac-Toe service.
+    struct FakeService {
+        latest_turn: TurnResponse,
+        opponent_has_joined: bool,
+        take_turn_rejection: Option<(u16, GameError)>,
+        turns_taken: Vec<GameTurnParams>,
+    }
+
+    fn local_player() -> PlayerInfo {
+        PlayerInfo::new("Player One".to_string(), GamePiece::X, false, "player-1".to_string())
+    }
+
+    fn opponent() -> PlayerInfo {
+        PlayerInfo::new("Player Two".to_string(), GamePiece::O, false, "player-2".to_string())
+    }
+
+    fn game_state(play_status: PlayStatus, moves: &[(usize, usize, GamePiece)]) -> GameState {
+        let mut game_board = vec![vec![GamePiece::Unselected; 3]; 3];
+        for (row, column, game_piece) in moves {
+            game_board[*row][*column] = *game_piece;
+        }
+        GameState::new(game_board, "player-1".to_string(), play_status)
+    }
+
+    fn current_game(other_player: Option<PlayerInfo>) -> GameCreationResponse {
+        let game_info = GameInfoResponse::new(GAME_ID.to_string(), game_state(PlayStatus::NotStarted, &[]), vec![local_player()]);
+        let mut response = GameCreationResponse::new(game_info, local_player(), SESSION_ID.to_string());
+        response.other_player = Some(other_player);
+        response
+    }
+
+    /// Starts the stand-in service. Its event plane broker is unreachable, so the GameClient falls
+    /// back to polling.
+    fn serve_game(service: Arc<Mutex<FakeService>>) -> String {
+        //
+
+        let event_plane_config = EventPlaneConfig::new("127.0.0.1".to_string(),
+                                                       unused_url().rsplit(':').next().unwrap().parse().unwrap(),
+                                                       "channel-1".to_string(),
+                                                       "RustMadeEasy.com/Channels/channel-1".to_string());
+
+        serve(move |request| {
+            //
+
+            let mut service = service.lock().unwrap();
+
+            match (request.method.as_str(), request.path.as_str()) {
+                ("POST", "/v1/gaming-sessions") => {
+                    json(&GamingSessionCreationResponse::new(event_plane_config.clone(), local_player(), INVITATION_CODE.to_string(), SESSION_ID.to_string()))
+                }
+                ("POST", "/v1/gaming-session/session-1/two-player-games") => json(&current_game(None)),
+                ("PUT", "/v1/gaming-sessions/session-1/current_game/players/player-1") => json(&current_game(None)),
+                ("GET", "/v1/gaming-sessions/session-1/current-game") => json(&current_game(Some(opponent()))),
+                ("GET", "/v1/games/game-1/players/readiness") => json(&PlayersReadinessResponse::new(service.opponent_has_joined)),
+                ("GET", "/v1/games/game-1/turns/latest") => json(&service.latest_turn),
+                ("POST", "/v1/games/game-1/turns") => {
+                    service.turns_taken.push(serde_json::from_str(&request.body).unwrap());
+                    match service.take_turn_rejection {
+                        Some((status, code)) => Some((status, serde_json::to_string(&ErrorResponse::new(code)).unwrap())),
+                        None => Some((200, serde_json::to_string(&service.latest_turn).unwrap())),
+                    }
+                }
+                _ => Some((404, String::new())),
+            }
+        })
+    }
+
+    /// Answers a request with the specified value.
+    fn json(value: &impl Serialize) -> Option<(u16, String)> {
+        Some((200, serde_json::to_string(value).unwrap()))
+    }
+
+    fn new_service() -> Arc<Mutex<FakeService>> {
+        Arc::new(Mutex::new(FakeService {
+            latest_turn: TurnResponse::new(game_state(PlayStatus::NotStarted, &[])),
+            opponent_has_joined: false,
+            take_turn_rejection: None,
+            turns_taken: vec![],
+        }))
+    }
+
+    fn new_client(url: String) -> GameClient {
+        let configuration = Configuration { base_path: url, ..Configuration::new() };
+        GameClient::create_session(configuration, "Player One").unwrap().with_poll_interval(Duration::from_millis(10))
+    }
+
+    #[test]
+    fn test_poll_fallback_reports_game_changes() {
+        //
+
+        let service = new_service();
+        let mut client = new_client(serve_game(service.clone()));
+        assert_eq!(client.invitation_code(), INVITATION_CODE);
+
+        client.start_two_player_game().unwrap();
+        assert_eq!(client.game_id(), Some(GAME_ID));
+        assert_eq!(client.next_event(QUIET_TIMEOUT), None);
+
+        // The other Player joins.
+        service.lock().unwrap().opponent_has_joined = true;
+        assert_eq!(client.next_event(EVENT_TIMEOUT), Some(GameClientEvent::OpponentJoined(opponent())));
+        assert_eq!(client.opponent(), Some(&opponent()));
+
+        // Polling an unchanged Game reports nothing.
+        assert_eq!(client.next_event(QUIET_TIMEOUT), None);
+
+        // A Turn is taken.
+        let turn = TurnResponse::new(game_state(PlayStatus::InProgress, &[(1, 1, GamePiece::X)]));
+        service.lock().unwrap().latest_turn = turn.clone();
+        assert_eq!(client.next_event(EVENT_TIMEOUT), Some(GameClientEvent::TurnTaken(turn.clone())));
+        assert_eq!(client.latest_turn(), Some(&turn));
+
+        // The Game is won.
+        let turn = TurnResponse::new(game_state(PlayStatus::EndedInWin, &[(0, 0, GamePiece::X), (1, 1, GamePiece::X), (2, 2, GamePiece::X)]));
+        service.lock().unwrap().latest_turn = turn.clone();
+        assert_eq!(client.next_event(EVENT_TIMEOUT), Some(GameClientEvent::GameEnded(turn.clone())));
+        assert_eq!(client.latest_turn(), Some(&turn));
+        assert_eq!(client.try_next_event(), None);
+    }
+
+    #[test]
+    fn test_play_maps_row_and_column() {
+        //
+
+        let service = new_service();
+        let mut client = new_client(serve_game(service.clone()));
+        client.start_two_player_game().unwrap();
+
+        client.play(0, 2).unwrap();
+        client.play(2, 1).unwrap();
+
+        let service = service.lock().unwrap();
+        let destinations: Vec<(i32, i32)> = service.turns_taken.iter().map(|turn| (turn.destination.row, turn.destination.column)).collect();
+        assert_eq!(destinations, vec![(0, 2), (2, 1)]);
+        assert_eq!(service.turns_taken[0].player_id, local_player().player_id);
+        assert_eq!(service.turns_taken[0].session_id, SESSION_ID);
+    }
+
+    #[test]
+    fn test_error_mapping() {
+        //
+
+        let service = new_service();
+        let mut client = new_client(serve_game(service.clone()));
+
+        // No Game has been created or joined yet.
+        assert_eq!(client.play(0, 0).unwrap_err(), GameClientError::NoCurrentGame);
+        assert_eq!(client.end_game().unwrap_err(), GameClientError::NoCurrentGame);
+
+        // The service rejects the Turn.
+        client.start_two_player_game().unwrap();
+        service.lock().unwrap().take_turn_rejection = Some((409, GameError::BoardLocationAlreadyOccupied));
+        assert_eq!(client.play(0, 0).unwrap_err(), GameClientError::Rejected(StatusCode::CONFLICT, Some(GameError::BoardLocationAlreadyOccupied)));
+
+        // The service cannot be reached.
+        let configuration = Configuration { base_path: unused_url(), ..Configuration::new() };
+        let error = GameClient::create_session(configuration, "Player One").err().unwrap();
+        assert!(matches!(error, GameClientError::Communication(_)), "{:?}", error);
+
+        // The service's response cannot be read.
+        let url = serve(|_| Some((200, "not json".to_string())));
+        let configuration = Configuration { base_path: url, ..Configuration::new() };
+        let error = GameClient::create_session(configuration, "Player One").err().unwrap();
+        assert!(matches!(error, GameClientError::Communication(_)), "{:?}", error);
+    }
+
+    #[test]
+    fn test_describe_rejected_move() {
+        //
+
+        let describe = |status: StatusCode, code: GameError| GameClientError::Rejected(status, Some(code)).describe_rejected_move();
+        assert_eq!(describe(StatusCode::BAD_REQUEST, GameError::InvalidBoardPosition), "That location is not on the board.");
+        assert_eq!(describe(StatusCode::METHOD_NOT_ALLOWED, GameError::WrongPlayerTakingTurn), "It is not your turn.");
+        assert_eq!(describe(StatusCode::NOT_ACCEPTABLE, GameError::GameHasAlreadyEnded), "The Game has already ended.");
+        assert_eq!(describe(StatusCode::CONFLICT, GameError::BoardLocationAlreadyOccupied), "That location is already taken.");
+        assert_eq!(describe(StatusCode::METHOD_NOT_ALLOWED, GameError::GameHasMaximumNumberOfPlayers),
+                   "The move was rejected: request rejected with status code 405 Method Not Allowed (GameHasMaximumNumberOfPlayers)");
+
+        // Not every bad request is about the board location.
+        assert_eq!(describe(StatusCode::BAD_REQUEST, GameError::GameNotStarted), "The Game has not started as yet.");
+        assert_eq!(describe(StatusCode::BAD_REQUEST, GameError::PlayerGamePieceNotSelected), "Your Game Piece has not been selected as yet.");
+
+        // Without an ErrorResponse, the status is all there is to go on.
+        let describe_status = |status: StatusCode| GameClientError::Rejected(status, None).describe_rejected_move();
+        assert_eq!(describe_status(StatusCode::CONFLICT), "That location is already taken.");
+        assert_eq!(describe_status(StatusCode::BAD_REQUEST), "The move was rejected: request rejected with status code 400 Bad Request");
+        assert_eq!(describe_status(StatusCode::INTERNAL_SERVER_ERROR),
+                   "The move was rejected: request rejected with status code 500 Internal Server Error");
+
+        assert_eq!(GameClientError::NoCurrentGame.describe_rejected_move(), "There is no Game to play.");
+        assert_eq!(GameClientError::Communication("timed out".to_string()).describe_rejected_move(),
+                   "Problem communicating with the Tic-Tac-Toe service: communication error: timed out");
+    }
+}
+
+#[cfg(test)]
+mod api_error_tests {
+    use crate::apis::{Error, ResponseContent};
+    use crate::models::GameError;
+    use reqwest::StatusCode;
+
+    fn response_error(status: StatusCode, content: &str) -> Error<()> {
+        Error::ResponseError(ResponseContent { status, content: content.to_string(), entity: None })
+    }
+
+    #[test]
+    fn test_game_error_is_read_from_body() {
+        //
+
+        // NOTE: The service's error_response_tests produce this same body.
+        let error = response_error(StatusCode::NOT_FOUND, r#"{"code":"GamingSessionNotFound"}"#);
+        assert_eq!(error.game_error(), Some(GameError::GamingSessionNotFound));
+        assert!(error.is_not_found());
+        assert!(error.is_session_not_found());
+
+        let error = response_error(StatusCode::NOT_FOUND, r#"{"code":"GameNotFound"}"#);
+        assert_eq!(error.game_error(), Some(GameError::GameNotFound));
+        assert!(error.is_not_found());
+        assert!(!error.is_session_not_found());
+    }
+
+    #[test]
+    fn test_bodies_without_an_error_code() {
+        //
+
+        // Text that merely mentions the error is not enough.
+        let error = response_error(StatusCode::NOT_FOUND, "GamingSessionNotFound");
+        assert_eq!(error.game_error(), None);
+        assert!(!error.is_session_not_found());
+
+        assert_eq!(response_error(StatusCode::INTERNAL_SERVER_ERROR, "").game_error(), None);
+    }
+}
@@ -29,6 +29,9 @@ use crate::models::requests::GameTurnParams;
 use crate::models::requests::JoinSessionParams;
 use crate::models::requests::NewGamingSessionParams;
 use crate::models::requests::NewSinglePlayerGameParams;
+use crate::models::requests::NewTwoPlayerGameParams;
+use crate::errors::GameError;
+use crate::models::responses::ErrorResponse;
 use crate::models::responses::GameCreationResponse;
 use crate::models::responses::GameInfoResponse;
 use crate::models::responses::GamingSessionCreationResponse;
@@ -62,9 +65,11 @@ use utoipa::OpenApi;
         BoardPosition,
         EndGameParams,
         EndGamingSessionParams,
+        ErrorResponse,
         EventPlaneConfig,
         EventPlaneTopicNames,
         GameCreationResponse,
+        GameError,
         GameInfoResponse,
         GameMode,
         GamePiece,
@@ -74,6 +79,7 @@ use utoipa::OpenApi;
         JoinSessionParams,
         NewGamingSessionParams,
         NewSinglePlayerGameParams,
+        NewTwoPlayerGameParams,
         PlayerInfo,
         PlayersReadinessResponse,
         PlayStatus,
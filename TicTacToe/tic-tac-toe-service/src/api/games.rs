@@ -19,10 +19,11 @@ use crate::gaming::tic_tac_toe_game::TicTacToeGame;
 use crate::models::game_state::GameState;
 use crate::models::play_status::PlayStatus;
 use crate::models::player_info::PlayerInfo;
-use crate::models::requests::{EndGameParams, GameTurnParams, NewSinglePlayerGameParams, ID_LENGTH_MAX, ID_LENGTH_MIN};
-use crate::models::responses::{GameCreationResponse, GameInfoResponse, PlayersReadinessResponse, TurnResponse};
+use crate::models::requests::{EndGameParams, GameTurnParams, NewSinglePlayerGameParams, NewTwoPlayerGameParams, ID_LENGTH_MAX, ID_LENGTH_MIN};
+use crate::models::responses::{ErrorResponse, GameCreationResponse, GameInfoResponse, PlayersReadinessResponse, TurnResponse};
 use actix_web::{delete, get, post, web, Error, HttpResponse};
 use log::debug;
+use serde::de::DeserializeOwned;
 use std::time::Duration;
 use validator::Validate;
 
@@ -78,16 +79,23 @@ pub(crate) async fn create_single_player_game(
 
 
 /// Creates a new Two-Player Game. Returns Game Creation Response.
+///
+/// The optional NewTwoPlayerGameParams specify which Player takes the first turn, e.g. when the
+/// Players switch sides for a rematch.
 #[utoipa::path(
     post,
     tag = "TicTacToe",
     path = "/v1/gaming-session/{session_id}/two-player-games",
+    request_body(content = Option<NewTwoPlayerGameParams>, content_type = "application/json"),
     responses(
     (status = 200, description = "Two-Player Game created successfully", body = GameCreationResponse, content_type = "application/json"),
+    (status = 400, description = "Bad request - Malformed NewTwoPlayerGameParams"),
+    (status = 404, description = "Gaming Session or starting Player not found", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[post("/gaming-session/{session_id}/two-player-games")]
 pub(crate) async fn create_two_player_game(
+    body: web::Bytes,
     session_id: web::Path<String>,
     manager: web::Data<tokio::sync::Mutex<GamingSessionsManager<TicTacToeGame>>>,
 ) -> actix_web::Result<web::Json<GameCreationResponse>> {
@@ -95,8 +103,12 @@ pub(crate) async fn create_two_player_game(
 
     // *** Validate input params ***
     validate_id_string(&session_id)?;
+    let new_game_params: NewTwoPlayerGameParams = parse_optional_params(&body)?;
+    if let Err(e) = new_game_params.validate() {
+        return Err(actix_web::error::ErrorBadRequest(e.to_string()));
+    }
 
-    debug!("HTTP POST to /gaming-session/{}/two-player-games", session_id);
+    debug!("HTTP POST to /gaming-session/{}/two-player-games. Params: {:?}", session_id, new_game_params);
 
     let mut manager = manager.lock().await;
 
@@ -105,7 +117,7 @@ pub(crate) async fn create_two_player_game(
         Some(session) => session,
     };
 
-    match manager.create_new_two_player_game(&session_id).await {
+    match manager.create_new_two_player_game(&session_id, new_game_params.starting_player_id.as_deref()).await {
         Ok(result) => {
             // Add the other Player if they are already part of the Gaming Session.
             let other_player = PlayerInfo::get_other_player_info(session.session_owner.player_id.clone(), &session.participants);
@@ -131,7 +143,7 @@ pub(crate) async fn create_two_player_game(
     (status = 200, description = "Game ended successfully"),
     (status = 400, description = "Bad request - Malformed Game ID"),
     (status = 403, description = "Unauthorized"),
-    (status = 404, description = "Game not found")
+    (status = 404, description = "Game not found", body = ErrorResponse, content_type = "application/json")
 ,), )]
 #[delete("/games/{game_id}")]
 pub(crate) async fn end_game(
@@ -169,7 +181,7 @@ pub(crate) async fn end_game(
     responses(
     (status = 200, description = "Game history retrieved successfully", body = Vec<GameState>, content_type = "application/json"),
     (status = 400, description = "Bad request - Malformed Game ID"),
-    (status = 404, description = "Game not found"),
+    (status = 404, description = "Game not found", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[get("/games/{game_id}/turns")]
@@ -200,7 +212,7 @@ pub(crate) async fn get_game_history(
     responses(
     (status = 200, description = "Latest Game Turn info retrieved successfully", body = TurnResponse, content_type = "application/json"),
     (status = 400, description = "Bad request - Malformed Game ID"),
-    (status = 404, description = "Game not found"),
+    (status = 404, description = "Game not found", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[get("/games/{game_id}/turns/latest")]
@@ -244,7 +256,7 @@ pub(crate) async fn get_latest_game_turn(
     responses(
     (status = 200, description = "Latest Game Turn info retrieved successfully", body = PlayersReadinessResponse, content_type = "application/json"),
     (status = 400, description = "Bad request - Malformed Game ID"),
-    (status = 404, description = "Game not found"),
+    (status = 404, description = "Game not found", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[get("/games/{game_id}/players/readiness")]
@@ -310,7 +322,7 @@ pub(crate) async fn get_players_readiness(
     responses(
     (status = 200, description = "Game turn added successfully", body = TurnResponse, content_type = "application/json"),
     (status = 400, description = "Bad Request - Malformed Game ID, Invalid Board Position"),
-    (status = 404, description = "Not Found - Game Not Found"),
+    (status = 404, description = "Not Found - Game Not Found", body = ErrorResponse, content_type = "application/json"),
     (status = 405, description = "Method Not Allowed - Wrong Player Taking Turn"),
     (status = 406, description = "Not Acceptable - Game Has Already Ended"),
     (status = 409, description = "Conflict - Board Location Already Occupied"),
@@ -343,6 +355,20 @@ pub(crate) async fn take_turn(
 }
 
 
+/// Deserializes the JSON params of a request whose body may be omitted. An empty body yields the
+/// default params. Malformed JSON is a bad request.
+fn parse_optional_params<T: DeserializeOwned + Default>(body: &[u8]) -> actix_web::Result<T> {
+    //
+
+    // Early return.
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(T::default());
+    }
+
+    serde_json::from_slice(body).map_err(|e| actix_web::error::ErrorBadRequest(e.to_string()))
+}
+
+
 /// Verifies that the specified ID is of the correct length.
 pub(crate) fn validate_id_string(id: &str) -> actix_web::Result<()> {
     if id.is_empty() {
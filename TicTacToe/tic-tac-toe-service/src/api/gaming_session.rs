@@ -18,7 +18,7 @@ use crate::gaming::gaming_sessions_manager::GamingSessionsManager;
 use crate::gaming::tic_tac_toe_game::TicTacToeGame;
 use crate::models::player_info::PlayerInfo;
 use crate::models::requests::{EndGamingSessionParams, JoinSessionParams, NewGamingSessionParams, ID_LENGTH_MAX};
-use crate::models::responses::{GameCreationResponse, GameInfoResponse, GamingSessionCreationResponse};
+use crate::models::responses::{ErrorResponse, GameCreationResponse, GameInfoResponse, GamingSessionCreationResponse};
 use actix_web::{delete, get, post, put, web, Error, HttpResponse};
 use log::debug;
 use validator::Validate;
@@ -75,7 +75,7 @@ pub(crate) async fn create_gaming_session(
     (status = 200, description = "Gaming Session ended successfully"),
     (status = 400, description = "Bad request - Malformed Gaming Session ID"),
     (status = 403, description = "Unauthorized"),
-    (status = 404, description = "Gaming Session not found")
+    (status = 404, description = "Gaming Session not found", body = ErrorResponse, content_type = "application/json")
 ,), )]
 #[delete("/gaming-sessions/{session_id}")]
 pub(crate) async fn end_gaming_session(
@@ -110,7 +110,7 @@ pub(crate) async fn end_gaming_session(
     responses(
     (status = 200, description = "Gaming Session Game retrieved successfully", body = GameCreationResponse, content_type = "application/json"),
     (status = 400, description = "Bad request - Malformed Session ID"),
-    (status = 404, description = "Session not found"),
+    (status = 404, description = "Session not found", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[get("/gaming-sessions/{session_id}/current-game")]
@@ -154,9 +154,9 @@ pub(crate) async fn get_session_current_game(
     path = "/v1/gaming-sessions/{session_id}/current_game/players/{player_id}",
     responses(
     (status = 200, description = "Player joined Game successfully", body = GameCreationResponse, content_type = "application/json"),
-    (status = 404, description = "Game not found"),
-    (status = 404, description = "Player not found"),
-    (status = 404, description = "Session not found"),
+    (status = 404, description = "Game not found", body = ErrorResponse, content_type = "application/json"),
+    (status = 404, description = "Player not found", body = ErrorResponse, content_type = "application/json"),
+    (status = 404, description = "Session not found", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[put("/gaming-sessions/{session_id}/current_game/players/{player_id}")]
@@ -213,7 +213,7 @@ pub(crate) async fn join_current_game(
     responses(
     (status = 200, description = "Player added to the Gaming Session", body = GamingSessionCreationResponse, content_type = "application/json"),
     (status = 400, description = "Bad request - Malformed JoinSessionParams"),
-    (status = 404, description = "No Game found for the specified Invitation"),
+    (status = 404, description = "No Game found for the specified Invitation", body = ErrorResponse, content_type = "application/json"),
     (status = 500, description = "Internal server error")
 ,), )]
 #[post("/gaming-sessions/players")]
@@ -5,13 +5,15 @@
 // © 2024 Rust Made Easy. All rights reserved.
 // @author JoelDavisEngineering@Gmail.com
 
-use actix_web::http::header::ContentType;
+use crate::models::responses::ErrorResponse;
 use actix_web::http::StatusCode;
 use actix_web::{HttpResponse, ResponseError};
 use derive_more::{Display, Error};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Defines the errors used throughout the service
-#[derive(Debug, Display, Error, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, Error, PartialEq, Serialize, ToSchema)]
 pub(crate) enum GameError {
     /// The specified board location is already occupied by another Game Piece
     BoardLocationAlreadyOccupied,
@@ -61,10 +63,10 @@ impl ResponseError for GameError {
         }
     }
 
-    /// Converts a GameError instance to an HttpResponse instance
+    /// Converts a GameError instance to an HttpResponse instance whose ErrorResponse body
+    /// identifies the error
     fn error_response(&self) -> HttpResponse {
         HttpResponse::build(self.status_code())
-            .insert_header(ContentType::html())
-            .body(self.to_string())
+            .json(ErrorResponse { code: *self })
     }
 }
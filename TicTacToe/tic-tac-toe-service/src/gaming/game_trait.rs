@@ -50,6 +50,9 @@ pub(crate) trait GameTrait: Sized {
     /// Creates a new instance.
     fn new(game_mode: GameMode, session_id: &str) -> Result<Self, GameError>;
 
+    /// Has the specified Player take the first turn instead of a randomly chosen Player.
+    fn set_starting_player(&mut self, player_id: &str);
+
     /// Make a Game move for the specified Player.
     fn take_turn(&mut self, game_turn_info: &GameTurnParams) -> Result<TurnResponse, GameError>;
 }
@@ -10,23 +10,35 @@ use crate::gaming::gaming_session::GamingSession;
 use crate::gaming::gaming_session_observer_trait::GamingSessionObserverTrait;
 use crate::gaming::gaming_session_state_changes::GamingSessionStateChanges;
 use crate::models::event_plane::EventPlaneTopicNames;
+use crate::models::game_state::GameState;
 use crate::models::play_status::PlayStatus;
 use async_trait::async_trait;
 use function_name::named;
-use log::debug;
+use log::{debug, error};
 use mqtt_publisher_lib::broker_info::{BrokerInfo, MqttProtocolVersion};
+use mqtt_publisher_lib::outbox::{OutboxConfig, OutboxStorage, OverflowPolicy};
+use mqtt_publisher_lib::publish_options::PublishOptions;
 use mqtt_publisher_lib::publisher::Publisher;
 use mqtt_publisher_lib::publisher_qos::PublisherQoS;
+use mqtt_publisher_lib::publisher_trait::PublisherTrait;
+use std::sync::Arc;
 use std::time::Duration;
 use uuid::Uuid;
 
+/// The length of time, in seconds, after which the broker discards a retained Game State
+pub(crate) const GAME_STATE_EXPIRY_SECS: u32 = 60 * 60;
+/// The MIME type of the Game State payloads
+pub(crate) const JSON_CONTENT_TYPE: &str = "application/json";
+/// The maximum number of updates to hold while the MQTT broker is unreachable
+const OUTBOX_CAPACITY: usize = 1000;
+
 /// Observes Game updates and publishes the via MQTT.
 #[derive(Clone)]
 pub(crate) struct GameUpdatesPublisher {
     //
 
     /// Provides MQTT message publishing functionality.
-    event_publisher: Publisher,
+    event_publisher: Arc<dyn PublisherTrait>,
 
     /// Unique ID of this Publisher instance.
     unique_id: String,
@@ -43,8 +55,51 @@ impl GameUpdatesPublisher {
                                      10,
                                      broker_port,
                                      Duration::from_secs(60),
-                                     MqttProtocolVersion::V5);
-        Self { event_publisher: Publisher::new(config), unique_id: Uuid::new_v4().to_string() }
+                                     MqttProtocolVersion::V5)
+            .with_outbox(OutboxConfig::new(OUTBOX_CAPACITY, OverflowPolicy::DropOldest, OutboxStorage::InMemory));
+        Self::new_with_publisher(Arc::new(Publisher::new(config)))
+    }
+
+    /// Creates a new instance that publishes via the specified Publisher.
+    pub(crate) fn new_with_publisher(event_publisher: Arc<dyn PublisherTrait>) -> Self {
+        Self { event_publisher, unique_id: Uuid::new_v4().to_string() }
+    }
+
+    /// Removes the retained Game State messages so that new subscribers do not receive stale state.
+    async fn clear_retained_game_state(&self, event_channel_id: &str) {
+        for topic_name in [EventPlaneTopicNames::TurnTaken,
+            EventPlaneTopicNames::GameEndedInStalemate,
+            EventPlaneTopicNames::GameEndedInWin] {
+            self.clear_retained_message(topic_name.build(event_channel_id).as_str()).await;
+        }
+    }
+
+    /// Removes the retained message on the specified topic.
+    async fn clear_retained_message(&self, topic: &str) {
+        // Per the MQTT spec, publishing an empty retained message removes the retained message.
+        let options = PublishOptions::new().retain(true);
+        let _ = self.event_publisher.publish_with_options("", topic, PublisherQoS::AtLeastOnce, options).await;
+    }
+
+    /// Publishes the Game State as a retained message so that late subscribers immediately receive
+    /// the current board.
+    async fn publish_game_state(&self, topic: &str, game_state: &GameState) {
+        //
+
+        let payload = match serde_json::to_string(game_state) {
+            Ok(payload) => payload,
+            Err(error) => {
+                error!("Failed to serialize the Game State: {}", error);
+                return;
+            }
+        };
+
+        let options = PublishOptions::new()
+            .retain(true)
+            .content_type(JSON_CONTENT_TYPE)
+            .message_expiry_interval(GAME_STATE_EXPIRY_SECS);
+
+        let _ = self.event_publisher.publish_with_options(payload.as_str(), topic, PublisherQoS::AtLeastOnce, options).await;
     }
 }
 
@@ -62,24 +117,35 @@ impl<T: GameTrait + Clone + Send + Sync + 'static> GamingSessionObserverTrait<T>
 
         let topic = match state_change {
             GamingSessionStateChanges::GameDeleted => {
+                self.clear_retained_game_state(event_channel_id).await;
                 EventPlaneTopicNames::GameDeleted.build(event_channel_id)
             }
             GamingSessionStateChanges::GameTurnTaken => {
                 if let Some(game) = game {
-                    match game.get_current_game_state().play_status {
+                    let game_state = game.get_current_game_state();
+                    let topic = match game_state.play_status {
                         PlayStatus::EndedInStalemate => EventPlaneTopicNames::GameEndedInStalemate.build(event_channel_id),
                         PlayStatus::EndedInWin => EventPlaneTopicNames::GameEndedInWin.build(event_channel_id),
                         PlayStatus::InProgress => EventPlaneTopicNames::TurnTaken.build(event_channel_id),
                         PlayStatus::NotStarted => return, // Early return. Nothing to publish.
+                    };
+                    self.publish_game_state(topic.as_str(), &game_state).await;
+                    if game_state.play_status != PlayStatus::InProgress {
+                        // Late subscribers must receive only the final state, not the last turn.
+                        self.clear_retained_message(EventPlaneTopicNames::TurnTaken.build(event_channel_id).as_str()).await;
                     }
+                    return; // Early return. The Game State has been published.
                 } else {
                     return; // Early return. Nothing to publish.
                 }
             }
             GamingSessionStateChanges::GameIsReady => {
+                // Late subscribers must not receive the final state of a previous Game.
+                self.clear_retained_game_state(event_channel_id).await;
                 EventPlaneTopicNames::AllPlayersReady.build(event_channel_id)
             }
             GamingSessionStateChanges::GamingSessionDeleted => {
+                self.clear_retained_game_state(event_channel_id).await;
                 EventPlaneTopicNames::SessionDeleted.build(event_channel_id)
             }
         };
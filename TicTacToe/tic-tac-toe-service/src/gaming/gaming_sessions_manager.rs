@@ -59,14 +59,20 @@ impl<T: GameTrait + Clone + Send + Sync + 'static> GamingSessionsManager<T> {
 
         debug!("{} called", function_name!());
 
-        let mut instance = Self {
+        let publisher = Box::new(GameUpdatesPublisher::new(MQTT_BROKER_ADDRESS.to_string(), MQTT_PORT));
+        Self::new_with_observers(vec![publisher])
+    }
+
+    /// Creates a new instance that notifies the specified observers, e.g. none in tests, of changes
+    /// to the Gaming Sessions.
+    pub(crate) fn new_with_observers(observers: Vec<Box<dyn GamingSessionObserverTrait<T> + Send>>) -> Self {
+        //
+
+        let instance = Self {
             sessions: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
-            observers: vec![],
+            observers,
         };
 
-        let publisher = Box::new(GameUpdatesPublisher::new(MQTT_BROKER_ADDRESS.to_string(), MQTT_PORT));
-        instance.observers.push(publisher.clone());
-
         Self::auto_cleanup(instance.sessions.clone(), ABANDONED_GAME_TTL_MS, CLEANUP_INTERVAL);
 
         instance
@@ -265,11 +271,14 @@ impl<T: GameTrait + Clone + Send + Sync + 'static> GamingSessionsManager<T> {
     }
 
     /// Creates a new Two-Player Game. Returns the new Game as well as a list of Players.
+    ///
+    /// If a starting Player is specified, that Player plays X and takes the first turn. This
+    /// allows, for instance, a rematch in which the Players switch sides.
     #[named]
-    pub(crate) async fn create_new_two_player_game(&mut self, session_id: &str) -> Result<(T, Vec<PlayerInfo>), GameError> {
+    pub(crate) async fn create_new_two_player_game(&mut self, session_id: &str, starting_player_id: Option<&str>) -> Result<(T, Vec<PlayerInfo>), GameError> {
         //
 
-        debug!("{} called for Session ID: {}.", function_name!(), session_id);
+        debug!("{} called for Session ID: {}, Starting Player ID: {:?}.", function_name!(), session_id, starting_player_id);
 
         let session = match self.get_session_by_id(session_id).await {
             Some(session) => *session,
@@ -278,7 +287,15 @@ impl<T: GameTrait + Clone + Send + Sync + 'static> GamingSessionsManager<T> {
             }
         };
 
-        let game = T::new(GameMode::TwoPlayers, &session.session_id)?;
+        let mut game = T::new(GameMode::TwoPlayers, &session.session_id)?;
+
+        if let Some(starting_player_id) = starting_player_id {
+            // The starting Player must be part of the Gaming Session.
+            if !session.participants.iter().any(|it| it.player_id == starting_player_id) {
+                return Err(GameError::PlayerNotFound);
+            }
+            game.set_starting_player(starting_player_id);
+        }
 
         let session = self.upsert_game(&session, &game).await;
 
@@ -335,8 +352,8 @@ impl<T: GameTrait + Clone + Send + Sync + 'static> GamingSessionsManager<T> {
                     let _ = self.end_game(&game.get_id(), session.session_owner.player_id.as_str(), session_id).await;
                 }
 
-                // Remove the Session
-                self.sessions.lock().await.remove(session_id);
+                // Remove the Session. Sessions are keyed by their Invitation Code.
+                self.sessions.lock().await.remove(&session.invitation_code);
 
                 self.notify_observers_of_session_change(GamingSessionStateChanges::GamingSessionDeleted, &session).await;
 
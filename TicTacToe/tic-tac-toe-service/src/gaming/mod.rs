@@ -15,4 +15,4 @@ pub(crate) mod gaming_sessions_manager;
 pub(crate) mod play_outcome;
 pub(crate) mod tic_tac_toe_game;
 pub(crate) mod game_updates_publisher;
-mod gaming_session_state_changes;
\ No newline at end of file
+pub(crate) mod gaming_session_state_changes;
\ No newline at end of file
@@ -49,6 +49,9 @@ pub(crate) struct TicTacToeGame {
 
     /// The list of Players engaged in the Game
     pub(crate) players: Vec<PlayerInfo>,
+
+    /// The Player who is to take the first turn. When None, the starting Player is chosen at random.
+    pub(crate) starting_player_id: Option<String>,
 }
 
 impl TicTacToeGame {
@@ -64,8 +67,12 @@ impl TicTacToeGame {
         let mut player = self.players.first().unwrap().clone();
         let mut other_player = self.players.last().unwrap().clone();
 
-        // Randomly assign the game piece for each player
-        player.game_piece = GamePiece::new_with_random_choice();
+        // Assign the game piece for each player - randomly, unless a starting Player has been requested.
+        player.game_piece = match &self.starting_player_id {
+            Some(starting_player_id) if *starting_player_id == player.player_id => GamePiece::X,
+            Some(_) => GamePiece::O,
+            None => GamePiece::new_with_random_choice(),
+        };
         other_player.game_piece = GamePiece::new_as_opposite(&player.game_piece);
 
         // By convention, whoever has X starts first.
@@ -210,11 +217,19 @@ impl GameTrait for TicTacToeGame {
             latest_turn_result: None,
             players: vec![],
             play_history: vec![],
+            starting_player_id: None,
         };
 
         Ok(game)
     }
 
+    /// Has the specified Player take the first turn instead of a randomly chosen Player.
+    #[named]
+    fn set_starting_player(&mut self, player_id: &str) {
+        debug!("{} called with Player ID: {}", function_name!(), player_id);
+        self.starting_player_id = Some(player_id.to_string());
+    }
+
     /// Make a Game move for the specified Player.
     #[named]
     fn take_turn(&mut self, game_turn_info: &GameTurnParams) -> Result<TurnResponse, GameError> {
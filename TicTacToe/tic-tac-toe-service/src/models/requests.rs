@@ -80,4 +80,13 @@ pub struct NewGamingSessionParams {
 pub struct NewSinglePlayerGameParams {
     /// The skill level at which the Automatic Player is to play the Game
     pub computer_skill_level: AutomaticPlayerSkillLevel,
-}
\ No newline at end of file
+}
+
+/// Models info needed to start a new Two-Player Game
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Validate)]
+pub struct NewTwoPlayerGameParams {
+    /// ID of the Player who is to play X and, so, take the first turn. When omitted, the Game
+    /// Pieces are assigned at random.
+    #[validate(length(min = "ID_LENGTH_MIN", max = "ID_LENGTH_MAX"))]
+    pub starting_player_id: Option<String>,
+}
@@ -5,6 +5,7 @@
 // © 2024 Rust Made Easy. All rights reserved.
 // @author JoelDavisEngineering@Gmail.com
 
+use crate::errors::GameError;
 use crate::gaming::game_trait::GameTrait;
 use crate::gaming::tic_tac_toe_game::TicTacToeGame;
 use crate::models::board_position::BoardPosition;
@@ -57,6 +58,14 @@ impl From<TicTacToeGame> for GameInfoResponse {
     }
 }
 
+/// Models the body of an error response, identifying the error so that clients need not parse
+/// any text
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    /// Identifies the error, e.g. GamingSessionNotFound
+    pub code: GameError,
+}
+
 /// Models the results of a call to the Create Gaming Session endpoint
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct GamingSessionCreationResponse {
@@ -439,6 +439,25 @@ mod game_play_tests {
         assert_eq!(game.play_history.len(), 2);
     }
 
+    #[test]
+    fn test_starting_player() {
+        //
+
+        let player_one = PlayerInfo::new(Uuid::new_v4(), false);
+        let player_two = PlayerInfo::new(Uuid::new_v4(), false);
+
+        // Let Player Two start, even though Player One joined first.
+        let mut game = TicTacToeGame::new(GameMode::TwoPlayers, Uuid::new_v4().to_string().as_str()).unwrap();
+        game.set_starting_player(&player_two.player_id);
+        let _ = game.add_player(&player_one);
+        let _ = game.add_player(&player_two);
+
+        // The starting Player plays X and takes the first turn.
+        assert_eq!(game.current_player.clone().unwrap_or_default().player_id, player_two.player_id);
+        assert_eq!(game.get_player_info_by_id(&player_two.player_id).unwrap().game_piece, GamePiece::X);
+        assert_eq!(game.get_player_info_by_id(&player_one.player_id).unwrap().game_piece, GamePiece::O);
+    }
+
     #[test]
     fn test_take_turn() {
         //
@@ -563,3 +582,284 @@ mod game_state_tests {
         assert_eq!(binary_representation.1, 0b_000_001_110);
     }
 }
+
+#[cfg(test)]
+mod game_updates_publisher_tests {
+    use crate::gaming::game_trait::GameTrait;
+    use crate::gaming::game_updates_publisher::{GameUpdatesPublisher, GAME_STATE_EXPIRY_SECS, JSON_CONTENT_TYPE};
+    use crate::gaming::gaming_session::GamingSession;
+    use crate::gaming::gaming_session_observer_trait::GamingSessionObserverTrait;
+    use crate::gaming::gaming_session_state_changes::GamingSessionStateChanges;
+    use crate::gaming::tic_tac_toe_game::TicTacToeGame;
+    use crate::models::board_position::BoardPosition;
+    use crate::models::event_plane::EventPlaneTopicNames;
+    use crate::models::game_mode::GameMode;
+    use crate::models::game_piece::GamePiece;
+    use crate::models::player_info::PlayerInfo;
+    use crate::models::requests::GameTurnParams;
+    use mqtt_publisher_lib::publish_options::PublishOptions;
+    use mqtt_publisher_lib::publisher_qos::PublisherQoS;
+    use mqtt_publisher_lib::test_support::RecordingPublisher;
+    use std::sync::Arc;
+
+    /// Creates a Gaming Session with a Two-Player Game in which Player One is to move first.
+    fn new_session_and_game() -> (GamingSession<TicTacToeGame>, TicTacToeGame, PlayerInfo, PlayerInfo) {
+        //
+
+        let mut player_one = PlayerInfo::new("Player One", false);
+        let mut player_two = PlayerInfo::new("Player Two", false);
+        player_one.game_piece = GamePiece::X;
+        player_two.game_piece = GamePiece::O;
+
+        let session = GamingSession::<TicTacToeGame>::new(player_one.clone(), "localhost".to_string(), 1883);
+
+        let mut game = TicTacToeGame::new(GameMode::TwoPlayers, &session.session_id).unwrap();
+        let _ = game.add_player(&player_one);
+        let _ = game.add_player(&player_two);
+        game.current_player = Some(player_one.clone());
+
+        (session, game, player_one, player_two)
+    }
+
+    fn take_turn(game: &mut TicTacToeGame, player: &PlayerInfo, row: usize, column: usize) {
+        let turn_info = GameTurnParams {
+            destination: BoardPosition::new(row, column),
+            player_id: player.player_id.clone(),
+            session_id: "".to_string(),
+        };
+        game.take_turn(&turn_info).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_turn_taken_publishes_retained_game_state() {
+        //
+
+        let recorder = RecordingPublisher::new();
+        let publisher = GameUpdatesPublisher::new_with_publisher(Arc::new(recorder.clone()));
+        let (session, mut game, player_one, _) = new_session_and_game();
+
+        take_turn(&mut game, &player_one, 1, 1);
+        publisher.session_updated(&GamingSessionStateChanges::GameTurnTaken, &session, Some(game.clone())).await;
+
+        let topic = EventPlaneTopicNames::TurnTaken.build(&session.event_plane_config.topic_prefix);
+        recorder.assert_topics(&[topic.as_str()]);
+        recorder.assert_retained(&topic);
+        recorder.assert_published_with_qos(&topic, PublisherQoS::AtLeastOnce);
+        let payload = serde_json::to_string(&game.get_current_game_state()).unwrap();
+        recorder.assert_published_with_payload(&topic, &payload);
+
+        // The Game State is retained only for as long as a Game could reasonably last.
+        let expected_options = PublishOptions::new()
+            .retain(true)
+            .content_type(JSON_CONTENT_TYPE)
+            .message_expiry_interval(GAME_STATE_EXPIRY_SECS);
+        assert_eq!(recorder.messages_for_topic(&topic)[0].options, expected_options);
+    }
+
+    #[actix_web::test]
+    async fn test_winning_turn_publishes_game_ended_in_win() {
+        //
+
+        let recorder = RecordingPublisher::new();
+        let publisher = GameUpdatesPublisher::new_with_publisher(Arc::new(recorder.clone()));
+        let (session, mut game, player_one, player_two) = new_session_and_game();
+
+        /*
+        X  X  X
+        O  O  -
+        -  -  -     */
+        take_turn(&mut game, &player_one, 0, 0);
+        take_turn(&mut game, &player_two, 1, 0);
+        take_turn(&mut game, &player_one, 0, 1);
+        take_turn(&mut game, &player_two, 1, 1);
+        take_turn(&mut game, &player_one, 0, 2);
+        publisher.session_updated(&GamingSessionStateChanges::GameTurnTaken, &session, Some(game)).await;
+
+        // The final state is retained and the retained last turn is cleared.
+        let topic_prefix = session.event_plane_config.topic_prefix.as_str();
+        let ended_in_win = EventPlaneTopicNames::GameEndedInWin.build(topic_prefix);
+        let turn_taken = EventPlaneTopicNames::TurnTaken.build(topic_prefix);
+        recorder.assert_topics(&[ended_in_win.as_str(), turn_taken.as_str()]);
+        recorder.assert_retained(&ended_in_win);
+        recorder.assert_retained(&turn_taken);
+        recorder.assert_published_with_payload(&turn_taken, "");
+    }
+
+    #[actix_web::test]
+    async fn test_new_game_clears_retained_game_state() {
+        //
+
+        let recorder = RecordingPublisher::new();
+        let publisher = GameUpdatesPublisher::new_with_publisher(Arc::new(recorder.clone()));
+        let (session, game, _, _) = new_session_and_game();
+
+        publisher.session_updated(&GamingSessionStateChanges::GameIsReady, &session, Some(game)).await;
+
+        let topic_prefix = session.event_plane_config.topic_prefix.as_str();
+        let turn_taken = EventPlaneTopicNames::TurnTaken.build(topic_prefix);
+        let ended_in_stalemate = EventPlaneTopicNames::GameEndedInStalemate.build(topic_prefix);
+        let ended_in_win = EventPlaneTopicNames::GameEndedInWin.build(topic_prefix);
+        let all_players_ready = EventPlaneTopicNames::AllPlayersReady.build(topic_prefix);
+        recorder.assert_topics(&[turn_taken.as_str(), ended_in_stalemate.as_str(), ended_in_win.as_str(), all_players_ready.as_str()]);
+        recorder.assert_retained(&turn_taken);
+        recorder.assert_published_with_payload(&turn_taken, "");
+    }
+
+    #[actix_web::test]
+    async fn test_session_deleted() {
+        //
+
+        let recorder = RecordingPublisher::new();
+        let publisher = GameUpdatesPublisher::new_with_publisher(Arc::new(recorder.clone()));
+        let (session, _, _, _) = new_session_and_game();
+
+        publisher.session_updated(&GamingSessionStateChanges::GamingSessionDeleted, &session, None).await;
+
+        recorder.assert_published(&EventPlaneTopicNames::SessionDeleted.build(&session.event_plane_config.topic_prefix));
+    }
+}
+
+#[cfg(test)]
+mod event_plane_contract_tests {
+    use crate::models::event_plane::{EventPlaneConfig, EventPlaneTopicNames};
+    use crate::models::game_piece::GamePiece;
+    use crate::models::game_state::GameState;
+    use crate::models::play_status::PlayStatus;
+
+    // NOTE: The Rust client SDK's event_plane_tests decode these same values. Changing them is a
+    // breaking change for clients.
+    const GAME_STATE_PAYLOAD: &str = r#"{"id_of_player_who_made_move":"player-1","game_board":[["X","Unselected","Unselected"],["Unselected","O","Unselected"],["Unselected","Unselected","Unselected"]],"play_status":"InProgress"}"#;
+
+    #[test]
+    fn test_topic_format() {
+        let config = EventPlaneConfig::new("localhost".to_string(), 1883);
+        let expected_prefix = format!("RustMadeEasy.com/Channels/{}", config.channel_id);
+        assert_eq!(config.topic_prefix, expected_prefix);
+        assert_eq!(EventPlaneTopicNames::TurnTaken.build(&config.topic_prefix), format!("{expected_prefix}/TurnTaken"));
+        assert_eq!(EventPlaneTopicNames::GameEndedInWin.build(&config.topic_prefix), format!("{expected_prefix}/GameEndedInWin"));
+    }
+
+    #[test]
+    fn test_game_state_payload_format() {
+        //
+
+        let mut game_state = GameState::new();
+        game_state.id_of_player_who_made_move = "player-1".to_string();
+        game_state.game_board[0][0] = GamePiece::X;
+        game_state.game_board[1][1] = GamePiece::O;
+        game_state.play_status = PlayStatus::InProgress;
+
+        let expected: serde_json::Value = serde_json::from_str(GAME_STATE_PAYLOAD).unwrap();
+        assert_eq!(serde_json::to_value(&game_state).unwrap(), expected);
+    }
+}
+
+#[cfg(test)]
+mod gaming_sessions_manager_tests {
+    use crate::errors::GameError;
+    use crate::gaming::gaming_sessions_manager::GamingSessionsManager;
+    use crate::gaming::tic_tac_toe_game::TicTacToeGame;
+
+    #[actix_web::test]
+    async fn test_starting_player_must_be_a_participant() {
+        //
+
+        let mut manager = GamingSessionsManager::<TicTacToeGame>::new_with_observers(vec![]);
+        let session = manager.create_new_session("Player One").await.unwrap();
+        let player_two = manager.join_session(&session.invitation_code, "Player Two").await.unwrap().other_player.unwrap();
+
+        let result = manager.create_new_two_player_game(&session.session_id, Some("not-a-participant")).await;
+        assert_eq!(result.err(), Some(GameError::PlayerNotFound));
+
+        let (_, players) = manager.create_new_two_player_game(&session.session_id, Some(&player_two.player_id)).await.unwrap();
+        assert_eq!(players.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_ending_a_gaming_session_removes_it() {
+        //
+
+        let mut manager = GamingSessionsManager::<TicTacToeGame>::new_with_observers(vec![]);
+        let session = manager.create_new_session("Player One").await.unwrap();
+        let other_session = manager.create_new_session("Player Three").await.unwrap();
+
+        manager.end_gaming_session(&session.session_owner.player_id, &session.session_id).await.unwrap();
+
+        // Gaming Sessions are keyed by their Invitation Code.
+        assert!(manager.get_session_by_id(&session.session_id).await.is_none());
+        assert_eq!(manager.join_session(&session.invitation_code, "Player Two").await.err(), Some(GameError::InvitationCodeNotFound));
+        assert!(manager.get_session_by_id(&other_session.session_id).await.is_some());
+
+        let result = manager.end_gaming_session(&session.session_owner.player_id, &session.session_id).await;
+        assert_eq!(result.err(), Some(GameError::GamingSessionNotFound));
+    }
+}
+
+#[cfg(test)]
+mod error_response_tests {
+    use crate::errors::GameError;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::ResponseError;
+
+    // NOTE: The Rust client SDK's is_session_not_found() relies on this format.
+    const SESSION_NOT_FOUND_BODY: &str = r#"{"code":"GamingSessionNotFound"}"#;
+
+    #[actix_web::test]
+    async fn test_error_code_is_in_body() {
+        //
+
+        let response = GameError::GamingSessionNotFound.error_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body, SESSION_NOT_FOUND_BODY);
+
+        // Other 404s are distinguishable.
+        let body = to_bytes(GameError::GameNotFound.error_response().into_body()).await.unwrap();
+        assert_eq!(body, r#"{"code":"GameNotFound"}"#);
+    }
+}
+
+#[cfg(test)]
+mod two_player_game_api_tests {
+    use crate::api::games::create_two_player_game;
+    use crate::gaming::gaming_sessions_manager::GamingSessionsManager;
+    use crate::gaming::tic_tac_toe_game::TicTacToeGame;
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web, App};
+
+    async fn create_game(body: &'static str) -> StatusCode {
+        //
+
+        let mut manager = GamingSessionsManager::<TicTacToeGame>::new_with_observers(vec![]);
+        let session = manager.create_new_session("Player One").await.unwrap();
+        let manager = web::Data::new(tokio::sync::Mutex::new(manager));
+
+        let app = test::init_service(App::new().app_data(manager).service(create_two_player_game)).await;
+        let request = test::TestRequest::post()
+            .uri(&format!("/gaming-session/{}/two-player-games", session.session_id))
+            .insert_header(("content-type", "application/json"))
+            .set_payload(body)
+            .to_request();
+        test::call_service(&app, request).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_params_are_optional() {
+        //
+
+        assert_eq!(create_game("").await, StatusCode::OK);
+        assert_eq!(create_game("{}").await, StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_malformed_params_are_rejected() {
+        //
+
+        assert_eq!(create_game("{ not json").await, StatusCode::BAD_REQUEST);
+        assert_eq!(create_game(r#"{"starting_player_id":7}"#).await, StatusCode::BAD_REQUEST);
+        assert_eq!(create_game("null").await, StatusCode::BAD_REQUEST);
+    }
+}